@@ -12,6 +12,7 @@ use bencher_schema::{
         project::{
             QueryProject,
             threshold::alert::{QueryAlert, UpdateAlert},
+            webhook::WebhookDispatch,
         },
         user::actor::{ApiActor, PubProjectBearerToken},
     },
@@ -25,6 +26,7 @@ use dropshot::{HttpError, Path, Query, RequestContext, TypedBody, endpoint};
 use futures::stream::{FuturesOrdered, StreamExt as _};
 use schemars::JsonSchema;
 use serde::Deserialize;
+use slog::Logger;
 
 #[derive(Deserialize, JsonSchema)]
 pub struct ProjAlertsParams {
@@ -386,6 +388,7 @@ pub async fn proj_alert_patch(
     )
     .await?;
     let json = patch_inner(
+        &rqctx.log,
         rqctx.context(),
         &api_actor,
         path_params.into_inner(),
@@ -397,6 +400,7 @@ pub async fn proj_alert_patch(
 }
 
 pub async fn patch_inner(
+    log: &Logger,
     context: &ApiContext,
     api_actor: &ApiActor,
     path_params: ProjAlertParams,
@@ -424,7 +428,18 @@ pub async fn patch_inner(
         .execute(write_conn!(context))
         .map_err(resource_conflict_err!(Alert, (&query_alert, &json_alert)))?;
 
-    auth_conn!(context, |conn| {
+    let json_alert = auth_conn!(context, |conn| {
         QueryAlert::get(conn, query_alert.id)?.into_json(conn)
-    })
+    })?;
+    if query_alert.status != AlertStatus::Dismissed && json_alert.status == AlertStatus::Dismissed {
+        WebhookDispatch::alert_dismissed(
+            log,
+            context,
+            query_project.id,
+            query_project.uuid,
+            json_alert.clone(),
+        )
+        .await;
+    }
+    Ok(json_alert)
 }
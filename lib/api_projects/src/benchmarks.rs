@@ -3,8 +3,9 @@ use bencher_endpoint::{
     TotalCount,
 };
 use bencher_json::{
-    BenchmarkName, BenchmarkResourceId, JsonBenchmark, JsonBenchmarks, JsonDirection,
-    JsonPagination, ProjectResourceId, Search,
+    BenchmarkAliasUuid, BenchmarkName, BenchmarkResourceId, JsonBenchmark, JsonBenchmarkAlias,
+    JsonBenchmarkAliases, JsonBenchmarks, JsonDirection, JsonMergeBenchmarks,
+    JsonNewBenchmarkAlias, JsonPagination, ProjectResourceId, Search,
     project::benchmark::{JsonNewBenchmark, JsonUpdateBenchmark},
 };
 use bencher_rbac::project::Permission;
@@ -12,20 +13,22 @@ use bencher_schema::{
     actor_conn, auth_conn,
     context::ApiContext,
     error::{
-        forbidden_error, resource_conflict_err, resource_not_found_err, with_auth_hint,
-        with_token_hint,
+        bad_request_error, conflict_error, forbidden_error, resource_conflict_err,
+        resource_not_found_err, with_auth_hint, with_token_hint,
     },
+    macros::sql::last_insert_rowid,
     model::{
         project::{
             QueryProject,
             benchmark::{QueryBenchmark, UpdateBenchmark},
+            benchmark_alias::{InsertBenchmarkAlias, QueryBenchmarkAlias},
         },
         user::{
             actor::{ApiActor, PubProjectBearerToken},
             auth::{AuthUser, BearerToken},
         },
     },
-    schema, write_conn,
+    schema, write_conn, write_transaction,
 };
 use diesel::{
     BelongingToDsl as _, BoolExpressionMethods as _, ExpressionMethods as _, QueryDsl as _,
@@ -442,3 +445,335 @@ async fn delete_inner(
 
     Ok(())
 }
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/benchmarks/{benchmark}/merge",
+    tags = ["projects", "benchmarks"]
+}]
+pub async fn proj_benchmark_merge_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjBenchmarkParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Post.into()]))
+}
+
+/// Merge benchmarks
+///
+/// Merge other benchmarks into a benchmark for a project.
+/// The user must have `delete` permissions for the project.
+/// All of the metrics, boundaries, and alerts for the merged benchmarks are moved to the benchmark,
+/// the names of the merged benchmarks become aliases of the benchmark,
+/// and then the merged benchmarks are deleted.
+/// Either everything is merged or nothing is.
+/// If a merged benchmark and the benchmark are both in the same report iteration,
+/// then the merge fails with a conflict.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/benchmarks/{benchmark}/merge",
+    tags = ["projects", "benchmarks"]
+}]
+pub async fn proj_benchmark_merge_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjBenchmarkParams>,
+    body: TypedBody<JsonMergeBenchmarks>,
+) -> Result<ResponseOk<JsonBenchmark>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = merge_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await
+    .map_err(with_token_hint)?;
+    Ok(Post::auth_response_ok(json))
+}
+
+async fn merge_inner(
+    context: &ApiContext,
+    path_params: ProjBenchmarkParams,
+    json_merge: JsonMergeBenchmarks,
+    auth_user: &AuthUser,
+) -> Result<JsonBenchmark, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        auth_conn!(context),
+        &context.rbac,
+        #[cfg(feature = "plus")]
+        &context.rate_limiting,
+        &path_params.project,
+        auth_user,
+        Permission::Delete,
+    )?;
+
+    let query_benchmark = QueryBenchmark::from_resource_id(
+        auth_conn!(context),
+        query_project.id,
+        &path_params.benchmark,
+    )?;
+    let mut sources: Vec<QueryBenchmark> = Vec::with_capacity(json_merge.benchmarks.len());
+    for benchmark in &json_merge.benchmarks {
+        let source =
+            QueryBenchmark::from_resource_id(auth_conn!(context), query_project.id, benchmark)?;
+        if source.id == query_benchmark.id {
+            return Err(bad_request_error(format!(
+                "Cannot merge benchmark ({}) into itself",
+                query_benchmark.name
+            )));
+        }
+        if sources.iter().all(|s| s.id != source.id) {
+            sources.push(source);
+        }
+    }
+    if sources.is_empty() {
+        return Err(bad_request_error(
+            "At least one benchmark to merge is required",
+        ));
+    }
+
+    let now = context.clock.now();
+    write_transaction!(context, |conn| query_benchmark.merge(conn, &sources, now)).map_err(
+        resource_conflict_err!(Benchmark, (&query_benchmark, &sources)),
+    )?;
+
+    QueryBenchmark::get(auth_conn!(context), query_benchmark.id)
+        .map(|benchmark| benchmark.into_json_for_project(&query_project))
+        .map_err(resource_not_found_err!(Benchmark, query_benchmark))
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/benchmarks/{benchmark}/aliases",
+    tags = ["projects", "benchmarks"]
+}]
+pub async fn proj_benchmark_aliases_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjBenchmarkParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+}
+
+/// List aliases for a benchmark
+///
+/// List all aliases for a benchmark.
+/// Reports that use an alias name are recorded under the benchmark.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project,
+/// or provide a valid project key for the project.
+/// The aliases are sorted in alphabetical order by name.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/benchmarks/{benchmark}/aliases",
+    tags = ["projects", "benchmarks"]
+}]
+pub async fn proj_benchmark_aliases_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubProjectBearerToken,
+    path_params: Path<ProjBenchmarkParams>,
+) -> Result<ResponseOk<JsonBenchmarkAliases>, HttpError> {
+    let api_actor = ApiActor::from_token(
+        &rqctx.log,
+        rqctx.context(),
+        #[cfg(feature = "plus")]
+        rqctx.request.headers(),
+        bearer_token,
+    )
+    .await?;
+    let json = get_aliases_inner(rqctx.context(), path_params.into_inner(), &api_actor)
+        .await
+        .map_err(with_auth_hint)?;
+    Ok(Get::response_ok(json, api_actor.is_auth()))
+}
+
+async fn get_aliases_inner(
+    context: &ApiContext,
+    path_params: ProjBenchmarkParams,
+    api_actor: &ApiActor,
+) -> Result<JsonBenchmarkAliases, HttpError> {
+    actor_conn!(context, api_actor, |conn| {
+        let query_project = QueryProject::is_allowed_actor_pub(
+            conn,
+            &context.rbac,
+            #[cfg(feature = "plus")]
+            &context.rate_limiting,
+            &path_params.project,
+            api_actor,
+        )?;
+        let query_benchmark =
+            QueryBenchmark::from_resource_id(conn, query_project.id, &path_params.benchmark)?;
+
+        let aliases = schema::benchmark_alias::table
+            .filter(schema::benchmark_alias::benchmark_id.eq(query_benchmark.id))
+            .order(schema::benchmark_alias::name.asc())
+            .load::<QueryBenchmarkAlias>(conn)
+            .map_err(resource_not_found_err!(BenchmarkAlias, query_benchmark))?;
+
+        Ok(aliases
+            .into_iter()
+            .map(|alias| alias.into_json_for_benchmark(&query_benchmark))
+            .collect())
+    })
+}
+
+/// Create an alias for a benchmark
+///
+/// Create an alias for a benchmark.
+/// Future reports that use the alias name are recorded under the benchmark.
+/// The alias name must not be used by another benchmark or alias in the project.
+/// The user must have `edit` permissions for the project.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/benchmarks/{benchmark}/aliases",
+    tags = ["projects", "benchmarks"]
+}]
+pub async fn proj_benchmark_alias_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjBenchmarkParams>,
+    body: TypedBody<JsonNewBenchmarkAlias>,
+) -> Result<ResponseCreated<JsonBenchmarkAlias>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_alias_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await
+    .map_err(with_token_hint)?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_alias_inner(
+    context: &ApiContext,
+    path_params: ProjBenchmarkParams,
+    json_alias: JsonNewBenchmarkAlias,
+    auth_user: &AuthUser,
+) -> Result<JsonBenchmarkAlias, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        auth_conn!(context),
+        &context.rbac,
+        #[cfg(feature = "plus")]
+        &context.rate_limiting,
+        &path_params.project,
+        auth_user,
+        Permission::Edit,
+    )?;
+    let query_benchmark = QueryBenchmark::from_resource_id(
+        auth_conn!(context),
+        query_project.id,
+        &path_params.benchmark,
+    )?;
+
+    let name_in_use = schema::benchmark::table
+        .filter(schema::benchmark::project_id.eq(query_project.id))
+        .filter(schema::benchmark::name.eq(&json_alias.name))
+        .count()
+        .get_result::<i64>(auth_conn!(context))
+        .map_err(resource_not_found_err!(
+            Benchmark,
+            (&query_project, &json_alias)
+        ))?
+        > 0;
+    if name_in_use {
+        return Err(conflict_error(format!(
+            "A benchmark named ({}) already exists. Merge it into this benchmark instead.",
+            json_alias.name
+        )));
+    }
+
+    let insert_alias = InsertBenchmarkAlias::from_json(&query_benchmark, json_alias);
+    write_transaction!(context, |conn| {
+        diesel::insert_into(schema::benchmark_alias::table)
+            .values(&insert_alias)
+            .execute(conn)?;
+        diesel::select(last_insert_rowid()).get_result(conn)
+    })
+    .map_err(resource_conflict_err!(BenchmarkAlias, &insert_alias))
+    .map(|id| {
+        insert_alias
+            .into_query(id)
+            .into_json_for_benchmark(&query_benchmark)
+    })
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjBenchmarkAliasParams {
+    /// The slug or UUID for a project.
+    pub project: ProjectResourceId,
+    /// The slug or UUID for a benchmark.
+    pub benchmark: BenchmarkResourceId,
+    /// The UUID for a benchmark alias.
+    pub alias: BenchmarkAliasUuid,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/benchmarks/{benchmark}/aliases/{alias}",
+    tags = ["projects", "benchmarks"]
+}]
+pub async fn proj_benchmark_alias_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjBenchmarkAliasParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Delete.into()]))
+}
+
+/// Delete an alias for a benchmark
+///
+/// Delete an alias for a benchmark.
+/// Future reports that use the alias name create a new benchmark.
+/// The user must have `edit` permissions for the project.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/benchmarks/{benchmark}/aliases/{alias}",
+    tags = ["projects", "benchmarks"]
+}]
+pub async fn proj_benchmark_alias_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjBenchmarkAliasParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_alias_inner(rqctx.context(), path_params.into_inner(), &auth_user)
+        .await
+        .map_err(with_token_hint)?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_alias_inner(
+    context: &ApiContext,
+    path_params: ProjBenchmarkAliasParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        auth_conn!(context),
+        &context.rbac,
+        #[cfg(feature = "plus")]
+        &context.rate_limiting,
+        &path_params.project,
+        auth_user,
+        Permission::Edit,
+    )?;
+    let query_benchmark = QueryBenchmark::from_resource_id(
+        auth_conn!(context),
+        query_project.id,
+        &path_params.benchmark,
+    )?;
+    let query_alias = QueryBenchmarkAlias::get_benchmark_alias(
+        auth_conn!(context),
+        query_benchmark.id,
+        path_params.alias,
+    )?;
+    diesel::delete(
+        schema::benchmark_alias::table.filter(schema::benchmark_alias::id.eq(query_alias.id)),
+    )
+    .execute(write_conn!(context))
+    .map_err(resource_conflict_err!(BenchmarkAlias, query_alias))?;
+
+    Ok(())
+}
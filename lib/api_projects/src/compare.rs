@@ -0,0 +1,384 @@
+use std::collections::HashMap;
+
+use bencher_boundary::compare::{Comparison, DEFAULT_CONFIDENCE, Sample};
+use bencher_endpoint::{CorsResponse, Endpoint, Get, ResponseOk};
+use bencher_json::{
+    JsonBenchmark, JsonCompare, JsonMeasure, ProjectResourceId, ReportUuid,
+    project::compare::{
+        CompareVerdict, JsonCompareHead, JsonCompareHeadQuery, JsonCompareQuery,
+        JsonCompareQueryParams, JsonCompareResult, JsonCompareSample,
+    },
+};
+#[cfg(feature = "plus")]
+use bencher_schema::model::spec::QuerySpec;
+use bencher_schema::model::spec::SpecId;
+use bencher_schema::{
+    actor_conn,
+    context::{ApiContext, DbConnection},
+    error::{bad_request_error, resource_not_found_err, with_auth_hint},
+    model::{
+        project::{
+            QueryProject,
+            benchmark::{BenchmarkId, QueryBenchmark},
+            branch::{QueryBranch, head::QueryHead, version::QueryVersion},
+            measure::{MeasureId, QueryMeasure},
+            report::ReportId,
+            testbed::{QueryTestbed, TestbedId},
+        },
+        user::actor::{ApiActor, PubProjectBearerToken},
+    },
+    schema,
+};
+use diesel::{
+    ExpressionMethods as _, QueryDsl as _, QueryableByName, RunQueryDsl as _,
+    SelectableHelper as _,
+    sql_types::{BigInt, Double, Integer, Nullable, Text},
+};
+use dropshot::{HttpError, Path, Query, RequestContext, endpoint};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjCompareParams {
+    /// The slug or UUID for a project.
+    pub project: ProjectResourceId,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/compare",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_compare_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjCompareParams>,
+    _query_params: Query<JsonCompareQueryParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// Compare two project branch heads
+///
+/// Compare the performance metrics for a baseline and a candidate branch head on the same testbed and spec.
+/// If a spec is not provided, then the current testbed spec is used, if there is one.
+/// For each benchmark and measure, the most recent reports for each branch head are used,
+/// optionally only those for a given git hash.
+/// The metrics for each report are reduced to their mean across iterations.
+/// For each benchmark and measure that both sides have in common,
+/// the relative change, a 95% confidence interval, and a significance verdict are returned.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project,
+/// or provide a valid project key for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/compare",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_compare_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubProjectBearerToken,
+    path_params: Path<ProjCompareParams>,
+    query_params: Query<JsonCompareQueryParams>,
+) -> Result<ResponseOk<JsonCompare>, HttpError> {
+    // Second round of marshaling
+    let json_compare_query = query_params
+        .into_inner()
+        .try_into()
+        .map_err(bad_request_error)?;
+
+    let api_actor = ApiActor::from_token(
+        &rqctx.log,
+        rqctx.context(),
+        #[cfg(feature = "plus")]
+        rqctx.request.headers(),
+        bearer_token,
+    )
+    .await?;
+    let json = get_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        json_compare_query,
+        &api_actor,
+    )
+    .await
+    .map_err(with_auth_hint)?;
+    Ok(Get::response_ok(json, api_actor.is_auth()))
+}
+
+async fn get_inner(
+    context: &ApiContext,
+    path_params: ProjCompareParams,
+    json_compare_query: JsonCompareQuery,
+    api_actor: &ApiActor,
+) -> Result<JsonCompare, HttpError> {
+    let project = QueryProject::is_allowed_actor_pub(
+        actor_conn!(context, api_actor),
+        &context.rbac,
+        #[cfg(feature = "plus")]
+        &context.rate_limiting,
+        &path_params.project,
+        api_actor,
+    )?;
+
+    actor_conn!(context, api_actor, |conn| compare(
+        conn,
+        project,
+        json_compare_query
+    ))
+}
+
+fn compare(
+    conn: &mut DbConnection,
+    project: QueryProject,
+    json_compare_query: JsonCompareQuery,
+) -> Result<JsonCompare, HttpError> {
+    let reports = json_compare_query.reports();
+    let JsonCompareQuery {
+        baseline,
+        candidate,
+        testbed,
+        #[cfg(feature = "plus")]
+        spec,
+        reports: _,
+    } = json_compare_query;
+
+    let query_testbed = QueryTestbed::from_name_id(conn, project.id, &testbed)?;
+    // Use the spec if it is provided.
+    // Otherwise, use the current testbed spec, if there is one.
+    #[cfg(feature = "plus")]
+    let spec_id = if let Some(spec_uuid) = spec {
+        Some(QuerySpec::from_uuid(conn, spec_uuid)?.id)
+    } else {
+        query_testbed.spec_id
+    };
+    #[cfg(not(feature = "plus"))]
+    let spec_id = query_testbed.spec_id;
+    let (baseline, baseline_metrics) = compare_head(
+        conn,
+        &project,
+        &baseline,
+        query_testbed.id,
+        spec_id,
+        reports,
+    )?;
+    let (candidate, candidate_metrics) = compare_head(
+        conn,
+        &project,
+        &candidate,
+        query_testbed.id,
+        spec_id,
+        reports,
+    )?;
+
+    let mut comparisons = Vec::new();
+    for (key, baseline_data) in baseline_metrics {
+        let Some(candidate_data) = candidate_metrics.get(&key) else {
+            continue;
+        };
+        if let Some(comparison) =
+            Comparison::new(&baseline_data, candidate_data, DEFAULT_CONFIDENCE)
+                .map_err(bad_request_error)?
+        {
+            comparisons.push((key, comparison));
+        }
+    }
+
+    let mut results = comparisons
+        .into_iter()
+        .map(|((benchmark_id, measure_id), comparison)| {
+            let benchmark =
+                QueryBenchmark::get(conn, benchmark_id)?.into_json_for_project(&project);
+            let measure = QueryMeasure::get(conn, measure_id)?.into_json_for_project(&project);
+            Ok(compare_result(benchmark, measure, &comparison))
+        })
+        .collect::<Result<Vec<_>, HttpError>>()?;
+    results.sort_by(|a, b| {
+        a.benchmark
+            .name
+            .as_ref()
+            .cmp(b.benchmark.name.as_ref())
+            .then_with(|| a.measure.name.as_ref().cmp(b.measure.name.as_ref()))
+    });
+
+    Ok(JsonCompare {
+        testbed: query_testbed.into_json_for_spec(conn, &project, spec_id)?,
+        project: project.into_json(conn)?,
+        baseline,
+        candidate,
+        confidence: DEFAULT_CONFIDENCE,
+        results,
+    })
+}
+
+type CompareMetrics = HashMap<(BenchmarkId, MeasureId), Vec<f64>>;
+
+/// The mean of the metrics across the iterations of each of the most recent reports
+/// for each benchmark and measure of a branch head.
+///
+/// The reports are ranked for each benchmark and measure in the database,
+/// so only the metrics for the latest reports are ever returned.
+/// Reports from a noisy host and reports for another testbed are excluded,
+/// along with reports for another version or spec when either is given.
+/// The results are ordered from the most recent report to the least recent.
+const COMPARE_HEAD_METRICS: &str = "\
+SELECT report_id, report_uuid, benchmark_id, measure_id, mean
+FROM (
+    SELECT r.id AS report_id, r.uuid AS report_uuid, rb.benchmark_id AS benchmark_id,
+        m.measure_id AS measure_id, AVG(m.value) AS mean,
+        v.number AS version_number, r.start_time AS start_time,
+        ROW_NUMBER() OVER (
+            PARTITION BY rb.benchmark_id, m.measure_id
+            ORDER BY v.number DESC, r.start_time DESC, r.id DESC
+        ) AS report_rank
+    FROM metric m
+    INNER JOIN report_benchmark rb ON m.report_benchmark_id = rb.id
+    INNER JOIN report r ON rb.report_id = r.id
+    INNER JOIN version v ON r.version_id = v.id
+    INNER JOIN head_version hv ON hv.version_id = v.id
+    WHERE hv.head_id = ?1
+        AND r.testbed_id = ?2
+        AND r.noisy = 0
+        AND (?3 IS NULL OR v.id = ?3)
+        AND (?4 IS NULL OR r.spec_id = ?4)
+    GROUP BY r.id, rb.benchmark_id, m.measure_id
+)
+WHERE report_rank <= ?5
+ORDER BY version_number DESC, start_time DESC, report_id DESC";
+
+#[derive(QueryableByName)]
+struct CompareHeadMetric {
+    #[diesel(sql_type = Integer)]
+    report_id: ReportId,
+    #[diesel(sql_type = Text)]
+    report_uuid: ReportUuid,
+    #[diesel(sql_type = Integer)]
+    benchmark_id: BenchmarkId,
+    #[diesel(sql_type = Integer)]
+    measure_id: MeasureId,
+    #[diesel(sql_type = Double)]
+    mean: f64,
+}
+
+/// Collect the metrics from the most recent reports for one side of the comparison.
+/// For each benchmark and measure, the most recent reports that have a metric for it are used,
+/// and the metrics from all of the iterations in a report are reduced to their mean.
+/// Reports from a noisy host are not trusted for a comparison.
+fn compare_head(
+    conn: &mut DbConnection,
+    project: &QueryProject,
+    head_query: &JsonCompareHeadQuery,
+    testbed_id: TestbedId,
+    spec_id: Option<SpecId>,
+    reports: u32,
+) -> Result<(JsonCompareHead, CompareMetrics), HttpError> {
+    let JsonCompareHeadQuery { branch, head, hash } = head_query;
+    let query_branch = QueryBranch::from_name_id(conn, project.id, branch)?;
+
+    // Use the branch head if it is provided.
+    // Otherwise, use the current, non-replaced head.
+    let query_head = if let Some(head_uuid) = head {
+        schema::head::table
+            .filter(schema::head::branch_id.eq(query_branch.id))
+            .filter(schema::head::uuid.eq(head_uuid))
+            .select(QueryHead::as_select())
+            .first(conn)
+            .map_err(resource_not_found_err!(Head, (&query_branch, head_uuid)))?
+    } else {
+        query_branch.head(conn)?
+    };
+
+    let query_version = if let Some(hash) = hash {
+        Some(
+            schema::version::table
+                .inner_join(schema::head_version::table)
+                .filter(schema::head_version::head_id.eq(query_head.id))
+                .filter(schema::version::hash.eq(hash.as_ref()))
+                .select(QueryVersion::as_select())
+                .first(conn)
+                .map_err(resource_not_found_err!(Version, (&query_head, hash)))?,
+        )
+    } else {
+        None
+    };
+
+    let head_metrics = diesel::sql_query(COMPARE_HEAD_METRICS)
+        .bind::<Integer, _>(query_head.id)
+        .bind::<Integer, _>(testbed_id)
+        .bind::<Nullable<Integer>, _>(query_version.as_ref().map(|version| version.id))
+        .bind::<Nullable<Integer>, _>(spec_id)
+        .bind::<BigInt, _>(i64::from(reports))
+        .load::<CompareHeadMetric>(conn)
+        .map_err(resource_not_found_err!(Metric, (&query_head, testbed_id)))?;
+
+    let mut metrics = CompareMetrics::new();
+    let mut head_reports = Vec::<(ReportId, ReportUuid)>::new();
+    for CompareHeadMetric {
+        report_id,
+        report_uuid,
+        benchmark_id,
+        measure_id,
+        mean,
+    } in head_metrics
+    {
+        metrics
+            .entry((benchmark_id, measure_id))
+            .or_default()
+            .push(mean);
+        // All of the metrics for a report are contiguous, as the report ID breaks any ties.
+        if head_reports
+            .last()
+            .is_none_or(|(last_id, _)| *last_id != report_id)
+        {
+            head_reports.push((report_id, report_uuid));
+        }
+    }
+
+    let json_head = JsonCompareHead {
+        branch: query_branch.into_json_for_head(conn, project, &query_head, query_version)?,
+        reports: head_reports.into_iter().map(|(_, uuid)| uuid).collect(),
+    };
+    Ok((json_head, metrics))
+}
+
+fn compare_result(
+    benchmark: JsonBenchmark,
+    measure: JsonMeasure,
+    comparison: &Comparison,
+) -> JsonCompareResult {
+    let verdict = if comparison.p_value.is_none() {
+        CompareVerdict::Inconclusive
+    } else if !comparison.is_significant(DEFAULT_CONFIDENCE) {
+        CompareVerdict::NoChange
+    } else if comparison.candidate.mean > comparison.baseline.mean {
+        CompareVerdict::Increase
+    } else {
+        CompareVerdict::Decrease
+    };
+    let (lower_change, upper_change) = comparison
+        .interval
+        .map_or((None, None), |(lower, upper)| (Some(lower), Some(upper)));
+    JsonCompareResult {
+        benchmark,
+        measure,
+        baseline: compare_sample(comparison.baseline),
+        candidate: compare_sample(comparison.candidate),
+        change: comparison.change,
+        lower_change,
+        upper_change,
+        p_value: comparison.p_value,
+        verdict,
+    }
+}
+
+fn compare_sample(sample: Sample) -> JsonCompareSample {
+    let Sample {
+        size,
+        mean,
+        std_dev,
+    } = sample;
+    JsonCompareSample {
+        size: u32::try_from(size).unwrap_or(u32::MAX),
+        mean,
+        std_dev,
+    }
+}
@@ -2,24 +2,34 @@
 #[cfg(test)]
 use bencher_api_tests as _;
 #[cfg(test)]
+use hex as _;
+#[cfg(test)]
+use hmac as _;
+#[cfg(test)]
 use serde_json as _;
 #[cfg(test)]
+use sha2 as _;
+#[cfg(test)]
 use tokio as _;
 
 pub mod alerts;
 mod allowed;
 pub mod benchmarks;
 pub mod branches;
+pub mod compare;
 pub mod jobs;
 mod keys;
 pub mod measures;
+mod members;
 pub mod metrics;
 pub mod perf;
 pub mod plots;
 pub mod projects;
 pub mod reports;
+mod retention;
 pub mod testbeds;
 pub mod thresholds;
+mod webhooks;
 
 mod macros;
 
@@ -62,6 +72,17 @@ impl bencher_endpoint::Registrar for Api {
         api_description.register(keys::proj_key_patch)?;
         api_description.register(keys::proj_key_delete)?;
 
+        // Project Members
+        if http_options {
+            api_description.register(members::proj_members_options)?;
+            api_description.register(members::proj_member_options)?;
+        }
+        api_description.register(members::proj_members_get)?;
+        api_description.register(members::proj_member_post)?;
+        api_description.register(members::proj_member_get)?;
+        api_description.register(members::proj_member_patch)?;
+        api_description.register(members::proj_member_delete)?;
+
         // Reports
         if http_options {
             api_description.register(reports::proj_reports_options)?;
@@ -95,6 +116,18 @@ impl bencher_endpoint::Registrar for Api {
         }
         api_description.register(perf::img::proj_perf_img_get)?;
 
+        // Perf Change Points
+        if http_options {
+            api_description.register(perf::change_points::proj_perf_change_points_options)?;
+        }
+        api_description.register(perf::change_points::proj_perf_change_points_get)?;
+
+        // Compare
+        if http_options {
+            api_description.register(compare::proj_compare_options)?;
+        }
+        api_description.register(compare::proj_compare_get)?;
+
         // Plots
         if http_options {
             api_description.register(plots::proj_plots_options)?;
@@ -132,12 +165,19 @@ impl bencher_endpoint::Registrar for Api {
         if http_options {
             api_description.register(benchmarks::proj_benchmarks_options)?;
             api_description.register(benchmarks::proj_benchmark_options)?;
+            api_description.register(benchmarks::proj_benchmark_merge_options)?;
+            api_description.register(benchmarks::proj_benchmark_aliases_options)?;
+            api_description.register(benchmarks::proj_benchmark_alias_options)?;
         }
         api_description.register(benchmarks::proj_benchmarks_get)?;
         api_description.register(benchmarks::proj_benchmark_post)?;
         api_description.register(benchmarks::proj_benchmark_get)?;
         api_description.register(benchmarks::proj_benchmark_patch)?;
         api_description.register(benchmarks::proj_benchmark_delete)?;
+        api_description.register(benchmarks::proj_benchmark_merge_post)?;
+        api_description.register(benchmarks::proj_benchmark_aliases_get)?;
+        api_description.register(benchmarks::proj_benchmark_alias_post)?;
+        api_description.register(benchmarks::proj_benchmark_alias_delete)?;
 
         // Measures
         if http_options {
@@ -176,6 +216,28 @@ impl bencher_endpoint::Registrar for Api {
         api_description.register(alerts::proj_alert_get)?;
         api_description.register(alerts::proj_alert_patch)?;
 
+        // Project Webhooks
+        if http_options {
+            api_description.register(webhooks::proj_webhooks_options)?;
+            api_description.register(webhooks::proj_webhook_options)?;
+            api_description.register(webhooks::proj_webhook_deliveries_options)?;
+        }
+        api_description.register(webhooks::proj_webhooks_get)?;
+        api_description.register(webhooks::proj_webhook_post)?;
+        api_description.register(webhooks::proj_webhook_get)?;
+        api_description.register(webhooks::proj_webhook_patch)?;
+        api_description.register(webhooks::proj_webhook_delete)?;
+        api_description.register(webhooks::proj_webhook_deliveries_get)?;
+
+        // Project Retention
+        if http_options {
+            api_description.register(retention::proj_retention_options)?;
+            api_description.register(retention::proj_retention_dry_run_options)?;
+        }
+        api_description.register(retention::proj_retention_get)?;
+        api_description.register(retention::proj_retention_put)?;
+        api_description.register(retention::proj_retention_dry_run_get)?;
+
         Ok(())
     }
 }
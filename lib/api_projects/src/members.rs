@@ -0,0 +1,513 @@
+use bencher_endpoint::{
+    CorsResponse, Delete, Endpoint, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
+    TotalCount,
+};
+use bencher_json::{
+    JsonDirection, JsonNewProjectMember, JsonPagination, JsonProjectMember, JsonProjectMembers,
+    JsonUpdateProjectMember, ProjectResourceId, Search, UserName, UserResourceId,
+    project::ProjectRole,
+};
+use bencher_rbac::project::Permission;
+use bencher_schema::{
+    auth_conn,
+    context::{ApiContext, DbConnection},
+    error::{conflict_error, resource_conflict_err, resource_not_found_err},
+    model::{
+        project::{
+            ProjectId, QueryProject, member::QueryProjectMember, project_role::InsertProjectRole,
+        },
+        user::{
+            QueryUser, UserId,
+            auth::{AuthUser, BearerToken},
+        },
+    },
+    schema, write_conn, write_transaction,
+};
+use diesel::{
+    BoolExpressionMethods as _, ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _,
+    TextExpressionMethods as _,
+};
+use dropshot::{HttpError, Path, Query, RequestContext, TypedBody, endpoint};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjMembersParams {
+    /// The slug or UUID for a project.
+    pub project: ProjectResourceId,
+}
+
+pub type ProjMembersPagination = JsonPagination<ProjMembersSort>;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjMembersSort {
+    /// Sort by user name.
+    #[default]
+    Name,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ProjMembersQuery {
+    /// Filter by user name, exact match.
+    pub name: Option<UserName>,
+    /// Search by user name, slug, or UUID.
+    pub search: Option<Search>,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/members",
+    tags = ["projects", "members"]
+}]
+pub async fn proj_members_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjMembersParams>,
+    _pagination_params: Query<ProjMembersPagination>,
+    _query_params: Query<ProjMembersQuery>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+}
+
+/// List project members
+///
+/// List members for a project.
+/// The user must have `view_role` permissions for the project.
+/// Organization leaders have access to every project in their organization,
+/// and they are not listed as project members unless they have been added explicitly.
+/// By default, the members are sorted in alphabetical order by name.
+/// The HTTP response header `X-Total-Count` contains the total number of members.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/members",
+    tags = ["projects", "members"]
+}]
+pub async fn proj_members_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjMembersParams>,
+    pagination_params: Query<ProjMembersPagination>,
+    query_params: Query<ProjMembersQuery>,
+) -> Result<ResponseOk<JsonProjectMembers>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        &auth_user,
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+        query_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    path_params: ProjMembersParams,
+    pagination_params: ProjMembersPagination,
+    query_params: ProjMembersQuery,
+) -> Result<(JsonProjectMembers, TotalCount), HttpError> {
+    let query_project = QueryProject::is_allowed(
+        auth_conn!(context),
+        &context.rbac,
+        #[cfg(feature = "plus")]
+        &context.rate_limiting,
+        &path_params.project,
+        auth_user,
+        Permission::ViewRole,
+    )?;
+
+    let members = get_ls_query(query_project.id, &pagination_params, &query_params)
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryProjectMember>(auth_conn!(context))
+        .map_err(resource_not_found_err!(
+            ProjectRole,
+            (&query_project, &pagination_params, &query_params)
+        ))?;
+
+    // Drop connection lock before iterating
+    let json_members = members
+        .into_iter()
+        .map(QueryProjectMember::into_json)
+        .collect();
+
+    let total_count = get_ls_query(query_project.id, &pagination_params, &query_params)
+        .count()
+        .get_result::<i64>(auth_conn!(context))
+        .map_err(resource_not_found_err!(
+            ProjectRole,
+            (&query_project, &pagination_params, &query_params)
+        ))?
+        .try_into()?;
+
+    Ok((json_members, total_count))
+}
+
+fn get_ls_query<'q>(
+    project_id: ProjectId,
+    pagination_params: &ProjMembersPagination,
+    query_params: &'q ProjMembersQuery,
+) -> BoxedQuery<'q> {
+    let mut query = schema::user::table
+        .inner_join(schema::project_role::table)
+        .filter(schema::project_role::project_id.eq(project_id))
+        .select((
+            schema::user::uuid,
+            schema::user::name,
+            schema::user::slug,
+            schema::user::email,
+            schema::project_role::role,
+            schema::project_role::created,
+            schema::project_role::modified,
+        ))
+        .into_boxed();
+
+    if let Some(name) = query_params.name.as_ref() {
+        query = query.filter(schema::user::name.eq(name));
+    }
+    if let Some(search) = query_params.search.as_ref() {
+        query = query.filter(
+            schema::user::name
+                .like(search)
+                .or(schema::user::slug.like(search))
+                .or(schema::user::uuid.like(search)),
+        );
+    }
+
+    match pagination_params.order() {
+        ProjMembersSort::Name => match pagination_params.direction {
+            Some(JsonDirection::Asc) | None => {
+                query.order((schema::user::name.asc(), schema::user::slug.asc()))
+            },
+            Some(JsonDirection::Desc) => {
+                query.order((schema::user::name.desc(), schema::user::slug.desc()))
+            },
+        },
+    }
+}
+
+// TODO refactor out internal types
+type BoxedQuery<'q> = diesel::internal::table_macro::BoxedSelectStatement<
+    'q,
+    (
+        diesel::sql_types::Text,
+        diesel::sql_types::Text,
+        diesel::sql_types::Text,
+        diesel::sql_types::Text,
+        diesel::sql_types::Text,
+        diesel::sql_types::BigInt,
+        diesel::sql_types::BigInt,
+    ),
+    diesel::internal::table_macro::FromClause<
+        diesel::helper_types::InnerJoinQuerySource<
+            schema::user::table,
+            schema::project_role::table,
+        >,
+    >,
+    diesel::sqlite::Sqlite,
+>;
+
+/// Add a project member
+///
+/// Add an existing user as a member of a project.
+/// The user must have `create_role` permissions for the project.
+/// The new member does not need to be a member of the project's organization.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/members",
+    tags = ["projects", "members"]
+}]
+pub async fn proj_member_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjMembersParams>,
+    body: TypedBody<JsonNewProjectMember>,
+) -> Result<ResponseCreated<JsonProjectMember>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: ProjMembersParams,
+    json_member: JsonNewProjectMember,
+    auth_user: &AuthUser,
+) -> Result<JsonProjectMember, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        auth_conn!(context),
+        &context.rbac,
+        #[cfg(feature = "plus")]
+        &context.rate_limiting,
+        &path_params.project,
+        auth_user,
+        Permission::CreateRole,
+    )?;
+    let query_user = QueryUser::get_with_email(auth_conn!(context), &json_member.email)?;
+
+    let timestamp = context.clock.now();
+    let insert_proj_role = InsertProjectRole {
+        user_id: query_user.id,
+        project_id: query_project.id,
+        role: json_member.role,
+        created: timestamp,
+        modified: timestamp,
+    };
+    diesel::insert_into(schema::project_role::table)
+        .values(&insert_proj_role)
+        .execute(write_conn!(context))
+        .map_err(resource_conflict_err!(ProjectRole, insert_proj_role))?;
+
+    json_member_for(auth_conn!(context), query_user.id, query_project.id)
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjMemberParams {
+    /// The slug or UUID for a project.
+    pub project: ProjectResourceId,
+    /// The slug or UUID for a project member.
+    pub user: UserResourceId,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/members/{user}",
+    tags = ["projects", "members"]
+}]
+pub async fn proj_member_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjMemberParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Patch.into(), Delete.into()]))
+}
+
+/// View a project member
+///
+/// View a member of a project.
+/// The user must have `view_role` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/members/{user}",
+    tags = ["projects", "members"]
+}]
+pub async fn proj_member_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjMemberParams>,
+) -> Result<ResponseOk<JsonProjectMember>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjMemberParams,
+    auth_user: &AuthUser,
+) -> Result<JsonProjectMember, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        auth_conn!(context),
+        &context.rbac,
+        #[cfg(feature = "plus")]
+        &context.rate_limiting,
+        &path_params.project,
+        auth_user,
+        Permission::ViewRole,
+    )?;
+    let query_user = QueryUser::from_resource_id(auth_conn!(context), &path_params.user)?;
+
+    json_member_for(auth_conn!(context), query_user.id, query_project.id)
+}
+
+/// Update a project member
+///
+/// Update the role for a member of a project.
+/// The user must have `edit_role` permissions for the project.
+#[endpoint {
+    method = PATCH,
+    path =  "/v0/projects/{project}/members/{user}",
+    tags = ["projects", "members"]
+}]
+pub async fn proj_member_patch(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjMemberParams>,
+    body: TypedBody<JsonUpdateProjectMember>,
+) -> Result<ResponseOk<JsonProjectMember>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = patch_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Patch::auth_response_ok(json))
+}
+
+async fn patch_inner(
+    context: &ApiContext,
+    path_params: ProjMemberParams,
+    json_update: JsonUpdateProjectMember,
+    auth_user: &AuthUser,
+) -> Result<JsonProjectMember, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        auth_conn!(context),
+        &context.rbac,
+        #[cfg(feature = "plus")]
+        &context.rate_limiting,
+        &path_params.project,
+        auth_user,
+        Permission::EditRole,
+    )?;
+    let query_user = QueryUser::from_resource_id(auth_conn!(context), &path_params.user)?;
+    // Verify that the user is a member of the project
+    let json_member = json_member_for(auth_conn!(context), query_user.id, query_project.id)?;
+
+    if let Some(role) = json_update.role {
+        let now = context.clock.now();
+        let updated = write_transaction!(context, |conn| {
+            if role != ProjectRole::Maintainer
+                && is_last_maintainer(conn, query_project.id, query_user.id)?
+            {
+                return Ok(false);
+            }
+            diesel::update(
+                schema::project_role::table
+                    .filter(schema::project_role::user_id.eq(query_user.id))
+                    .filter(schema::project_role::project_id.eq(query_project.id)),
+            )
+            .set((
+                schema::project_role::role.eq(role.to_string()),
+                schema::project_role::modified.eq(now),
+            ))
+            .execute(conn)
+            .map(|_| true)
+        })
+        .map_err(resource_conflict_err!(
+            ProjectRole,
+            (&query_user, &query_project, role)
+        ))?;
+        if !updated {
+            return Err(last_maintainer_error(&json_member));
+        }
+    }
+
+    json_member_for(auth_conn!(context), query_user.id, query_project.id)
+}
+
+/// Remove a project member
+///
+/// Remove a member of a project.
+/// The user must have `delete_role` permissions for the project.
+/// This does not affect any access the user has through the project's organization.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/members/{user}",
+    tags = ["projects", "members"]
+}]
+pub async fn proj_member_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjMemberParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: ProjMemberParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    let query_project = QueryProject::is_allowed(
+        auth_conn!(context),
+        &context.rbac,
+        #[cfg(feature = "plus")]
+        &context.rate_limiting,
+        &path_params.project,
+        auth_user,
+        Permission::DeleteRole,
+    )?;
+    let query_user = QueryUser::from_resource_id(auth_conn!(context), &path_params.user)?;
+    // Verify that the user is a member of the project
+    let json_member = json_member_for(auth_conn!(context), query_user.id, query_project.id)?;
+
+    let deleted = write_transaction!(context, |conn| {
+        if is_last_maintainer(conn, query_project.id, query_user.id)? {
+            return Ok(false);
+        }
+        diesel::delete(
+            schema::project_role::table
+                .filter(schema::project_role::user_id.eq(query_user.id))
+                .filter(schema::project_role::project_id.eq(query_project.id)),
+        )
+        .execute(conn)
+        .map(|_| true)
+    })
+    .map_err(resource_conflict_err!(
+        ProjectRole,
+        (&query_user, &query_project)
+    ))?;
+    if !deleted {
+        return Err(last_maintainer_error(&json_member));
+    }
+
+    Ok(())
+}
+
+/// A project must always keep at least one maintainer,
+/// otherwise no one would be able to manage its members.
+/// This must be checked in the same transaction as the change,
+/// so concurrent changes cannot remove every maintainer.
+fn is_last_maintainer(
+    conn: &mut DbConnection,
+    project_id: ProjectId,
+    user_id: UserId,
+) -> diesel::QueryResult<bool> {
+    let maintainers = schema::project_role::table
+        .filter(schema::project_role::project_id.eq(project_id))
+        .filter(schema::project_role::role.eq(ProjectRole::Maintainer.to_string()))
+        .select(schema::project_role::user_id)
+        .load::<UserId>(conn)?;
+    Ok(maintainers == [user_id])
+}
+
+fn last_maintainer_error(json_member: &JsonProjectMember) -> HttpError {
+    conflict_error(format!(
+        "User ({}) is the last maintainer of the project",
+        json_member.uuid
+    ))
+}
+
+fn json_member_for(
+    conn: &mut DbConnection,
+    user_id: UserId,
+    project_id: ProjectId,
+) -> Result<JsonProjectMember, HttpError> {
+    Ok(schema::user::table
+        .inner_join(schema::project_role::table)
+        .filter(schema::project_role::user_id.eq(user_id))
+        .filter(schema::project_role::project_id.eq(project_id))
+        .select((
+            schema::user::uuid,
+            schema::user::name,
+            schema::user::slug,
+            schema::user::email,
+            schema::project_role::role,
+            schema::project_role::created,
+            schema::project_role::modified,
+        ))
+        .first::<QueryProjectMember>(conn)
+        .map_err(resource_not_found_err!(ProjectRole, (user_id, project_id)))?
+        .into_json())
+}
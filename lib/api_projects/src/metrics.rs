@@ -161,6 +161,10 @@ pub async fn get_one_inner(
                     schema::model::upper_boundary,
                     schema::model::created,
                     schema::model::replaced,
+                    schema::model::confirm_outliers,
+                    schema::model::confirm_reports,
+                    schema::model::resolve_reports,
+                    schema::model::version_window,
                 ),
                 (
                     schema::alert::id,
@@ -169,6 +173,7 @@ pub async fn get_one_inner(
                     schema::alert::boundary_limit,
                     schema::alert::status,
                     schema::alert::modified,
+                    schema::alert::resolved_report_id,
                 ).nullable(),
             ).nullable(),
             QueryMetricBoundary::as_select(),
@@ -0,0 +1,154 @@
+use bencher_boundary::{BoundaryError, change_point::DEFAULT_PENALTY, change_points};
+use bencher_endpoint::{CorsResponse, Endpoint, Get, ResponseOk};
+use bencher_json::{
+    JsonPerf, JsonPerfChangePoints, JsonPerfQuery,
+    project::perf::{
+        JsonPerfChangePoint, JsonPerfChangePointMetrics, JsonPerfChangePointsQueryParams,
+        JsonPerfMetrics, JsonPerfQueryParams,
+    },
+};
+use bencher_schema::{
+    context::ApiContext,
+    error::{bad_request_error, with_auth_hint},
+    model::user::actor::{ApiActor, PubProjectBearerToken},
+};
+use dropshot::{HttpError, Path, Query, RequestContext, endpoint};
+
+use super::ProjPerfParams;
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/perf/change-points",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_perf_change_points_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjPerfParams>,
+    _query_params: Query<JsonPerfChangePointsQueryParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// Detect project performance change points
+///
+/// Detect the change points in the performance metrics for a project.
+/// A change point is the branch version where the distribution of a metric shifted.
+/// The query results are every permutation of each branch, testbed, benchmark, and measure.
+/// There is a limit of 255 permutations for a single request.
+/// Therefore, only the first 255 permutations are returned.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project,
+/// or provide a valid project key for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/perf/change-points",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_perf_change_points_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubProjectBearerToken,
+    path_params: Path<ProjPerfParams>,
+    query_params: Query<JsonPerfChangePointsQueryParams>,
+) -> Result<ResponseOk<JsonPerfChangePoints>, HttpError> {
+    let mut json_change_points_query_params = query_params.into_inner();
+    let penalty = json_change_points_query_params
+        .penalty
+        .take()
+        .unwrap_or(DEFAULT_PENALTY);
+    let json_perf_query_params: JsonPerfQueryParams = json_change_points_query_params.into();
+    // Second round of marshaling
+    let json_perf_query = json_perf_query_params
+        .try_into()
+        .map_err(bad_request_error)?;
+
+    let api_actor = ApiActor::from_token(
+        &rqctx.log,
+        rqctx.context(),
+        #[cfg(feature = "plus")]
+        rqctx.request.headers(),
+        bearer_token,
+    )
+    .await?;
+    let json = get_inner(
+        &rqctx.log,
+        rqctx.context(),
+        path_params.into_inner(),
+        penalty,
+        json_perf_query,
+        &api_actor,
+    )
+    .await
+    .map_err(with_auth_hint)?;
+    Ok(Get::response_ok(json, api_actor.is_auth()))
+}
+
+async fn get_inner(
+    log: &slog::Logger,
+    context: &ApiContext,
+    path_params: ProjPerfParams,
+    penalty: f64,
+    json_perf_query: JsonPerfQuery,
+    api_actor: &ApiActor,
+) -> Result<JsonPerfChangePoints, HttpError> {
+    let JsonPerf {
+        project,
+        start_time,
+        end_time,
+        results,
+    } = super::get_inner(log, context, path_params, json_perf_query, api_actor).await?;
+
+    let results = results
+        .into_iter()
+        .map(|perf_metrics| change_point_metrics(perf_metrics, penalty))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(bad_request_error)?;
+
+    Ok(JsonPerfChangePoints {
+        project,
+        start_time,
+        end_time,
+        results,
+    })
+}
+
+fn change_point_metrics(
+    perf_metrics: JsonPerfMetrics,
+    penalty: f64,
+) -> Result<JsonPerfChangePointMetrics, BoundaryError> {
+    let JsonPerfMetrics {
+        branch,
+        testbed,
+        benchmark,
+        measure,
+        metrics,
+    } = perf_metrics;
+
+    // The perf metrics are already ordered from the oldest to the newest version.
+    let data = metrics
+        .iter()
+        .map(|perf_metric| perf_metric.metric.value.into_inner())
+        .collect::<Vec<f64>>();
+    let change_points = change_points(&data, penalty)?
+        .into_iter()
+        .filter_map(|change_point| {
+            let perf_metric = metrics.get(change_point.index)?;
+            Some(JsonPerfChangePoint {
+                report: perf_metric.report,
+                iteration: perf_metric.iteration,
+                start_time: perf_metric.start_time,
+                end_time: perf_metric.end_time,
+                version: perf_metric.version.clone(),
+                before: change_point.before,
+                after: change_point.after,
+            })
+        })
+        .collect();
+
+    Ok(JsonPerfChangePointMetrics {
+        branch,
+        testbed,
+        benchmark,
+        measure,
+        change_points,
+    })
+}
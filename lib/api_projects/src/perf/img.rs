@@ -1,6 +1,6 @@
 use bencher_endpoint::{CorsResponse, Endpoint, Get};
 use bencher_json::{
-    JsonPerfQuery,
+    JsonPerfQuery, PerfImgFormat, PerfImgPanels,
     project::perf::{JsonPerfImgQueryParams, JsonPerfQueryParams},
 };
 use bencher_plot::LinePlot;
@@ -33,6 +33,10 @@ pub async fn proj_perf_img_options(
 /// The query results are every permutation of each branch, testbed, benchmark, and measure.
 /// There is a limit of 8 permutations for a single image.
 /// Therefore, only the first 8 permutations are plotted.
+/// The image is a JPEG by default.
+/// Request an SVG with `format=svg` or an `Accept: image/svg+xml` header.
+/// Use `panels=measure` or `panels=benchmark` to draw a grid of panels,
+/// one for each measure or benchmark, of up to 16 panels.
 /// If the project is public, then the user does not need to be authenticated.
 /// If the project is private, then the user must be authenticated and have `view` permissions for the project,
 /// or provide a valid project key for the project.
@@ -49,6 +53,16 @@ pub async fn proj_perf_img_get(
 ) -> Result<Response<Body>, HttpError> {
     let mut json_perf_img_query_params = query_params.into_inner();
     let title = json_perf_img_query_params.title.take();
+    let format = json_perf_img_query_params.format.unwrap_or_else(|| {
+        rqctx
+            .request
+            .headers()
+            .get(http::header::ACCEPT)
+            .and_then(|accept| accept.to_str().ok())
+            .map(PerfImgFormat::from_accept)
+            .unwrap_or_default()
+    });
+    let panels = json_perf_img_query_params.panels;
     let json_perf_query_params: JsonPerfQueryParams = json_perf_img_query_params.into();
     // Second round of marshaling
     let json_perf_query = json_perf_query_params
@@ -63,11 +77,13 @@ pub async fn proj_perf_img_get(
         bearer_token,
     )
     .await?;
-    let jpeg = get_inner(
+    let img = get_inner(
         &rqctx.log,
         rqctx.context(),
         path_params.into_inner(),
         title.as_deref(),
+        format,
+        panels,
         json_perf_query,
         &api_actor,
     )
@@ -76,22 +92,36 @@ pub async fn proj_perf_img_get(
 
     Response::builder()
         .status(http::StatusCode::OK)
-        .header(http::header::CONTENT_TYPE, "image/jpeg")
+        .header(http::header::CONTENT_TYPE, format.content_type())
         .header(http::header::CACHE_CONTROL, "private, max-age=0, no-cache")
-        .body(jpeg.into())
+        .header(http::header::VARY, http::header::ACCEPT)
+        .body(img.into())
         .map_err(Into::into)
 }
 
+#[expect(
+    clippy::too_many_arguments,
+    reason = "perf image requires the query and all image options"
+)]
 pub async fn get_inner(
     log: &slog::Logger,
     context: &ApiContext,
     path_params: ProjPerfParams,
     title: Option<&str>,
+    format: PerfImgFormat,
+    panels: Option<PerfImgPanels>,
     json_perf_query: JsonPerfQuery,
     api_actor: &ApiActor,
 ) -> Result<Vec<u8>, HttpError> {
     let json_perf = super::get_inner(log, context, path_params, json_perf_query, api_actor).await?;
-    LinePlot::new().draw(title, &json_perf).map_err(|e| {
+    let line_plot = LinePlot::new().with_panels(panels);
+    match format {
+        PerfImgFormat::Jpeg => line_plot.draw(title, &json_perf),
+        PerfImgFormat::Svg => line_plot
+            .draw_svg(title, &json_perf)
+            .map(String::into_bytes),
+    }
+    .map_err(|e| {
         issue_error(
             "Failed to draw perf plot",
             &format!("Failed draw perf plot: {json_perf:?}"),
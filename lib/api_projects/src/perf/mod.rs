@@ -3,7 +3,7 @@ use bencher_endpoint::{CorsResponse, Endpoint, Get, ResponseOk};
 use bencher_json::SpecUuid;
 use bencher_json::{
     BenchmarkUuid, BranchUuid, DateTime, GitHash, HeadUuid, JsonPerf, JsonPerfQuery, MeasureUuid,
-    ProjectResourceId, ReportUuid, TestbedUuid,
+    ProjectResourceId, ReportUuid, SampleSize, TestbedUuid,
     project::{
         alert::JsonPerfAlert,
         head::{JsonVersion, VersionNumber},
@@ -23,7 +23,11 @@ use bencher_schema::{
         project::{
             QueryProject,
             benchmark::QueryBenchmark,
-            branch::{QueryBranch, head::QueryHead},
+            branch::{
+                QueryBranch,
+                head::{HeadId, QueryHead},
+                version::QueryVersion,
+            },
             measure::QueryMeasure,
             metric_boundary::QueryMetricBoundary,
             testbed::QueryTestbed,
@@ -36,13 +40,14 @@ use bencher_schema::{
     schema, view,
 };
 use diesel::{
-    ExpressionMethods as _, JoinOnDsl as _, NullableExpressionMethods as _, QueryDsl as _,
-    RunQueryDsl as _, SelectableHelper as _,
+    ExpressionMethods as _, JoinOnDsl as _, NullableExpressionMethods as _, OptionalExtension as _,
+    QueryDsl as _, RunQueryDsl as _, SelectableHelper as _,
 };
 use dropshot::{HttpError, Path, Query, RequestContext, endpoint};
 use schemars::JsonSchema;
 use serde::Deserialize;
 
+pub mod change_points;
 pub mod img;
 
 const MAX_PERMUTATIONS: usize = 255;
@@ -138,11 +143,13 @@ pub async fn get_inner(
         measures,
         start_time,
         end_time,
+        versions,
     } = json_perf_query;
 
     let times = Times {
         start_time,
         end_time,
+        versions,
     };
 
     let results = perf_results(
@@ -173,6 +180,7 @@ pub async fn get_inner(
 struct Times {
     start_time: Option<DateTime>,
     end_time: Option<DateTime>,
+    versions: Option<SampleSize>,
 }
 
 #[expect(
@@ -342,6 +350,7 @@ async fn perf_query(
     let Times {
         start_time,
         end_time,
+        versions,
     } = times;
     if let Some(start_time) = start_time {
         query = query.filter(schema::report::start_time.ge(start_time));
@@ -349,6 +358,17 @@ async fn perf_query(
     if let Some(end_time) = end_time {
         query = query.filter(schema::report::end_time.le(end_time));
     }
+    if let Some(versions) = versions
+        && let Some(start_number) = version_window_start(
+            actor_conn!(context, api_actor),
+            project,
+            branch_uuid,
+            head_uuid,
+            versions,
+        )?
+    {
+        query = query.filter(schema::version::number.ge(start_number));
+    }
 
     let query = query
         // Order by the version number so that the oldest version is first.
@@ -395,6 +415,10 @@ async fn perf_query(
                     schema::model::upper_boundary,
                     schema::model::created,
                     schema::model::replaced,
+                    schema::model::confirm_outliers,
+                    schema::model::confirm_reports,
+                    schema::model::resolve_reports,
+                    schema::model::version_window,
                 ),
                 (
                     schema::alert::id,
@@ -403,6 +427,7 @@ async fn perf_query(
                     schema::alert::boundary_limit,
                     schema::alert::status,
                     schema::alert::modified,
+                    schema::alert::resolved_report_id,
                 ).nullable(),
             ).nullable(),
             QueryMetricBoundary::as_select(),
@@ -420,6 +445,42 @@ async fn perf_query(
         .map_err(resource_not_found_err!(Metric, (project,  branch_uuid, testbed_uuid, benchmark_uuid, measure_uuid)))
 }
 
+/// Find the oldest version number within the most recent `versions` for the branch head.
+/// If no branch head is provided, then the current, non-replaced head is used.
+fn version_window_start(
+    conn: &mut DbConnection,
+    project: &QueryProject,
+    branch_uuid: BranchUuid,
+    head_uuid: Option<HeadUuid>,
+    versions: SampleSize,
+) -> Result<Option<VersionNumber>, HttpError> {
+    let mut query = schema::head::table
+        .inner_join(schema::branch::table.on(schema::head::branch_id.eq(schema::branch::id)))
+        .filter(schema::branch::uuid.eq(branch_uuid))
+        .filter(schema::branch::project_id.eq(project.id))
+        .into_boxed();
+    if let Some(head_uuid) = head_uuid {
+        query = query.filter(schema::head::uuid.eq(head_uuid));
+    } else {
+        query = query.filter(schema::branch::head_id.eq(schema::head::id.nullable()));
+    }
+    let Some(head_id) = query
+        .select(schema::head::id)
+        .first::<HeadId>(conn)
+        .optional()
+        .map_err(resource_not_found_err!(
+            Head,
+            (project, branch_uuid, head_uuid)
+        ))?
+    else {
+        return Ok(None);
+    };
+    QueryVersion::window_start(conn, head_id, versions).map_err(resource_not_found_err!(
+        Version,
+        (project, head_id, versions)
+    ))
+}
+
 type PerfQuery = (
     QueryBranch,
     QueryHead,
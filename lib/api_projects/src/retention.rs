@@ -0,0 +1,232 @@
+use bencher_endpoint::{CorsResponse, Endpoint, Get, Put, ResponseOk};
+use bencher_json::{JsonRetention, JsonRetentionReport, JsonUpdateRetention, ProjectResourceId};
+use bencher_rbac::project::Permission;
+use bencher_schema::{
+    auth_conn,
+    context::ApiContext,
+    error::{issue_error, resource_conflict_err, with_token_hint},
+    model::{
+        project::{
+            QueryProject,
+            retention::{ProjectRetention, QueryRetention},
+        },
+        user::auth::{AuthUser, BearerToken},
+    },
+    schema, write_conn,
+};
+use diesel::{ExpressionMethods as _, RunQueryDsl as _, upsert::excluded};
+use dropshot::{HttpError, Path, RequestContext, TypedBody, endpoint};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjRetentionParams {
+    /// The slug or UUID for a project.
+    pub project: ProjectResourceId,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/retention",
+    tags = ["projects", "retention"]
+}]
+pub async fn proj_retention_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjRetentionParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Put.into()]))
+}
+
+/// View the retention policy for a project
+///
+/// View the retention policy for a project.
+/// A project without a retention policy keeps all of its reports and metrics.
+/// Requires `manage` permission on the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/retention",
+    tags = ["projects", "retention"]
+}]
+pub async fn proj_retention_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjRetentionParams>,
+) -> Result<ResponseOk<JsonRetention>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_inner(rqctx.context(), path_params.into_inner(), &auth_user)
+        .await
+        .map_err(with_token_hint)?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_inner(
+    context: &ApiContext,
+    path_params: ProjRetentionParams,
+    auth_user: &AuthUser,
+) -> Result<JsonRetention, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        auth_conn!(context),
+        &context.rbac,
+        #[cfg(feature = "plus")]
+        &context.rate_limiting,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let retention = QueryRetention::get(auth_conn!(context), query_project.id)?;
+    QueryRetention::into_json(retention.as_ref(), auth_conn!(context), &query_project)
+}
+
+/// Update the retention policy for a project
+///
+/// Replace the retention policy for a project.
+/// Reports older than `report_days` are deleted, except for those on the `default_branch`.
+/// Metrics older than `downsample_days` are downsampled to their daily median.
+/// Leave a setting unset to disable it.
+/// Policies are applied periodically by the server.
+/// Requires `manage` permission on the project.
+#[endpoint {
+    method = PUT,
+    path =  "/v0/projects/{project}/retention",
+    tags = ["projects", "retention"]
+}]
+pub async fn proj_retention_put(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjRetentionParams>,
+    body: TypedBody<JsonUpdateRetention>,
+) -> Result<ResponseOk<JsonRetention>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = put_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await
+    .map_err(with_token_hint)?;
+    Ok(Put::auth_response_ok(json))
+}
+
+async fn put_inner(
+    context: &ApiContext,
+    path_params: ProjRetentionParams,
+    json_retention: JsonUpdateRetention,
+    auth_user: &AuthUser,
+) -> Result<JsonRetention, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        auth_conn!(context),
+        &context.rbac,
+        #[cfg(feature = "plus")]
+        &context.rate_limiting,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let retention = QueryRetention::from_json(
+        auth_conn!(context),
+        query_project.id,
+        json_retention,
+        context.clock.now(),
+    )?;
+    diesel::insert_into(schema::project_retention::table)
+        .values(&retention)
+        .on_conflict(schema::project_retention::project_id)
+        .do_update()
+        .set((
+            schema::project_retention::report_days
+                .eq(excluded(schema::project_retention::report_days)),
+            schema::project_retention::default_branch_id
+                .eq(excluded(schema::project_retention::default_branch_id)),
+            schema::project_retention::downsample_days
+                .eq(excluded(schema::project_retention::downsample_days)),
+            schema::project_retention::modified.eq(excluded(schema::project_retention::modified)),
+        ))
+        .execute(write_conn!(context))
+        .map_err(resource_conflict_err!(
+            Project,
+            (&query_project, &retention)
+        ))?;
+
+    QueryRetention::into_json(Some(&retention), auth_conn!(context), &query_project)
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/retention/dry-run",
+    tags = ["projects", "retention"]
+}]
+pub async fn proj_retention_dry_run_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjRetentionParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// Dry run the retention policy for a project
+///
+/// Report what the retention policy for a project would delete if it were applied now.
+/// Nothing is deleted.
+/// Requires `manage` permission on the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/retention/dry-run",
+    tags = ["projects", "retention"]
+}]
+pub async fn proj_retention_dry_run_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjRetentionParams>,
+) -> Result<ResponseOk<JsonRetentionReport>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_dry_run_inner(rqctx.context(), path_params.into_inner(), &auth_user)
+        .await
+        .map_err(with_token_hint)?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_dry_run_inner(
+    context: &ApiContext,
+    path_params: ProjRetentionParams,
+    auth_user: &AuthUser,
+) -> Result<JsonRetentionReport, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        auth_conn!(context),
+        &context.rbac,
+        #[cfg(feature = "plus")]
+        &context.rate_limiting,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let Some(retention) = QueryRetention::get(auth_conn!(context), query_project.id)? else {
+        return Ok(JsonRetentionReport {
+            project: query_project.uuid,
+            dry_run: true,
+            reports: 0,
+            report_metrics: 0,
+            downsampled_metrics: 0,
+        });
+    };
+    ProjectRetention::run(
+        &context.database.connection,
+        query_project.uuid,
+        &retention,
+        context.clock.now(),
+        true,
+    )
+    .await
+    .map_err(|e| {
+        issue_error(
+            "Failed to dry run retention policy",
+            &format!(
+                "Failed to dry run retention policy for project ({}).",
+                query_project.uuid
+            ),
+            e,
+        )
+    })
+}
@@ -0,0 +1,515 @@
+use bencher_endpoint::{
+    CorsResponse, Delete, Endpoint, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
+    TotalCount,
+};
+use bencher_json::{
+    JsonDirection, JsonNewWebhook, JsonPagination, JsonUpdateWebhook, JsonWebhook,
+    JsonWebhookCreated, JsonWebhookDeliveries, JsonWebhooks, ProjectResourceId, WebhookUuid,
+};
+use bencher_rbac::project::Permission;
+use bencher_schema::{
+    auth_conn,
+    context::ApiContext,
+    error::{resource_conflict_err, resource_not_found_err, with_token_hint},
+    model::{
+        project::{
+            ProjectId, QueryProject,
+            webhook::{
+                InsertWebhook, QueryWebhook, UpdateWebhook, WebhookId,
+                delivery::QueryWebhookDelivery,
+            },
+        },
+        user::auth::{AuthUser, BearerToken},
+    },
+    schema, write_conn,
+};
+use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+use dropshot::{HttpError, Path, Query, RequestContext, TypedBody, endpoint};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjWebhooksParams {
+    /// The slug or UUID for a project.
+    pub project: ProjectResourceId,
+}
+
+pub type ProjWebhooksPagination = JsonPagination<ProjWebhooksSort>;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjWebhooksSort {
+    /// Sort by webhook creation date time.
+    #[default]
+    Created,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhooks_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjWebhooksParams>,
+    _pagination_params: Query<ProjWebhooksPagination>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+}
+
+/// List webhooks for a project
+///
+/// List all webhooks for a project.
+/// Requires `manage` permission on the project.
+/// By default, the webhooks are sorted by creation date time in chronological order.
+/// The HTTP response header `X-Total-Count` contains the total number of webhooks.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhooks_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhooksParams>,
+    pagination_params: Query<ProjWebhooksPagination>,
+) -> Result<ResponseOk<JsonWebhooks>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+        &auth_user,
+    )
+    .await
+    .map_err(with_token_hint)?;
+    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    path_params: ProjWebhooksParams,
+    pagination_params: ProjWebhooksPagination,
+    auth_user: &AuthUser,
+) -> Result<(JsonWebhooks, TotalCount), HttpError> {
+    let query_project = QueryProject::is_allowed(
+        auth_conn!(context),
+        &context.rbac,
+        #[cfg(feature = "plus")]
+        &context.rate_limiting,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let webhooks = get_ls_query(&pagination_params, query_project.id)
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryWebhook>(auth_conn!(context))
+        .map_err(resource_not_found_err!(
+            Webhook,
+            (&query_project, &pagination_params)
+        ))?;
+
+    let json_webhooks = webhooks
+        .into_iter()
+        .map(|webhook| webhook.into_json_for_project(&query_project))
+        .collect();
+
+    let total_count = get_ls_query(&pagination_params, query_project.id)
+        .count()
+        .get_result::<i64>(auth_conn!(context))
+        .map_err(resource_not_found_err!(
+            Webhook,
+            (&query_project, &pagination_params)
+        ))?
+        .try_into()?;
+
+    Ok((json_webhooks, total_count))
+}
+
+fn get_ls_query(
+    pagination_params: &ProjWebhooksPagination,
+    project_id: ProjectId,
+) -> schema::webhook::BoxedQuery<'static, diesel::sqlite::Sqlite> {
+    let query = schema::webhook::table
+        .filter(schema::webhook::project_id.eq(project_id))
+        .into_boxed();
+
+    match pagination_params.order() {
+        ProjWebhooksSort::Created => match pagination_params.direction {
+            Some(JsonDirection::Asc) | None => {
+                query.order((schema::webhook::created.asc(), schema::webhook::id.asc()))
+            },
+            Some(JsonDirection::Desc) => {
+                query.order((schema::webhook::created.desc(), schema::webhook::id.desc()))
+            },
+        },
+    }
+}
+
+/// Create a webhook for a project
+///
+/// Create a new webhook for a project.
+/// If no events are given, then the webhook is subscribed to all events.
+/// The webhook secret is only returned once in the response.
+/// Use it to verify the `X-Bencher-Signature-256` header of each delivery.
+/// Requires `manage` permission on the project.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhooksParams>,
+    body: TypedBody<JsonNewWebhook>,
+) -> Result<ResponseCreated<JsonWebhookCreated>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await
+    .map_err(with_token_hint)?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: ProjWebhooksParams,
+    json_webhook: JsonNewWebhook,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhookCreated, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        auth_conn!(context),
+        &context.rbac,
+        #[cfg(feature = "plus")]
+        &context.rate_limiting,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let now = context.clock.now();
+    let insert_webhook = InsertWebhook::from_json(
+        query_project.id,
+        json_webhook,
+        now,
+        context.allow_insecure_webhooks,
+    )
+    .await?;
+
+    diesel::insert_into(schema::webhook::table)
+        .values(&insert_webhook)
+        .execute(write_conn!(context))
+        .map_err(resource_conflict_err!(Webhook, &insert_webhook))?;
+
+    Ok(insert_webhook.into_json(query_project.uuid))
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjWebhookParams {
+    /// The slug or UUID for a project.
+    pub project: ProjectResourceId,
+    /// The UUID for a webhook.
+    pub webhook: WebhookUuid,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjWebhookParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Patch.into(), Delete.into()]))
+}
+
+/// View a webhook for a project
+///
+/// View a webhook for a project.
+/// Requires `manage` permission on the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+) -> Result<ResponseOk<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user)
+        .await
+        .map_err(with_token_hint)?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        auth_conn!(context),
+        &context.rbac,
+        #[cfg(feature = "plus")]
+        &context.rate_limiting,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    Ok(QueryWebhook::get_project_webhook(
+        auth_conn!(context),
+        query_project.id,
+        path_params.webhook,
+    )?
+    .into_json_for_project(&query_project))
+}
+
+/// Update a webhook for a project
+///
+/// Update a webhook for a project.
+/// The webhook secret cannot be changed.
+/// Requires `manage` permission on the project.
+#[endpoint {
+    method = PATCH,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_patch(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+    body: TypedBody<JsonUpdateWebhook>,
+) -> Result<ResponseOk<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = patch_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await
+    .map_err(with_token_hint)?;
+    Ok(Patch::auth_response_ok(json))
+}
+
+async fn patch_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    json_webhook: JsonUpdateWebhook,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        auth_conn!(context),
+        &context.rbac,
+        #[cfg(feature = "plus")]
+        &context.rate_limiting,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let query_webhook = QueryWebhook::get_project_webhook(
+        auth_conn!(context),
+        query_project.id,
+        path_params.webhook,
+    )?;
+
+    let update_webhook = UpdateWebhook::from_json(
+        json_webhook,
+        context.clock.now(),
+        context.allow_insecure_webhooks,
+    )
+    .await?;
+    diesel::update(schema::webhook::table.filter(schema::webhook::id.eq(query_webhook.id)))
+        .set(&update_webhook)
+        .execute(write_conn!(context))
+        .map_err(resource_conflict_err!(
+            Webhook,
+            (&query_webhook, &update_webhook)
+        ))?;
+
+    Ok(QueryWebhook::get(auth_conn!(context), query_webhook.id)?
+        .into_json_for_project(&query_project))
+}
+
+/// Delete a webhook for a project
+///
+/// Delete a webhook for a project, along with its delivery history.
+/// Requires `manage` permission on the project.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &auth_user)
+        .await
+        .map_err(with_token_hint)?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    let query_project = QueryProject::is_allowed(
+        auth_conn!(context),
+        &context.rbac,
+        #[cfg(feature = "plus")]
+        &context.rate_limiting,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let query_webhook = QueryWebhook::get_project_webhook(
+        auth_conn!(context),
+        query_project.id,
+        path_params.webhook,
+    )?;
+
+    diesel::delete(schema::webhook::table.filter(schema::webhook::id.eq(query_webhook.id)))
+        .execute(write_conn!(context))
+        .map_err(resource_conflict_err!(Webhook, query_webhook))?;
+
+    Ok(())
+}
+
+pub type ProjWebhookDeliveriesPagination = JsonPagination<ProjWebhookDeliveriesSort>;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjWebhookDeliveriesSort {
+    /// Sort by delivery creation date time.
+    #[default]
+    Created,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/webhooks/{webhook}/deliveries",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_deliveries_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjWebhookParams>,
+    _pagination_params: Query<ProjWebhookDeliveriesPagination>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// List deliveries for a webhook
+///
+/// List the delivery log for a webhook.
+/// Requires `manage` permission on the project.
+/// By default, the deliveries are sorted by creation date time in reverse chronological order.
+/// The HTTP response header `X-Total-Count` contains the total number of deliveries.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks/{webhook}/deliveries",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_deliveries_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+    pagination_params: Query<ProjWebhookDeliveriesPagination>,
+) -> Result<ResponseOk<JsonWebhookDeliveries>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let (json, total_count) = get_deliveries_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+        &auth_user,
+    )
+    .await
+    .map_err(with_token_hint)?;
+    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+}
+
+async fn get_deliveries_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    pagination_params: ProjWebhookDeliveriesPagination,
+    auth_user: &AuthUser,
+) -> Result<(JsonWebhookDeliveries, TotalCount), HttpError> {
+    let query_project = QueryProject::is_allowed(
+        auth_conn!(context),
+        &context.rbac,
+        #[cfg(feature = "plus")]
+        &context.rate_limiting,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let query_webhook = QueryWebhook::get_project_webhook(
+        auth_conn!(context),
+        query_project.id,
+        path_params.webhook,
+    )?;
+
+    let deliveries = get_deliveries_query(&pagination_params, query_webhook.id)
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryWebhookDelivery>(auth_conn!(context))
+        .map_err(resource_not_found_err!(
+            WebhookDelivery,
+            (&query_webhook, &pagination_params)
+        ))?;
+
+    let json_deliveries = deliveries
+        .into_iter()
+        .map(|delivery| delivery.into_json_for_webhook(&query_webhook))
+        .collect();
+
+    let total_count = get_deliveries_query(&pagination_params, query_webhook.id)
+        .count()
+        .get_result::<i64>(auth_conn!(context))
+        .map_err(resource_not_found_err!(
+            WebhookDelivery,
+            (&query_webhook, &pagination_params)
+        ))?
+        .try_into()?;
+
+    Ok((json_deliveries, total_count))
+}
+
+fn get_deliveries_query(
+    pagination_params: &ProjWebhookDeliveriesPagination,
+    webhook_id: WebhookId,
+) -> schema::webhook_delivery::BoxedQuery<'static, diesel::sqlite::Sqlite> {
+    let query = schema::webhook_delivery::table
+        .filter(schema::webhook_delivery::webhook_id.eq(webhook_id))
+        .into_boxed();
+
+    match pagination_params.order() {
+        ProjWebhookDeliveriesSort::Created => match pagination_params.direction {
+            Some(JsonDirection::Asc) => query.order((
+                schema::webhook_delivery::created.asc(),
+                schema::webhook_delivery::id.asc(),
+            )),
+            Some(JsonDirection::Desc) | None => query.order((
+                schema::webhook_delivery::created.desc(),
+                schema::webhook_delivery::id.desc(),
+            )),
+        },
+    }
+}
@@ -1,13 +1,15 @@
 #![expect(
     unused_crate_dependencies,
+    clippy::expect_used,
+    clippy::missing_assert_message,
     clippy::tests_outside_test_module,
     clippy::uninlined_format_args,
     reason = "integration test file"
 )]
 //! Integration tests for project benchmark endpoints.
 
-use bencher_api_tests::TestServer;
-use bencher_json::JsonBenchmarks;
+use bencher_api_tests::{TestServer, TestUser};
+use bencher_json::{JsonBenchmark, JsonBenchmarkAlias, JsonBenchmarkAliases, JsonBenchmarks};
 use http::StatusCode;
 
 // GET /v0/projects/{project}/benchmarks - list benchmarks (empty)
@@ -129,3 +131,187 @@ async fn benchmarks_delete_not_found() {
 
     assert_eq!(resp.status(), StatusCode::NOT_FOUND);
 }
+
+async fn post_benchmark_report(
+    server: &TestServer,
+    user: &TestUser,
+    project_slug: &str,
+    minute: u32,
+    benchmark: &str,
+) {
+    let resp = server
+        .client
+        .post(server.api_url(&format!("/v0/projects/{project_slug}/reports")))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&serde_json::json!({
+            "branch": "main",
+            "testbed": "localhost",
+            "start_time": format!("2024-01-01T00:{minute:02}:00Z"),
+            "end_time": format!("2024-01-01T00:{minute:02}:30Z"),
+            "results": [format!("{{\"{benchmark}\": {{\"latency\": {{\"value\": 100.0}}}}}}")]
+        }))
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::CREATED);
+}
+
+async fn list_benchmarks(
+    server: &TestServer,
+    user: &TestUser,
+    project_slug: &str,
+) -> JsonBenchmarks {
+    let resp = server
+        .client
+        .get(server.api_url(&format!("/v0/projects/{project_slug}/benchmarks")))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    resp.json().await.expect("Failed to parse response")
+}
+
+// POST /v0/projects/{project}/benchmarks/{benchmark}/merge
+#[tokio::test]
+async fn benchmarks_merge_and_alias() {
+    let server = TestServer::new().await;
+    let user = server
+        .signup("Test User", "benchmarkmerge@example.com")
+        .await;
+    let org = server.create_org(&user, "Benchmark Merge Org").await;
+    let project = server
+        .create_project(&user, &org, "Benchmark Merge Project")
+        .await;
+    let project_slug: &str = project.slug.as_ref();
+
+    post_benchmark_report(&server, &user, project_slug, 0, "bench_old").await;
+    post_benchmark_report(&server, &user, project_slug, 1, "bench_new").await;
+    let benchmarks = list_benchmarks(&server, &user, project_slug).await;
+    assert_eq!(benchmarks.0.len(), 2);
+    let find = |name: &str| {
+        benchmarks
+            .0
+            .iter()
+            .find(|benchmark| benchmark.name.as_ref() == name)
+            .map(|benchmark| benchmark.uuid)
+            .expect("Missing benchmark")
+    };
+    let old_uuid = find("bench_old");
+    let new_uuid = find("bench_new");
+
+    let resp = server
+        .client
+        .post(server.api_url(&format!(
+            "/v0/projects/{project_slug}/benchmarks/{new_uuid}/merge"
+        )))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&serde_json::json!({ "benchmarks": [old_uuid.to_string()] }))
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let merged: JsonBenchmark = resp.json().await.expect("Failed to parse response");
+    assert_eq!(merged.uuid, new_uuid);
+
+    let resp = server
+        .client
+        .get(server.api_url(&format!(
+            "/v0/projects/{project_slug}/benchmarks/{new_uuid}/aliases"
+        )))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let aliases: JsonBenchmarkAliases = resp.json().await.expect("Failed to parse response");
+    assert_eq!(aliases.0.len(), 1);
+    assert_eq!(
+        aliases.0.first().map(|alias| alias.name.as_ref()),
+        Some("bench_old")
+    );
+
+    // Reports under the old name land on the merged benchmark
+    post_benchmark_report(&server, &user, project_slug, 2, "bench_old").await;
+    let benchmarks = list_benchmarks(&server, &user, project_slug).await;
+    assert_eq!(benchmarks.0.len(), 1);
+    assert_eq!(benchmarks.0.first().map(|b| b.uuid), Some(new_uuid));
+}
+
+// POST /v0/projects/{project}/benchmarks/{benchmark}/aliases
+#[tokio::test]
+async fn benchmarks_alias_create_delete() {
+    let server = TestServer::new().await;
+    let user = server
+        .signup("Test User", "benchmarkalias@example.com")
+        .await;
+    let org = server.create_org(&user, "Benchmark Alias Org").await;
+    let project = server
+        .create_project(&user, &org, "Benchmark Alias Project")
+        .await;
+    let project_slug: &str = project.slug.as_ref();
+
+    post_benchmark_report(&server, &user, project_slug, 0, "bench_a").await;
+    post_benchmark_report(&server, &user, project_slug, 1, "bench_b").await;
+    let benchmarks = list_benchmarks(&server, &user, project_slug).await;
+    let bench_a = benchmarks
+        .0
+        .iter()
+        .find(|benchmark| benchmark.name.as_ref() == "bench_a")
+        .map(|benchmark| benchmark.uuid)
+        .expect("Missing benchmark");
+
+    let post_alias = |name: &'static str| {
+        server
+            .client
+            .post(server.api_url(&format!(
+                "/v0/projects/{project_slug}/benchmarks/{bench_a}/aliases"
+            )))
+            .header(
+                bencher_json::AUTHORIZATION,
+                bencher_json::bearer_header(&user.token),
+            )
+            .json(&serde_json::json!({ "name": name }))
+            .send()
+    };
+
+    // The name of another benchmark cannot be an alias
+    let resp = post_alias("bench_b").await.expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+    let resp = post_alias("bench_renamed").await.expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let alias: JsonBenchmarkAlias = resp.json().await.expect("Failed to parse response");
+    assert_eq!(alias.benchmark, bench_a);
+
+    // An alias name can only be used once
+    let resp = post_alias("bench_renamed").await.expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+    let resp = server
+        .client
+        .delete(server.api_url(&format!(
+            "/v0/projects/{project_slug}/benchmarks/{bench_a}/aliases/{}",
+            alias.uuid
+        )))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+}
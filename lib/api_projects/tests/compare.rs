@@ -0,0 +1,491 @@
+#![expect(
+    unused_crate_dependencies,
+    clippy::expect_used,
+    clippy::missing_assert_message,
+    clippy::tests_outside_test_module,
+    clippy::too_many_arguments,
+    reason = "integration test file"
+)]
+//! Integration tests for the `/v0/projects/{project}/compare` endpoint.
+
+use bencher_api_tests::{TestServer, TestUser};
+use bencher_json::{JsonCompare, project::compare::CompareVerdict};
+use http::StatusCode;
+
+const HASH: &str = "0123456789abcdef0123456789abcdef01234567";
+
+async fn create_report(
+    server: &TestServer,
+    user: &TestUser,
+    project_slug: &str,
+    branch: &str,
+    hash: Option<&str>,
+    minute: u32,
+    latency: f64,
+    throughput: f64,
+) {
+    let results = vec![format!(
+        "{{\"bench\": {{\"latency\": {{\"value\": {latency}}}, \"throughput\": {{\"value\": {throughput}}}}}}}"
+    )];
+    create_results_report(server, user, project_slug, branch, hash, minute, results).await;
+}
+
+// Each result is a separate iteration of the report
+async fn create_results_report(
+    server: &TestServer,
+    user: &TestUser,
+    project_slug: &str,
+    branch: &str,
+    hash: Option<&str>,
+    minute: u32,
+    results: Vec<String>,
+) {
+    let body = serde_json::json!({
+        "branch": branch,
+        "hash": hash,
+        "testbed": "localhost",
+        "start_time": format!("2024-01-01T00:{minute:02}:00Z"),
+        "end_time": format!("2024-01-01T00:{minute:02}:30Z"),
+        "results": results,
+    });
+    let resp = server
+        .client
+        .post(server.api_url(&format!("/v0/projects/{project_slug}/reports")))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&body)
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::CREATED);
+}
+
+async fn get_compare(
+    server: &TestServer,
+    user: &TestUser,
+    project_slug: &str,
+    query: &str,
+) -> (StatusCode, Option<JsonCompare>) {
+    let resp = server
+        .client
+        .get(server.api_url(&format!(
+            "/v0/projects/{project_slug}/compare?testbed=localhost&{query}"
+        )))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+    let status = resp.status();
+    let compare = if status == StatusCode::OK {
+        Some(resp.json().await.expect("Failed to parse compare"))
+    } else {
+        None
+    };
+    (status, compare)
+}
+
+// GET /v0/projects/{project}/compare - compare the latest reports of two branches
+#[tokio::test]
+async fn compare_branches() {
+    let server = TestServer::new().await;
+    let user = server
+        .signup("Test User", "comparebranches@example.com")
+        .await;
+    let org = server.create_org(&user, "Compare Org").await;
+    let project = server.create_project(&user, &org, "Compare Project").await;
+    let project_slug: &str = project.slug.as_ref();
+
+    let latencies = [100.0, 101.0, 99.0, 100.5, 99.5];
+    let mut minute = 0;
+    for latency in latencies {
+        create_report(
+            &server,
+            &user,
+            project_slug,
+            "main",
+            None,
+            minute,
+            latency,
+            50.0 + latency,
+        )
+        .await;
+        minute += 1;
+    }
+    for latency in latencies {
+        // Latency is 20% slower while throughput is within the noise
+        create_report(
+            &server,
+            &user,
+            project_slug,
+            "feature",
+            None,
+            minute,
+            latency * 1.2,
+            250.0 - latency,
+        )
+        .await;
+        minute += 1;
+    }
+
+    let (status, compare) = get_compare(
+        &server,
+        &user,
+        project_slug,
+        "baseline_branch=main&candidate_branch=feature",
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let compare = compare.expect("Missing compare");
+    assert_eq!(compare.baseline.reports.len(), 5);
+    assert_eq!(compare.candidate.reports.len(), 5);
+    assert_eq!(compare.results.len(), 2);
+
+    let latency = compare
+        .results
+        .iter()
+        .find(|result| result.measure.slug.to_string() == "latency")
+        .expect("Missing latency");
+    assert_eq!(latency.verdict, CompareVerdict::Increase);
+    assert_eq!(latency.baseline.size, 5);
+    let change = latency.change.expect("Missing change");
+    assert!((change - 0.2).abs() < 1e-9);
+    let lower = latency.lower_change.expect("Missing lower change");
+    let upper = latency.upper_change.expect("Missing upper change");
+    assert!(0.0 < lower && lower < change && change < upper);
+
+    let throughput = compare
+        .results
+        .iter()
+        .find(|result| result.measure.slug.to_string() == "throughput")
+        .expect("Missing throughput");
+    assert_eq!(throughput.verdict, CompareVerdict::NoChange);
+
+    // Only the latest reports are compared
+    let (status, compare) = get_compare(
+        &server,
+        &user,
+        project_slug,
+        "baseline_branch=main&candidate_branch=feature&reports=2",
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let compare = compare.expect("Missing compare");
+    assert_eq!(compare.baseline.reports.len(), 2);
+    assert_eq!(compare.candidate.reports.len(), 2);
+    assert!(
+        compare
+            .results
+            .iter()
+            .all(|result| result.candidate.size == 2)
+    );
+}
+
+// GET /v0/projects/{project}/compare - compare a branch at a given hash
+#[tokio::test]
+async fn compare_hash() {
+    let server = TestServer::new().await;
+    let user = server.signup("Test User", "comparehash@example.com").await;
+    let org = server.create_org(&user, "Compare Hash Org").await;
+    let project = server
+        .create_project(&user, &org, "Compare Hash Project")
+        .await;
+    let project_slug: &str = project.slug.as_ref();
+
+    create_report(&server, &user, project_slug, "main", None, 0, 100.0, 100.0).await;
+    create_report(&server, &user, project_slug, "main", None, 1, 101.0, 100.0).await;
+    create_report(
+        &server,
+        &user,
+        project_slug,
+        "feature",
+        Some(HASH),
+        2,
+        120.0,
+        100.0,
+    )
+    .await;
+    create_report(
+        &server,
+        &user,
+        project_slug,
+        "feature",
+        None,
+        3,
+        121.0,
+        100.0,
+    )
+    .await;
+
+    let (status, compare) = get_compare(
+        &server,
+        &user,
+        project_slug,
+        &format!("baseline_branch=main&candidate_branch=feature&candidate_hash={HASH}"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let compare = compare.expect("Missing compare");
+    assert_eq!(compare.baseline.reports.len(), 2);
+    assert_eq!(compare.candidate.reports.len(), 1);
+    let version = compare
+        .candidate
+        .branch
+        .head
+        .version
+        .expect("Missing version");
+    assert_eq!(version.hash.expect("Missing hash").as_ref(), HASH);
+    // A single candidate report is too few to test for significance
+    assert!(
+        compare
+            .results
+            .iter()
+            .all(|result| result.verdict == CompareVerdict::Inconclusive)
+    );
+}
+
+// GET /v0/projects/{project}/compare - unknown branch
+#[tokio::test]
+async fn compare_unknown_branch() {
+    let server = TestServer::new().await;
+    let user = server
+        .signup("Test User", "compareunknown@example.com")
+        .await;
+    let org = server.create_org(&user, "Compare Unknown Org").await;
+    let project = server
+        .create_project(&user, &org, "Compare Unknown Project")
+        .await;
+    let project_slug: &str = project.slug.as_ref();
+
+    create_report(&server, &user, project_slug, "main", None, 0, 100.0, 100.0).await;
+
+    let (status, compare) = get_compare(
+        &server,
+        &user,
+        project_slug,
+        "baseline_branch=main&candidate_branch=missing",
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(compare.is_none());
+}
+
+// GET /v0/projects/{project}/compare - the latest reports are selected for each benchmark and measure
+#[tokio::test]
+async fn compare_latest_per_benchmark() {
+    let server = TestServer::new().await;
+    let user = server
+        .signup("Test User", "comparelatest@example.com")
+        .await;
+    let org = server.create_org(&user, "Compare Latest Org").await;
+    let project = server
+        .create_project(&user, &org, "Compare Latest Project")
+        .await;
+    let project_slug: &str = project.slug.as_ref();
+
+    let mut minute = 0;
+    for branch in ["main", "feature"] {
+        for bench in ["older", "older", "older", "newer", "newer", "newer"] {
+            let results = vec![format!(
+                "{{\"{bench}\": {{\"latency\": {{\"value\": {}}}}}}}",
+                100 + minute
+            )];
+            create_results_report(&server, &user, project_slug, branch, None, minute, results)
+                .await;
+            minute += 1;
+        }
+    }
+
+    let (status, compare) = get_compare(
+        &server,
+        &user,
+        project_slug,
+        "baseline_branch=main&candidate_branch=feature&reports=3",
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let compare = compare.expect("Missing compare");
+    // Every report is used by one of the benchmarks
+    assert_eq!(compare.baseline.reports.len(), 6);
+    assert_eq!(compare.candidate.reports.len(), 6);
+    assert_eq!(compare.results.len(), 2);
+    for result in &compare.results {
+        assert_eq!(result.baseline.size, 3);
+        assert_eq!(result.candidate.size, 3);
+    }
+}
+
+// GET /v0/projects/{project}/compare - the iterations of a report are reduced to their mean
+#[tokio::test]
+async fn compare_iterations() {
+    let server = TestServer::new().await;
+    let user = server
+        .signup("Test User", "compareiterations@example.com")
+        .await;
+    let org = server.create_org(&user, "Compare Iterations Org").await;
+    let project = server
+        .create_project(&user, &org, "Compare Iterations Project")
+        .await;
+    let project_slug: &str = project.slug.as_ref();
+
+    let mut minute = 0;
+    for (branch, latency) in [("main", 100.0), ("feature", 200.0)] {
+        for _ in 0..2 {
+            let results = [latency - 10.0, latency + 10.0]
+                .into_iter()
+                .map(|value| format!("{{\"bench\": {{\"latency\": {{\"value\": {value}}}}}}}"))
+                .collect();
+            create_results_report(&server, &user, project_slug, branch, None, minute, results)
+                .await;
+            minute += 1;
+        }
+    }
+
+    let (status, compare) = get_compare(
+        &server,
+        &user,
+        project_slug,
+        "baseline_branch=main&candidate_branch=feature",
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let compare = compare.expect("Missing compare");
+    assert_eq!(compare.results.len(), 1);
+    let result = compare.results.first().expect("Missing result");
+    assert_eq!(result.baseline.size, 2);
+    assert_eq!(result.candidate.size, 2);
+    assert!((result.baseline.mean - 100.0).abs() < f64::EPSILON);
+    assert!((result.candidate.mean - 200.0).abs() < f64::EPSILON);
+    // Each report has the same mean, so the iterations do not add any variance
+    let std_dev = result.baseline.std_dev.expect("Missing std dev");
+    assert!(std_dev.abs() < f64::EPSILON);
+}
+
+// Create a spec and set it on all of the existing reports for the project
+#[cfg(feature = "plus")]
+fn create_spec(server: &TestServer, project_slug: &str) -> bencher_json::SpecUuid {
+    use bencher_api_tests::helpers::{base_timestamp, get_project_id};
+    use bencher_json::SpecUuid;
+    use bencher_schema::schema;
+    use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+
+    let spec_uuid = SpecUuid::new();
+    let mut conn = server.db_conn();
+    let now = base_timestamp();
+    diesel::insert_into(schema::spec::table)
+        .values((
+            schema::spec::uuid.eq(&spec_uuid),
+            schema::spec::name.eq("compare-spec"),
+            schema::spec::slug.eq("compare-spec"),
+            schema::spec::os.eq("linux"),
+            schema::spec::architecture.eq("x86_64"),
+            schema::spec::cpu.eq(4),
+            schema::spec::memory.eq(0x0002_0000_0000i64),
+            schema::spec::disk.eq(0x0005_0000_0000i64),
+            schema::spec::network.eq(true),
+            schema::spec::created.eq(&now),
+            schema::spec::modified.eq(&now),
+        ))
+        .execute(&mut conn)
+        .expect("insert spec");
+    let spec_id: i32 = schema::spec::table
+        .filter(schema::spec::uuid.eq(&spec_uuid))
+        .select(schema::spec::id)
+        .first(&mut conn)
+        .expect("get spec id");
+    let project_id = get_project_id(server, project_slug);
+    diesel::update(schema::report::table.filter(schema::report::project_id.eq(project_id)))
+        .set(schema::report::spec_id.eq(Some(spec_id)))
+        .execute(&mut conn)
+        .expect("set report spec_id");
+    spec_uuid
+}
+
+// GET /v0/projects/{project}/compare - only compare reports run on the same spec
+#[cfg(feature = "plus")]
+#[tokio::test]
+async fn compare_spec() {
+    use bencher_json::SpecUuid;
+
+    let server = TestServer::new().await;
+    let user = server.signup("Test User", "comparespec@example.com").await;
+    let org = server.create_org(&user, "Compare Spec Org").await;
+    let project = server
+        .create_project(&user, &org, "Compare Spec Project")
+        .await;
+    let project_slug: &str = project.slug.as_ref();
+
+    let mut minute = 0;
+    for branch in ["main", "feature", "main", "feature"] {
+        create_report(
+            &server,
+            &user,
+            project_slug,
+            branch,
+            None,
+            minute,
+            100.0,
+            100.0,
+        )
+        .await;
+        minute += 1;
+    }
+
+    // Run the existing reports on a spec
+    let spec_uuid = create_spec(&server, project_slug);
+
+    for branch in ["main", "feature"] {
+        create_report(
+            &server,
+            &user,
+            project_slug,
+            branch,
+            None,
+            minute,
+            100.0,
+            100.0,
+        )
+        .await;
+        minute += 1;
+    }
+
+    let query = "baseline_branch=main&candidate_branch=feature";
+    let (status, compare) = get_compare(&server, &user, project_slug, query).await;
+    assert_eq!(status, StatusCode::OK);
+    let compare = compare.expect("Missing compare");
+    assert_eq!(compare.baseline.reports.len(), 3);
+    assert_eq!(compare.candidate.reports.len(), 3);
+
+    let (status, compare) = get_compare(
+        &server,
+        &user,
+        project_slug,
+        &format!("{query}&spec={spec_uuid}"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let compare = compare.expect("Missing compare");
+    assert_eq!(compare.baseline.reports.len(), 2);
+    assert_eq!(compare.candidate.reports.len(), 2);
+    assert!(
+        compare
+            .results
+            .iter()
+            .all(|result| result.baseline.size == 2)
+    );
+
+    // Unknown spec
+    let (status, compare) = get_compare(
+        &server,
+        &user,
+        project_slug,
+        &format!("{query}&spec={}", SpecUuid::new()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(compare.is_none());
+}
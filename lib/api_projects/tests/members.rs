@@ -0,0 +1,466 @@
+#![expect(
+    unused_crate_dependencies,
+    clippy::tests_outside_test_module,
+    clippy::uninlined_format_args,
+    reason = "integration test file"
+)]
+//! Integration tests for project member endpoints.
+
+use bencher_api_tests::{TestProject, TestServer, TestUser};
+use bencher_json::{
+    JsonNewProjectMember, JsonProjectMember, JsonProjectMembers, JsonUpdateProjectMember,
+    project::ProjectRole,
+};
+use http::StatusCode;
+
+#[expect(clippy::expect_used, reason = "test helper")]
+fn make_private(server: &TestServer, project: &TestProject) {
+    use bencher_json::project::Visibility;
+    use bencher_schema::schema;
+    use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+
+    let mut conn = server.db_conn();
+    diesel::update(schema::project::table.filter(schema::project::uuid.eq(project.uuid)))
+        .set(schema::project::visibility.eq(Visibility::Private))
+        .execute(&mut conn)
+        .expect("Failed to update project visibility");
+}
+
+#[expect(clippy::expect_used, reason = "test helper")]
+async fn add_member(
+    server: &TestServer,
+    maintainer: &TestUser,
+    project: &TestProject,
+    member: &TestUser,
+    role: ProjectRole,
+) -> Result<JsonProjectMember, StatusCode> {
+    let project_slug: &str = project.slug.as_ref();
+    let body = JsonNewProjectMember {
+        email: member.email.clone(),
+        role,
+    };
+    let resp = server
+        .client
+        .post(server.api_url(&format!("/v0/projects/{}/members", project_slug)))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&maintainer.token),
+        )
+        .json(&body)
+        .send()
+        .await
+        .expect("Request failed");
+    if resp.status() == StatusCode::CREATED {
+        Ok(resp.json().await.expect("Failed to parse response"))
+    } else {
+        Err(resp.status())
+    }
+}
+
+#[expect(clippy::expect_used, reason = "test helper")]
+async fn get_project(server: &TestServer, user: &TestUser, project: &TestProject) -> StatusCode {
+    let project_slug: &str = project.slug.as_ref();
+    server
+        .client
+        .get(server.api_url(&format!("/v0/projects/{}", project_slug)))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .send()
+        .await
+        .expect("Request failed")
+        .status()
+}
+
+#[expect(clippy::expect_used, reason = "test helper")]
+async fn patch_project(server: &TestServer, user: &TestUser, project: &TestProject) -> StatusCode {
+    let project_slug: &str = project.slug.as_ref();
+    server
+        .client
+        .patch(server.api_url(&format!("/v0/projects/{}", project_slug)))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&serde_json::json!({ "name": "Renamed Project" }))
+        .send()
+        .await
+        .expect("Request failed")
+        .status()
+}
+
+#[expect(clippy::expect_used, reason = "test helper")]
+async fn post_testbed(server: &TestServer, user: &TestUser, project: &TestProject) -> StatusCode {
+    let project_slug: &str = project.slug.as_ref();
+    server
+        .client
+        .post(server.api_url(&format!("/v0/projects/{}/testbeds", project_slug)))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&serde_json::json!({ "name": "Member Testbed" }))
+        .send()
+        .await
+        .expect("Request failed")
+        .status()
+}
+
+// GET /v0/projects/{project}/members - creator is a maintainer
+#[tokio::test]
+async fn members_list_creator() {
+    let server = TestServer::new().await;
+    let user = server
+        .signup("Test User", "projmemberlist@example.com")
+        .await;
+    let org = server.create_org(&user, "Proj Member Org").await;
+    let project = server
+        .create_project(&user, &org, "Proj Member Project")
+        .await;
+
+    let project_slug: &str = project.slug.as_ref();
+    let resp = server
+        .client
+        .get(server.api_url(&format!("/v0/projects/{}/members", project_slug)))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let members: JsonProjectMembers = resp.json().await.expect("Failed to parse response");
+    assert_eq!(members.0.len(), 1);
+    let creator = members.0.first().expect("Creator should be a member");
+    assert_eq!(creator.uuid, user.uuid);
+    assert_eq!(creator.role, ProjectRole::Maintainer);
+}
+
+// POST /v0/projects/{project}/members - a viewer can read a private project and nothing else
+#[tokio::test]
+async fn members_viewer_private_project() {
+    let server = TestServer::new().await;
+    let owner = server.signup("Owner", "projmemberowner@example.com").await;
+    let contractor = server
+        .signup("Contractor", "projmembercontractor@example.com")
+        .await;
+    let org = server.create_org(&owner, "Proj Viewer Org").await;
+    let project = server
+        .create_project(&owner, &org, "Proj Viewer Project")
+        .await;
+    let other_project = server
+        .create_project(&owner, &org, "Proj Other Project")
+        .await;
+    make_private(&server, &project);
+    make_private(&server, &other_project);
+
+    assert_eq!(
+        get_project(&server, &contractor, &project).await,
+        StatusCode::NOT_FOUND
+    );
+
+    let member = add_member(&server, &owner, &project, &contractor, ProjectRole::Viewer)
+        .await
+        .expect("Failed to add member");
+    assert_eq!(member.uuid, contractor.uuid);
+    assert_eq!(member.role, ProjectRole::Viewer);
+
+    assert_eq!(
+        get_project(&server, &contractor, &project).await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        post_testbed(&server, &contractor, &project).await,
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+        patch_project(&server, &contractor, &project).await,
+        StatusCode::NOT_FOUND
+    );
+    // The rest of the organization stays hidden
+    assert_eq!(
+        get_project(&server, &contractor, &other_project).await,
+        StatusCode::NOT_FOUND
+    );
+    let org_slug: &str = org.slug.as_ref();
+    let resp = server
+        .client
+        .get(server.api_url(&format!("/v0/organizations/{}", org_slug)))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&contractor.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+    assert!(
+        resp.status() == StatusCode::NOT_FOUND || resp.status() == StatusCode::FORBIDDEN,
+        "Expected NOT_FOUND or FORBIDDEN for organization, got {}",
+        resp.status()
+    );
+
+    // A viewer cannot add other members
+    let other = server.signup("Other", "projmemberother@example.com").await;
+    let resp = add_member(&server, &contractor, &project, &other, ProjectRole::Viewer).await;
+    assert_eq!(resp.err(), Some(StatusCode::NOT_FOUND));
+}
+
+// PATCH /v0/projects/{project}/members/{user} - a developer can edit but not manage members
+#[tokio::test]
+async fn members_update_developer() {
+    let server = TestServer::new().await;
+    let owner = server
+        .signup("Owner", "projmemberdevowner@example.com")
+        .await;
+    let dev = server
+        .signup("Developer", "projmemberdev@example.com")
+        .await;
+    let org = server.create_org(&owner, "Proj Dev Org").await;
+    let project = server
+        .create_project(&owner, &org, "Proj Dev Project")
+        .await;
+
+    add_member(&server, &owner, &project, &dev, ProjectRole::Viewer)
+        .await
+        .expect("Failed to add member");
+    assert_eq!(
+        post_testbed(&server, &dev, &project).await,
+        StatusCode::FORBIDDEN
+    );
+
+    let project_slug: &str = project.slug.as_ref();
+    let dev_slug: &str = dev.slug.as_ref();
+    let resp = server
+        .client
+        .patch(server.api_url(&format!(
+            "/v0/projects/{}/members/{}",
+            project_slug, dev_slug
+        )))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&owner.token),
+        )
+        .json(&JsonUpdateProjectMember {
+            role: Some(ProjectRole::Developer),
+        })
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let member: JsonProjectMember = resp.json().await.expect("Failed to parse response");
+    assert_eq!(member.role, ProjectRole::Developer);
+
+    assert_eq!(
+        post_testbed(&server, &dev, &project).await,
+        StatusCode::CREATED
+    );
+    assert_eq!(patch_project(&server, &dev, &project).await, StatusCode::OK);
+    let other = server
+        .signup("Other", "projmemberdevother@example.com")
+        .await;
+    let resp = add_member(&server, &dev, &project, &other, ProjectRole::Viewer).await;
+    assert_eq!(resp.err(), Some(StatusCode::FORBIDDEN));
+}
+
+// DELETE /v0/projects/{project}/members/{user} - removing a member revokes access
+#[tokio::test]
+async fn members_remove() {
+    let server = TestServer::new().await;
+    let owner = server
+        .signup("Owner", "projmemberrmowner@example.com")
+        .await;
+    let viewer = server.signup("Viewer", "projmemberrm@example.com").await;
+    let org = server.create_org(&owner, "Proj Remove Org").await;
+    let project = server
+        .create_project(&owner, &org, "Proj Remove Project")
+        .await;
+    make_private(&server, &project);
+
+    add_member(&server, &owner, &project, &viewer, ProjectRole::Viewer)
+        .await
+        .expect("Failed to add member");
+    assert_eq!(
+        get_project(&server, &viewer, &project).await,
+        StatusCode::OK
+    );
+
+    let project_slug: &str = project.slug.as_ref();
+    let viewer_uuid = viewer.uuid;
+    let resp = server
+        .client
+        .delete(server.api_url(&format!(
+            "/v0/projects/{}/members/{}",
+            project_slug, viewer_uuid
+        )))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&owner.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    assert_eq!(
+        get_project(&server, &viewer, &project).await,
+        StatusCode::NOT_FOUND
+    );
+
+    // Removing them again is not found
+    let resp = server
+        .client
+        .delete(server.api_url(&format!(
+            "/v0/projects/{}/members/{}",
+            project_slug, viewer_uuid
+        )))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&owner.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+// POST /v0/projects/{project}/members - the user must exist and not already be a member
+#[tokio::test]
+async fn members_add_errors() {
+    let server = TestServer::new().await;
+    let owner = server
+        .signup("Owner", "projmembererrowner@example.com")
+        .await;
+    let member = server.signup("Member", "projmembererr@example.com").await;
+    let org = server.create_org(&owner, "Proj Error Org").await;
+    let project = server
+        .create_project(&owner, &org, "Proj Error Project")
+        .await;
+
+    let project_slug: &str = project.slug.as_ref();
+    let resp = server
+        .client
+        .post(server.api_url(&format!("/v0/projects/{}/members", project_slug)))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&owner.token),
+        )
+        .json(&serde_json::json!({
+            "email": "projmembernobody@example.com",
+            "role": "viewer"
+        }))
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    add_member(&server, &owner, &project, &member, ProjectRole::Viewer)
+        .await
+        .expect("Failed to add member");
+    let resp = add_member(&server, &owner, &project, &member, ProjectRole::Developer).await;
+    assert_eq!(resp.err(), Some(StatusCode::CONFLICT));
+}
+
+// PATCH/DELETE /v0/projects/{project}/members/{user} - the last maintainer cannot be demoted or removed
+#[tokio::test]
+async fn members_last_maintainer() {
+    let server = TestServer::new().await;
+    let owner = server
+        .signup("Owner", "projmemberlastowner@example.com")
+        .await;
+    let other = server
+        .signup("Other", "projmemberlastother@example.com")
+        .await;
+    let org = server.create_org(&owner, "Proj Last Org").await;
+    let project = server
+        .create_project(&owner, &org, "Proj Last Project")
+        .await;
+
+    let project_slug: &str = project.slug.as_ref();
+    let owner_uuid = owner.uuid;
+    let demote = |token: &str| {
+        server
+            .client
+            .patch(server.api_url(&format!(
+                "/v0/projects/{}/members/{}",
+                project_slug, owner_uuid
+            )))
+            .header(
+                bencher_json::AUTHORIZATION,
+                bencher_json::bearer_header(token),
+            )
+            .json(&JsonUpdateProjectMember {
+                role: Some(ProjectRole::Developer),
+            })
+    };
+    let remove = |token: &str| {
+        server
+            .client
+            .delete(server.api_url(&format!(
+                "/v0/projects/{}/members/{}",
+                project_slug, owner_uuid
+            )))
+            .header(
+                bencher_json::AUTHORIZATION,
+                bencher_json::bearer_header(token),
+            )
+    };
+
+    let resp = demote(&owner.token).send().await.expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+    let resp = remove(&owner.token).send().await.expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+    // Once there is another maintainer, the original one can step down
+    add_member(&server, &owner, &project, &other, ProjectRole::Maintainer)
+        .await
+        .expect("Failed to add member");
+    let resp = demote(&owner.token).send().await.expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let resp = remove(&other.token).send().await.expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+}
+
+// DELETE /v0/projects/{project}/members/{user} - concurrent removals cannot remove every maintainer
+#[tokio::test]
+async fn members_last_maintainer_concurrent() {
+    let server = TestServer::new().await;
+    let owner = server
+        .signup("Owner", "projmemberconcurrentowner@example.com")
+        .await;
+    let other = server
+        .signup("Other", "projmemberconcurrentother@example.com")
+        .await;
+    let org = server.create_org(&owner, "Proj Concurrent Org").await;
+    let project = server
+        .create_project(&owner, &org, "Proj Concurrent Project")
+        .await;
+    add_member(&server, &owner, &project, &other, ProjectRole::Maintainer)
+        .await
+        .expect("Failed to add member");
+
+    // Both maintainers try to step down at the same time
+    let project_slug: &str = project.slug.as_ref();
+    let remove = |user: &TestUser| {
+        server
+            .client
+            .delete(server.api_url(&format!(
+                "/v0/projects/{}/members/{}",
+                project_slug, user.uuid
+            )))
+            .header(
+                bencher_json::AUTHORIZATION,
+                bencher_json::bearer_header(&user.token),
+            )
+            .send()
+    };
+    let (owner_resp, other_resp) = tokio::join!(remove(&owner), remove(&other));
+    let mut statuses = [
+        owner_resp.expect("Request failed").status(),
+        other_resp.expect("Request failed").status(),
+    ];
+    statuses.sort();
+    assert_eq!(statuses, [StatusCode::NO_CONTENT, StatusCode::CONFLICT]);
+}
@@ -3046,3 +3046,97 @@ async fn perf_access_by_project_uuid() {
     let perf: JsonPerf = resp.json().await.expect("parse response");
     assert_eq!(perf.results.len(), 1);
 }
+
+// =============================================================================
+// Section: Perf image format and panels
+// =============================================================================
+
+async fn get_perf_img(
+    server: &TestServer,
+    token: &str,
+    url: &str,
+    accept: Option<&str>,
+) -> (String, Vec<u8>) {
+    let mut request = server.client.get(server.api_url(url)).header(
+        bencher_json::AUTHORIZATION,
+        bencher_json::bearer_header(token),
+    );
+    if let Some(accept) = accept {
+        request = request.header(http::header::ACCEPT, accept);
+    }
+    let resp = request.send().await.expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::OK, "Failed to get perf image");
+    let content_type = resp
+        .headers()
+        .get(http::header::CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .expect("Missing content type")
+        .to_owned();
+    let body = resp.bytes().await.expect("Failed to read body").to_vec();
+    (content_type, body)
+}
+
+#[tokio::test]
+async fn perf_img_format() {
+    let server = TestServer::new().await;
+    let user = server.signup("Test User", "perfimg@example.com").await;
+    let org = server.create_org(&user, "Perf Img Org").await;
+    let project = server.create_project(&user, &org, "Perf Img Project").await;
+
+    let project_id = get_project_id(&server, project.slug.as_ref());
+    let data = create_perf_data(&server, project_id);
+
+    let url = build_perf_url(
+        project.slug.as_ref(),
+        &[data.branch_uuid],
+        &[data.testbed_uuid],
+        &[data.benchmark_uuid],
+        &[data.measure_uuid],
+        "",
+    )
+    .replacen("/perf?", "/perf/img?", 1);
+    let token = &user.token;
+
+    // JPEG by default
+    let (content_type, body) = get_perf_img(&server, token, &url, None).await;
+    assert_eq!(content_type, "image/jpeg");
+    assert!(body.starts_with(&[0xFF, 0xD8]));
+
+    // SVG with the Accept header
+    let (content_type, body) =
+        get_perf_img(&server, token, &url, Some("image/svg+xml, image/*;q=0.8")).await;
+    assert_eq!(content_type, "image/svg+xml");
+    assert!(body.starts_with(b"<svg"));
+
+    // A browser image request does not prefer SVG over JPEG
+    let (content_type, _body) = get_perf_img(
+        &server,
+        token,
+        &url,
+        Some("image/avif,image/webp,image/apng,image/svg+xml,image/*,*/*;q=0.8"),
+    )
+    .await;
+    assert_eq!(content_type, "image/jpeg");
+
+    // The query parameter takes precedence over the Accept header
+    let (content_type, _body) = get_perf_img(
+        &server,
+        token,
+        &format!("{url}&format=jpeg"),
+        Some("image/svg+xml"),
+    )
+    .await;
+    assert_eq!(content_type, "image/jpeg");
+
+    // SVG with small multiples
+    let (content_type, body) = get_perf_img(
+        &server,
+        token,
+        &format!("{url}&format=svg&panels=benchmark"),
+        None,
+    )
+    .await;
+    assert_eq!(content_type, "image/svg+xml");
+    let svg = String::from_utf8(body).expect("Invalid SVG");
+    assert!(svg.contains(r#"width="1024" height="768""#));
+}
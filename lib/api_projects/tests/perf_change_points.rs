@@ -0,0 +1,328 @@
+#![cfg(feature = "plus")]
+#![expect(
+    unused_crate_dependencies,
+    clippy::expect_used,
+    clippy::tests_outside_test_module,
+    clippy::too_many_lines,
+    reason = "integration test file"
+)]
+//! Integration tests for the `/v0/projects/{project}/perf/change-points` endpoint.
+
+use bencher_api_tests::{
+    TestServer,
+    helpers::{base_timestamp, get_project_id},
+};
+use bencher_json::{
+    BenchmarkUuid, BranchUuid, JsonPerfChangePoints, MeasureUuid, MetricUuid, ReportBenchmarkUuid,
+    ReportUuid, TestbedUuid, VersionUuid,
+};
+use bencher_schema::schema;
+use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+use http::StatusCode;
+
+struct Series {
+    branch_uuid: BranchUuid,
+    testbed_uuid: TestbedUuid,
+    benchmark_uuid: BenchmarkUuid,
+    measure_uuid: MeasureUuid,
+    reports: Vec<ReportUuid>,
+}
+
+// Create a single branch/testbed/benchmark/measure series with one report per version.
+fn create_series(server: &TestServer, project_id: i32, values: &[f64]) -> Series {
+    let mut conn = server.db_conn();
+    let now = base_timestamp();
+
+    let testbed_uuid = TestbedUuid::new();
+    diesel::insert_into(schema::testbed::table)
+        .values((
+            schema::testbed::uuid.eq(&testbed_uuid),
+            schema::testbed::project_id.eq(project_id),
+            schema::testbed::name.eq(&format!("test-testbed-{testbed_uuid}")),
+            schema::testbed::slug.eq(&format!("test-testbed-{testbed_uuid}")),
+            schema::testbed::created.eq(&now),
+            schema::testbed::modified.eq(&now),
+        ))
+        .execute(&mut conn)
+        .expect("insert testbed");
+    let testbed_id: i32 = schema::testbed::table
+        .filter(schema::testbed::uuid.eq(&testbed_uuid))
+        .select(schema::testbed::id)
+        .first(&mut conn)
+        .expect("get testbed id");
+
+    let branch_uuid = BranchUuid::new();
+    diesel::insert_into(schema::branch::table)
+        .values((
+            schema::branch::uuid.eq(&branch_uuid),
+            schema::branch::project_id.eq(project_id),
+            schema::branch::name.eq(&format!("main-{branch_uuid}")),
+            schema::branch::slug.eq(&format!("main-{branch_uuid}")),
+            schema::branch::created.eq(&now),
+            schema::branch::modified.eq(&now),
+        ))
+        .execute(&mut conn)
+        .expect("insert branch");
+    let branch_id: i32 = schema::branch::table
+        .filter(schema::branch::uuid.eq(&branch_uuid))
+        .select(schema::branch::id)
+        .first(&mut conn)
+        .expect("get branch id");
+
+    let head_uuid = bencher_json::HeadUuid::new();
+    diesel::insert_into(schema::head::table)
+        .values((
+            schema::head::uuid.eq(&head_uuid),
+            schema::head::branch_id.eq(branch_id),
+            schema::head::created.eq(&now),
+        ))
+        .execute(&mut conn)
+        .expect("insert head");
+    let head_id: i32 = schema::head::table
+        .filter(schema::head::uuid.eq(&head_uuid))
+        .select(schema::head::id)
+        .first(&mut conn)
+        .expect("get head id");
+    diesel::update(schema::branch::table.filter(schema::branch::id.eq(branch_id)))
+        .set(schema::branch::head_id.eq(head_id))
+        .execute(&mut conn)
+        .expect("update branch head_id");
+
+    let benchmark_uuid = BenchmarkUuid::new();
+    diesel::insert_into(schema::benchmark::table)
+        .values((
+            schema::benchmark::uuid.eq(&benchmark_uuid),
+            schema::benchmark::project_id.eq(project_id),
+            schema::benchmark::name.eq(&format!("test-benchmark-{benchmark_uuid}")),
+            schema::benchmark::slug.eq(&format!("test-benchmark-{benchmark_uuid}")),
+            schema::benchmark::created.eq(&now),
+            schema::benchmark::modified.eq(&now),
+        ))
+        .execute(&mut conn)
+        .expect("insert benchmark");
+    let benchmark_id: i32 = schema::benchmark::table
+        .filter(schema::benchmark::uuid.eq(&benchmark_uuid))
+        .select(schema::benchmark::id)
+        .first(&mut conn)
+        .expect("get benchmark id");
+
+    let measure_uuid = MeasureUuid::new();
+    diesel::insert_into(schema::measure::table)
+        .values((
+            schema::measure::uuid.eq(&measure_uuid),
+            schema::measure::project_id.eq(project_id),
+            schema::measure::name.eq(&format!("test-measure-{measure_uuid}")),
+            schema::measure::slug.eq(&format!("test-measure-{measure_uuid}")),
+            schema::measure::units.eq("ns"),
+            schema::measure::created.eq(&now),
+            schema::measure::modified.eq(&now),
+        ))
+        .execute(&mut conn)
+        .expect("insert measure");
+    let measure_id: i32 = schema::measure::table
+        .filter(schema::measure::uuid.eq(&measure_uuid))
+        .select(schema::measure::id)
+        .first(&mut conn)
+        .expect("get measure id");
+
+    let mut reports = Vec::with_capacity(values.len());
+    for (number, value) in (1..).zip(values) {
+        let version_uuid = VersionUuid::new();
+        diesel::insert_into(schema::version::table)
+            .values((
+                schema::version::uuid.eq(&version_uuid),
+                schema::version::project_id.eq(project_id),
+                schema::version::number.eq(number),
+            ))
+            .execute(&mut conn)
+            .expect("insert version");
+        let version_id: i32 = schema::version::table
+            .filter(schema::version::uuid.eq(&version_uuid))
+            .select(schema::version::id)
+            .first(&mut conn)
+            .expect("get version id");
+        diesel::insert_into(schema::head_version::table)
+            .values((
+                schema::head_version::head_id.eq(head_id),
+                schema::head_version::version_id.eq(version_id),
+            ))
+            .execute(&mut conn)
+            .expect("insert head_version");
+
+        let report_uuid = ReportUuid::new();
+        diesel::insert_into(schema::report::table)
+            .values((
+                schema::report::uuid.eq(&report_uuid),
+                schema::report::project_id.eq(project_id),
+                schema::report::head_id.eq(head_id),
+                schema::report::version_id.eq(version_id),
+                schema::report::testbed_id.eq(testbed_id),
+                schema::report::adapter.eq(0),
+                schema::report::start_time.eq(&now),
+                schema::report::end_time.eq(&now),
+                schema::report::created.eq(&now),
+            ))
+            .execute(&mut conn)
+            .expect("insert report");
+        let report_id: i32 = schema::report::table
+            .filter(schema::report::uuid.eq(&report_uuid))
+            .select(schema::report::id)
+            .first(&mut conn)
+            .expect("get report id");
+
+        let report_benchmark_uuid = ReportBenchmarkUuid::new();
+        diesel::insert_into(schema::report_benchmark::table)
+            .values((
+                schema::report_benchmark::uuid.eq(&report_benchmark_uuid),
+                schema::report_benchmark::report_id.eq(report_id),
+                schema::report_benchmark::iteration.eq(0),
+                schema::report_benchmark::benchmark_id.eq(benchmark_id),
+            ))
+            .execute(&mut conn)
+            .expect("insert report_benchmark");
+        let report_benchmark_id: i32 = schema::report_benchmark::table
+            .filter(schema::report_benchmark::uuid.eq(&report_benchmark_uuid))
+            .select(schema::report_benchmark::id)
+            .first(&mut conn)
+            .expect("get report_benchmark id");
+
+        diesel::insert_into(schema::metric::table)
+            .values((
+                schema::metric::uuid.eq(&MetricUuid::new()),
+                schema::metric::report_benchmark_id.eq(report_benchmark_id),
+                schema::metric::measure_id.eq(measure_id),
+                schema::metric::value.eq(value),
+            ))
+            .execute(&mut conn)
+            .expect("insert metric");
+
+        reports.push(report_uuid);
+    }
+
+    Series {
+        branch_uuid,
+        testbed_uuid,
+        benchmark_uuid,
+        measure_uuid,
+        reports,
+    }
+}
+
+fn build_change_points_url(project_slug: &str, series: &Series, extra: &str) -> String {
+    format!(
+        "/v0/projects/{project_slug}/perf/change-points?branches={}&testbeds={}&benchmarks={}&measures={}{extra}",
+        series.branch_uuid, series.testbed_uuid, series.benchmark_uuid, series.measure_uuid,
+    )
+}
+
+#[tokio::test]
+async fn perf_change_points_step() {
+    let server = TestServer::new().await;
+    let user = server.signup("Test User", "perfcpstep@example.com").await;
+    let org = server.create_org(&user, "Perf CP Step Org").await;
+    let project = server
+        .create_project(&user, &org, "Perf CP Step Project")
+        .await;
+
+    let project_id = get_project_id(&server, project.slug.as_ref());
+    let series = create_series(
+        &server,
+        project_id,
+        &[
+            100.0, 101.0, 99.0, 100.0, 102.0, 200.0, 201.0, 199.0, 200.0, 202.0,
+        ],
+    );
+
+    let url = build_change_points_url(project.slug.as_ref(), &series, "");
+    let resp = server
+        .client
+        .get(server.api_url(&url))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let json: JsonPerfChangePoints = resp.json().await.expect("parse response");
+    assert_eq!(json.project.uuid, project.uuid);
+    assert_eq!(json.results.len(), 1);
+    let result = json.results.first().expect("result");
+    assert_eq!(result.benchmark.uuid, series.benchmark_uuid);
+    assert_eq!(result.change_points.len(), 1);
+    let change_point = result.change_points.first().expect("change point");
+    assert_eq!(change_point.version.number.0, 6);
+    assert_eq!(Some(&change_point.report), series.reports.get(5));
+    assert!(change_point.before < change_point.after);
+}
+
+#[tokio::test]
+async fn perf_change_points_flat() {
+    let server = TestServer::new().await;
+    let user = server.signup("Test User", "perfcpflat@example.com").await;
+    let org = server.create_org(&user, "Perf CP Flat Org").await;
+    let project = server
+        .create_project(&user, &org, "Perf CP Flat Project")
+        .await;
+
+    let project_id = get_project_id(&server, project.slug.as_ref());
+    let series = create_series(
+        &server,
+        project_id,
+        &[100.0, 101.0, 99.0, 100.0, 102.0, 98.0, 101.0, 99.0],
+    );
+
+    let url = build_change_points_url(project.slug.as_ref(), &series, "");
+    let resp = server
+        .client
+        .get(server.api_url(&url))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let json: JsonPerfChangePoints = resp.json().await.expect("parse response");
+    assert_eq!(json.results.len(), 1);
+    assert!(
+        json.results
+            .first()
+            .expect("result")
+            .change_points
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn perf_change_points_invalid_penalty() {
+    let server = TestServer::new().await;
+    let user = server
+        .signup("Test User", "perfcppenalty@example.com")
+        .await;
+    let org = server.create_org(&user, "Perf CP Penalty Org").await;
+    let project = server
+        .create_project(&user, &org, "Perf CP Penalty Project")
+        .await;
+
+    let project_id = get_project_id(&server, project.slug.as_ref());
+    let series = create_series(&server, project_id, &[100.0, 101.0, 200.0, 201.0]);
+
+    let url = build_change_points_url(project.slug.as_ref(), &series, "&penalty=-1");
+    let resp = server
+        .client
+        .get(server.api_url(&url))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,183 @@
+#![expect(
+    unused_crate_dependencies,
+    clippy::expect_used,
+    clippy::missing_assert_message,
+    clippy::tests_outside_test_module,
+    clippy::too_many_lines,
+    reason = "integration test file"
+)]
+//! Integration tests for project retention endpoints.
+
+use bencher_api_tests::{TestServer, TestUser};
+use bencher_json::{JsonReports, JsonRetention, JsonRetentionReport};
+use http::StatusCode;
+
+async fn post_report(
+    server: &TestServer,
+    user: &TestUser,
+    project_slug: &str,
+    branch: &str,
+    minute: u32,
+) {
+    let resp = server
+        .client
+        .post(server.api_url(&format!("/v0/projects/{project_slug}/reports")))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&serde_json::json!({
+            "branch": branch,
+            "testbed": "localhost",
+            "start_time": format!("2024-01-01T00:{minute:02}:00Z"),
+            "end_time": format!("2024-01-01T00:{minute:02}:30Z"),
+            "results": ["{\"bench\": {\"latency\": {\"value\": 100.0}}}"]
+        }))
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::CREATED);
+}
+
+async fn dry_run(server: &TestServer, user: &TestUser, project_slug: &str) -> JsonRetentionReport {
+    let resp = server
+        .client
+        .get(server.api_url(&format!("/v0/projects/{project_slug}/retention/dry-run")))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    resp.json().await.expect("Failed to parse response")
+}
+
+// GET and PUT /v0/projects/{project}/retention
+// GET /v0/projects/{project}/retention/dry-run
+#[tokio::test]
+async fn retention_policy_dry_run() {
+    let server = TestServer::new().await;
+    let user = server
+        .signup("Test User", "retentiondryrun@example.com")
+        .await;
+    let org = server.create_org(&user, "Retention Org").await;
+    let project = server
+        .create_project(&user, &org, "Retention Project")
+        .await;
+    let project_slug: &str = project.slug.as_ref();
+
+    post_report(&server, &user, project_slug, "main", 0).await;
+    post_report(&server, &user, project_slug, "feature", 1).await;
+    post_report(&server, &user, project_slug, "feature", 2).await;
+
+    let retention_url = server.api_url(&format!("/v0/projects/{project_slug}/retention"));
+    let resp = server
+        .client
+        .get(&retention_url)
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let retention: JsonRetention = resp.json().await.expect("Failed to parse response");
+    assert_eq!(retention.report_days, None);
+    assert_eq!(retention.modified, None);
+
+    // Without a policy, nothing would be deleted.
+    let report = dry_run(&server, &user, project_slug).await;
+    assert!(report.dry_run);
+    assert_eq!(report.reports, 0);
+
+    let resp = server
+        .client
+        .put(&retention_url)
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&serde_json::json!({ "report_days": 0, "default_branch": "main" }))
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    // Deleting old reports requires a default branch to keep.
+    let resp = server
+        .client
+        .put(&retention_url)
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&serde_json::json!({ "report_days": 30 }))
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let resp = server
+        .client
+        .put(&retention_url)
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&serde_json::json!({
+            "report_days": 30,
+            "default_branch": "main",
+            "downsample_days": 30
+        }))
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let retention: JsonRetention = resp.json().await.expect("Failed to parse response");
+    assert_eq!(retention.report_days, Some(30));
+    assert_eq!(retention.downsample_days, Some(30));
+    assert!(retention.default_branch.is_some());
+
+    let report = dry_run(&server, &user, project_slug).await;
+    assert_eq!(report.reports, 2);
+    assert_eq!(report.report_metrics, 2);
+    // The only remaining metric for its day is kept as the median.
+    assert_eq!(report.downsampled_metrics, 0);
+
+    // A dry run deletes nothing.
+    let resp = server
+        .client
+        .get(server.api_url(&format!("/v0/projects/{project_slug}/reports")))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let reports: JsonReports = resp.json().await.expect("Failed to parse response");
+    assert_eq!(reports.0.len(), 3);
+
+    // Clearing the policy keeps everything.
+    let resp = server
+        .client
+        .put(&retention_url)
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&serde_json::json!({}))
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let retention: JsonRetention = resp.json().await.expect("Failed to parse response");
+    assert_eq!(retention.report_days, None);
+    assert_eq!(retention.default_branch, None);
+    assert!(retention.modified.is_some());
+    assert_eq!(dry_run(&server, &user, project_slug).await.reports, 0);
+}
@@ -11,7 +11,10 @@
 //! Integration tests for project threshold endpoints.
 
 use bencher_api_tests::{TestServer, TestUser};
-use bencher_json::{JsonThreshold, JsonThresholds, ModelUuid};
+use bencher_json::{
+    JsonAlerts, JsonPerf, JsonReport, JsonThreshold, JsonThresholds, ModelUuid,
+    project::alert::AlertStatus,
+};
 use http::StatusCode;
 
 // GET /v0/projects/{project}/thresholds - list thresholds
@@ -589,3 +592,385 @@ async fn create_threshold_iqr_max_sample_size_one_rejected() {
         resp.status()
     );
 }
+
+#[tokio::test]
+async fn create_threshold_confirm_outliers() {
+    let server = TestServer::new().await;
+    let user = server.signup("Test User", "confirm@example.com").await;
+    let project_slug = create_project_with_branch_testbed_measure(
+        &server,
+        &user,
+        "Confirm Org",
+        "Confirm Project",
+    )
+    .await;
+
+    let body = serde_json::json!({
+        "branch": "ssize-branch",
+        "testbed": "ssize-testbed",
+        "measure": "latency",
+        "test": "t_test",
+        "upper_boundary": 0.99,
+        "confirm_outliers": 2,
+        "confirm_reports": 3,
+    });
+    let resp = server
+        .client
+        .post(server.api_url(&format!("/v0/projects/{project_slug}/thresholds")))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&body)
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let threshold: JsonThreshold = resp.json().await.expect("Failed to parse threshold");
+    let model = threshold.model.expect("threshold should have a model");
+    assert_eq!(model.confirm_outliers.map(u32::from), Some(2));
+    assert_eq!(model.confirm_reports.map(u32::from), Some(3));
+}
+
+#[tokio::test]
+async fn create_threshold_confirm_outliers_greater_than_reports_rejected() {
+    let server = TestServer::new().await;
+    let user = server.signup("Test User", "confirm-bad@example.com").await;
+    let project_slug = create_project_with_branch_testbed_measure(
+        &server,
+        &user,
+        "Confirm Bad Org",
+        "Confirm Bad Project",
+    )
+    .await;
+
+    let body = serde_json::json!({
+        "branch": "ssize-branch",
+        "testbed": "ssize-testbed",
+        "measure": "latency",
+        "test": "t_test",
+        "upper_boundary": 0.99,
+        "confirm_outliers": 3,
+        "confirm_reports": 2,
+    });
+    let resp = server
+        .client
+        .post(server.api_url(&format!("/v0/projects/{project_slug}/thresholds")))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&body)
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+// An alert is resolved once enough later reports are back within the boundary
+#[tokio::test]
+async fn threshold_resolve_reports_resolves_alert() {
+    let server = TestServer::new().await;
+    let user = server.signup("Test User", "resolve@example.com").await;
+    let project_slug = create_project_with_branch_testbed_measure(
+        &server,
+        &user,
+        "Resolve Org",
+        "Resolve Project",
+    )
+    .await;
+
+    let body = serde_json::json!({
+        "branch": "ssize-branch",
+        "testbed": "ssize-testbed",
+        "measure": "latency",
+        "test": "static",
+        "upper_boundary": 150.0,
+        "resolve_reports": 2,
+    });
+    let resp = server
+        .client
+        .post(server.api_url(&format!("/v0/projects/{project_slug}/thresholds")))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&body)
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let threshold: JsonThreshold = resp.json().await.expect("Failed to parse threshold");
+    let model = threshold.model.expect("threshold should have a model");
+    assert_eq!(model.resolve_reports.map(u32::from), Some(2));
+
+    let mut reports = Vec::new();
+    // The first report is the baseline and the second report is an outlier.
+    for (minute, value) in [(0, 100.0), (1, 200.0), (2, 100.0), (3, 100.0)] {
+        let resp = server
+            .client
+            .post(server.api_url(&format!("/v0/projects/{project_slug}/reports")))
+            .header(
+                bencher_json::AUTHORIZATION,
+                bencher_json::bearer_header(&user.token),
+            )
+            .json(&serde_json::json!({
+                "branch": "ssize-branch",
+                "testbed": "ssize-testbed",
+                "start_time": format!("2024-01-01T00:{minute:02}:00Z"),
+                "end_time": format!("2024-01-01T00:{minute:02}:30Z"),
+                "results": [format!("{{\"bench\": {{\"latency\": {{\"value\": {value}}}}}}}")]
+            }))
+            .send()
+            .await
+            .expect("Request failed");
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let report: JsonReport = resp.json().await.expect("Failed to parse report");
+        reports.push(report);
+
+        let resp = server
+            .client
+            .get(server.api_url(&format!("/v0/projects/{project_slug}/alerts")))
+            .header(
+                bencher_json::AUTHORIZATION,
+                bencher_json::bearer_header(&user.token),
+            )
+            .send()
+            .await
+            .expect("Request failed");
+        assert_eq!(resp.status(), StatusCode::OK);
+        let alerts: JsonAlerts = resp.json().await.expect("Failed to parse alerts");
+        if reports.len() < 2 {
+            assert!(alerts.0.is_empty());
+            continue;
+        }
+        assert_eq!(alerts.0.len(), 1);
+        let alert = alerts.0.first().expect("Missing alert");
+        if reports.len() < 4 {
+            assert_eq!(alert.status, AlertStatus::Active);
+            assert_eq!(alert.resolved_report, None);
+        } else {
+            assert_eq!(alert.status, AlertStatus::Resolved);
+            assert_eq!(
+                alert.resolved_report,
+                reports.last().map(|report| report.uuid)
+            );
+        }
+    }
+}
+
+// Reports from a noisy host are excluded from the historical metrics
+#[tokio::test]
+async fn threshold_excludes_noisy_reports() {
+    let server = TestServer::new().await;
+    let user = server.signup("Test User", "noisy@example.com").await;
+    let project_slug =
+        create_project_with_branch_testbed_measure(&server, &user, "Noisy Org", "Noisy Project")
+            .await;
+
+    let body = serde_json::json!({
+        "branch": "ssize-branch",
+        "testbed": "ssize-testbed",
+        "measure": "latency",
+        "test": "percentage",
+        "lower_boundary": 0.1,
+        "upper_boundary": 0.1,
+    });
+    let resp = server
+        .client
+        .post(server.api_url(&format!("/v0/projects/{project_slug}/thresholds")))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&body)
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::CREATED);
+
+    // The second report is from a noisy host, so it is not part of the baseline for the third report.
+    for (minute, value, noisy) in [(0, 100.0, false), (1, 1000.0, true), (2, 105.0, false)] {
+        let resp = server
+            .client
+            .post(server.api_url(&format!("/v0/projects/{project_slug}/reports")))
+            .header(
+                bencher_json::AUTHORIZATION,
+                bencher_json::bearer_header(&user.token),
+            )
+            .json(&serde_json::json!({
+                "branch": "ssize-branch",
+                "testbed": "ssize-testbed",
+                "start_time": format!("2024-01-01T00:{minute:02}:00Z"),
+                "end_time": format!("2024-01-01T00:{minute:02}:30Z"),
+                "results": [format!("{{\"bench\": {{\"latency\": {{\"value\": {value}}}}}}}")],
+                "noise": {
+                    "results": "{\"bencher::noise::composite\": {\"noise-score\": {\"value\": 42.0}}}",
+                    "noisy": noisy,
+                },
+            }))
+            .send()
+            .await
+            .expect("Request failed");
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let report: JsonReport = resp.json().await.expect("Failed to parse report");
+        assert_eq!(report.noisy, noisy);
+
+        let results = report.results.expect("Missing results");
+        let iteration = results.first().expect("Missing iteration");
+        let benchmarks = iteration
+            .iter()
+            .map(|result| result.benchmark.name.as_ref().to_owned())
+            .collect::<Vec<String>>();
+        assert!(benchmarks.contains(&"bench".to_owned()));
+        assert!(benchmarks.contains(&"bencher::noise::composite".to_owned()));
+
+        let alerts = report.alerts.expect("Missing alerts");
+        if minute == 1 {
+            assert_eq!(alerts.len(), 1);
+        } else {
+            assert!(alerts.is_empty());
+        }
+    }
+}
+
+// A version window limits the baseline to the most recent branch versions
+#[tokio::test]
+async fn threshold_version_window() {
+    let server = TestServer::new().await;
+    let user = server
+        .signup("Test User", "versionwindow@example.com")
+        .await;
+    let project_slug = create_project_with_branch_testbed_measure(
+        &server,
+        &user,
+        "Version Window Org",
+        "Version Window Project",
+    )
+    .await;
+
+    let body = serde_json::json!({
+        "branch": "ssize-branch",
+        "testbed": "ssize-testbed",
+        "measure": "latency",
+        "test": "percentage",
+        "lower_boundary": 0.1,
+        "upper_boundary": 0.1,
+        "version_window": 2,
+    });
+    let resp = server
+        .client
+        .post(server.api_url(&format!("/v0/projects/{project_slug}/thresholds")))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&body)
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let threshold: JsonThreshold = resp.json().await.expect("Failed to parse threshold");
+    let model = threshold.model.expect("Missing model");
+    assert_eq!(model.version_window.map(u32::from), Some(2));
+    assert!(model.window.is_none());
+
+    // Each report is a new version, so the first report is outside of the window for the third report.
+    let mut last_report = None;
+    for (minute, value) in [(0, 1000.0), (1, 100.0), (2, 105.0)] {
+        let resp = server
+            .client
+            .post(server.api_url(&format!("/v0/projects/{project_slug}/reports")))
+            .header(
+                bencher_json::AUTHORIZATION,
+                bencher_json::bearer_header(&user.token),
+            )
+            .json(&serde_json::json!({
+                "branch": "ssize-branch",
+                "testbed": "ssize-testbed",
+                "start_time": format!("2024-01-01T00:{minute:02}:00Z"),
+                "end_time": format!("2024-01-01T00:{minute:02}:30Z"),
+                "results": [format!("{{\"bench\": {{\"latency\": {{\"value\": {value}}}}}}}")],
+            }))
+            .send()
+            .await
+            .expect("Request failed");
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let report: JsonReport = resp.json().await.expect("Failed to parse report");
+        let alerts = report.alerts.as_ref().expect("Missing alerts");
+        if minute == 1 {
+            assert_eq!(alerts.len(), 1);
+        } else {
+            assert!(alerts.is_empty());
+        }
+        last_report = Some(report);
+    }
+
+    // The perf query honors the same window of versions
+    let report = last_report.expect("Missing report");
+    let results = report.results.expect("Missing results");
+    let result = results
+        .first()
+        .and_then(|iteration| iteration.first())
+        .expect("Missing result");
+    let measure = result.measures.first().expect("Missing measure");
+    let perf_url = |extra: &str| {
+        format!(
+            "/v0/projects/{project_slug}/perf?branches={}&testbeds={}&benchmarks={}&measures={}{extra}",
+            report.branch.uuid, report.testbed.uuid, result.benchmark.uuid, measure.measure.uuid,
+        )
+    };
+    for (extra, count) in [("", 3), ("&versions=2", 2), ("&versions=10", 3)] {
+        let resp = server
+            .client
+            .get(server.api_url(&perf_url(extra)))
+            .header(
+                bencher_json::AUTHORIZATION,
+                bencher_json::bearer_header(&user.token),
+            )
+            .send()
+            .await
+            .expect("Request failed");
+        assert_eq!(resp.status(), StatusCode::OK);
+        let perf: JsonPerf = resp.json().await.expect("Failed to parse perf");
+        let metrics = &perf.results.first().expect("Missing perf results").metrics;
+        assert_eq!(metrics.len(), count, "versions query: {extra}");
+    }
+}
+
+#[tokio::test]
+async fn create_threshold_version_window_with_window_rejected() {
+    let server = TestServer::new().await;
+    let user = server
+        .signup("Test User", "versionwindowrej@example.com")
+        .await;
+    let project_slug = create_project_with_branch_testbed_measure(
+        &server,
+        &user,
+        "Version Window Reject Org",
+        "Version Window Reject Project",
+    )
+    .await;
+
+    let body = serde_json::json!({
+        "branch": "ssize-branch",
+        "testbed": "ssize-testbed",
+        "measure": "latency",
+        "test": "t_test",
+        "upper_boundary": 0.99,
+        "window": 2_419_200,
+        "version_window": 30,
+    });
+    let resp = server
+        .client
+        .post(server.api_url(&format!("/v0/projects/{project_slug}/thresholds")))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&body)
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
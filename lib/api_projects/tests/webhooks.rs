@@ -0,0 +1,473 @@
+#![expect(
+    unused_crate_dependencies,
+    clippy::expect_used,
+    clippy::tests_outside_test_module,
+    clippy::uninlined_format_args,
+    reason = "integration test file"
+)]
+//! Integration tests for project webhook endpoints.
+
+use std::time::Duration;
+
+use bencher_api_tests::{TestServer, TestUser};
+use bencher_json::{
+    JsonWebhook, JsonWebhookCreated, JsonWebhookDeliveries, JsonWebhooks, WebhookEvent,
+    project::webhook::{
+        WEBHOOK_DELIVERY_HEADER, WEBHOOK_EVENT_HEADER, WEBHOOK_SIGNATURE_HEADER,
+        WebhookDeliveryStatus,
+    },
+};
+use hmac::{Hmac, KeyInit as _, Mac as _};
+use http::StatusCode;
+use sha2::Sha256;
+use tokio::io::{AsyncReadExt as _, AsyncWriteExt as _};
+use tokio::net::TcpListener;
+
+async fn create_webhook(
+    server: &TestServer,
+    user: &TestUser,
+    project_slug: &str,
+    body: serde_json::Value,
+) -> (StatusCode, Option<JsonWebhookCreated>) {
+    let resp = server
+        .client
+        .post(server.api_url(&format!("/v0/projects/{}/webhooks", project_slug)))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&body)
+        .send()
+        .await
+        .expect("Request failed");
+    let status = resp.status();
+    if status == StatusCode::CREATED {
+        (
+            status,
+            Some(resp.json().await.expect("Failed to parse response")),
+        )
+    } else {
+        (status, None)
+    }
+}
+
+// POST, GET, PATCH, and DELETE /v0/projects/{project}/webhooks
+#[tokio::test]
+async fn webhooks_crud() {
+    let server = TestServer::new().await;
+    let user = server.signup("Test User", "webhookcrud@example.com").await;
+    let org = server.create_org(&user, "Webhook Org").await;
+    let project = server.create_project(&user, &org, "Webhook Project").await;
+    let project_slug: &str = project.slug.as_ref();
+
+    let (status, created) = create_webhook(
+        &server,
+        &user,
+        project_slug,
+        serde_json::json!({ "url": "https://93.184.216.34/hook" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let created = created.expect("Missing webhook");
+    assert!(created.secret.as_ref().starts_with("bencher_whsec_"));
+    assert_eq!(created.events, WebhookEvent::ALL.to_vec());
+    assert!(created.enabled);
+
+    let resp = server
+        .client
+        .get(server.api_url(&format!("/v0/projects/{}/webhooks", project_slug)))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let webhooks: JsonWebhooks = resp.json().await.expect("Failed to parse response");
+    assert_eq!(webhooks.0.len(), 1);
+    assert_eq!(webhooks.0[0].uuid, created.uuid);
+
+    let webhook_url = server.api_url(&format!(
+        "/v0/projects/{}/webhooks/{}",
+        project_slug, created.uuid
+    ));
+    let resp = server
+        .client
+        .patch(&webhook_url)
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&serde_json::json!({
+            "events": ["report.created"],
+            "enabled": false
+        }))
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let updated: JsonWebhook = resp.json().await.expect("Failed to parse response");
+    assert_eq!(updated.events, vec![WebhookEvent::ReportCreated]);
+    assert!(!updated.enabled);
+    assert_eq!(updated.url, created.url);
+
+    // The URL cannot be changed to a private address
+    let resp = server
+        .client
+        .patch(&webhook_url)
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&serde_json::json!({ "url": "https://10.0.0.1/hook" }))
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let resp = server
+        .client
+        .delete(&webhook_url)
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    let resp = server
+        .client
+        .get(&webhook_url)
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+// POST /v0/projects/{project}/webhooks - invalid URL scheme, non-public host, or no events
+#[tokio::test]
+async fn webhooks_create_invalid() {
+    let server = TestServer::new().await;
+    let user = server
+        .signup("Test User", "webhookinvalid@example.com")
+        .await;
+    let org = server.create_org(&user, "Webhook Invalid Org").await;
+    let project = server
+        .create_project(&user, &org, "Webhook Invalid Project")
+        .await;
+    let project_slug: &str = project.slug.as_ref();
+
+    for url in [
+        "ftp://93.184.216.34/hook",
+        "http://93.184.216.34/hook",
+        "https://127.0.0.1/hook",
+        "https://localhost/hook",
+        "https://[::1]/hook",
+        "https://192.168.1.1/hook",
+        "https://169.254.169.254/latest/meta-data",
+    ] {
+        let (status, _) = create_webhook(
+            &server,
+            &user,
+            project_slug,
+            serde_json::json!({ "url": url }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{url}");
+    }
+
+    let (status, _) = create_webhook(
+        &server,
+        &user,
+        project_slug,
+        serde_json::json!({ "url": "https://93.184.216.34/hook", "events": [] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+// GET /v0/projects/{project}/webhooks - requires manage permission
+#[tokio::test]
+async fn webhooks_list_forbidden() {
+    let server = TestServer::new().await;
+    let user = server.signup("Test User", "webhookowner@example.com").await;
+    let other = server
+        .signup("Other User", "webhookother@example.com")
+        .await;
+    let org = server.create_org(&user, "Webhook Owner Org").await;
+    let project = server
+        .create_project(&user, &org, "Webhook Owner Project")
+        .await;
+    let project_slug: &str = project.slug.as_ref();
+
+    let resp = server
+        .client
+        .get(server.api_url(&format!("/v0/projects/{}/webhooks", project_slug)))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&other.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+    assert!(resp.status().is_client_error());
+}
+
+/// Accept a single HTTP request, respond with `200 OK`, and return its head and body.
+async fn receive_request(listener: &TcpListener) -> (String, Vec<u8>) {
+    let (mut stream, _) = listener.accept().await.expect("Failed to accept");
+    let mut buf = Vec::new();
+    let mut chunk = [0; 4096];
+    let (head, body_start) = loop {
+        let n = stream.read(&mut chunk).await.expect("Failed to read");
+        assert!(n > 0, "Connection closed before headers");
+        buf.extend_from_slice(chunk.get(..n).expect("Invalid read length"));
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            let head = buf.get(..pos).expect("Invalid header length");
+            break (String::from_utf8_lossy(head).into_owned(), pos + 4);
+        }
+    };
+    let content_length: usize = head
+        .lines()
+        .find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.eq_ignore_ascii_case("content-length")
+                .then(|| value.trim().parse().expect("Invalid content length"))
+        })
+        .expect("Missing content length");
+    let mut body = buf.get(body_start..).expect("Invalid body start").to_vec();
+    while body.len() < content_length {
+        let n = stream.read(&mut chunk).await.expect("Failed to read");
+        assert!(n > 0, "Connection closed before body");
+        body.extend_from_slice(chunk.get(..n).expect("Invalid read length"));
+    }
+    stream
+        .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+        .await
+        .expect("Failed to write");
+    (head, body)
+}
+
+fn header<'h>(head: &'h str, name: &str) -> Option<&'h str> {
+    head.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        key.eq_ignore_ascii_case(name).then(|| value.trim())
+    })
+}
+
+// A new report is delivered, signed, and logged
+#[tokio::test]
+async fn webhooks_report_created_delivery() {
+    let server = TestServer::new_with_insecure_webhooks().await;
+    let user = server
+        .signup("Test User", "webhookdelivery@example.com")
+        .await;
+    let org = server.create_org(&user, "Webhook Delivery Org").await;
+    let project = server
+        .create_project(&user, &org, "Webhook Delivery Project")
+        .await;
+    let project_slug: &str = project.slug.as_ref();
+
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind");
+    let addr = listener.local_addr().expect("Failed to get address");
+
+    let (status, webhook) = create_webhook(
+        &server,
+        &user,
+        project_slug,
+        serde_json::json!({
+            "url": format!("http://{addr}/hook"),
+            "events": ["report.created"]
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let webhook = webhook.expect("Missing webhook");
+
+    let resp = server
+        .client
+        .post(server.api_url(&format!("/v0/projects/{}/reports", project_slug)))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&serde_json::json!({
+            "branch": "main",
+            "testbed": "localhost",
+            "start_time": "2024-01-01T00:00:00Z",
+            "end_time": "2024-01-01T00:01:00Z",
+            "results": ["{\"bench\": {\"latency\": {\"value\": 100.0}}}"]
+        }))
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::CREATED);
+
+    let (head, body) = tokio::time::timeout(Duration::from_secs(30), receive_request(&listener))
+        .await
+        .expect("Timed out waiting for webhook");
+    assert!(head.starts_with("POST /hook "));
+    assert_eq!(header(&head, WEBHOOK_EVENT_HEADER), Some("report.created"));
+
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(webhook.secret.as_ref().as_bytes()).expect("Invalid key");
+    mac.update(&body);
+    let signature = format!("sha256={}", hex::encode(mac.finalize().into_bytes()));
+    assert_eq!(
+        header(&head, WEBHOOK_SIGNATURE_HEADER),
+        Some(signature.as_str())
+    );
+
+    let payload: serde_json::Value = serde_json::from_slice(&body).expect("Invalid payload");
+    assert_eq!(payload["event"], "report.created");
+    assert_eq!(payload["webhook"], webhook.uuid.to_string());
+    assert_eq!(
+        payload["delivery"].as_str(),
+        header(&head, WEBHOOK_DELIVERY_HEADER)
+    );
+
+    let deliveries_url = server.api_url(&format!(
+        "/v0/projects/{}/webhooks/{}/deliveries",
+        project_slug, webhook.uuid
+    ));
+    let mut status = WebhookDeliveryStatus::Pending;
+    for _ in 0..50 {
+        let resp = server
+            .client
+            .get(&deliveries_url)
+            .header(
+                bencher_json::AUTHORIZATION,
+                bencher_json::bearer_header(&user.token),
+            )
+            .send()
+            .await
+            .expect("Request failed");
+        assert_eq!(resp.status(), StatusCode::OK);
+        let deliveries: JsonWebhookDeliveries =
+            resp.json().await.expect("Failed to parse response");
+        assert_eq!(deliveries.0.len(), 1);
+        let delivery = &deliveries.0[0];
+        assert_eq!(delivery.event, WebhookEvent::ReportCreated);
+        status = delivery.status;
+        if status != WebhookDeliveryStatus::Pending {
+            assert_eq!(delivery.attempts, 1);
+            assert_eq!(delivery.response_status, Some(200));
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(status, WebhookDeliveryStatus::Succeeded);
+}
+
+async fn get_deliveries(
+    server: &TestServer,
+    user: &TestUser,
+    project_slug: &str,
+    webhook: &JsonWebhookCreated,
+) -> JsonWebhookDeliveries {
+    let resp = server
+        .client
+        .get(server.api_url(&format!(
+            "/v0/projects/{}/webhooks/{}/deliveries",
+            project_slug, webhook.uuid
+        )))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::OK, "Failed to get deliveries");
+    resp.json().await.expect("Failed to parse response")
+}
+
+// Pending deliveries are resumed at startup, and stale ones are expired
+#[tokio::test]
+async fn webhooks_resume_pending() {
+    use bencher_schema::{
+        model::project::webhook::{QueryWebhook, WebhookDispatch, delivery::InsertWebhookDelivery},
+        schema,
+    };
+    use diesel::RunQueryDsl as _;
+
+    let server = TestServer::new_with_insecure_webhooks().await;
+    let user = server
+        .signup("Test User", "webhookresume@example.com")
+        .await;
+    let org = server.create_org(&user, "Webhook Resume Org").await;
+    let project = server
+        .create_project(&user, &org, "Webhook Resume Project")
+        .await;
+    let project_slug: &str = project.slug.as_ref();
+
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind");
+    let addr = listener.local_addr().expect("Failed to get address");
+    let (status, webhook) = create_webhook(
+        &server,
+        &user,
+        project_slug,
+        serde_json::json!({ "url": format!("http://{addr}/hook") }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let webhook = webhook.expect("Missing webhook");
+
+    // Record one recent and one stale pending delivery, as if the server stopped mid-retry
+    let mut conn = server.db_conn();
+    let webhook_id = QueryWebhook::get_id(&mut conn, webhook.uuid).expect("Missing webhook");
+    let now = bencher_json::DateTime::now();
+    let recent = InsertWebhookDelivery::new(
+        bencher_json::WebhookDeliveryUuid::new(),
+        webhook_id,
+        WebhookEvent::ReportCreated,
+        "{}".to_owned(),
+        now,
+    );
+    let mut stale = InsertWebhookDelivery::new(
+        bencher_json::WebhookDeliveryUuid::new(),
+        webhook_id,
+        WebhookEvent::ReportCreated,
+        "{}".to_owned(),
+        now,
+    );
+    stale.created = bencher_json::DateTime::try_from(now.timestamp() - 7 * 24 * 60 * 60)
+        .expect("Invalid date time");
+    diesel::insert_into(schema::webhook_delivery::table)
+        .values(&[recent, stale])
+        .execute(&mut conn)
+        .expect("Failed to insert deliveries");
+
+    let log = slog::Logger::root(slog::Discard, slog::o!());
+    WebhookDispatch::resume(&log, server.context()).await;
+
+    let (head, body) = tokio::time::timeout(Duration::from_secs(30), receive_request(&listener))
+        .await
+        .expect("Timed out waiting for webhook");
+    assert!(head.starts_with("POST /hook "));
+    assert_eq!(body, b"{}");
+
+    let mut statuses = Vec::new();
+    for _ in 0..50 {
+        let deliveries = get_deliveries(&server, &user, project_slug, &webhook).await;
+        statuses = deliveries.0.iter().map(|d| d.status).collect();
+        if !statuses.contains(&WebhookDeliveryStatus::Pending) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(statuses.len(), 2);
+    assert!(statuses.contains(&WebhookDeliveryStatus::Succeeded));
+    assert!(statuses.contains(&WebhookDeliveryStatus::Failed));
+}
@@ -87,25 +87,25 @@ pub async fn post_run(
 ) -> Result<JsonReport, HttpError> {
     match api_actor {
         ApiActor::ProjectKey(project_key_actor) => {
-            post_inner_project_key(
+            Box::pin(post_inner_project_key(
                 log,
                 context,
                 project_key_actor,
                 #[cfg(feature = "plus")]
                 headers,
                 json_run,
-            )
+            ))
             .await
         },
         ApiActor::Public(public_user) => {
-            post_inner(
+            Box::pin(post_inner(
                 log,
                 context,
                 &public_user,
                 #[cfg(feature = "plus")]
                 headers,
                 json_run,
-            )
+            ))
             .await
         },
     }
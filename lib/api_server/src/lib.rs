@@ -8,6 +8,8 @@ use tokio as _;
 
 mod backup;
 mod config;
+mod registry;
+mod restore;
 mod root;
 mod spec;
 #[cfg(feature = "plus")]
@@ -37,12 +39,14 @@ impl bencher_endpoint::Registrar for Api {
             api_description.register(config::server_config_options)?;
             api_description.register(config::server_config_console_options)?;
             api_description.register(backup::server_backup_options)?;
+            api_description.register(restore::server_restore_options)?;
         }
         api_description.register(version::server_version_get)?;
         api_description.register(spec::server_spec_get)?;
         api_description.register(config::server_config_get)?;
         api_description.register(config::server_config_console_get)?;
         api_description.register(backup::server_backup_post)?;
+        api_description.register(restore::server_restore_post)?;
 
         #[cfg(feature = "plus")]
         {
@@ -52,6 +56,12 @@ impl bencher_endpoint::Registrar for Api {
             }
             api_description.register(stats::server_stats_get)?;
 
+            // Container registry garbage collection
+            if http_options {
+                api_description.register(registry::server_registry_gc_options)?;
+            }
+            api_description.register(registry::server_registry_gc_post)?;
+
             // Bencher Cloud only
             if is_bencher_cloud {
                 if http_options {
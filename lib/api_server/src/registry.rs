@@ -0,0 +1,66 @@
+#![cfg(feature = "plus")]
+
+use bencher_endpoint::{CorsResponse, Endpoint, Post, ResponseOk};
+use bencher_json::{JsonRegistryGc, JsonRegistryGcReport};
+use bencher_schema::{
+    context::ApiContext,
+    error::issue_error,
+    model::{
+        server::RegistryGc,
+        user::{admin::AdminUser, auth::BearerToken},
+    },
+};
+use dropshot::{HttpError, RequestContext, TypedBody, endpoint};
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/server/registry/gc",
+    tags = ["server"]
+}]
+pub async fn server_registry_gc_options(
+    _rqctx: RequestContext<ApiContext>,
+    _body: TypedBody<JsonRegistryGc>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Post.into()]))
+}
+
+/// Collect registry garbage
+///
+/// Remove all container registry manifests, blobs, and referrers
+/// that are no longer reachable from a tag or an unfinished job,
+/// along with any stale uploads.
+/// Set `dry_run` to report what would be removed without removing anything.
+/// The user must be an admin on the server to use this route.
+#[endpoint {
+    method = POST,
+    path =  "/v0/server/registry/gc",
+    tags = ["server"]
+}]
+pub async fn server_registry_gc_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    body: TypedBody<JsonRegistryGc>,
+) -> Result<ResponseOk<JsonRegistryGcReport>, HttpError> {
+    let _admin_user = AdminUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(rqctx.context(), body.into_inner()).await?;
+    Ok(Post::auth_response_ok(json))
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    json_registry_gc: JsonRegistryGc,
+) -> Result<JsonRegistryGcReport, HttpError> {
+    RegistryGc::run(
+        &context.database.connection,
+        context.oci_storage(),
+        json_registry_gc.dry_run.unwrap_or_default(),
+    )
+    .await
+    .map_err(|e| {
+        issue_error(
+            "Failed to collect registry garbage",
+            "Failed to collect garbage from the container registry.",
+            e,
+        )
+    })
+}
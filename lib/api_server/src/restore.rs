@@ -0,0 +1,53 @@
+use bencher_endpoint::{CorsResponse, Endpoint, Post, ResponseCreated};
+use bencher_json::{JsonRestore, JsonRestored};
+use bencher_schema::{
+    context::ApiContext,
+    error::bad_request_error,
+    model::{
+        server::ServerRestore,
+        user::{admin::AdminUser, auth::BearerToken},
+    },
+};
+use dropshot::{HttpError, RequestContext, TypedBody, endpoint};
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/server/restore",
+    tags = ["server"]
+}]
+pub async fn server_restore_options(
+    _rqctx: RequestContext<ApiContext>,
+    _body: TypedBody<JsonRestore>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Post.into()]))
+}
+
+/// Restore server
+///
+/// Restore the API server database from a backup.
+/// The backup is verified before it is restored,
+/// and a backup of the current database is taken first.
+/// The user must be an admin on the server to use this route.
+#[endpoint {
+    method = POST,
+    path =  "/v0/server/restore",
+    tags = ["server"]
+}]
+pub async fn server_restore_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    body: TypedBody<JsonRestore>,
+) -> Result<ResponseCreated<JsonRestored>, HttpError> {
+    let _admin_user = AdminUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(rqctx.context(), body.into_inner()).await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    json_restore: JsonRestore,
+) -> Result<JsonRestored, HttpError> {
+    ServerRestore::run(&context.database, json_restore)
+        .await
+        .map_err(bad_request_error)
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "plus")]
+#![expect(
+    unused_crate_dependencies,
+    clippy::tests_outside_test_module,
+    reason = "integration test file"
+)]
+//! Integration tests for the registry garbage collection endpoint.
+
+use bencher_api_tests::TestServer;
+use bencher_json::JsonRegistryGcReport;
+use http::StatusCode;
+
+// POST /v0/server/registry/gc - requires admin auth
+#[tokio::test]
+async fn registry_gc_requires_auth() {
+    let server = TestServer::new().await;
+
+    let resp = server
+        .client
+        .post(server.api_url("/v0/server/registry/gc"))
+        .json(&serde_json::json!({}))
+        .send()
+        .await
+        .expect("Request failed");
+
+    // Should require authentication
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+// POST /v0/server/registry/gc - non-admin cannot collect garbage
+#[tokio::test]
+async fn registry_gc_forbidden_for_non_admin() {
+    let server = TestServer::new().await;
+    // First user is admin
+    let _admin = server.signup("Admin User", "gcadmin@example.com").await;
+    // Second user is NOT admin
+    let user = server.signup("Regular User", "gcuser@example.com").await;
+
+    let resp = server
+        .client
+        .post(server.api_url("/v0/server/registry/gc"))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&serde_json::json!({ "dry_run": true }))
+        .send()
+        .await
+        .expect("Request failed");
+
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+}
+
+// POST /v0/server/registry/gc - admin can collect garbage
+#[tokio::test]
+async fn registry_gc_as_admin() {
+    let server = TestServer::new().await;
+    let admin = server.signup("Admin User", "gcrun@example.com").await;
+
+    for dry_run in [true, false] {
+        let resp = server
+            .client
+            .post(server.api_url("/v0/server/registry/gc"))
+            .header(
+                bencher_json::AUTHORIZATION,
+                bencher_json::bearer_header(&admin.token),
+            )
+            .json(&serde_json::json!({ "dry_run": dry_run }))
+            .send()
+            .await
+            .expect("Request failed");
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let report: JsonRegistryGcReport = resp.json().await.expect("Failed to parse response");
+        assert_eq!(report.dry_run, dry_run);
+        assert_eq!(report.manifests, 0);
+        assert_eq!(report.blobs, 0);
+    }
+}
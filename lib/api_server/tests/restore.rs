@@ -0,0 +1,186 @@
+#![expect(
+    unused_crate_dependencies,
+    clippy::tests_outside_test_module,
+    reason = "integration test file"
+)]
+//! Integration tests for server restore endpoint.
+
+use bencher_api_tests::TestServer;
+use bencher_json::{JsonBackupCreated, JsonRestored};
+use http::StatusCode;
+
+// POST /v0/server/restore - requires admin auth
+#[tokio::test]
+async fn restore_requires_auth() {
+    let server = TestServer::new().await;
+
+    let resp = server
+        .client
+        .post(server.api_url("/v0/server/restore"))
+        .send()
+        .await
+        .expect("Request failed");
+
+    // Should require authentication
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+// POST /v0/server/restore - non-admin cannot restore
+#[tokio::test]
+async fn restore_forbidden_for_non_admin() {
+    let server = TestServer::new().await;
+    // First user is admin
+    let _admin = server
+        .signup("Admin User", "restoreadmin@example.com")
+        .await;
+    // Second user is NOT admin
+    let user = server
+        .signup("Regular User", "restoreuser@example.com")
+        .await;
+
+    let body = serde_json::json!({
+        "file_name": "backup-bencher-2025-01-01-00-00-00.db"
+    });
+
+    let resp = server
+        .client
+        .post(server.api_url("/v0/server/restore"))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&user.token),
+        )
+        .json(&body)
+        .send()
+        .await
+        .expect("Request failed");
+
+    // Non-admin should be forbidden
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+}
+
+// POST /v0/server/restore - file name must be a plain backup file name
+#[tokio::test]
+async fn restore_rejects_path() {
+    let server = TestServer::new().await;
+    let admin = server.signup("Admin User", "restorepath@example.com").await;
+
+    let body = serde_json::json!({
+        "file_name": "../backup-bencher-2025-01-01-00-00-00.db"
+    });
+
+    let resp = server
+        .client
+        .post(server.api_url("/v0/server/restore"))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&admin.token),
+        )
+        .json(&body)
+        .send()
+        .await
+        .expect("Request failed");
+
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+// POST /v0/server/restore - missing backup
+#[tokio::test]
+async fn restore_not_found() {
+    let server = TestServer::new().await;
+    let admin = server
+        .signup("Admin User", "restoremissing@example.com")
+        .await;
+
+    let body = serde_json::json!({
+        "file_name": "backup-missing-2025-01-01-00-00-00.db"
+    });
+
+    let resp = server
+        .client
+        .post(server.api_url("/v0/server/restore"))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&admin.token),
+        )
+        .json(&body)
+        .send()
+        .await
+        .expect("Request failed");
+
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+// POST /v0/server/backup then /v0/server/restore - admin can restore a backup
+#[tokio::test]
+async fn restore_as_admin() {
+    let server = TestServer::new().await;
+    // First user is admin
+    let admin = server
+        .signup("Admin User", "adminrestore@example.com")
+        .await;
+    let org = server.create_org(&admin, "Restore Org").await;
+
+    let body = serde_json::json!({
+        "compress": true,
+        "rm": false
+    });
+    let resp = server
+        .client
+        .post(server.api_url("/v0/server/backup"))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&admin.token),
+        )
+        .json(&body)
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let backup: JsonBackupCreated = resp.json().await.expect("Failed to parse response");
+    assert!(
+        std::path::Path::new(&backup.file_name)
+            .extension()
+            .is_some_and(|extension| extension == "gz")
+    );
+
+    // This project is not in the backup
+    let project = server
+        .create_project(&admin, &org, "After Backup Project")
+        .await;
+
+    let body = serde_json::json!({
+        "file_name": backup.file_name
+    });
+    let resp = server
+        .client
+        .post(server.api_url("/v0/server/restore"))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&admin.token),
+        )
+        .json(&body)
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let restored: JsonRestored = resp.json().await.expect("Failed to parse response");
+    assert!(restored.previous.starts_with("backup-"));
+
+    // The project created after the backup is gone
+    let resp = server
+        .client
+        .get(server.api_url(&format!("/v0/projects/{}", project.slug)))
+        .header(
+            bencher_json::AUTHORIZATION,
+            bencher_json::bearer_header(&admin.token),
+        )
+        .send()
+        .await
+        .expect("Request failed");
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    let dir = server.db_path().parent().expect("No database directory");
+    for file_name in [&backup.file_name, &restored.previous] {
+        let _rm = std::fs::remove_file(dir.join(file_name));
+    }
+}
@@ -1,4 +1,4 @@
-use bencher_json::{BenchmarkName, JsonAny, JsonNewMetric, project::report::JsonAverage};
+use bencher_json::{BenchmarkName, JsonAny, JsonNewMetric, project::report::JsonStatistic};
 
 use rust_decimal::Decimal;
 use serde::Deserialize;
@@ -36,8 +36,13 @@ pub struct Benchmarks(pub Vec<Benchmark>);
 pub struct Benchmark {
     pub namespace: Option<BenchmarkName>,
     pub method: BenchmarkName,
+    /// The `[Params]` of the benchmark (ex: `N=1000&Mode=Fast`)
+    pub parameters: Option<String>,
     pub statistics: Statistics,
     pub memory: Option<Memory>,
+    /// The diagnoser metrics (ex: `[ThreadingDiagnoser]` and `[ExceptionDiagnoser]`)
+    #[serde(default)]
+    pub metrics: Vec<Metric>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -51,6 +56,27 @@ pub struct Statistics {
     pub median: Decimal,
     #[serde(with = "rust_decimal::serde::float")]
     pub interquartile_range: Decimal,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub min: Option<Decimal>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub max: Option<Decimal>,
+    #[serde(default, rename = "Q1", with = "rust_decimal::serde::float_option")]
+    pub q1: Option<Decimal>,
+    #[serde(default, rename = "Q3", with = "rust_decimal::serde::float_option")]
+    pub q3: Option<Decimal>,
+    pub percentiles: Option<Percentiles>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Percentiles {
+    #[serde(rename = "P0", with = "rust_decimal::serde::float")]
+    pub p0: Decimal,
+    #[serde(rename = "P90", with = "rust_decimal::serde::float")]
+    pub p90: Decimal,
+    #[serde(rename = "P95", with = "rust_decimal::serde::float")]
+    pub p95: Decimal,
+    #[serde(rename = "P100", with = "rust_decimal::serde::float")]
+    pub p100: Decimal,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -63,6 +89,19 @@ pub struct Memory {
     pub bytes_allocated_per_operation: u32,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Metric {
+    pub value: f64,
+    pub descriptor: MetricDescriptor,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MetricDescriptor {
+    pub id: String,
+}
+
 impl DotNet {
     fn convert(self, settings: Settings) -> Result<Option<AdapterResults>, AdapterError> {
         let benchmarks = self.benchmarks.0;
@@ -71,39 +110,35 @@ impl DotNet {
             let Benchmark {
                 namespace,
                 method,
+                parameters,
                 statistics,
                 memory,
+                metrics,
             } = benchmark;
 
-            let Statistics {
-                mean,
-                standard_deviation,
-                median,
-                interquartile_range,
-            } = statistics;
-
-            let benchmark_name = match namespace {
+            let mut benchmark_name = match namespace {
                 Some(mut name) => {
                     name.try_push('.', &method)?;
                     name
                 },
                 None => method,
             };
+            // If enabled, each set of parameters is a separate benchmark (ex: `Method(N=1000, Mode=Fast)`)
+            if let Some(parameters) =
+                parameters.filter(|parameters| settings.params && !parameters.is_empty())
+            {
+                benchmark_name = format!(
+                    "{benchmark_name}({})",
+                    parameters.split('&').collect::<Vec<_>>().join(", ")
+                )
+                .parse()?;
+            }
 
-            // JSON output is always in nanos
-            let units = Units::Nano;
             // The `Mode` is called `Throughput` but it appears to be measuring latency
             // https://benchmarkdotnet.org/articles/guides/choosing-run-strategy.html#throughput
-            let (average, spread) = match settings.average.unwrap_or_default() {
-                JsonAverage::Mean => (mean, standard_deviation),
-                JsonAverage::Median => (median, interquartile_range),
-            };
-            let latency_value = latency_as_nanos(average, units);
-            let spread = latency_as_nanos(spread, units);
-            let json_latency_metric = JsonNewMetric {
-                value: latency_value,
-                lower_value: Some(latency_value - spread),
-                upper_value: Some(latency_value + spread),
+            // Skip any benchmark that is missing the statistic (ex: older versions do not report percentiles).
+            let Some(json_latency_metric) = statistics.latency(settings) else {
+                continue;
             };
 
             let latency_measure = DotNetMeasure::Latency(json_latency_metric);
@@ -163,6 +198,24 @@ impl DotNet {
                 measures.push(total_operations_measure);
             }
 
+            // The memory diagnoser metrics are already covered by the `Memory` above.
+            for metric in metrics {
+                let json_metric = JsonNewMetric {
+                    value: metric.value.into(),
+                    lower_value: None,
+                    upper_value: None,
+                };
+                let measure = match metric.descriptor.id.as_str() {
+                    // `[ThreadingDiagnoser]`
+                    "CompletedWorkItemCount" => DotNetMeasure::CompletedWorkItems(json_metric),
+                    "LockContentionCount" => DotNetMeasure::LockContentions(json_metric),
+                    // `[ExceptionDiagnoser]`
+                    "ExceptionFrequency" => DotNetMeasure::Exceptions(json_metric),
+                    _ => continue,
+                };
+                measures.push(measure);
+            }
+
             benchmark_metrics.push((benchmark_name, measures));
         }
 
@@ -170,9 +223,55 @@ impl DotNet {
     }
 }
 
+impl Statistics {
+    fn latency(&self, settings: Settings) -> Option<JsonNewMetric> {
+        // JSON output is always in nanos
+        let units = Units::Nano;
+        let nanos = |time: Decimal| latency_as_nanos(time, units);
+        let statistic = settings
+            .statistic
+            .unwrap_or_else(|| settings.average.unwrap_or_default().into());
+        let (value, lower_value, upper_value) = match statistic {
+            JsonStatistic::Mean => {
+                let (mean, stddev) = (nanos(self.mean), nanos(self.standard_deviation));
+                (mean, Some(mean - stddev), Some(mean + stddev))
+            },
+            // The operations per second are not reported, so fall back to the median.
+            JsonStatistic::Median | JsonStatistic::Ops => {
+                let (median, iqr) = (nanos(self.median), nanos(self.interquartile_range));
+                (median, Some(median - iqr), Some(median + iqr))
+            },
+            JsonStatistic::Min => (nanos(self.min?), None, Some(nanos(self.max?))),
+            JsonStatistic::Iqr => (
+                nanos(self.median),
+                Some(nanos(self.q1?)),
+                Some(nanos(self.q3?)),
+            ),
+            // The tail latency is bounded by the fastest and slowest iterations.
+            JsonStatistic::P90 | JsonStatistic::P95 => {
+                let Percentiles { p0, p90, p95, p100 } = self.percentiles.as_ref()?;
+                let percentile = if matches!(statistic, JsonStatistic::P90) {
+                    p90
+                } else {
+                    p95
+                };
+                (nanos(*percentile), Some(nanos(*p0)), Some(nanos(*p100)))
+            },
+        };
+        Some(JsonNewMetric {
+            value,
+            lower_value,
+            upper_value,
+        })
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod test_c_sharp_dot_net {
-    use bencher_json::project::report::JsonAverage;
+    use bencher_json::project::{
+        measure::built_in::{BuiltInMeasure as _, default::Latency},
+        report::{JsonAverage, JsonStatistic},
+    };
     use ordered_float::OrderedFloat;
     use pretty_assertions::assert_eq;
 
@@ -180,6 +279,7 @@ pub(crate) mod test_c_sharp_dot_net {
         AdapterResults, Settings,
         adapters::test_util::{
             convert_file_path, convert_file_path_median, opt_convert_file_path, validate_latency,
+            validate_measure,
         },
     };
 
@@ -199,6 +299,17 @@ pub(crate) mod test_c_sharp_dot_net {
         format!("./tool_output/c_sharp/dot_net/{suffix}.json")
     }
 
+    fn convert_c_sharp_dot_net_statistic(
+        suffix: &str,
+        statistic: JsonStatistic,
+    ) -> Option<AdapterResults> {
+        let settings = Settings {
+            statistic: Some(statistic),
+            ..Settings::default()
+        };
+        opt_convert_file_path::<AdapterCSharpDotNet>(&file_path(suffix), settings)
+    }
+
     #[test]
     fn adapter_c_sharp_dot_net_null_namespace() {
         let null_namespace = "null_namespace";
@@ -211,6 +322,7 @@ pub(crate) mod test_c_sharp_dot_net {
             &file_path,
             Settings {
                 average: Some(JsonAverage::Mean),
+                ..Settings::default()
             },
         )
         .unwrap();
@@ -235,6 +347,7 @@ pub(crate) mod test_c_sharp_dot_net {
             &file_path,
             Settings {
                 average: Some(JsonAverage::Mean),
+                ..Settings::default()
             },
         )
         .unwrap();
@@ -371,4 +484,137 @@ pub(crate) mod test_c_sharp_dot_net {
             Some(52.305_598_497_390_75),
         );
     }
+
+    #[test]
+    fn adapter_c_sharp_dot_net_statistic() {
+        let results = convert_c_sharp_dot_net_statistic("memory", JsonStatistic::P95).unwrap();
+        let metrics = results
+            .get("BenchmarkDotNet.Samples.AllocEmptyList")
+            .unwrap();
+        validate_measure(
+            metrics,
+            Latency::SLUG_STR,
+            78.577_911_853_790_28,
+            Some(76.358_580_589_294_43),
+            Some(78.706_777_095_794_68),
+        );
+
+        let results = convert_c_sharp_dot_net_statistic("memory", JsonStatistic::P90).unwrap();
+        let metrics = results
+            .get("BenchmarkDotNet.Samples.AllocEmptyList")
+            .unwrap();
+        validate_measure(
+            metrics,
+            Latency::SLUG_STR,
+            78.449_046_611_785_89,
+            Some(76.358_580_589_294_43),
+            Some(78.706_777_095_794_68),
+        );
+
+        let results = convert_c_sharp_dot_net_statistic("memory", JsonStatistic::Min).unwrap();
+        let metrics = results
+            .get("BenchmarkDotNet.Samples.AllocEmptyList")
+            .unwrap();
+        validate_measure(
+            metrics,
+            Latency::SLUG_STR,
+            76.358_580_589_294_43,
+            None,
+            Some(78.706_777_095_794_68),
+        );
+
+        let results = convert_c_sharp_dot_net_statistic("memory", JsonStatistic::Iqr).unwrap();
+        let metrics = results
+            .get("BenchmarkDotNet.Samples.AllocEmptyList")
+            .unwrap();
+        validate_measure(
+            metrics,
+            Latency::SLUG_STR,
+            77.418_124_675_750_73,
+            Some(76.888_352_632_522_58),
+            Some(78.062_450_885_772_7),
+        );
+
+        // Older versions of BenchmarkDotNet do not report percentiles.
+        assert_eq!(
+            None,
+            convert_c_sharp_dot_net_statistic("null_namespace", JsonStatistic::P95)
+        );
+    }
+
+    #[test]
+    fn adapter_c_sharp_dot_net_statistic_missing() {
+        // Only the benchmark that is missing the statistic is skipped.
+        for statistic in [
+            JsonStatistic::P90,
+            JsonStatistic::P95,
+            JsonStatistic::Min,
+            JsonStatistic::Iqr,
+        ] {
+            let results = convert_c_sharp_dot_net_statistic("percentiles", statistic).unwrap();
+            assert_eq!(results.inner.len(), 1);
+            assert!(
+                results
+                    .get("BenchmarkDotNet.Samples.AllocEmptyList")
+                    .is_some()
+            );
+            assert!(
+                results
+                    .get("BenchmarkDotNet.Samples.AllocExactList")
+                    .is_none()
+            );
+        }
+
+        let results = convert_c_sharp_dot_net("percentiles");
+        assert_eq!(results.inner.len(), 2);
+        let metrics = results
+            .get("BenchmarkDotNet.Samples.AllocExactList")
+            .unwrap();
+        validate_latency(
+            metrics,
+            24.420_208_500_964_3,
+            Some(24.222_087_247_885_93),
+            Some(24.618_329_754_042_67),
+        );
+    }
+
+    #[test]
+    fn adapter_c_sharp_dot_net_params() {
+        // By default, all sets of parameters share the name of the benchmark
+        let results = convert_c_sharp_dot_net("params");
+        assert_eq!(results.inner.len(), 1);
+        assert!(results.get("BenchmarkDotNet.Samples.QueueWork").is_some());
+
+        let results = opt_convert_file_path::<AdapterCSharpDotNet>(
+            &file_path("params"),
+            Settings {
+                params: true,
+                ..Settings::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results
+            .get("BenchmarkDotNet.Samples.QueueWork(N=10, Mode=Fast)")
+            .unwrap();
+        assert_eq!(metrics.inner.len(), 4);
+        let completed_work_items = metrics.get("completed-work-items").unwrap();
+        assert_eq!(completed_work_items.value, OrderedFloat::from(2.0));
+        let lock_contentions = metrics.get("lock-contentions").unwrap();
+        assert_eq!(lock_contentions.value, OrderedFloat::from(0.007_812_5));
+        let exceptions = metrics.get("exceptions").unwrap();
+        assert_eq!(exceptions.value, OrderedFloat::from(0.0));
+
+        let metrics = results
+            .get("BenchmarkDotNet.Samples.QueueWork(N=100, Mode=Fast)")
+            .unwrap();
+        assert_eq!(metrics.inner.len(), 4);
+        let completed_work_items = metrics.get("completed-work-items").unwrap();
+        assert_eq!(completed_work_items.value, OrderedFloat::from(20.0));
+        let lock_contentions = metrics.get("lock-contentions").unwrap();
+        assert_eq!(lock_contentions.value, OrderedFloat::from(0.125));
+        let exceptions = metrics.get("exceptions").unwrap();
+        assert_eq!(exceptions.value, OrderedFloat::from(1.0));
+    }
 }
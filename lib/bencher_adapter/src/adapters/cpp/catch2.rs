@@ -249,6 +249,7 @@ pub(crate) mod test_cpp_catch2 {
             file_path,
             Settings {
                 average: Some(JsonAverage::Mean),
+                ..Settings::default()
             },
         )
         .unwrap();
@@ -259,7 +260,8 @@ pub(crate) mod test_cpp_catch2 {
             opt_convert_file_path::<AdapterCppCatch2>(
                 file_path,
                 Settings {
-                    average: Some(JsonAverage::Median)
+                    average: Some(JsonAverage::Median),
+                    ..Settings::default()
                 }
             )
         );
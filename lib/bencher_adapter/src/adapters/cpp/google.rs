@@ -1,29 +1,50 @@
-use bencher_json::{BenchmarkName, JsonAny, JsonNewMetric, project::report::JsonAverage};
+use std::collections::HashMap;
+
+use bencher_json::{
+    BenchmarkName, BenchmarkNameId, JsonAny, JsonNewMetric, MeasureNameId,
+    project::{
+        measure::built_in::{self, BuiltInMeasure as _},
+        report::JsonAverage,
+    },
+};
 use rust_decimal::Decimal;
 use serde::Deserialize;
+use serde_json::Value;
 
 use crate::{
-    Adaptable, AdapterError, Settings,
+    Adaptable, Settings,
     adapters::util::{Units, latency_as_nanos},
-    results::adapter_results::AdapterResults,
+    results::{
+        adapter_metrics::AdapterMetrics,
+        adapter_results::{AdapterResults, ResultsMap},
+        bootstrap::{Statistic, confidence_interval},
+    },
 };
 
 pub struct AdapterCppGoogle;
 
 impl Adaptable for AdapterCppGoogle {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
-        match settings.average {
-            Some(JsonAverage::Mean) | None => {},
-            Some(JsonAverage::Median) => return None,
-        }
-
         serde_json::from_str::<Google>(input)
             .ok()?
-            .try_into()
-            .ok()?
+            .convert(settings)
     }
 }
 
+// Numeric fields that are reported for benchmark runs but are not user counters.
+// https://github.com/google/benchmark/blob/v1.8.3/src/json_reporter.cc
+const NON_COUNTER_FIELDS: &[&str] = &[
+    "family_index",
+    "per_family_instance_index",
+    "repetitions",
+    "repetition_index",
+    "threads",
+    "iterations",
+    "cpu_coefficient",
+    "real_coefficient",
+    "rms",
+];
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Google {
     pub context: Context,
@@ -38,89 +59,327 @@ pub struct Context {
 #[derive(Debug, Clone, Deserialize)]
 pub struct Benchmark {
     pub name: BenchmarkName,
-    #[serde(with = "rust_decimal::serde::float")]
-    pub real_time: Decimal,
-    pub time_unit: Units,
+    /// The benchmark name without the aggregate suffix (ex: `_mean`)
+    pub run_name: Option<BenchmarkName>,
+    pub run_type: Option<RunType>,
+    pub aggregate_name: Option<String>,
+    #[serde(default)]
+    pub error_occurred: bool,
+    // Complexity aggregates do not have a real time, CPU time, or time unit.
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub real_time: Option<Decimal>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub cpu_time: Option<Decimal>,
+    pub time_unit: Option<Units>,
+    pub bytes_per_second: Option<f64>,
+    pub items_per_second: Option<f64>,
+    /// User counters
+    #[serde(flatten)]
+    pub counters: HashMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunType {
+    Iteration,
+    Aggregate,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Row {
+    Iteration,
+    Mean,
+    Median,
+    StdDev,
+}
+
+/// All of the values for a single measure of a benchmark,
+/// from both the repetitions and the aggregates (ie `--benchmark_repetitions`).
+#[derive(Debug, Default)]
+struct Samples {
+    iterations: Vec<f64>,
+    mean: Option<f64>,
+    median: Option<f64>,
+    std_dev: Option<f64>,
 }
 
-impl TryFrom<Google> for Option<AdapterResults> {
-    type Error = AdapterError;
-
-    fn try_from(google: Google) -> Result<Self, Self::Error> {
-        let mut benchmark_metrics = Vec::with_capacity(google.benchmarks.len());
-        for benchmark in google.benchmarks {
-            let Benchmark {
-                name,
-                real_time,
-                time_unit,
-            } = benchmark;
-            let value = latency_as_nanos(real_time, time_unit);
-            let json_metric = JsonNewMetric {
-                value,
-                lower_value: None,
-                upper_value: None,
+impl Google {
+    fn convert(self, settings: Settings) -> Option<AdapterResults> {
+        let mut benchmarks = HashMap::<BenchmarkNameId, HashMap<MeasureNameId, Samples>>::new();
+        for benchmark in self.benchmarks {
+            let (Some(row), Some(measures)) = (benchmark.row(), benchmark.measures()) else {
+                continue;
             };
+            // Aggregates are named after their run (ex: `BM_copy/1024_mean` for `BM_copy/1024`),
+            // so they are folded into the same benchmark as the repetitions.
+            let name = benchmark.run_name.unwrap_or(benchmark.name);
+            let benchmark_samples = benchmarks
+                .entry(BenchmarkNameId::new_name(name))
+                .or_default();
+            for (measure, value) in measures {
+                let samples = benchmark_samples.entry(measure).or_default();
+                match row {
+                    Row::Iteration => samples.iterations.push(value),
+                    Row::Mean => samples.mean = Some(value),
+                    Row::Median => samples.median = Some(value),
+                    Row::StdDev => samples.std_dev = Some(value),
+                }
+            }
+        }
 
-            benchmark_metrics.push((name, json_metric));
+        let results_map = benchmarks
+            .into_iter()
+            .filter_map(|(name, benchmark_samples)| {
+                let inner = benchmark_samples
+                    .into_iter()
+                    .filter_map(|(measure, samples)| Some((measure, samples.metric(settings)?)))
+                    .collect::<HashMap<_, _>>();
+                (!inner.is_empty()).then_some((name, AdapterMetrics { inner }))
+            })
+            .collect::<ResultsMap>();
+        (!results_map.is_empty()).then(|| results_map.into())
+    }
+}
+
+impl Benchmark {
+    fn row(&self) -> Option<Row> {
+        if self.error_occurred {
+            return None;
         }
+        match self.run_type {
+            Some(RunType::Iteration) | None => Some(Row::Iteration),
+            // The coefficient of variation (`cv`), complexity (`BigO` and `RMS`),
+            // and any user-defined statistics are ignored.
+            Some(RunType::Aggregate) => match self.aggregate_name.as_deref()? {
+                "mean" => Some(Row::Mean),
+                "median" => Some(Row::Median),
+                "stddev" => Some(Row::StdDev),
+                _ => None,
+            },
+        }
+    }
 
-        Ok(AdapterResults::new_latency(benchmark_metrics))
+    fn measures(&self) -> Option<Vec<(MeasureNameId, f64)>> {
+        let time_unit = self.time_unit?;
+        let real_time = latency_as_nanos(self.real_time?, time_unit).into_inner();
+        let mut measures = vec![(built_in::default::Latency::name_id(), real_time)];
+        if let Some(cpu_time) = self.cpu_time {
+            let cpu_time = latency_as_nanos(cpu_time, time_unit).into_inner();
+            measures.push((built_in::google::CpuTime::name_id(), cpu_time));
+        }
+        if let Some(items_per_second) = self.items_per_second {
+            measures.push((built_in::default::Throughput::name_id(), items_per_second));
+        }
+        if let Some(bytes_per_second) = self.bytes_per_second {
+            measures.push((
+                built_in::throughput::ByteThroughput::name_id(),
+                bytes_per_second,
+            ));
+        }
+        // User counters are created as custom measures.
+        for (name, value) in &self.counters {
+            if NON_COUNTER_FIELDS.contains(&name.as_str()) {
+                continue;
+            }
+            if let (Some(value), Ok(measure)) = (value.as_f64(), name.parse()) {
+                measures.push((measure, value));
+            }
+        }
+        Some(measures)
+    }
+}
+
+impl Samples {
+    fn metric(mut self, settings: Settings) -> Option<JsonNewMetric> {
+        let average = settings.average.unwrap_or_default();
+        let value = match average {
+            JsonAverage::Mean => self.mean,
+            JsonAverage::Median => self.median,
+        }
+        .or_else(|| Statistic::from(average).estimate(&mut self.iterations))?;
+
+        let spread = if settings.bootstrap
+            && let Some(interval) = confidence_interval(&self.iterations, average.into())
+        {
+            Some(interval)
+        } else {
+            match average {
+                JsonAverage::Mean => self
+                    .std_dev
+                    .map(|std_dev| (value - std_dev, value + std_dev)),
+                // There is no aggregate spread for the median,
+                // so use the range of the repetitions.
+                JsonAverage::Median => Statistic::Min
+                    .estimate(&mut self.iterations)
+                    .zip(Statistic::Max.estimate(&mut self.iterations))
+                    .filter(|_| self.iterations.len() > 1),
+            }
+        };
+
+        Some(JsonNewMetric {
+            value: value.into(),
+            lower_value: spread.map(|(lower, _)| lower.into()),
+            upper_value: spread.map(|(_, upper)| upper.into()),
+        })
     }
 }
 
 #[cfg(test)]
 pub(crate) mod test_cpp_google {
-    use bencher_json::project::report::JsonAverage;
+    use bencher_json::project::{
+        measure::built_in::{
+            BuiltInMeasure as _,
+            default::{Latency, Throughput},
+            google::CpuTime,
+            throughput::ByteThroughput,
+        },
+        report::JsonAverage,
+    };
+    use ordered_float::OrderedFloat;
     use pretty_assertions::assert_eq;
 
     use crate::{
         AdapterResults, Settings,
-        adapters::test_util::{convert_file_path, opt_convert_file_path, validate_latency},
+        adapters::test_util::{
+            convert_file_path, convert_file_path_median, opt_convert_file_path, validate_measure,
+        },
     };
 
     use super::AdapterCppGoogle;
 
-    fn convert_cpp_google(suffix: &str) -> AdapterResults {
-        let file_path = format!("./tool_output/cpp/google/{suffix}.txt");
-        convert_file_path::<AdapterCppGoogle>(&file_path)
+    fn file_path(suffix: &str) -> String {
+        format!("./tool_output/cpp/google/{suffix}.txt")
     }
 
     #[test]
     fn adapter_cpp_google_average() {
-        let file_path = "./tool_output/cpp/google/two.txt";
+        let file_path = file_path("two");
         let results = opt_convert_file_path::<AdapterCppGoogle>(
-            file_path,
+            &file_path,
             Settings {
                 average: Some(JsonAverage::Mean),
+                ..Settings::default()
             },
         )
         .unwrap();
         validate_adapter_cpp_google(&results);
 
-        assert_eq!(
-            None,
-            opt_convert_file_path::<AdapterCppGoogle>(
-                file_path,
-                Settings {
-                    average: Some(JsonAverage::Median)
-                }
-            )
-        );
+        // Without any repetitions, the median is the single result.
+        let results = convert_file_path_median::<AdapterCppGoogle>(&file_path);
+        validate_adapter_cpp_google(&results);
     }
 
     #[test]
     fn adapter_cpp_google() {
-        let results = convert_cpp_google("two");
+        let results = convert_file_path::<AdapterCppGoogle>(&file_path("two"));
         validate_adapter_cpp_google(&results);
     }
 
+    #[test]
+    fn adapter_cpp_google_repetitions() {
+        let results = convert_file_path::<AdapterCppGoogle>(&file_path("repetitions"));
+        // The complexity aggregates are ignored.
+        assert_eq!(results.inner.len(), 2);
+
+        // The aggregates are folded into the value, lower value, and upper value.
+        let metrics = results.get("BM_copy/1024").unwrap();
+        assert_eq!(metrics.inner.len(), 5);
+        validate_measure(metrics, Latency::SLUG_STR, 110.0, Some(100.0), Some(120.0));
+        validate_measure(metrics, CpuTime::SLUG_STR, 100.0, Some(90.0), Some(110.0));
+        validate_measure(
+            metrics,
+            Throughput::SLUG_STR,
+            9_141_414.141_414_141,
+            Some(9_141_414.141_414_141 - 833_333.333_333_333_3),
+            Some(9_141_414.141_414_141 + 833_333.333_333_333_3),
+        );
+        validate_measure(
+            metrics,
+            ByteThroughput::SLUG_STR,
+            9_360_808_080.808_08,
+            Some(9_360_808_080.808_08 - 853_333_333.333_333_3),
+            Some(9_360_808_080.808_08 + 853_333_333.333_333_3),
+        );
+        // User counters are custom measures.
+        validate_measure(metrics, "cache_misses", 5.0, Some(4.0), Some(6.0));
+
+        let metrics = results.get("BM_sort/8").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_measure(metrics, Latency::SLUG_STR, 2.5 * 1_000.0, None, None);
+        validate_measure(metrics, CpuTime::SLUG_STR, 2.4 * 1_000.0, None, None);
+    }
+
+    #[test]
+    fn adapter_cpp_google_repetitions_median() {
+        let results = convert_file_path_median::<AdapterCppGoogle>(&file_path("repetitions"));
+        assert_eq!(results.inner.len(), 2);
+
+        // The lower and upper values are the range of the repetitions.
+        let metrics = results.get("BM_copy/1024").unwrap();
+        validate_measure(metrics, Latency::SLUG_STR, 110.0, Some(100.0), Some(120.0));
+        validate_measure(metrics, CpuTime::SLUG_STR, 100.0, Some(90.0), Some(110.0));
+        validate_measure(metrics, "cache_misses", 5.0, Some(4.0), Some(6.0));
+    }
+
+    #[test]
+    fn adapter_cpp_google_repetitions_bootstrap() {
+        let results = opt_convert_file_path::<AdapterCppGoogle>(
+            &file_path("repetitions"),
+            Settings {
+                bootstrap: true,
+                ..Settings::default()
+            },
+        )
+        .unwrap();
+
+        let metrics = results.get("BM_copy/1024").unwrap();
+        let latency = metrics.get(Latency::SLUG_STR).unwrap();
+        assert_eq!(latency.value, OrderedFloat::from(110.0));
+        let lower_value = latency.lower_value.unwrap().into_inner();
+        let upper_value = latency.upper_value.unwrap().into_inner();
+        assert!((100.0..=110.0).contains(&lower_value), "{lower_value}");
+        assert!((110.0..=120.0).contains(&upper_value), "{upper_value}");
+
+        // A single repetition can not be bootstrapped.
+        let metrics = results.get("BM_sort/8").unwrap();
+        validate_measure(metrics, Latency::SLUG_STR, 2.5 * 1_000.0, None, None);
+    }
+
     pub fn validate_adapter_cpp_google(results: &AdapterResults) {
         assert_eq!(results.inner.len(), 2);
 
         let metrics = results.get("fib_10").unwrap();
-        validate_latency(metrics, 214.989_801_145_479_53, None, None);
+        assert_eq!(metrics.inner.len(), 2);
+        validate_measure(
+            metrics,
+            Latency::SLUG_STR,
+            214.989_801_145_479_53,
+            None,
+            None,
+        );
+        validate_measure(
+            metrics,
+            CpuTime::SLUG_STR,
+            213.655_072_061_632_95,
+            None,
+            None,
+        );
 
         let metrics = results.get("fib_20").unwrap();
-        validate_latency(metrics, 27_455.600_415_007_055, None, None);
+        assert_eq!(metrics.inner.len(), 2);
+        validate_measure(
+            metrics,
+            Latency::SLUG_STR,
+            27_455.600_415_007_055,
+            None,
+            None,
+        );
+        validate_measure(
+            metrics,
+            CpuTime::SLUG_STR,
+            27_364.903_204_272_36,
+            None,
+            None,
+        );
     }
 }
@@ -93,7 +93,8 @@ pub(crate) mod test_dart_benchmark_harness {
             opt_convert_file_path::<AdapterDartBenchmarkHarness>(
                 file_path,
                 Settings {
-                    average: Some(JsonAverage::Median)
+                    average: Some(JsonAverage::Median),
+                    ..Settings::default()
                 }
             )
         );
@@ -194,6 +194,7 @@ pub(crate) mod test_go_bench {
             file_path,
             Settings {
                 average: Some(JsonAverage::Mean),
+                ..Settings::default()
             },
         )
         .unwrap();
@@ -204,7 +205,8 @@ pub(crate) mod test_go_bench {
             opt_convert_file_path::<AdapterGoBench>(
                 file_path,
                 Settings {
-                    average: Some(JsonAverage::Median)
+                    average: Some(JsonAverage::Median),
+                    ..Settings::default()
                 }
             )
         );
@@ -165,6 +165,7 @@ pub(crate) mod test_java_jmh {
             file_path,
             Settings {
                 average: Some(JsonAverage::Mean),
+                ..Settings::default()
             },
         )
         .unwrap();
@@ -175,7 +176,8 @@ pub(crate) mod test_java_jmh {
             opt_convert_file_path::<AdapterJavaJmh>(
                 file_path,
                 Settings {
-                    average: Some(JsonAverage::Median)
+                    average: Some(JsonAverage::Median),
+                    ..Settings::default()
                 }
             )
         );
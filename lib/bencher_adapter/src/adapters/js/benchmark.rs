@@ -111,7 +111,8 @@ pub(crate) mod test_js_benchmark {
             opt_convert_file_path::<AdapterJsBenchmark>(
                 file_path,
                 Settings {
-                    average: Some(JsonAverage::Mean)
+                    average: Some(JsonAverage::Mean),
+                    ..Settings::default()
                 }
             )
         );
@@ -120,6 +121,7 @@ pub(crate) mod test_js_benchmark {
             file_path,
             Settings {
                 average: Some(JsonAverage::Median),
+                ..Settings::default()
             },
         )
         .unwrap();
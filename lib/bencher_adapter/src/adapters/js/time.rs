@@ -105,7 +105,8 @@ pub(crate) mod test_js_time {
             opt_convert_file_path::<AdapterJsTime>(
                 file_path,
                 Settings {
-                    average: Some(JsonAverage::Mean)
+                    average: Some(JsonAverage::Mean),
+                    ..Settings::default()
                 }
             )
         );
@@ -115,7 +116,8 @@ pub(crate) mod test_js_time {
             opt_convert_file_path::<AdapterJsTime>(
                 file_path,
                 Settings {
-                    average: Some(JsonAverage::Median)
+                    average: Some(JsonAverage::Median),
+                    ..Settings::default()
                 }
             )
         );
@@ -143,13 +143,13 @@ fn combine_name(full_name: &str, name: BenchmarkName) -> BenchmarkName {
 #[cfg(test)]
 pub(crate) mod test_js_vitest {
     use bencher_json::project::report::JsonAverage;
-    use ordered_float::OrderedFloat;
     use pretty_assertions::assert_eq;
 
     use crate::{
         AdapterResults, Settings,
-        adapters::test_util::{convert_file_path, convert_file_path_median, opt_convert_file_path},
-        results::adapter_metrics::AdapterMetrics,
+        adapters::test_util::{
+            convert_file_path, convert_file_path_median, opt_convert_file_path, validate_measure,
+        },
     };
 
     use super::AdapterJsVitest;
@@ -168,19 +168,6 @@ pub(crate) mod test_js_vitest {
         format!("./tool_output/js/vitest/{suffix}.json")
     }
 
-    fn validate_measure(
-        metrics: &AdapterMetrics,
-        slug: &str,
-        value: f64,
-        lower_value: Option<f64>,
-        upper_value: Option<f64>,
-    ) {
-        let metric = metrics.get(slug).unwrap();
-        assert_eq!(metric.value, OrderedFloat::from(value));
-        assert_eq!(metric.lower_value, lower_value.map(OrderedFloat::from));
-        assert_eq!(metric.upper_value, upper_value.map(OrderedFloat::from));
-    }
-
     #[test]
     fn combine_name_fallbacks() {
         use bencher_json::BenchmarkName;
@@ -246,6 +233,7 @@ pub(crate) mod test_js_vitest {
             &file_path,
             Settings {
                 average: Some(JsonAverage::Mean),
+                ..Settings::default()
             },
         )
         .unwrap();
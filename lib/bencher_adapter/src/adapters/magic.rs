@@ -37,8 +37,8 @@ mod test_magic {
         ruby::benchmark::test_ruby_benchmark,
         rust::{
             bench::test_rust_bench, criterion::test_rust_criterion,
-            gungraun_json::test_rust_gungraun_json, gungraun_stdout::test_rust_gungraun_stdout,
-            iai::test_rust_iai,
+            criterion_json::test_rust_criterion_json, gungraun_json::test_rust_gungraun_json,
+            gungraun_stdout::test_rust_gungraun_stdout, iai::test_rust_iai,
         },
         shell::hyperfine::test_shell_hyperfine,
         test_util::convert_file_path,
@@ -141,6 +141,13 @@ mod test_magic {
         test_rust_criterion::validate_adapter_rust_criterion(&results);
     }
 
+    #[test]
+    fn adapter_magic_rust_criterion_json() {
+        let results =
+            convert_file_path::<AdapterMagic>("./tool_output/rust/criterion_json/three.json");
+        test_rust_criterion_json::validate_adapter_rust_criterion_json(&results);
+    }
+
     #[test]
     fn adapter_magic_rust_iai() {
         let results = convert_file_path::<AdapterMagic>("./tool_output/rust/iai/two.txt");
@@ -58,6 +58,7 @@ pub(crate) mod test_util {
     {
         let settings = Settings {
             average: Some(JsonAverage::Median),
+            ..Settings::default()
         };
         opt_convert_file_path::<A>(file_path, settings)
             .unwrap_or_else(|| panic!("Failed to convert contents of {file_path}"))
@@ -104,7 +105,17 @@ pub(crate) mod test_util {
         upper_value: Option<f64>,
     ) {
         assert_eq!(metrics.inner.len(), 1);
-        let metric = metrics.get(key).unwrap();
+        validate_measure(metrics, key, value, lower_value, upper_value);
+    }
+
+    pub fn validate_measure(
+        metrics: &AdapterMetrics,
+        slug: &str,
+        value: f64,
+        lower_value: Option<f64>,
+        upper_value: Option<f64>,
+    ) {
+        let metric = metrics.get(slug).unwrap();
         assert_eq!(metric.value, OrderedFloat::from(value));
         assert_eq!(metric.lower_value, lower_value.map(OrderedFloat::from));
         assert_eq!(metric.upper_value, upper_value.map(OrderedFloat::from));
@@ -103,7 +103,8 @@ pub(crate) mod test_python_asv {
             opt_convert_file_path::<AdapterPythonAsv>(
                 file_path,
                 Settings {
-                    average: Some(JsonAverage::Mean)
+                    average: Some(JsonAverage::Mean),
+                    ..Settings::default()
                 }
             )
         );
@@ -112,6 +113,7 @@ pub(crate) mod test_python_asv {
             file_path,
             Settings {
                 average: Some(JsonAverage::Median),
+                ..Settings::default()
             },
         )
         .unwrap();
@@ -1,12 +1,26 @@
-use bencher_json::{BenchmarkName, JsonAny, JsonNewMetric, project::report::JsonAverage};
+use std::collections::HashMap;
+
+use bencher_json::{
+    BenchmarkName, BenchmarkNameId, JsonAny, JsonNewMetric, MeasureNameId,
+    project::{
+        measure::built_in::{self, BuiltInMeasure as _},
+        report::JsonStatistic,
+    },
+};
 
-use rust_decimal::Decimal;
+use ordered_float::OrderedFloat;
+use rust_decimal::{Decimal, prelude::ToPrimitive as _};
 use serde::Deserialize;
+use serde_json::Value;
 
 use crate::{
-    Adaptable, AdapterError, Settings,
+    Adaptable, Settings,
     adapters::util::{Units, latency_as_nanos},
-    results::adapter_results::AdapterResults,
+    results::{
+        adapter_metrics::AdapterMetrics,
+        adapter_results::{AdapterResults, ResultsMap},
+        bootstrap::{Statistic, confidence_interval},
+    },
 };
 
 pub struct AdapterPythonPytest;
@@ -16,7 +30,6 @@ impl Adaptable for AdapterPythonPytest {
         serde_json::from_str::<Pytest>(input)
             .ok()?
             .convert(settings)
-            .ok()?
     }
 }
 
@@ -36,11 +49,17 @@ pub struct Benchmarks(pub Vec<Benchmark>);
 pub struct Benchmark {
     pub fullname: BenchmarkName,
     pub stats: Stats,
+    /// Any extra information added with `benchmark.extra_info`.
+    pub extra_info: Option<HashMap<String, Value>>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct Stats {
+    #[serde(with = "rust_decimal::serde::float")]
+    pub min: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub max: Decimal,
     #[serde(with = "rust_decimal::serde::float")]
     pub mean: Decimal,
     #[serde(with = "rust_decimal::serde::float")]
@@ -49,59 +68,161 @@ pub struct Stats {
     pub median: Decimal,
     #[serde(with = "rust_decimal::serde::float")]
     pub iqr: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub q1: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub q3: Decimal,
+    /// Operations per second (ie `1 / mean`)
+    pub ops: Option<f64>,
+    /// The raw timings for each round, if they were saved.
+    pub data: Option<Vec<Value>>,
 }
 
 impl Pytest {
-    #[expect(
-        clippy::unnecessary_wraps,
-        reason = "signature must match adapter convert trait"
-    )]
-    fn convert(self, settings: Settings) -> Result<Option<AdapterResults>, AdapterError> {
+    fn convert(self, settings: Settings) -> Option<AdapterResults> {
         let benchmarks = self.benchmarks.0;
-        let mut benchmark_metrics = Vec::with_capacity(benchmarks.len());
+        let mut results_map = ResultsMap::with_capacity(benchmarks.len());
         for benchmark in benchmarks {
             let Benchmark {
                 fullname: benchmark_name,
                 stats,
+                extra_info,
             } = benchmark;
-            let Stats {
-                mean,
-                stddev,
-                median,
-                iqr,
-            } = stats;
-
-            // JSON output is always in seconds
-            let units = Units::Sec;
-            let (average, spread) = match settings.average.unwrap_or_default() {
-                JsonAverage::Mean => (mean, stddev),
-                JsonAverage::Median => (median, iqr),
-            };
-            let value = latency_as_nanos(average, units);
-            let spread = latency_as_nanos(spread, units);
-            let json_metric = JsonNewMetric {
-                value,
-                lower_value: Some(value - spread),
-                upper_value: Some(value + spread),
-            };
-
-            benchmark_metrics.push((benchmark_name, json_metric));
+
+            let mut inner = HashMap::new();
+            inner.insert(
+                built_in::default::Latency::name_id(),
+                stats.latency(settings),
+            );
+            if let Some(ops) = stats.ops {
+                inner.insert(
+                    built_in::default::Throughput::name_id(),
+                    stats.throughput(ops, settings),
+                );
+            }
+            // Numeric extra info is created as custom measures.
+            for (name, value) in extra_info.into_iter().flatten() {
+                if let (Some(value), Ok(measure)) = (value.as_f64(), name.parse::<MeasureNameId>())
+                {
+                    inner.entry(measure).or_insert(JsonNewMetric {
+                        value: value.into(),
+                        lower_value: None,
+                        upper_value: None,
+                    });
+                }
+            }
+
+            results_map.insert(
+                BenchmarkNameId::new_name(benchmark_name),
+                AdapterMetrics { inner },
+            );
         }
 
-        Ok(AdapterResults::new_latency(benchmark_metrics))
+        (!results_map.is_empty()).then(|| results_map.into())
     }
 }
 
+impl Stats {
+    fn latency(&self, settings: Settings) -> JsonNewMetric {
+        // JSON output is always in seconds
+        let units = Units::Sec;
+        let statistic = settings
+            .statistic
+            .unwrap_or_else(|| settings.average.unwrap_or_default().into());
+        let nanos = |time: Decimal| latency_as_nanos(time, units);
+        let data = self.data.as_deref().and_then(raw_data);
+        let (value, lower_value, upper_value) = match statistic {
+            // The operations per second are the reciprocal of the mean.
+            JsonStatistic::Mean | JsonStatistic::Ops => {
+                let (mean, stddev) = (nanos(self.mean), nanos(self.stddev));
+                (mean, Some(mean - stddev), Some(mean + stddev))
+            },
+            JsonStatistic::Median | JsonStatistic::P90 | JsonStatistic::P95 => {
+                // Percentiles are only available from the raw data,
+                // otherwise fall back to the median.
+                let percentile = matches!(statistic, JsonStatistic::P90 | JsonStatistic::P95)
+                    .then(|| Statistic::from(statistic).estimate(&mut data.clone()?))
+                    .flatten();
+                if let Some(percentile) = percentile {
+                    (latency_as_nanos(percentile, units), None, None)
+                } else {
+                    let (median, iqr) = (nanos(self.median), nanos(self.iqr));
+                    (median, Some(median - iqr), Some(median + iqr))
+                }
+            },
+            JsonStatistic::Min => (nanos(self.min), None, Some(nanos(self.max))),
+            JsonStatistic::Iqr => (
+                nanos(self.median),
+                Some(nanos(self.q1)),
+                Some(nanos(self.q3)),
+            ),
+        };
+
+        // Without the raw data, there is no bootstrap,
+        // so a percentile that falls back to the median is never bounded by the percentile.
+        let bootstrap = settings
+            .bootstrap
+            .then_some(data)
+            .flatten()
+            .and_then(|data| confidence_interval(&data, statistic.into()));
+        let (lower_value, upper_value) = if let Some((lower, upper)) = bootstrap {
+            (
+                Some(latency_as_nanos(lower, units)),
+                Some(latency_as_nanos(upper, units)),
+            )
+        } else {
+            (lower_value, upper_value)
+        };
+
+        JsonNewMetric {
+            value,
+            lower_value,
+            upper_value,
+        }
+    }
+
+    fn throughput(&self, ops: f64, settings: Settings) -> JsonNewMetric {
+        // The slowest and fastest rounds bound the operations per second.
+        let (lower_value, upper_value) = if matches!(settings.statistic, Some(JsonStatistic::Ops)) {
+            (per_second(self.max), per_second(self.min))
+        } else {
+            (None, None)
+        };
+        JsonNewMetric {
+            value: ops.into(),
+            lower_value,
+            upper_value,
+        }
+    }
+}
+
+fn per_second(time: Decimal) -> Option<OrderedFloat<f64>> {
+    let seconds = time.to_f64()?;
+    (seconds > 0.0).then(|| (1.0 / seconds).into())
+}
+
+// The raw data may be truncated, in which case it is not usable.
+fn raw_data(data: &[Value]) -> Option<Vec<f64>> {
+    data.iter().map(Value::as_f64).collect()
+}
+
 #[cfg(test)]
 pub(crate) mod test_python_pytest {
-    use bencher_json::project::report::JsonAverage;
+    use bencher_json::project::{
+        measure::built_in::{
+            BuiltInMeasure as _,
+            default::{Latency, Throughput},
+        },
+        report::{JsonAverage, JsonStatistic},
+    };
     use pretty_assertions::assert_eq;
 
     use crate::{
         AdapterResults, Settings,
         adapters::test_util::{
-            convert_file_path, convert_file_path_median, opt_convert_file_path, validate_latency,
+            convert_file_path, convert_file_path_median, opt_convert_file_path, validate_measure,
         },
+        results::adapter_metrics::AdapterMetrics,
     };
 
     use super::AdapterPythonPytest;
@@ -120,9 +241,33 @@ pub(crate) mod test_python_pytest {
         format!("./tool_output/python/pytest/{suffix}.json")
     }
 
+    fn convert_python_pytest_statistic(suffix: &str, statistic: JsonStatistic) -> AdapterResults {
+        let settings = Settings {
+            statistic: Some(statistic),
+            ..Settings::default()
+        };
+        opt_convert_file_path::<AdapterPythonPytest>(&file_path(suffix), settings).unwrap()
+    }
+
+    // Every benchmark also has its operations per second as a throughput.
+    fn validate_latency(
+        metrics: &AdapterMetrics,
+        value: f64,
+        lower_value: Option<f64>,
+        upper_value: Option<f64>,
+    ) {
+        assert_eq!(metrics.inner.len(), 2);
+        assert!(metrics.get(Throughput::SLUG_STR).is_some());
+        validate_measure(metrics, Latency::SLUG_STR, value, lower_value, upper_value);
+    }
+
     #[test]
     fn adapter_python_pytest_two() {
         let results = convert_python_pytest("two");
+        validate_adapter_python_pytest_two(&results);
+    }
+
+    fn validate_adapter_python_pytest_two(results: &AdapterResults) {
         assert_eq!(results.inner.len(), 2);
 
         let metrics = results.get("bench.py::test_fib_10").unwrap();
@@ -176,6 +321,7 @@ pub(crate) mod test_python_pytest {
             &file_path,
             Settings {
                 average: Some(JsonAverage::Mean),
+                ..Settings::default()
             },
         )
         .unwrap();
@@ -256,4 +402,158 @@ pub(crate) mod test_python_pytest {
             Some(3_572_745.000_000_266),
         );
     }
+
+    #[test]
+    fn adapter_python_pytest_bootstrap() {
+        let settings = Settings {
+            bootstrap: true,
+            ..Settings::default()
+        };
+        let results =
+            opt_convert_file_path::<AdapterPythonPytest>(&file_path("data"), settings).unwrap();
+        assert_eq!(results.inner.len(), 1);
+        let metric = results
+            .get("bench.py::test_fib_10")
+            .unwrap()
+            .get(Latency::SLUG_STR)
+            .unwrap();
+        let value = metric.value.into_inner();
+        assert!((value - 25_106.75).abs() < 0.001, "{value}");
+        let lower = metric.lower_value.unwrap().into_inner();
+        let upper = metric.upper_value.unwrap().into_inner();
+        // The confidence interval of the mean is narrower than the standard deviation.
+        let stddev = 350.16;
+        assert!(value - stddev < lower && lower < value, "{lower}");
+        assert!(value < upper && upper < value + stddev, "{upper}");
+
+        // Truncated raw data falls back to the standard deviation.
+        let results =
+            opt_convert_file_path::<AdapterPythonPytest>(&file_path("two"), settings).unwrap();
+        validate_adapter_python_pytest_two(&results);
+    }
+
+    #[test]
+    fn adapter_python_pytest_bootstrap_percentile() {
+        let settings = Settings {
+            bootstrap: true,
+            statistic: Some(JsonStatistic::P90),
+            ..Settings::default()
+        };
+        let results =
+            opt_convert_file_path::<AdapterPythonPytest>(&file_path("data"), settings).unwrap();
+        let metric = results
+            .get("bench.py::test_fib_10")
+            .unwrap()
+            .get(Latency::SLUG_STR)
+            .unwrap();
+        // The value is the percentile that is bootstrapped, not the median.
+        let value = metric.value.into_inner();
+        assert!((value - 25_518.0).abs() < 0.001, "{value}");
+        let lower = metric.lower_value.unwrap().into_inner();
+        let upper = metric.upper_value.unwrap().into_inner();
+        assert!(lower <= value && value <= upper, "{lower} {value} {upper}");
+
+        // Truncated raw data falls back to the median, without a bootstrap.
+        let results =
+            opt_convert_file_path::<AdapterPythonPytest>(&file_path("two"), settings).unwrap();
+        assert_eq!(results, convert_python_pytest_median("two"));
+    }
+
+    #[test]
+    fn adapter_python_pytest_throughput() {
+        let results = convert_python_pytest("two");
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("bench.py::test_fib_10").unwrap();
+        validate_measure(
+            metrics,
+            Throughput::SLUG_STR,
+            41_513.272_817_492_856,
+            None,
+            None,
+        );
+
+        let metrics = results.get("bench.py::test_fib_20").unwrap();
+        validate_measure(
+            metrics,
+            Throughput::SLUG_STR,
+            335.004_932_833_156_7,
+            None,
+            None,
+        );
+    }
+
+    #[test]
+    fn adapter_python_pytest_statistic() {
+        // The statistic takes precedence over the average.
+        let results = convert_python_pytest_statistic("two", JsonStatistic::Mean);
+        validate_adapter_python_pytest_two(&results);
+
+        let results = convert_python_pytest_statistic("two", JsonStatistic::Median);
+        assert_eq!(results, convert_python_pytest_median("two"));
+
+        let results = convert_python_pytest_statistic("two", JsonStatistic::Min);
+        let metrics = results.get("bench.py::test_fib_10").unwrap();
+        validate_latency(
+            metrics,
+            22_067.000_000_181_026,
+            None,
+            Some(312_599.999_999_996_2),
+        );
+
+        let results = convert_python_pytest_statistic("two", JsonStatistic::Iqr);
+        let metrics = results.get("bench.py::test_fib_10").unwrap();
+        validate_latency(
+            metrics,
+            22_300.000_000_363_696,
+            Some(22_255.999_999_831_743),
+            Some(23_523.000_000_191_81),
+        );
+
+        // Percentiles are not reported, so without the raw data they fall back to the median.
+        let results = convert_python_pytest_statistic("two", JsonStatistic::P95);
+        assert_eq!(results, convert_python_pytest_median("two"));
+
+        // With the raw data, the percentile is calculated from it.
+        let results = convert_python_pytest_statistic("data", JsonStatistic::P90);
+        let metrics = results.get("bench.py::test_fib_10").unwrap();
+        validate_latency(metrics, 25_518.0, None, None);
+
+        // The operations per second are bounded by the slowest and fastest rounds.
+        let results = convert_python_pytest_statistic("two", JsonStatistic::Ops);
+        let metrics = results.get("bench.py::test_fib_10").unwrap();
+        validate_measure(
+            metrics,
+            Latency::SLUG_STR,
+            24_088.681_333_229_408,
+            Some(17_913.591_143_368_08),
+            Some(30_263.771_523_090_734),
+        );
+        validate_measure(
+            metrics,
+            Throughput::SLUG_STR,
+            41_513.272_817_492_856,
+            Some(1.0 / 0.000_312_599_999_999_996_2),
+            Some(1.0 / 0.000_022_067_000_000_181_025),
+        );
+    }
+
+    #[test]
+    fn adapter_python_pytest_extra_info() {
+        let results = convert_python_pytest("extra_info");
+        assert_eq!(results.inner.len(), 1);
+
+        // Only the numeric extra info is kept.
+        let metrics = results.get("bench.py::test_compress").unwrap();
+        assert_eq!(metrics.inner.len(), 4);
+        validate_measure(
+            metrics,
+            Latency::SLUG_STR,
+            24_088.681_333_229_408,
+            Some(17_913.591_143_368_08),
+            Some(30_263.771_523_090_734),
+        );
+        validate_measure(metrics, "compressed_bytes", 1_024.0, None, None);
+        validate_measure(metrics, "Compression Ratio", 2.5, None, None);
+    }
 }
@@ -125,7 +125,8 @@ pub(crate) mod test_ruby_benchmark {
             opt_convert_file_path::<AdapterRubyBenchmark>(
                 file_path,
                 Settings {
-                    average: Some(JsonAverage::Mean)
+                    average: Some(JsonAverage::Mean),
+                    ..Settings::default()
                 }
             )
         );
@@ -135,7 +136,8 @@ pub(crate) mod test_ruby_benchmark {
             opt_convert_file_path::<AdapterRubyBenchmark>(
                 file_path,
                 Settings {
-                    average: Some(JsonAverage::Median)
+                    average: Some(JsonAverage::Median),
+                    ..Settings::default()
                 }
             )
         );
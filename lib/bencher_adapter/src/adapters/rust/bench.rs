@@ -127,7 +127,8 @@ pub(crate) mod test_rust_bench {
             opt_convert_file_path::<AdapterRustBench>(
                 file_path,
                 Settings {
-                    average: Some(JsonAverage::Mean)
+                    average: Some(JsonAverage::Mean),
+                    ..Settings::default()
                 }
             )
         );
@@ -136,6 +137,7 @@ pub(crate) mod test_rust_bench {
             file_path,
             Settings {
                 average: Some(JsonAverage::Median),
+                ..Settings::default()
             },
         )
         .unwrap();
@@ -176,6 +176,7 @@ pub(crate) mod test_rust_criterion {
             file_path,
             Settings {
                 average: Some(JsonAverage::Mean),
+                ..Settings::default()
             },
         )
         .unwrap();
@@ -186,7 +187,8 @@ pub(crate) mod test_rust_criterion {
             opt_convert_file_path::<AdapterRustCriterion>(
                 file_path,
                 Settings {
-                    average: Some(JsonAverage::Median)
+                    average: Some(JsonAverage::Median),
+                    ..Settings::default()
                 }
             )
         );
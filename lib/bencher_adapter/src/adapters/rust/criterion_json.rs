@@ -0,0 +1,397 @@
+use bencher_json::{BenchmarkName, JsonNewMetric, project::report::JsonAverage};
+use ordered_float::OrderedFloat;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Adaptable, Settings,
+    results::{
+        adapter_results::{AdapterMeasure, AdapterResults},
+        bootstrap::{Statistic, confidence_interval},
+    },
+};
+
+pub struct AdapterRustCriterionJson;
+
+impl Adaptable for AdapterRustCriterionJson {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        serde_json::from_str::<CriterionJson>(input)
+            .ok()?
+            .convert(settings)
+    }
+}
+
+// Scale the median absolute deviation to be a consistent estimator of the standard deviation.
+// This is the same scale factor that Criterion uses for its `median_abs_dev` estimate.
+const MAD_SCALE: f64 = 1.4826;
+const NANOS_PER_SEC: f64 = 1_000_000_000.0;
+
+/// The files that Criterion writes to `target/criterion/**/new/` for each benchmark.
+/// `bencher run --file <directory>` bundles them together for all benchmarks in a Criterion output directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CriterionJson {
+    pub criterion: Vec<CriterionBenchmark>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CriterionBenchmark {
+    /// The contents of `benchmark.json`
+    pub benchmark: CriterionBenchmarkId,
+    /// The contents of `estimates.json`
+    pub estimates: Option<CriterionEstimates>,
+    /// The contents of `sample.json`
+    pub sample: Option<CriterionSample>,
+}
+
+// https://github.com/bheisler/criterion.rs/blob/0.5.1/src/report.rs#L38
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CriterionBenchmarkId {
+    pub full_id: BenchmarkName,
+    pub throughput: Option<CriterionThroughput>,
+}
+
+// https://github.com/bheisler/criterion.rs/blob/0.5.1/src/lib.rs#L1203
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum CriterionThroughput {
+    Bytes(u64),
+    BytesDecimal(u64),
+    Bits(u64),
+    Elements(u64),
+    ElementsAndBytes { elements: u64, bytes: u64 },
+}
+
+// https://github.com/bheisler/criterion.rs/blob/0.5.1/src/estimate.rs#L31
+// All times are in nanoseconds.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct CriterionEstimates {
+    pub mean: CriterionEstimate,
+    pub median: CriterionEstimate,
+    pub median_abs_dev: CriterionEstimate,
+    pub std_dev: CriterionEstimate,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct CriterionEstimate {
+    pub confidence_interval: CriterionConfidenceInterval,
+    pub point_estimate: f64,
+    pub standard_error: f64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct CriterionConfidenceInterval {
+    pub confidence_level: f64,
+    pub lower_bound: f64,
+    pub upper_bound: f64,
+}
+
+// https://github.com/bheisler/criterion.rs/blob/0.5.1/src/fs.rs
+// The total time in nanoseconds for each sample of `iters` iterations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CriterionSample {
+    pub iters: Vec<f64>,
+    pub times: Vec<f64>,
+}
+
+impl CriterionJson {
+    fn convert(self, settings: Settings) -> Option<AdapterResults> {
+        let mut benchmark_metrics = Vec::with_capacity(self.criterion.len());
+        for benchmark in self.criterion {
+            let CriterionBenchmark {
+                benchmark:
+                    CriterionBenchmarkId {
+                        full_id,
+                        throughput,
+                    },
+                estimates,
+                sample,
+            } = benchmark;
+
+            let latency = match (estimates, sample) {
+                (Some(estimates), _) => Some(estimates.latency(settings)),
+                (None, Some(sample)) => sample.latency(settings),
+                (None, None) => None,
+            };
+            let Some(latency) = latency else {
+                continue;
+            };
+
+            let mut measures = vec![AdapterMeasure::Latency(latency)];
+            if let Some(throughput) = throughput {
+                measures.extend(throughput.measures(&latency));
+            }
+            benchmark_metrics.push((full_id, measures));
+        }
+
+        AdapterResults::new_measures(benchmark_metrics)
+    }
+}
+
+impl CriterionEstimates {
+    // For the mean, the spread is the standard deviation.
+    // For the median, the spread is the median absolute deviation.
+    // With bootstrap enabled, use Criterion's own bootstrap confidence interval instead.
+    fn latency(self, settings: Settings) -> JsonNewMetric {
+        let (estimate, spread) = match settings.average.unwrap_or_default() {
+            JsonAverage::Mean => (self.mean, self.std_dev),
+            JsonAverage::Median => (self.median, self.median_abs_dev),
+        };
+        let value = estimate.point_estimate;
+        let (lower_value, upper_value) = if settings.bootstrap {
+            (
+                estimate.confidence_interval.lower_bound,
+                estimate.confidence_interval.upper_bound,
+            )
+        } else {
+            (value - spread.point_estimate, value + spread.point_estimate)
+        };
+        JsonNewMetric {
+            value: value.into(),
+            lower_value: Some(lower_value.into()),
+            upper_value: Some(upper_value.into()),
+        }
+    }
+}
+
+impl CriterionSample {
+    // Fall back to the raw samples if there are no estimates.
+    fn latency(self, settings: Settings) -> Option<JsonNewMetric> {
+        let mut per_iteration = self
+            .times
+            .iter()
+            .zip(&self.iters)
+            .filter(|(_, iters)| **iters > 0.0)
+            .map(|(time, iters)| time / iters)
+            .collect::<Vec<_>>();
+        let average = settings.average.unwrap_or_default();
+        let value = Statistic::from(average).estimate(&mut per_iteration)?;
+        let spread = if settings.bootstrap
+            && let Some(interval) = confidence_interval(&per_iteration, average.into())
+        {
+            Some(interval)
+        } else {
+            match average {
+                JsonAverage::Mean => std_dev(&per_iteration, value),
+                JsonAverage::Median => median_abs_dev(&per_iteration, value),
+            }
+            .map(|spread| (value - spread, value + spread))
+        };
+        Some(JsonNewMetric {
+            value: value.into(),
+            lower_value: spread.map(|(lower, _)| lower.into()),
+            upper_value: spread.map(|(_, upper)| upper.into()),
+        })
+    }
+}
+
+// Sample standard deviation
+fn std_dev(data: &[f64], mean: f64) -> Option<f64> {
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "sample size as f64 is fine for stats"
+    )]
+    let degrees = data.len().checked_sub(1).filter(|len| *len > 0)? as f64;
+    let variance = data.iter().map(|datum| (datum - mean).powi(2)).sum::<f64>() / degrees;
+    Some(variance.sqrt())
+}
+
+fn median_abs_dev(data: &[f64], median: f64) -> Option<f64> {
+    let mut deviations = data
+        .iter()
+        .map(|datum| (datum - median).abs())
+        .collect::<Vec<_>>();
+    Statistic::Median
+        .estimate(&mut deviations)
+        .map(|deviation| deviation * MAD_SCALE)
+}
+
+impl CriterionThroughput {
+    fn measures(self, latency: &JsonNewMetric) -> Vec<AdapterMeasure> {
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "throughput count as f64 is fine for stats"
+        )]
+        let (elements, bytes) = match self {
+            Self::Elements(elements) => (Some(elements as f64), None),
+            Self::Bytes(bytes) | Self::BytesDecimal(bytes) => (None, Some(bytes as f64)),
+            Self::Bits(bits) => (None, Some(bits as f64 / 8.0)),
+            Self::ElementsAndBytes { elements, bytes } => {
+                (Some(elements as f64), Some(bytes as f64))
+            },
+        };
+        elements
+            .and_then(|elements| per_second(elements, latency))
+            .map(AdapterMeasure::Throughput)
+            .into_iter()
+            .chain(
+                bytes
+                    .and_then(|bytes| per_second(bytes, latency))
+                    .map(AdapterMeasure::ByteThroughput),
+            )
+            .collect()
+    }
+}
+
+// Convert the latency in nanoseconds per iteration into a count per second.
+// The lower latency bound becomes the upper throughput bound and vice versa.
+fn per_second(count: f64, latency: &JsonNewMetric) -> Option<JsonNewMetric> {
+    let rate = |nanos: OrderedFloat<f64>| {
+        (nanos.into_inner() > 0.0).then(|| (count * NANOS_PER_SEC / nanos.into_inner()).into())
+    };
+    Some(JsonNewMetric {
+        value: rate(latency.value)?,
+        lower_value: latency.upper_value.and_then(rate),
+        upper_value: latency.lower_value.and_then(rate),
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod test_rust_criterion_json {
+    use bencher_json::project::{
+        measure::built_in::{
+            BuiltInMeasure as _,
+            default::{Latency, Throughput},
+            throughput::ByteThroughput,
+        },
+        report::JsonAverage,
+    };
+    use ordered_float::OrderedFloat;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        AdapterResults, Settings,
+        adapters::test_util::{
+            convert_file_path, convert_file_path_median, opt_convert_file_path, validate_measure,
+        },
+    };
+
+    use super::{AdapterRustCriterionJson, MAD_SCALE, NANOS_PER_SEC};
+
+    fn file_path(suffix: &str) -> String {
+        format!("./tool_output/rust/criterion_json/{suffix}.json")
+    }
+
+    #[test]
+    fn adapter_rust_criterion_json_three() {
+        let results = convert_file_path::<AdapterRustCriterionJson>(&file_path("three"));
+        validate_adapter_rust_criterion_json(&results);
+    }
+
+    #[test]
+    fn adapter_rust_criterion_json_median() {
+        let results = convert_file_path_median::<AdapterRustCriterionJson>(&file_path("three"));
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("fib/20").unwrap();
+        validate_measure(
+            metrics,
+            Latency::SLUG_STR,
+            19_500.0,
+            Some(19_500.0 - 3_000.0),
+            Some(19_500.0 + 3_000.0),
+        );
+        validate_measure(
+            metrics,
+            Throughput::SLUG_STR,
+            20.0 * NANOS_PER_SEC / 19_500.0,
+            Some(20.0 * NANOS_PER_SEC / 22_500.0),
+            Some(20.0 * NANOS_PER_SEC / 16_500.0),
+        );
+
+        // The raw samples are [100, 110, 100, 110] nanoseconds per iteration.
+        let metrics = results.get("parse").unwrap();
+        validate_measure(
+            metrics,
+            Latency::SLUG_STR,
+            105.0,
+            Some(105.0 - 5.0 * MAD_SCALE),
+            Some(105.0 + 5.0 * MAD_SCALE),
+        );
+    }
+
+    #[test]
+    fn adapter_rust_criterion_json_bootstrap() {
+        let results = opt_convert_file_path::<AdapterRustCriterionJson>(
+            &file_path("three"),
+            Settings {
+                average: Some(JsonAverage::Mean),
+                bootstrap: true,
+                ..Settings::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(results.inner.len(), 3);
+
+        // Criterion's own bootstrap confidence interval is used.
+        let metrics = results.get("fib/20").unwrap();
+        validate_measure(
+            metrics,
+            Latency::SLUG_STR,
+            20_000.0,
+            Some(19_800.0),
+            Some(20_200.0),
+        );
+
+        // The raw samples are bootstrapped.
+        let metrics = results.get("parse").unwrap();
+        let latency = metrics.get(Latency::SLUG_STR).unwrap();
+        assert_eq!(latency.value, OrderedFloat::from(105.0));
+        let lower_value = latency.lower_value.unwrap().into_inner();
+        let upper_value = latency.upper_value.unwrap().into_inner();
+        assert!((100.0..=105.0).contains(&lower_value), "{lower_value}");
+        assert!((105.0..=110.0).contains(&upper_value), "{upper_value}");
+    }
+
+    #[test]
+    fn adapter_rust_criterion_json_invalid() {
+        assert!(
+            opt_convert_file_path::<AdapterRustCriterionJson>(
+                "./tool_output/rust/criterion/many.txt",
+                Settings::default(),
+            )
+            .is_none()
+        );
+    }
+
+    pub fn validate_adapter_rust_criterion_json(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("fib/20").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_measure(
+            metrics,
+            Latency::SLUG_STR,
+            20_000.0,
+            Some(16_000.0),
+            Some(24_000.0),
+        );
+        validate_measure(
+            metrics,
+            Throughput::SLUG_STR,
+            20.0 * NANOS_PER_SEC / 20_000.0,
+            Some(20.0 * NANOS_PER_SEC / 24_000.0),
+            Some(20.0 * NANOS_PER_SEC / 16_000.0),
+        );
+
+        let metrics = results.get("checksum/1024").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_measure(metrics, Latency::SLUG_STR, 512.0, Some(448.0), Some(576.0));
+        validate_measure(
+            metrics,
+            ByteThroughput::SLUG_STR,
+            1024.0 * NANOS_PER_SEC / 512.0,
+            Some(1024.0 * NANOS_PER_SEC / 576.0),
+            Some(1024.0 * NANOS_PER_SEC / 448.0),
+        );
+
+        // There is no `estimates.json`, so the raw samples are used.
+        let metrics = results.get("parse").unwrap();
+        assert_eq!(metrics.inner.len(), 1);
+        let std_dev = f64::sqrt(100.0 / 3.0);
+        validate_measure(
+            metrics,
+            Latency::SLUG_STR,
+            105.0,
+            Some(105.0 - std_dev),
+            Some(105.0 + std_dev),
+        );
+    }
+}
@@ -1,11 +1,15 @@
 pub mod bench;
 pub mod criterion;
+pub mod criterion_json;
 pub mod gungraun;
 pub mod gungraun_json;
 pub mod gungraun_stdout;
 pub mod iai;
 
-use self::{criterion::AdapterRustCriterion, gungraun::AdapterRustGungraun, iai::AdapterRustIai};
+use self::{
+    criterion::AdapterRustCriterion, criterion_json::AdapterRustCriterionJson,
+    gungraun::AdapterRustGungraun, iai::AdapterRustIai,
+};
 use crate::{Adaptable, AdapterResults, Settings};
 use bench::AdapterRustBench;
 
@@ -15,6 +19,7 @@ impl Adaptable for AdapterRust {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
         AdapterRustBench::parse(input, settings)
             .or_else(|| AdapterRustCriterion::parse(input, settings))
+            .or_else(|| AdapterRustCriterionJson::parse(input, settings))
             .or_else(|| AdapterRustIai::parse(input, settings))
             .or_else(|| AdapterRustGungraun::parse(input, settings))
     }
@@ -26,8 +31,8 @@ mod test_rust {
     use crate::adapters::{
         rust::{
             bench::test_rust_bench, criterion::test_rust_criterion,
-            gungraun_json::test_rust_gungraun_json, gungraun_stdout::test_rust_gungraun_stdout,
-            iai::test_rust_iai,
+            criterion_json::test_rust_criterion_json, gungraun_json::test_rust_gungraun_json,
+            gungraun_stdout::test_rust_gungraun_stdout, iai::test_rust_iai,
         },
         test_util::convert_file_path,
     };
@@ -44,6 +49,13 @@ mod test_rust {
         test_rust_criterion::validate_adapter_rust_criterion(&results);
     }
 
+    #[test]
+    fn adapter_rust_criterion_json() {
+        let results =
+            convert_file_path::<AdapterRust>("./tool_output/rust/criterion_json/three.json");
+        test_rust_criterion_json::validate_adapter_rust_criterion_json(&results);
+    }
+
     #[test]
     fn adapter_rust_iai() {
         let results = convert_file_path::<AdapterRust>("./tool_output/rust/iai/two.txt");
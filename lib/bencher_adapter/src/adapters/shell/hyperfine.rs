@@ -5,7 +5,7 @@ use serde::Deserialize;
 use crate::{
     Adaptable, AdapterError, Settings,
     adapters::util::{Units, latency_as_nanos},
-    results::adapter_results::AdapterResults,
+    results::{adapter_results::AdapterResults, bootstrap::confidence_interval},
 };
 
 pub struct AdapterShellHyperfine;
@@ -58,15 +58,24 @@ impl Hyperfine {
                 median,
                 min,
                 max,
+                times,
                 ..
             } = result;
 
             // JSON output is always in seconds
             let units = Units::Sec;
-            let (average, spread) = match settings.average.unwrap_or_default() {
+            let json_average = settings.average.unwrap_or_default();
+            let (average, mut spread) = match json_average {
                 JsonAverage::Mean => (mean, stddev.map(|stddev| (mean - stddev, mean + stddev))),
                 JsonAverage::Median => (median, Some((min, max))),
             };
+            if settings.bootstrap
+                && let Some((lower, upper)) = times
+                    .as_deref()
+                    .and_then(|times| confidence_interval(times, json_average.into()))
+            {
+                spread = Some((lower.into(), upper.into()));
+            }
             let value = latency_as_nanos(average, units);
             let (lower_value, upper_value) = spread.map_or((None, None), |(lower, upper)| {
                 (
@@ -89,7 +98,10 @@ impl Hyperfine {
 
 #[cfg(test)]
 pub(crate) mod test_shell_hyperfine {
-    use bencher_json::project::report::JsonAverage;
+    use bencher_json::project::{
+        measure::built_in::{BuiltInMeasure as _, default::Latency},
+        report::JsonAverage,
+    };
     use pretty_assertions::assert_eq;
 
     use crate::{
@@ -127,6 +139,7 @@ pub(crate) mod test_shell_hyperfine {
             &file_path,
             Settings {
                 average: Some(JsonAverage::Mean),
+                ..Settings::default()
             },
         )
         .unwrap();
@@ -136,6 +149,38 @@ pub(crate) mod test_shell_hyperfine {
         validate_adapter_shell_hyperfine_median(&results);
     }
 
+    #[test]
+    fn adapter_shell_hyperfine_bootstrap() {
+        for average in [JsonAverage::Mean, JsonAverage::Median] {
+            let results = opt_convert_file_path::<AdapterShellHyperfine>(
+                &file_path("two"),
+                Settings {
+                    average: Some(average),
+                    bootstrap: true,
+                    ..Settings::default()
+                },
+            )
+            .unwrap();
+            assert_eq!(results.inner.len(), 2);
+
+            for benchmark in ["sleep 0.1", "sleep 0.2"] {
+                let metric = results
+                    .get(benchmark)
+                    .unwrap()
+                    .get(Latency::SLUG_STR)
+                    .unwrap();
+                let lower = metric.lower_value.unwrap();
+                let upper = metric.upper_value.unwrap();
+                assert!(
+                    lower <= metric.value && metric.value <= upper,
+                    "{average:?} {benchmark}: {lower} <= {} <= {upper}",
+                    metric.value
+                );
+                assert!(lower < upper, "{average:?} {benchmark}: {lower} < {upper}");
+            }
+        }
+    }
+
     pub fn validate_adapter_shell_hyperfine(results: &AdapterResults) {
         assert_eq!(results.inner.len(), 2);
 
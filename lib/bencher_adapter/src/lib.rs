@@ -18,12 +18,12 @@ use adapters::{
     ruby::{AdapterRuby, benchmark::AdapterRubyBenchmark},
     rust::{
         AdapterRust, bench::AdapterRustBench, criterion::AdapterRustCriterion,
-        gungraun_json::AdapterRustGungraunJson, gungraun_stdout::AdapterRustGungraunStdout,
-        iai::AdapterRustIai,
+        criterion_json::AdapterRustCriterionJson, gungraun_json::AdapterRustGungraunJson,
+        gungraun_stdout::AdapterRustGungraunStdout, iai::AdapterRustIai,
     },
     shell::{AdapterShell, hyperfine::AdapterShellHyperfine},
 };
-use bencher_json::project::report::{Adapter, JsonAverage};
+use bencher_json::project::report::{Adapter, JsonAverage, JsonStatistic};
 pub use bencher_json::{BenchmarkName, JsonNewMetric};
 pub use error::AdapterError;
 pub use results::{AdapterResultsArray, adapter_results::AdapterResults};
@@ -66,6 +66,7 @@ impl Adaptable for Adapter {
             Adapter::Rust => AdapterRust::parse(input, settings),
             Adapter::RustBench => AdapterRustBench::parse(input, settings),
             Adapter::RustCriterion => AdapterRustCriterion::parse(input, settings),
+            Adapter::RustCriterionJson => AdapterRustCriterionJson::parse(input, settings),
             Adapter::RustIai => AdapterRustIai::parse(input, settings),
             Adapter::RustGungraun => AdapterRustGungraun::parse(input, settings),
             Adapter::RustGungraunStdout => AdapterRustGungraunStdout::parse(input, settings),
@@ -83,10 +84,29 @@ impl Adaptable for Adapter {
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Settings {
     pub average: Option<JsonAverage>,
+    /// Use bootstrap confidence intervals for the lower and upper values
+    /// whenever there are raw samples or multiple iterations to fold.
+    pub bootstrap: bool,
+    /// The statistic to use for harnesses that report many statistics.
+    /// If set, this takes precedence over the `average`.
+    pub statistic: Option<JsonStatistic>,
+    /// Name parameterized benchmarks with their parameters,
+    /// so each set of parameters is a separate benchmark.
+    pub params: bool,
 }
 
 impl Settings {
-    pub fn new(average: Option<JsonAverage>) -> Self {
-        Self { average }
+    pub fn new(
+        average: Option<JsonAverage>,
+        bootstrap: bool,
+        statistic: Option<JsonStatistic>,
+        params: bool,
+    ) -> Self {
+        Self {
+            average,
+            bootstrap,
+            statistic,
+            params,
+        }
     }
 }
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use bencher_json::{
-    BenchmarkName, BenchmarkNameId, JsonNewMetric,
+    BenchmarkName, BenchmarkNameId, JsonNewMetric, JsonResultsMap,
     project::{
         measure::built_in::{self, BuiltInMeasure as _},
         metric::Mean,
@@ -30,6 +30,7 @@ impl From<ResultsMap> for AdapterResults {
 pub enum AdapterMeasure {
     Latency(JsonNewMetric),
     Throughput(JsonNewMetric),
+    ByteThroughput(JsonNewMetric),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -40,6 +41,9 @@ pub enum DotNetMeasure {
     Gen2Collects(JsonNewMetric),
     TotalOperations(JsonNewMetric),
     Allocated(JsonNewMetric),
+    CompletedWorkItems(JsonNewMetric),
+    LockContentions(JsonNewMetric),
+    Exceptions(JsonNewMetric),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -162,6 +166,11 @@ impl AdapterResults {
                             built_in::default::Throughput::name_id() => json_metric
                         }
                     },
+                    AdapterMeasure::ByteThroughput(json_metric) => {
+                        hmap! {
+                            built_in::throughput::ByteThroughput::name_id() => json_metric
+                        }
+                    },
                 },
             };
             results_map.insert(BenchmarkNameId::new_name(benchmark_name), adapter_metrics);
@@ -170,6 +179,17 @@ impl AdapterResults {
         Some(results_map.into())
     }
 
+    /// Results that are already in Bencher Metric Format (BMF).
+    pub fn from_results_map(results_map: JsonResultsMap) -> Self {
+        results_map
+            .into_iter()
+            .map(|(benchmark_name, metrics)| {
+                (BenchmarkNameId::new_name(benchmark_name), metrics.into())
+            })
+            .collect::<ResultsMap>()
+            .into()
+    }
+
     pub fn new_latency(benchmark_metrics: Vec<(BenchmarkName, JsonNewMetric)>) -> Option<Self> {
         Self::new(
             benchmark_metrics
@@ -214,6 +234,9 @@ impl AdapterResults {
                     AdapterMeasure::Throughput(json_metric) => {
                         (built_in::default::Throughput::name_id(), json_metric)
                     },
+                    AdapterMeasure::ByteThroughput(json_metric) => {
+                        (built_in::throughput::ByteThroughput::name_id(), json_metric)
+                    },
                 };
                 metrics_value.inner.insert(resource_id, metric);
             }
@@ -287,6 +310,15 @@ impl AdapterResults {
                     DotNetMeasure::TotalOperations(json_metric) => {
                         (built_in::dotnet::TotalOperations::name_id(), json_metric)
                     },
+                    DotNetMeasure::CompletedWorkItems(json_metric) => {
+                        (built_in::dotnet::CompletedWorkItems::name_id(), json_metric)
+                    },
+                    DotNetMeasure::LockContentions(json_metric) => {
+                        (built_in::dotnet::LockContentions::name_id(), json_metric)
+                    },
+                    DotNetMeasure::Exceptions(json_metric) => {
+                        (built_in::dotnet::Exceptions::name_id(), json_metric)
+                    },
                 };
                 metrics_value.inner.insert(resource_id, metric);
             }
@@ -0,0 +1,195 @@
+use bencher_json::project::report::{JsonAverage, JsonFold, JsonStatistic};
+use rand::{RngExt as _, SeedableRng as _, rngs::StdRng};
+
+/// The number of bootstrap resamples.
+pub const BOOTSTRAP_RESAMPLES: usize = 1_000;
+/// The confidence level for the bootstrap percentile interval.
+pub const BOOTSTRAP_CONFIDENCE: f64 = 0.95;
+// A fixed seed so that the same samples always produce the same interval.
+const BOOTSTRAP_SEED: u64 = 0;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Statistic {
+    Min,
+    Max,
+    Mean,
+    Median,
+    /// A percentile of the samples, between `0.0` and `1.0`.
+    Percentile(f64),
+}
+
+impl From<JsonAverage> for Statistic {
+    fn from(average: JsonAverage) -> Self {
+        match average {
+            JsonAverage::Mean => Self::Mean,
+            JsonAverage::Median => Self::Median,
+        }
+    }
+}
+
+impl From<JsonFold> for Statistic {
+    fn from(fold: JsonFold) -> Self {
+        match fold {
+            JsonFold::Min => Self::Min,
+            JsonFold::Max => Self::Max,
+            JsonFold::Mean => Self::Mean,
+            JsonFold::Median => Self::Median,
+        }
+    }
+}
+
+impl From<JsonStatistic> for Statistic {
+    fn from(statistic: JsonStatistic) -> Self {
+        match statistic {
+            // The operations per second are the reciprocal of the mean.
+            JsonStatistic::Mean | JsonStatistic::Ops => Self::Mean,
+            // The interquartile range is centered on the median.
+            JsonStatistic::Median | JsonStatistic::Iqr => Self::Median,
+            JsonStatistic::Min => Self::Min,
+            JsonStatistic::P90 => Self::Percentile(0.90),
+            JsonStatistic::P95 => Self::Percentile(0.95),
+        }
+    }
+}
+
+impl Statistic {
+    pub(crate) fn estimate(self, sample: &mut [f64]) -> Option<f64> {
+        match self {
+            Self::Min => sample.iter().copied().reduce(f64::min),
+            Self::Max => sample.iter().copied().reduce(f64::max),
+            Self::Mean => {
+                #[expect(
+                    clippy::cast_precision_loss,
+                    reason = "sample size as f64 is fine for stats"
+                )]
+                let len = sample.len() as f64;
+                (!sample.is_empty()).then(|| sample.iter().sum::<f64>() / len)
+            },
+            Self::Median => {
+                sample.sort_unstable_by(f64::total_cmp);
+                #[expect(clippy::integer_division, reason = "middle index")]
+                let middle = sample.len() / 2;
+                if sample.len().is_multiple_of(2) {
+                    let left = sample.get(middle.checked_sub(1)?)?;
+                    let right = sample.get(middle)?;
+                    Some(f64::midpoint(*left, *right))
+                } else {
+                    sample.get(middle).copied()
+                }
+            },
+            Self::Percentile(percent) => {
+                sample.sort_unstable_by(f64::total_cmp);
+                percentile(sample, percent)
+            },
+        }
+    }
+}
+
+/// Bootstrap percentile confidence interval for a statistic of the samples.
+/// <https://en.wikipedia.org/wiki/Bootstrapping_(statistics)>
+///
+/// Returns `None` if there are fewer than two samples or any sample is not finite.
+pub fn confidence_interval(samples: &[f64], statistic: Statistic) -> Option<(f64, f64)> {
+    if samples.len() < 2 || samples.iter().any(|sample| !sample.is_finite()) {
+        return None;
+    }
+
+    let mut rng = StdRng::seed_from_u64(BOOTSTRAP_SEED);
+    let mut resample = vec![0.0; samples.len()];
+    let mut estimates = Vec::with_capacity(BOOTSTRAP_RESAMPLES);
+    for _ in 0..BOOTSTRAP_RESAMPLES {
+        for value in &mut resample {
+            *value = *samples.get(rng.random_range(0..samples.len()))?;
+        }
+        estimates.push(statistic.estimate(&mut resample)?);
+    }
+    estimates.sort_unstable_by(f64::total_cmp);
+
+    let tail = (1.0 - BOOTSTRAP_CONFIDENCE) / 2.0;
+    Some((
+        percentile(&estimates, tail)?,
+        percentile(&estimates, 1.0 - tail)?,
+    ))
+}
+
+// Nearest rank percentile of sorted data.
+fn percentile(sorted: &[f64], percent: f64) -> Option<f64> {
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss,
+        reason = "rank is a non-negative index within the data"
+    )]
+    let rank = ((sorted.len().checked_sub(1)? as f64) * percent).round() as usize;
+    sorted.get(rank).copied()
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::float_cmp,
+    reason = "exact float equality is intended in tests"
+)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{Statistic, confidence_interval};
+
+    const DATA_ONE: &[f64] = &[1.0];
+    const DATA_CONST: &[f64] = &[3.0, 3.0, 3.0, 3.0];
+    const DATA_TEN: &[f64] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+
+    #[test]
+    fn bootstrap_too_few() {
+        assert_eq!(confidence_interval(&[], Statistic::Mean), None);
+        assert_eq!(confidence_interval(DATA_ONE, Statistic::Mean), None);
+        assert_eq!(confidence_interval(&[1.0, f64::NAN], Statistic::Mean), None);
+    }
+
+    #[test]
+    fn bootstrap_const() {
+        for statistic in [
+            Statistic::Min,
+            Statistic::Max,
+            Statistic::Mean,
+            Statistic::Median,
+            Statistic::Percentile(0.95),
+        ] {
+            assert_eq!(confidence_interval(DATA_CONST, statistic), Some((3.0, 3.0)));
+        }
+    }
+
+    #[test]
+    fn bootstrap_mean() {
+        let (lower, upper) = confidence_interval(DATA_TEN, Statistic::Mean).unwrap();
+        assert!(lower < 5.5 && 5.5 < upper, "{lower} < 5.5 < {upper}");
+        assert!(lower >= 1.0 && upper <= 10.0, "{lower}, {upper}");
+        // The interval is deterministic.
+        assert_eq!(
+            confidence_interval(DATA_TEN, Statistic::Mean),
+            Some((lower, upper))
+        );
+    }
+
+    #[test]
+    fn bootstrap_median() {
+        let (lower, upper) = confidence_interval(DATA_TEN, Statistic::Median).unwrap();
+        assert!(lower < 5.5 && 5.5 < upper, "{lower} < 5.5 < {upper}");
+    }
+
+    #[test]
+    fn bootstrap_percentile() {
+        let (lower, upper) = confidence_interval(DATA_TEN, Statistic::Percentile(0.9)).unwrap();
+        assert!(lower <= 9.0 && 9.0 <= upper, "{lower} <= 9.0 <= {upper}");
+        assert!(upper <= 10.0, "{upper}");
+    }
+
+    #[test]
+    fn bootstrap_min_max() {
+        let (lower, upper) = confidence_interval(DATA_TEN, Statistic::Min).unwrap();
+        assert_eq!(lower, 1.0);
+        assert!(upper >= 1.0, "{upper}");
+        let (lower, upper) = confidence_interval(DATA_TEN, Statistic::Max).unwrap();
+        assert_eq!(upper, 10.0);
+        assert!(lower <= 10.0, "{lower}");
+    }
+}
@@ -9,6 +9,7 @@ use crate::{Adaptable as _, AdapterError, Settings};
 
 pub mod adapter_metrics;
 pub mod adapter_results;
+pub mod bootstrap;
 pub mod results_reducer;
 
 use adapter_results::{AdapterResults, ResultsMap};
@@ -17,13 +18,17 @@ use results_reducer::ResultsReducer;
 #[derive(Debug, Clone)]
 pub struct AdapterResultsArray {
     pub inner: ResultsArray,
+    pub settings: Settings,
 }
 
 pub type ResultsArray = Vec<AdapterResults>;
 
 impl From<ResultsArray> for AdapterResultsArray {
     fn from(inner: ResultsArray) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            settings: Settings::default(),
+        }
     }
 }
 
@@ -40,7 +45,10 @@ impl AdapterResultsArray {
                 .ok_or_else(|| AdapterError::Convert((results).to_owned()))?;
             parsed_results_array.push(parsed_results);
         }
-        Ok(parsed_results_array.into())
+        Ok(Self {
+            inner: parsed_results_array,
+            settings,
+        })
     }
 
     pub fn min(self) -> AdapterResults {
@@ -78,12 +86,19 @@ impl AdapterResultsArray {
             return AdapterResults::default();
         }
 
-        match fold {
+        // Bootstrap the folded statistic across all of the iterations.
+        let results_reducer = (self.settings.bootstrap && self.inner.len() > 1)
+            .then(|| ResultsReducer::from(self.clone()));
+        let mut results = match fold {
             JsonFold::Min => self.min(),
             JsonFold::Max => self.max(),
             JsonFold::Mean => self.mean(),
             JsonFold::Median => self.median(),
+        };
+        if let Some(results_reducer) = results_reducer {
+            results_reducer.bootstrap(&mut results, fold.into());
         }
+        results
     }
 }
 
@@ -98,3 +113,58 @@ pub(crate) enum CombinedKind {
     Ord(OrdKind),
     Add,
 }
+
+#[cfg(test)]
+mod tests {
+    use bencher_json::project::{
+        measure::built_in::{BuiltInMeasure as _, default::Latency},
+        report::{Adapter, JsonFold},
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::AdapterResultsArray;
+    use crate::Settings;
+
+    const RESULTS: &[&str] = &[
+        r#"{"tests::bench": {"latency": {"value": 1.0}}}"#,
+        r#"{"tests::bench": {"latency": {"value": 2.0}}}"#,
+        r#"{"tests::bench": {"latency": {"value": 3.0}}}"#,
+        r#"{"tests::bench": {"latency": {"value": 4.0}}}"#,
+    ];
+
+    #[test]
+    fn fold_bootstrap() {
+        let results_array =
+            AdapterResultsArray::new(RESULTS, Adapter::Json, Settings::default()).unwrap();
+        let results = results_array.fold(JsonFold::Mean);
+        let metric = results
+            .get("tests::bench")
+            .unwrap()
+            .get(Latency::SLUG_STR)
+            .unwrap();
+        assert_eq!(metric.value, 2.5);
+        assert_eq!(metric.lower_value, None);
+        assert_eq!(metric.upper_value, None);
+
+        let results_array = AdapterResultsArray::new(
+            RESULTS,
+            Adapter::Json,
+            Settings {
+                bootstrap: true,
+                ..Settings::default()
+            },
+        )
+        .unwrap();
+        let results = results_array.fold(JsonFold::Mean);
+        let metric = results
+            .get("tests::bench")
+            .unwrap()
+            .get(Latency::SLUG_STR)
+            .unwrap();
+        assert_eq!(metric.value, 2.5);
+        let lower = metric.lower_value.unwrap().into_inner();
+        let upper = metric.upper_value.unwrap().into_inner();
+        assert!((1.0..2.5).contains(&lower), "{lower}");
+        assert!(2.5 < upper && upper <= 4.0, "{upper}");
+    }
+}
@@ -3,7 +3,10 @@ use std::collections::HashMap;
 use bencher_json::{BenchmarkNameId, JsonNewMetric, MeasureNameId, project::metric::Median as _};
 
 use super::{
-    AdapterResultsArray, adapter_metrics::AdapterMetrics, adapter_results::AdapterResults,
+    AdapterResultsArray,
+    adapter_metrics::AdapterMetrics,
+    adapter_results::AdapterResults,
+    bootstrap::{Statistic, confidence_interval},
 };
 
 #[derive(Debug, Clone, Default)]
@@ -22,6 +25,29 @@ impl From<AdapterResultsArray> for ResultsReducer {
 }
 
 impl ResultsReducer {
+    /// Set the lower and upper values of the folded results
+    /// to the bootstrap confidence interval of the statistic across all iterations.
+    pub(crate) fn bootstrap(&self, results: &mut AdapterResults, statistic: Statistic) {
+        for (benchmark, measures_map) in &self.inner {
+            let Some(metrics) = results.inner.get_mut(benchmark) else {
+                continue;
+            };
+            for (measure, list) in &measures_map.inner {
+                let Some(metric) = metrics.inner.get_mut(measure) else {
+                    continue;
+                };
+                let values = list
+                    .iter()
+                    .map(|metric| metric.value.into_inner())
+                    .collect::<Vec<_>>();
+                if let Some((lower, upper)) = confidence_interval(&values, statistic) {
+                    metric.lower_value = Some(lower.into());
+                    metric.upper_value = Some(upper.into());
+                }
+            }
+        }
+    }
+
     fn reduce(&mut self, results: AdapterResults) {
         for (benchmark, metrics) in results.inner {
             if let Some(measures_map) = self.inner.get_mut(&benchmark) {
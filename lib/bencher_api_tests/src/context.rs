@@ -16,7 +16,7 @@ use diesel::{
     r2d2::{ConnectionManager, Pool},
 };
 use dropshot::{ApiDescription, ConfigDropshot, ConfigLogging, ConfigLoggingLevel, HttpServer};
-use tempfile::NamedTempFile;
+use tempfile::{NamedTempFile, TempDir};
 use tokio::sync::Mutex;
 
 const ISSUER: &str = "http://localhost:3000";
@@ -39,18 +39,28 @@ pub struct TestServer {
     db_path: String,
     /// Keep the temp file alive for the duration of the test
     db_file: NamedTempFile,
+    /// Keep the temp directory alive for the duration of the test.
+    /// The local OCI storage is placed next to the database,
+    /// so each test server has its own registry data.
+    _db_dir: TempDir,
 }
 
 impl TestServer {
     /// Create a new test server with default settings.
     pub async fn new() -> Self {
-        Self::build(None, None, None, None).await
+        Self::build(None, None, None, None, false).await
+    }
+
+    /// Create a new test server that allows webhooks to use `http` and non-public addresses,
+    /// so they can be delivered to a local listener.
+    pub async fn new_with_insecure_webhooks() -> Self {
+        Self::build(None, None, None, None, true).await
     }
 
     /// Create a new test server with custom upload timeout and max body size.
     #[cfg(feature = "plus")]
     pub async fn new_with_limits(upload_timeout: u64, max_body_size: u64) -> Self {
-        Self::build(Some(upload_timeout), Some(max_body_size), None, None).await
+        Self::build(Some(upload_timeout), Some(max_body_size), None, None, false).await
     }
 
     /// Create a new test server with custom upload timeout, max body size, and injectable clock.
@@ -60,13 +70,20 @@ impl TestServer {
         max_body_size: u64,
         clock: bencher_json::Clock,
     ) -> Self {
-        Self::build(Some(upload_timeout), Some(max_body_size), Some(clock), None).await
+        Self::build(
+            Some(upload_timeout),
+            Some(max_body_size),
+            Some(clock),
+            None,
+            false,
+        )
+        .await
     }
 
     /// Create a new test server with a custom runner self-update base URL.
     #[cfg(feature = "plus")]
     pub async fn new_with_runner_update_base_url(base_url: url::Url) -> Self {
-        Self::build(None, None, None, Some(base_url)).await
+        Self::build(None, None, None, Some(base_url), false).await
     }
 
     #[cfg(feature = "plus")]
@@ -80,6 +97,7 @@ impl TestServer {
         max_body_size: Option<u64>,
         clock: Option<bencher_json::Clock>,
         runner_update_base_url: Option<url::Url>,
+        allow_insecure_webhooks: bool,
     ) -> Self {
         // Create logger early so it can be used for OCI storage
         let log_config = ConfigLogging::StderrTerminal {
@@ -89,8 +107,9 @@ impl TestServer {
             .to_logger("bencher_api_tests")
             .expect("Failed to create logger");
 
-        // Create a temporary database file
-        let db_file = NamedTempFile::new().expect("Failed to create temp db file");
+        // Create a temporary database file in its own directory
+        let db_dir = TempDir::new().expect("Failed to create temp db directory");
+        let db_file = NamedTempFile::new_in(db_dir.path()).expect("Failed to create temp db file");
         let db_path = db_file.path().to_str().expect("Invalid db path").to_owned();
 
         // Establish connection and run migrations
@@ -141,6 +160,7 @@ impl TestServer {
             recaptcha_client: None,
             is_bencher_cloud: false,
             clock: clock.clone().unwrap_or(bencher_json::Clock::System),
+            allow_insecure_webhooks,
             registry_url: bencher_json::LOCALHOST_BENCHER_REGISTRY_URL.clone(),
             oci_storage: bencher_oci_storage::OciStorage::try_from_config(
                 log.clone(),
@@ -150,7 +170,9 @@ impl TestServer {
                 max_body_size,
                 clock,
             )
-            .expect("Failed to create OCI storage"),
+            .expect("Failed to create OCI storage")
+            .into(),
+            registry_gc_interval: None,
             heartbeat_timeout: std::time::Duration::from_secs(5),
             job_timeout_grace_period: std::time::Duration::from_mins(1),
             heartbeat_tasks: bencher_schema::context::HeartbeatTasks::new(),
@@ -158,7 +180,7 @@ impl TestServer {
             shutdown: bencher_schema::context::CancellationToken::new(),
         };
 
-        Self::start_server(context, &log, token_key, db_path, db_file)
+        Self::start_server(context, &log, token_key, db_path, db_file, db_dir)
     }
 
     #[cfg(not(feature = "plus"))]
@@ -173,6 +195,7 @@ impl TestServer {
         max_body_size: Option<u64>,
         _clock: Option<()>,
         _runner_update_base_url: Option<url::Url>,
+        allow_insecure_webhooks: bool,
     ) -> Self {
         // Create logger early so it can be used for OCI storage
         let log_config = ConfigLogging::StderrTerminal {
@@ -182,8 +205,9 @@ impl TestServer {
             .to_logger("bencher_api_tests")
             .expect("Failed to create logger");
 
-        // Create a temporary database file
-        let db_file = NamedTempFile::new().expect("Failed to create temp db file");
+        // Create a temporary database file in its own directory
+        let db_dir = TempDir::new().expect("Failed to create temp db directory");
+        let db_file = NamedTempFile::new_in(db_dir.path()).expect("Failed to create temp db file");
         let db_path = db_file.path().to_str().expect("Invalid db path").to_owned();
 
         // Establish connection and run migrations
@@ -223,9 +247,11 @@ impl TestServer {
             messenger: Messenger::default(),
             database,
             clock: bencher_json::Clock::System,
+            allow_insecure_webhooks,
+            shutdown: bencher_schema::context::CancellationToken::new(),
         };
 
-        Self::start_server(context, &log, token_key, db_path, db_file)
+        Self::start_server(context, &log, token_key, db_path, db_file, db_dir)
     }
 
     #[expect(clippy::expect_used, reason = "test server startup with fallible init")]
@@ -235,6 +261,7 @@ impl TestServer {
         token_key: TokenKey,
         db_path: String,
         db_file: NamedTempFile,
+        db_dir: TempDir,
     ) -> Self {
         // Create API description and register endpoints
         let mut api_description = ApiDescription::new();
@@ -281,6 +308,7 @@ impl TestServer {
             token_key,
             db_path,
             db_file,
+            _db_dir: db_dir,
         }
     }
 
@@ -1,16 +1,21 @@
-use bencher_json::{Boundary, ModelTest, SampleSize, project::boundary::BoundaryLimit};
+use bencher_json::{
+    Boundary, CdfBoundary, ModelTest, SampleSize, project::boundary::BoundaryLimit,
+};
 use slog::Logger;
 
 use crate::limits::{MetricsLimits, NormalTestKind};
 use crate::ln::Ln;
 use crate::mean::Mean;
 use crate::quartiles::Quartiles;
+use crate::rank::{MANN_WHITNEY_RECENT_SIZE, MannWhitney};
 use crate::{BoundaryError, MetricsData};
 
 #[derive(Debug, Default)]
 pub struct MetricsBoundary {
     pub limits: MetricsLimits,
     pub outlier: Option<BoundaryLimit>,
+    /// The p-value of the test, if the model test is a hypothesis test.
+    pub p_value: Option<f64>,
 }
 
 impl MetricsBoundary {
@@ -109,6 +114,9 @@ impl MetricsBoundary {
             ModelTest::DeltaIqr => {
                 Self::new_iqr(log, datum, data, true, lower_boundary, upper_boundary)
             },
+            ModelTest::MannWhitney => {
+                Self::new_mann_whitney(log, datum, data, lower_boundary, upper_boundary)
+            },
         }
     }
 
@@ -120,7 +128,11 @@ impl MetricsBoundary {
         let limits = MetricsLimits::new_static(lower_boundary, upper_boundary);
         let outlier = limits.outlier(datum);
 
-        Self { limits, outlier }
+        Self {
+            limits,
+            outlier,
+            p_value: None,
+        }
     }
 
     fn new_percentage(
@@ -147,7 +159,11 @@ impl MetricsBoundary {
         let limits = MetricsLimits::new_percentage(log, mean, lower_boundary, upper_boundary);
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: None,
+        }))
     }
 
     fn new_normal(
@@ -186,7 +202,11 @@ impl MetricsBoundary {
         )?;
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: None,
+        }))
     }
 
     fn new_log_normal(
@@ -212,7 +232,11 @@ impl MetricsBoundary {
         let limits = MetricsLimits::new_log_normal(log, ln, lower_boundary, upper_boundary)?;
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: None,
+        }))
     }
 
     fn new_iqr(
@@ -254,6 +278,207 @@ impl MetricsBoundary {
         );
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value: None,
+        }))
+    }
+
+    fn new_mann_whitney(
+        log: &Logger,
+        datum: f64,
+        data: &[f64],
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary: Option<CdfBoundary> = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary: Option<CdfBoundary> = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        // The historical data is ordered from most to least recent.
+        // The new datum and the most recent results make up the recent sample,
+        // and the rest of the window makes up the historical sample.
+        // Always leave at least half of the data in the historical sample.
+        #[expect(
+            clippy::integer_division,
+            reason = "the recent sample is at most half of the data"
+        )]
+        let recent_len = (MANN_WHITNEY_RECENT_SIZE - 1).min(data.len() / 2);
+        let (recent, historical) = data.split_at(recent_len);
+
+        let Some(mann_whitney) = MannWhitney::with_datum(recent, datum, historical) else {
+            return Ok(None);
+        };
+        let Some(Quartiles { q2: median, .. }) = Quartiles::new(historical) else {
+            return Ok(None);
+        };
+
+        let limits = MetricsLimits::new_mann_whitney(
+            log,
+            median,
+            recent,
+            historical,
+            lower_boundary,
+            upper_boundary,
+        );
+
+        // The outlier is decided by the test itself, not the limits,
+        // as the limits only account for where the new datum falls.
+        let p_less = lower_boundary.map(|boundary| (mann_whitney.p_less(), boundary));
+        let p_greater = upper_boundary.map(|boundary| (mann_whitney.p_greater(), boundary));
+        let significant =
+            |(p_value, boundary): (f64, CdfBoundary)| p_value < 1.0 - f64::from(boundary);
+        let outlier = if p_less.is_some_and(significant) {
+            Some(BoundaryLimit::Lower)
+        } else if p_greater.is_some_and(significant) {
+            Some(BoundaryLimit::Upper)
+        } else {
+            None
+        };
+        let p_value = p_less
+            .into_iter()
+            .chain(p_greater)
+            .map(|(p_value, _)| p_value)
+            .reduce(f64::min);
+        slog::debug!(
+            log,
+            "Mann-Whitney U: u={}, mean={}, std_dev={}, p_value={p_value:?}, outlier={outlier:?}",
+            mann_whitney.u,
+            mann_whitney.mean,
+            mann_whitney.std_dev,
+        );
+
+        Ok(Some(Self {
+            limits,
+            outlier,
+            p_value,
+        }))
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::float_cmp,
+    reason = "exact float equality is intended in tests"
+)]
+mod tests {
+    use std::sync::LazyLock;
+
+    use bencher_json::{Boundary, CdfBoundary, ModelTest, project::boundary::BoundaryLimit};
+    use bencher_logger::bootstrap_logger;
+    use pretty_assertions::assert_eq;
+
+    use crate::{MetricsData, rank::MannWhitney};
+
+    use super::MetricsBoundary;
+
+    static PERCENTILE: LazyLock<Boundary> =
+        LazyLock::new(|| 0.95.try_into().expect("Failed to parse boundary."));
+
+    // The historical data is ordered from most to least recent.
+    const DATA_TEN: &[f64] = &[5.0, 3.0, 8.0, 1.0, 10.0, 2.0, 7.0, 4.0, 9.0, 6.0];
+    const DATA_SHIFT: &[f64] = &[
+        21.0, 22.0, 20.0, 23.0, 5.0, 3.0, 8.0, 1.0, 10.0, 2.0, 7.0, 4.0, 9.0, 6.0,
+    ];
+
+    fn mann_whitney(
+        datum: f64,
+        data: &[f64],
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> MetricsBoundary {
+        let log = bootstrap_logger();
+        let metrics_data = MetricsData {
+            data: data.to_vec(),
+        };
+        MetricsBoundary::new(
+            &log,
+            datum,
+            &metrics_data,
+            ModelTest::MannWhitney,
+            None,
+            lower_boundary,
+            upper_boundary,
+        )
+        .unwrap()
+    }
+
+    fn alpha() -> f64 {
+        let boundary: CdfBoundary = (*PERCENTILE).try_into().unwrap();
+        1.0 - f64::from(boundary)
+    }
+
+    #[test]
+    fn boundary_mann_whitney_regression() {
+        // The datum and the four most recent results are all slower than the rest of the history.
+        let boundary = mann_whitney(24.0, DATA_SHIFT, None, Some(*PERCENTILE));
+        assert_eq!(boundary.outlier, Some(BoundaryLimit::Upper));
+        let p_value = boundary.p_value.unwrap();
+        assert!(p_value < alpha(), "{p_value}");
+        let expected = MannWhitney::with_datum(&DATA_SHIFT[..4], 24.0, &DATA_SHIFT[4..]).unwrap();
+        assert_eq!(p_value, expected.p_greater());
+
+        // A faster shift is not a regression for an upper boundary.
+        let boundary = mann_whitney(-24.0, &negate(DATA_SHIFT), None, Some(*PERCENTILE));
+        assert_eq!(boundary.outlier, None);
+        assert!(boundary.p_value.unwrap() >= alpha());
+
+        let boundary = mann_whitney(-24.0, &negate(DATA_SHIFT), Some(*PERCENTILE), None);
+        assert_eq!(boundary.outlier, Some(BoundaryLimit::Lower));
+        assert!(boundary.p_value.unwrap() < alpha());
+    }
+
+    #[test]
+    fn boundary_mann_whitney_no_regression() {
+        let boundary = mann_whitney(5.5, DATA_TEN, Some(*PERCENTILE), Some(*PERCENTILE));
+        assert_eq!(boundary.outlier, None);
+        let p_value = boundary.p_value.unwrap();
+        assert!(p_value >= alpha(), "{p_value}");
+
+        // A single extreme datum with typical recent results is not a shift.
+        let boundary = mann_whitney(1_000.0, DATA_TEN, None, Some(*PERCENTILE));
+        assert_eq!(boundary.outlier, None);
+        assert!(boundary.p_value.unwrap() >= alpha());
+    }
+
+    #[test]
+    fn boundary_mann_whitney_too_few_samples() {
+        let boundary = mann_whitney(5.0, &[], Some(*PERCENTILE), Some(*PERCENTILE));
+        assert_eq!(boundary.outlier, None);
+        assert_eq!(boundary.p_value, None);
+        assert_eq!(boundary.limits.baseline, None);
+        assert_eq!(boundary.limits.lower, None);
+        assert_eq!(boundary.limits.upper, None);
+
+        // A single result can never be significant.
+        let boundary = mann_whitney(1_000.0, &[1.0], Some(*PERCENTILE), Some(*PERCENTILE));
+        assert_eq!(boundary.outlier, None);
+        assert!(boundary.p_value.unwrap() >= alpha());
+    }
+
+    #[test]
+    fn boundary_mann_whitney_recent_size() {
+        // The recent sample is at most four results plus the datum,
+        // and it never takes more than half of the data.
+        for (len, recent_len) in [(1, 0), (2, 1), (3, 1), (8, 4), (9, 4), (14, 4)] {
+            let data = &DATA_SHIFT[..len];
+            let boundary = mann_whitney(24.0, data, Some(*PERCENTILE), Some(*PERCENTILE));
+            let (recent, historical) = data.split_at(recent_len);
+            let expected = MannWhitney::with_datum(recent, 24.0, historical).unwrap();
+            let p_value = expected.p_less().min(expected.p_greater());
+            assert_eq!(boundary.p_value, Some(p_value), "{len}");
+            let outlier = (expected.p_greater() < alpha()).then_some(BoundaryLimit::Upper);
+            assert_eq!(boundary.outlier, outlier, "{len}");
+        }
+    }
+
+    fn negate(data: &[f64]) -> Vec<f64> {
+        data.iter().map(|datum| -datum).collect()
     }
 }
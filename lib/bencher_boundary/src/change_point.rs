@@ -0,0 +1,276 @@
+use std::f64::consts::SQRT_2;
+
+use crate::{BoundaryError, mean::Mean, quartiles::Quartiles};
+
+/// The minimum number of results in a segment between change points.
+pub const MIN_SEGMENT_SIZE: usize = 2;
+/// The default multiplier for the change point penalty.
+pub const DEFAULT_PENALTY: f64 = 1.0;
+
+// Scale the median absolute deviation to be a consistent estimator of the standard deviation.
+// https://en.wikipedia.org/wiki/Median_absolute_deviation#Relation_to_standard_deviation
+const MAD_SCALE: f64 = 1.4826;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChangePoint {
+    /// The index of the first result after the change point.
+    pub index: usize,
+    /// The mean of the segment before the change point.
+    pub before: f64,
+    /// The mean of the segment after the change point.
+    pub after: f64,
+}
+
+/// Detect the change points in the mean of a series of results
+/// using Pruned Exact Linear Time (PELT) change point detection.
+/// <https://arxiv.org/abs/1101.1438>
+///
+/// The `penalty` is a multiplier for the Bayesian Information Criterion (BIC) penalty.
+/// A larger `penalty` results in fewer change points.
+pub fn change_points(data: &[f64], penalty: f64) -> Result<Vec<ChangePoint>, BoundaryError> {
+    if !penalty.is_finite() || penalty <= 0.0 {
+        return Err(BoundaryError::ChangePointPenalty(penalty));
+    }
+    if data.len() < MIN_SEGMENT_SIZE * 2 || data.iter().any(|datum| !datum.is_finite()) {
+        return Ok(Vec::new());
+    }
+    let Some(variance) = variance(data) else {
+        return Ok(Vec::new());
+    };
+
+    let segments = Segments::new(data);
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "sample size as f64 is fine for stats"
+    )]
+    let beta = penalty * 2.0 * variance * (data.len() as f64).ln();
+
+    // `costs[t]` is the optimal cost of segmenting the first `t` results
+    // and `last[t]` is the final change point for that optimal segmentation.
+    let mut costs = vec![f64::INFINITY; data.len() + 1];
+    let mut last = vec![0; data.len() + 1];
+    if let Some(cost) = costs.first_mut() {
+        *cost = -beta;
+    }
+    let mut candidates = vec![0];
+    for end in MIN_SEGMENT_SIZE..=data.len() {
+        let best = candidates
+            .iter()
+            .filter(|&&start| end - start >= MIN_SEGMENT_SIZE)
+            .filter_map(|&start| {
+                let cost = costs.get(start)? + segments.cost(start, end) + beta;
+                Some((start, cost))
+            })
+            .min_by(|(_, x), (_, y)| x.total_cmp(y));
+        let Some((start, cost)) = best else {
+            continue;
+        };
+        if let (Some(end_cost), Some(end_last)) = (costs.get_mut(end), last.get_mut(end)) {
+            *end_cost = cost;
+            *end_last = start;
+        }
+        // Prune any candidates that can never be optimal again.
+        candidates.retain(|&start| {
+            end - start < MIN_SEGMENT_SIZE
+                || costs
+                    .get(start)
+                    .is_some_and(|&start_cost| start_cost + segments.cost(start, end) <= cost)
+        });
+        candidates.push(end);
+    }
+
+    // Walk back through the optimal segmentation.
+    let mut boundaries = Vec::new();
+    let mut end = data.len();
+    while let Some(&start) = last.get(end)
+        && start > 0
+    {
+        boundaries.push(start);
+        end = start;
+    }
+    boundaries.reverse();
+
+    let mut change_points = Vec::with_capacity(boundaries.len());
+    let mut start = 0;
+    for (position, &index) in boundaries.iter().enumerate() {
+        let next = boundaries.get(position + 1).copied().unwrap_or(data.len());
+        if let (Some(before), Some(after)) =
+            (segments.mean(start, index), segments.mean(index, next))
+        {
+            change_points.push(ChangePoint {
+                index,
+                before,
+                after,
+            });
+        }
+        start = index;
+    }
+    Ok(change_points)
+}
+
+// Estimate the variance of the noise around the mean using the first differences,
+// which are robust to any shifts in the mean.
+// If the first differences are degenerate, then fall back to the sample variance.
+fn variance(data: &[f64]) -> Option<f64> {
+    let differences = data
+        .windows(2)
+        .filter_map(|window| match window {
+            [x, y] => Some((y - x).abs()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let std_dev = Quartiles::new(&differences)
+        .map(|quartiles| MAD_SCALE * quartiles.q2 / SQRT_2)
+        .filter(|std_dev| *std_dev > 0.0)
+        .or_else(|| Mean::new(data)?.std_deviation(data))?;
+    (std_dev.is_finite() && std_dev > 0.0).then_some(std_dev * std_dev)
+}
+
+// Prefix sums for constant time segment costs.
+struct Segments {
+    sums: Vec<f64>,
+    squares: Vec<f64>,
+}
+
+impl Segments {
+    fn new(data: &[f64]) -> Self {
+        let mut sums = Vec::with_capacity(data.len() + 1);
+        let mut squares = Vec::with_capacity(data.len() + 1);
+        let (mut sum, mut square) = (0.0, 0.0);
+        sums.push(sum);
+        squares.push(square);
+        for datum in data {
+            sum += datum;
+            square += datum * datum;
+            sums.push(sum);
+            squares.push(square);
+        }
+        Self { sums, squares }
+    }
+
+    fn totals(&self, start: usize, end: usize) -> Option<(f64, f64, f64)> {
+        let sum = self.sums.get(end)? - self.sums.get(start)?;
+        let square = self.squares.get(end)? - self.squares.get(start)?;
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "segment size as f64 is fine for stats"
+        )]
+        let len = end.checked_sub(start).filter(|len| *len > 0)? as f64;
+        Some((sum, square, len))
+    }
+
+    // The sum of squared deviations from the segment mean.
+    fn cost(&self, start: usize, end: usize) -> f64 {
+        self.totals(start, end)
+            .map_or(f64::INFINITY, |(sum, square, len)| {
+                (square - sum * sum / len).max(0.0)
+            })
+    }
+
+    fn mean(&self, start: usize, end: usize) -> Option<f64> {
+        self.totals(start, end).map(|(sum, _, len)| sum / len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{ChangePoint, DEFAULT_PENALTY, change_points};
+    use crate::BoundaryError;
+
+    const DATA_ZERO: &[f64] = &[];
+    const DATA_THREE: &[f64] = &[1.0, 2.0, 3.0];
+    const DATA_CONST: &[f64] = &[1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
+    const DATA_STEP: &[f64] = &[1.0, 1.0, 1.0, 1.0, 5.0, 5.0, 5.0, 5.0];
+    const DATA_NOISY: &[f64] = &[
+        10.1, 9.9, 10.2, 9.8, 10.0, 10.1, 9.9, 10.0, 10.2, 9.8, 10.1, 9.9,
+    ];
+    const DATA_NOISY_STEP: &[f64] = &[
+        10.1, 9.9, 10.2, 9.8, 10.0, 10.1, 12.1, 11.9, 12.2, 11.8, 12.0, 12.1,
+    ];
+    const DATA_TWO_STEPS: &[f64] = &[
+        10.1, 9.9, 10.2, 9.8, 10.0, 20.1, 19.9, 20.2, 19.8, 20.0, 15.1, 14.9, 15.2, 14.8, 15.0,
+    ];
+
+    #[test]
+    fn change_points_penalty() {
+        assert!(matches!(
+            change_points(DATA_STEP, 0.0),
+            Err(BoundaryError::ChangePointPenalty(_))
+        ));
+        assert!(matches!(
+            change_points(DATA_STEP, -1.0),
+            Err(BoundaryError::ChangePointPenalty(_))
+        ));
+        assert!(matches!(
+            change_points(DATA_STEP, f64::NAN),
+            Err(BoundaryError::ChangePointPenalty(_))
+        ));
+    }
+
+    #[test]
+    fn change_points_too_short() {
+        assert_eq!(
+            change_points(DATA_ZERO, DEFAULT_PENALTY).unwrap(),
+            Vec::new()
+        );
+        assert_eq!(
+            change_points(DATA_THREE, DEFAULT_PENALTY).unwrap(),
+            Vec::new()
+        );
+    }
+
+    #[test]
+    fn change_points_const() {
+        assert_eq!(
+            change_points(DATA_CONST, DEFAULT_PENALTY).unwrap(),
+            Vec::new()
+        );
+    }
+
+    #[test]
+    fn change_points_step() {
+        assert_eq!(
+            change_points(DATA_STEP, DEFAULT_PENALTY).unwrap(),
+            vec![ChangePoint {
+                index: 4,
+                before: 1.0,
+                after: 5.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn change_points_noisy() {
+        assert_eq!(
+            change_points(DATA_NOISY, DEFAULT_PENALTY).unwrap(),
+            Vec::new()
+        );
+    }
+
+    #[test]
+    fn change_points_noisy_step() {
+        let change_points = change_points(DATA_NOISY_STEP, DEFAULT_PENALTY).unwrap();
+        assert_eq!(change_points.len(), 1);
+        let change_point = change_points.first().unwrap();
+        assert_eq!(change_point.index, 6);
+        assert!((change_point.before - 10.016_666).abs() < 0.001);
+        assert!((change_point.after - 12.016_666).abs() < 0.001);
+    }
+
+    #[test]
+    fn change_points_two_steps() {
+        let indices = change_points(DATA_TWO_STEPS, DEFAULT_PENALTY)
+            .unwrap()
+            .into_iter()
+            .map(|change_point| change_point.index)
+            .collect::<Vec<_>>();
+        assert_eq!(indices, vec![5, 10]);
+    }
+
+    #[test]
+    fn change_points_large_penalty() {
+        assert_eq!(change_points(DATA_NOISY_STEP, 1_000.0).unwrap(), Vec::new());
+    }
+}
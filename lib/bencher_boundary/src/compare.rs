@@ -0,0 +1,245 @@
+use statrs::distribution::{ContinuousCDF as _, StudentsT};
+
+use crate::{BoundaryError, mean::Mean};
+
+/// The default confidence level for the change interval.
+pub const DEFAULT_CONFIDENCE: f64 = 0.95;
+
+/// Summary statistics for one side of a comparison.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sample {
+    /// The number of results in the sample.
+    pub size: usize,
+    /// The mean of the sample.
+    pub mean: f64,
+    /// The sample standard deviation, if there are at least two results.
+    pub std_dev: Option<f64>,
+}
+
+/// A comparison of a candidate sample against a baseline sample
+/// using Welch's unequal variances t-test.
+/// <https://en.wikipedia.org/wiki/Welch%27s_t-test>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    pub baseline: Sample,
+    pub candidate: Sample,
+    /// The relative change of the candidate mean from the baseline mean.
+    /// This is `None` if the baseline mean is zero.
+    pub change: Option<f64>,
+    /// The confidence interval for the relative change.
+    /// This is `None` if either sample has fewer than two results or the baseline mean is zero.
+    pub interval: Option<(f64, f64)>,
+    /// The two-sided p-value for the difference in means.
+    /// This is `None` if either sample has fewer than two results.
+    pub p_value: Option<f64>,
+}
+
+impl Comparison {
+    /// Compare the `candidate` sample against the `baseline` sample.
+    /// Returns `None` if either sample is empty or contains a non-finite value.
+    pub fn new(
+        baseline: &[f64],
+        candidate: &[f64],
+        confidence: f64,
+    ) -> Result<Option<Self>, BoundaryError> {
+        if !(confidence > 0.0 && confidence < 1.0) {
+            return Err(BoundaryError::Confidence(confidence));
+        }
+        let (Some(baseline), Some(candidate)) = (Sample::new(baseline), Sample::new(candidate))
+        else {
+            return Ok(None);
+        };
+
+        let diff = candidate.mean - baseline.mean;
+        let change = relative(baseline.mean, diff);
+        let (interval, p_value) = match (baseline.variance(), candidate.variance()) {
+            (Some(baseline_variance), Some(candidate_variance)) => welch(
+                diff,
+                baseline,
+                baseline_variance,
+                candidate,
+                candidate_variance,
+                confidence,
+            )?,
+            _ => (None, None),
+        };
+        let interval = interval.and_then(|(lower, upper)| {
+            let lower = relative(baseline.mean, lower)?;
+            let upper = relative(baseline.mean, upper)?;
+            // A negative baseline mean flips the direction of the interval.
+            Some((lower.min(upper), lower.max(upper)))
+        });
+
+        Ok(Some(Self {
+            baseline,
+            candidate,
+            change,
+            interval,
+            p_value,
+        }))
+    }
+
+    /// Whether the difference in means is statistically significant at the given `confidence` level.
+    pub fn is_significant(&self, confidence: f64) -> bool {
+        self.p_value
+            .is_some_and(|p_value| p_value < 1.0 - confidence)
+    }
+}
+
+impl Sample {
+    fn new(data: &[f64]) -> Option<Self> {
+        if data.iter().any(|datum| !datum.is_finite()) {
+            return None;
+        }
+        let Mean { mean } = Mean::new(data)?;
+        Some(Self {
+            size: data.len(),
+            mean,
+            std_dev: crate::mean::variance(mean, data).map(f64::sqrt),
+        })
+    }
+
+    fn variance(self) -> Option<f64> {
+        self.std_dev.map(|std_dev| std_dev.powi(2))
+    }
+}
+
+fn relative(baseline: f64, diff: f64) -> Option<f64> {
+    if baseline == 0.0 {
+        None
+    } else {
+        Some(diff / baseline.abs())
+    }
+}
+
+type Welch = (Option<(f64, f64)>, Option<f64>);
+
+fn welch(
+    diff: f64,
+    baseline: Sample,
+    baseline_variance: f64,
+    candidate: Sample,
+    candidate_variance: f64,
+    confidence: f64,
+) -> Result<Welch, BoundaryError> {
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "sample size as f64 is fine for stats"
+    )]
+    let (n, m) = (baseline.size as f64, candidate.size as f64);
+    let (baseline_error, candidate_error) = (baseline_variance / n, candidate_variance / m);
+    let std_error = (baseline_error + candidate_error).sqrt();
+
+    // If both samples are constant, then any difference is exact.
+    if std_error == 0.0 {
+        let p_value = if diff == 0.0 { 1.0 } else { 0.0 };
+        return Ok((Some((diff, diff)), Some(p_value)));
+    }
+
+    // Welch-Satterthwaite degrees of freedom
+    let freedom = (baseline_error + candidate_error).powi(2)
+        / (baseline_error.powi(2) / (n - 1.0) + candidate_error.powi(2) / (m - 1.0));
+    let students_t =
+        StudentsT::new(0.0, 1.0, freedom).map_err(|error| BoundaryError::StudentsT {
+            mean: 0.0,
+            scale: 1.0,
+            freedom,
+            error,
+        })?;
+
+    let t = diff / std_error;
+    let p_value = (2.0 * students_t.sf(t.abs())).min(1.0);
+    let critical = students_t.inverse_cdf(f64::midpoint(1.0, confidence));
+    let margin = critical * std_error;
+
+    Ok((Some((diff - margin, diff + margin)), Some(p_value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{Comparison, DEFAULT_CONFIDENCE};
+
+    #[test]
+    fn compare_empty() {
+        assert_eq!(
+            Comparison::new(&[], &[1.0], DEFAULT_CONFIDENCE).unwrap(),
+            None
+        );
+        assert_eq!(
+            Comparison::new(&[1.0], &[], DEFAULT_CONFIDENCE).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn compare_invalid_confidence() {
+        Comparison::new(&[1.0], &[1.0], 0.0).unwrap_err();
+        Comparison::new(&[1.0], &[1.0], 1.0).unwrap_err();
+    }
+
+    #[test]
+    fn compare_single() {
+        let comparison = Comparison::new(&[100.0], &[110.0], DEFAULT_CONFIDENCE)
+            .unwrap()
+            .unwrap();
+        assert!((comparison.change.unwrap() - 0.1).abs() < f64::EPSILON);
+        assert_eq!(comparison.interval, None);
+        assert_eq!(comparison.p_value, None);
+        assert!(!comparison.is_significant(DEFAULT_CONFIDENCE));
+    }
+
+    #[test]
+    fn compare_zero_baseline() {
+        let comparison = Comparison::new(&[0.0, 0.0], &[1.0, 2.0], DEFAULT_CONFIDENCE)
+            .unwrap()
+            .unwrap();
+        assert_eq!(comparison.change, None);
+        assert_eq!(comparison.interval, None);
+        assert!(comparison.p_value.is_some());
+    }
+
+    #[test]
+    fn compare_constant() {
+        let comparison = Comparison::new(&[10.0, 10.0], &[12.0, 12.0], DEFAULT_CONFIDENCE)
+            .unwrap()
+            .unwrap();
+        assert_eq!(comparison.change, Some(0.2));
+        assert_eq!(comparison.interval, Some((0.2, 0.2)));
+        assert!(comparison.is_significant(DEFAULT_CONFIDENCE));
+
+        let comparison = Comparison::new(&[10.0, 10.0], &[10.0, 10.0], DEFAULT_CONFIDENCE)
+            .unwrap()
+            .unwrap();
+        assert_eq!(comparison.change, Some(0.0));
+        assert!(!comparison.is_significant(DEFAULT_CONFIDENCE));
+    }
+
+    #[test]
+    fn compare_significant() {
+        let baseline = [100.0, 101.0, 99.0, 100.5, 99.5];
+        let candidate = [120.0, 121.0, 119.0, 120.5, 119.5];
+        let comparison = Comparison::new(&baseline, &candidate, DEFAULT_CONFIDENCE)
+            .unwrap()
+            .unwrap();
+        let change = comparison.change.unwrap();
+        assert!((change - 0.2).abs() < 1e-9);
+        let (lower, upper) = comparison.interval.unwrap();
+        assert!(lower < change && change < upper);
+        assert!(lower > 0.0);
+        assert!(comparison.is_significant(DEFAULT_CONFIDENCE));
+    }
+
+    #[test]
+    fn compare_not_significant() {
+        let baseline = [100.0, 110.0, 90.0, 105.0, 95.0];
+        let candidate = [101.0, 111.0, 91.0, 106.0, 96.0];
+        let comparison = Comparison::new(&baseline, &candidate, DEFAULT_CONFIDENCE)
+            .unwrap()
+            .unwrap();
+        let (lower, upper) = comparison.interval.unwrap();
+        assert!(lower < 0.0 && upper > 0.0);
+        assert!(!comparison.is_significant(DEFAULT_CONFIDENCE));
+    }
+}
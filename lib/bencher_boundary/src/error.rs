@@ -2,6 +2,10 @@
 pub enum BoundaryError {
     #[error("Invalid Boundary: {0}")]
     Valid(bencher_json::ValidError),
+    #[error("Invalid comparison confidence ({0}): must be greater than zero and less than one")]
+    Confidence(f64),
+    #[error("Invalid change point penalty ({0}): must be a finite number greater than zero")]
+    ChangePointPenalty(f64),
     #[error("Invalid Normal Distribution (mean: {mean} | std dev: {std_dev}): {error}")]
     Normal {
         mean: f64,
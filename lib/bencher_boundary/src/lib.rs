@@ -1,11 +1,15 @@
 pub mod boundary;
+pub mod change_point;
+pub mod compare;
 mod error;
 pub mod limits;
 mod ln;
 mod mean;
 mod quartiles;
+mod rank;
 
 pub use boundary::MetricsBoundary;
+pub use change_point::{ChangePoint, change_points};
 pub use error::BoundaryError;
 
 #[derive(Debug, Clone)]
@@ -67,6 +67,10 @@ impl MetricsLimit {
         }
     }
 
+    pub fn rank(value: f64) -> Self {
+        Self { value }
+    }
+
     fn delta(median: f64, delta_quartiles: Quartiles, boundary: IqrBoundary) -> f64 {
         median * delta_quartiles.iqr(boundary)
     }
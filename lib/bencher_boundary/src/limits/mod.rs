@@ -4,7 +4,7 @@ use bencher_json::{
 use slog::{Logger, debug};
 use statrs::distribution::{ContinuousCDF as _, LogNormal, Normal, StudentsT};
 
-use crate::{BoundaryError, ln::Ln, quartiles::Quartiles};
+use crate::{BoundaryError, ln::Ln, quartiles::Quartiles, rank::MannWhitney};
 
 mod limit;
 
@@ -202,6 +202,41 @@ impl MetricsLimits {
         }
     }
 
+    pub fn new_mann_whitney(
+        log: &Logger,
+        median: f64,
+        recent: &[f64],
+        historical: &[f64],
+        lower_boundary: Option<CdfBoundary>,
+        upper_boundary: Option<CdfBoundary>,
+    ) -> Self {
+        if lower_boundary.is_none() && upper_boundary.is_none() {
+            return Self::default();
+        }
+
+        debug!(
+            log,
+            "Mann-Whitney U: median={median}, recent={recent:?}, historical_size={}",
+            historical.len()
+        );
+        let lower = lower_boundary
+            .and_then(|boundary| {
+                MannWhitney::lower_limit(recent, historical, 1.0 - f64::from(boundary))
+            })
+            .map(MetricsLimit::rank);
+        let upper = upper_boundary
+            .and_then(|boundary| {
+                MannWhitney::upper_limit(recent, historical, 1.0 - f64::from(boundary))
+            })
+            .map(MetricsLimit::rank);
+
+        Self {
+            baseline: Some(median),
+            lower,
+            upper,
+        }
+    }
+
     // An outlier occurs when the  datum exceeds a boundary limit.
     pub fn outlier(&self, datum: f64) -> Option<BoundaryLimit> {
         match (self.lower.as_ref(), self.upper.as_ref()) {
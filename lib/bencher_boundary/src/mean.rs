@@ -34,7 +34,7 @@ pub fn std_deviation(location: f64, data: &[f64]) -> Option<f64> {
         .and_then(|std_dev| std_dev.is_finite().then_some(std_dev))
 }
 
-fn variance(location: f64, data: &[f64]) -> Option<f64> {
+pub(crate) fn variance(location: f64, data: &[f64]) -> Option<f64> {
     // Do not calculate variance if there are less than 2 data points
     if data.len() < 2 {
         None
@@ -0,0 +1,228 @@
+use std::iter;
+
+use statrs::distribution::{ContinuousCDF as _, Normal};
+
+/// The number of most recent results, including the new datum,
+/// that are compared against the rest of the historical window.
+pub const MANN_WHITNEY_RECENT_SIZE: usize = 5;
+
+/// Mann-Whitney U test of a recent sample against a historical sample.
+/// <https://en.wikipedia.org/wiki/Mann%E2%80%93Whitney_U_test>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MannWhitney {
+    /// The U statistic for the recent sample.
+    pub u: f64,
+    /// The expected value of U under the null hypothesis.
+    pub mean: f64,
+    /// The standard deviation of U under the null hypothesis, corrected for ties.
+    pub std_dev: f64,
+}
+
+impl MannWhitney {
+    pub fn new(recent: &[f64], historical: &[f64]) -> Option<Self> {
+        if recent.is_empty() || historical.is_empty() {
+            return None;
+        }
+
+        let mut combined = recent
+            .iter()
+            .map(|&value| (value, true))
+            .chain(historical.iter().map(|&value| (value, false)))
+            .collect::<Vec<_>>();
+        if combined.iter().any(|(value, _)| !value.is_finite()) {
+            return None;
+        }
+        combined.sort_unstable_by(|(x, _), (y, _)| x.total_cmp(y));
+
+        // Assign average ranks to ties, tracking the tie correction term as we go.
+        let mut recent_rank_sum = 0.0;
+        let mut tie_correction = 0.0;
+        let mut rank = 0.0;
+        for group in combined.chunk_by(|(x, _), (y, _)| x.total_cmp(y).is_eq()) {
+            #[expect(
+                clippy::cast_precision_loss,
+                reason = "rank counts as f64 are fine for stats"
+            )]
+            let (tied, recent_tied) = (
+                group.len() as f64,
+                group.iter().filter(|(_, is_recent)| *is_recent).count() as f64,
+            );
+            let average_rank = rank + f64::midpoint(tied, 1.0);
+            recent_rank_sum += average_rank * recent_tied;
+            tie_correction += tied.powi(3) - tied;
+            rank += tied;
+        }
+
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "sample size as f64 is fine for stats"
+        )]
+        let (n, m) = (recent.len() as f64, historical.len() as f64);
+        let total = n + m;
+        let u = recent_rank_sum - n * (n + 1.0) / 2.0;
+        let mean = n * m / 2.0;
+        let variance = n * m / 12.0 * ((total + 1.0) - tie_correction / (total * (total - 1.0)));
+        let std_dev = variance.max(0.0).sqrt();
+
+        Some(Self { u, mean, std_dev })
+    }
+
+    /// The largest historical value that a new datum must fall strictly below
+    /// for the recent sample, including that datum, to be significantly less than the historical sample.
+    pub fn lower_limit(recent: &[f64], historical: &[f64], alpha: f64) -> Option<f64> {
+        let mut sorted = distinct_sorted(historical);
+        sorted.reverse();
+        sorted.iter().enumerate().find_map(|(index, &value)| {
+            let below = sorted.get(index + 1).map_or_else(
+                || value - value.abs().max(1.0),
+                |&next| f64::midpoint(value, next),
+            );
+            let significant =
+                Self::with_datum(recent, below, historical).is_some_and(|mw| mw.p_less() < alpha);
+            significant.then_some(value)
+        })
+    }
+
+    /// The smallest historical value that a new datum must fall strictly above
+    /// for the recent sample, including that datum, to be significantly greater than the historical sample.
+    pub fn upper_limit(recent: &[f64], historical: &[f64], alpha: f64) -> Option<f64> {
+        let sorted = distinct_sorted(historical);
+        sorted.iter().enumerate().find_map(|(index, &value)| {
+            let above = sorted.get(index + 1).map_or_else(
+                || value + value.abs().max(1.0),
+                |&next| f64::midpoint(value, next),
+            );
+            let significant = Self::with_datum(recent, above, historical)
+                .is_some_and(|mw| mw.p_greater() < alpha);
+            significant.then_some(value)
+        })
+    }
+
+    pub fn with_datum(recent: &[f64], datum: f64, historical: &[f64]) -> Option<Self> {
+        let recent = iter::once(datum)
+            .chain(recent.iter().copied())
+            .collect::<Vec<_>>();
+        Self::new(&recent, historical)
+    }
+
+    /// The one-sided p-value that the recent sample is stochastically less than the historical sample.
+    pub fn p_less(self) -> f64 {
+        self.p_value(self.u - self.mean + 0.5)
+    }
+
+    /// The one-sided p-value that the recent sample is stochastically greater than the historical sample.
+    pub fn p_greater(self) -> f64 {
+        self.p_value(self.mean - self.u + 0.5)
+    }
+
+    // Normal approximation with continuity correction.
+    // If every value is tied, then there is no evidence of a shift.
+    fn p_value(self, corrected: f64) -> f64 {
+        if self.std_dev > 0.0 {
+            Normal::standard()
+                .cdf(corrected / self.std_dev)
+                .clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+fn distinct_sorted(data: &[f64]) -> Vec<f64> {
+    let mut sorted = data.to_vec();
+    sorted.sort_unstable_by(f64::total_cmp);
+    sorted.dedup_by(|x, y| x.total_cmp(y).is_eq());
+    sorted
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::float_cmp,
+    reason = "exact float equality is intended in tests"
+)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::MannWhitney;
+
+    const DATA_ZERO: &[f64] = &[];
+    const DATA_FIVE: &[f64] = &[1.0, 2.0, 3.0, 4.0, 5.0];
+    const DATA_FIVE_CONST: &[f64] = &[1.0, 1.0, 1.0, 1.0, 1.0];
+    const DATA_TEN: &[f64] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+    const DATA_TEN_BI: &[f64] = &[1.0, 1.1, 1.2, 1.0, 1.1, 9.0, 9.1, 9.2, 9.0, 9.1];
+
+    #[test]
+    fn mann_whitney_empty() {
+        assert_eq!(MannWhitney::new(DATA_ZERO, DATA_FIVE), None);
+        assert_eq!(MannWhitney::new(DATA_FIVE, DATA_ZERO), None);
+    }
+
+    #[test]
+    fn mann_whitney_non_finite() {
+        assert_eq!(MannWhitney::new(&[f64::NAN], DATA_FIVE), None);
+        assert_eq!(MannWhitney::new(DATA_FIVE, &[f64::INFINITY]), None);
+    }
+
+    #[test]
+    fn mann_whitney_same() {
+        let mw = MannWhitney::new(DATA_FIVE, DATA_FIVE).unwrap();
+        assert_eq!(mw.u, 12.5);
+        assert_eq!(mw.mean, 12.5);
+        assert!(mw.p_less() > 0.5);
+        assert!(mw.p_greater() > 0.5);
+    }
+
+    #[test]
+    fn mann_whitney_const() {
+        let mw = MannWhitney::new(DATA_FIVE_CONST, DATA_FIVE_CONST).unwrap();
+        assert_eq!(mw.std_dev, 0.0);
+        assert_eq!(mw.p_less(), 1.0);
+        assert_eq!(mw.p_greater(), 1.0);
+    }
+
+    #[test]
+    fn mann_whitney_greater() {
+        let mw = MannWhitney::new(&[20.0, 21.0, 22.0, 23.0, 24.0], DATA_TEN).unwrap();
+        assert_eq!(mw.u, 50.0);
+        assert_eq!(mw.mean, 25.0);
+        assert!(mw.p_greater() < 0.01, "{}", mw.p_greater());
+        assert!(mw.p_less() > 0.99, "{}", mw.p_less());
+    }
+
+    #[test]
+    fn mann_whitney_less() {
+        let mw = MannWhitney::new(&[-4.0, -3.0, -2.0, -1.0, 0.0], DATA_TEN).unwrap();
+        assert_eq!(mw.u, 0.0);
+        assert!(mw.p_less() < 0.01, "{}", mw.p_less());
+        assert!(mw.p_greater() > 0.99, "{}", mw.p_greater());
+    }
+
+    #[test]
+    fn mann_whitney_single_outlier() {
+        // One extreme datum among otherwise typical recent results is not a shift.
+        let mw = MannWhitney::new(&[1_000.0, 4.0, 5.0, 6.0, 7.0], DATA_TEN).unwrap();
+        assert!(mw.p_greater() > 0.05, "{}", mw.p_greater());
+    }
+
+    #[test]
+    fn mann_whitney_limits() {
+        let recent = &[11.0, 12.0, 13.0, 14.0];
+        let upper = MannWhitney::upper_limit(recent, DATA_TEN, 0.05).unwrap();
+        assert_eq!(upper, 1.0);
+        let above = MannWhitney::with_datum(recent, 1.5, DATA_TEN).unwrap();
+        assert!(above.p_greater() < 0.05, "{}", above.p_greater());
+        assert_eq!(MannWhitney::lower_limit(recent, DATA_TEN, 0.05), None);
+
+        let recent = &[5.0, 6.0, 5.0, 6.0];
+        assert_eq!(MannWhitney::upper_limit(recent, DATA_TEN, 0.05), None);
+        assert_eq!(MannWhitney::lower_limit(recent, DATA_TEN, 0.05), None);
+    }
+
+    #[test]
+    fn mann_whitney_bimodal() {
+        // Recent results drawn from both modes of a multimodal history are not a shift.
+        let mw = MannWhitney::new(&[1.05, 9.05, 1.15, 9.15, 1.0], DATA_TEN_BI).unwrap();
+        assert!(mw.p_greater() > 0.05, "{}", mw.p_greater());
+        assert!(mw.p_less() > 0.05, "{}", mw.p_less());
+    }
+}
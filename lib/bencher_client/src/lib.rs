@@ -58,6 +58,7 @@ from_client!(
     HeadUuid,
     TestbedUuid,
     BenchmarkUuid,
+    BenchmarkAliasUuid,
     MeasureUuid,
     MetricUuid,
     ThresholdUuid,
@@ -65,7 +66,8 @@ from_client!(
     AlertUuid,
     UserUuid,
     UserKeyUuid,
-    TokenUuid
+    TokenUuid,
+    WebhookUuid
 );
 
 #[cfg(feature = "plus")]
@@ -159,15 +161,27 @@ try_from_client!(
     JsonProjectKeys,
     JsonProjectKey,
     JsonProjectKeyCreated,
+    JsonProjectMembers,
+    JsonProjectMember,
+    JsonWebhooks,
+    JsonWebhook,
+    JsonWebhookCreated,
+    JsonWebhookDeliveries,
+    JsonRetention,
+    JsonRetentionReport,
     JsonReports,
     JsonReport,
     JsonPerf,
+    JsonPerfChangePoints,
+    JsonCompare,
     JsonPlots,
     JsonPlot,
     JsonBranches,
     JsonBranch,
     JsonBenchmarks,
     JsonBenchmark,
+    JsonBenchmarkAliases,
+    JsonBenchmarkAlias,
     JsonTestbeds,
     JsonTestbed,
     JsonMeasures,
@@ -193,6 +207,7 @@ try_from_client!(
     JsonAuthAck,
     JsonAuthUser,
     JsonBackupCreated,
+    JsonRestored,
     JsonConfig,
     JsonConsole,
     JsonApiVersion,
@@ -207,6 +222,7 @@ try_from_client!(
     JsonSso,
     JsonUsage,
     JsonServerStats,
+    JsonRegistryGcReport,
     JsonRunners,
     JsonRunner,
     JsonRunnerKey,
@@ -253,6 +269,7 @@ into_uuids!(
     JsonOrganizations[JsonOrganization],
     JsonMembers[JsonMember],
     JsonProjects[JsonProject],
+    JsonProjectMembers[JsonProjectMember],
     JsonReports[JsonReport],
     JsonPlots[JsonPlot],
     JsonBranches[JsonBranch],
@@ -291,6 +308,7 @@ into_uuid!(
     JsonOrganization,
     JsonMember,
     JsonProject,
+    JsonProjectMember,
     JsonReport,
     JsonPlot,
     JsonBranch,
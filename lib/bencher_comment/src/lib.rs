@@ -167,6 +167,141 @@ impl ReportComment {
         serde_json::to_string_pretty(&self.json_report)
     }
 
+    /// Render the Report as `JUnit` XML.
+    /// Each Benchmark and Measure is a test case,
+    /// and each Alert is a failure of its test case.
+    pub fn junit(&self) -> String {
+        let mut xml = String::new();
+        xml.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        xml.push_str(&format!(
+            "\n<testsuites name=\"{name}\" tests=\"{tests}\" failures=\"{failures}\">",
+            name = xml_escape(&format!("Bencher Report ({})", self.project_name())),
+            tests = self.junit_test_count(None),
+            failures = self.alerts().len(),
+        ));
+        for (i, iteration) in self.results().iter().enumerate() {
+            self.junit_test_suite(&mut xml, i, iteration);
+        }
+        xml.push_str("\n</testsuites>\n");
+        xml
+    }
+
+    fn junit_test_count(&self, iteration: Option<usize>) -> usize {
+        self.results()
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| iteration.is_none_or(|iteration| iteration == *i))
+            .flat_map(|(_, results)| results)
+            .map(|result| result.measures.len())
+            .sum()
+    }
+
+    fn junit_test_suite(&self, xml: &mut String, i: usize, iteration: &[JsonReportResult]) {
+        let mut name = format!(
+            "{project}: {branch} on {testbed}",
+            project = self.project_name(),
+            branch = self.json_report.branch.name,
+            testbed = self.json_report.testbed.name,
+        );
+        if self.multiple_iterations {
+            name.push_str(&format!(" (Iteration {i})"));
+        }
+        let failures = iteration
+            .iter()
+            .flat_map(|result| {
+                result
+                    .measures
+                    .iter()
+                    .filter(|report_measure| self.junit_alert(result, report_measure).is_some())
+            })
+            .count();
+        xml.push_str(&format!(
+            "\n  <testsuite name=\"{name}\" tests=\"{tests}\" failures=\"{failures}\" timestamp=\"{timestamp}\">",
+            name = xml_escape(&name),
+            tests = self.junit_test_count(Some(i)),
+            timestamp = self
+                .json_report
+                .start_time
+                .into_inner()
+                .format("%Y-%m-%dT%H:%M:%S"),
+        ));
+        for result in iteration {
+            for report_measure in &result.measures {
+                self.junit_test_case(xml, result, report_measure);
+            }
+        }
+        xml.push_str("\n  </testsuite>");
+    }
+
+    fn junit_test_case(
+        &self,
+        xml: &mut String,
+        result: &JsonReportResult,
+        report_measure: &JsonReportMeasure,
+    ) {
+        xml.push_str(&format!(
+            "\n    <testcase classname=\"{benchmark}\" name=\"{measure}\">",
+            benchmark = xml_escape(result.benchmark.name.as_ref()),
+            measure = xml_escape(report_measure.measure.name.as_ref()),
+        ));
+        let units = &report_measure.measure.units;
+        if let Some(alert) = self.junit_alert(result, report_measure) {
+            let (boundary, limit_type, limit, comparison) = match alert.limit {
+                BoundaryLimit::Lower => (
+                    "Lower Boundary",
+                    LOWER_BOUNDARY,
+                    alert.boundary.lower_limit,
+                    "below",
+                ),
+                BoundaryLimit::Upper => (
+                    "Upper Boundary",
+                    UPPER_BOUNDARY,
+                    alert.boundary.upper_limit,
+                    "above",
+                ),
+            };
+            let limit = limit.map_or_else(|| "unknown".to_owned(), |limit| limit.to_string());
+            let message = format!(
+                "{value} {units} is {comparison} the {boundary} Limit of {limit} {units}",
+                value = alert.metric.value,
+            );
+            xml.push_str(&format!(
+                "\n      <failure message=\"{message}\" type=\"{limit_type}\">{body}</failure>",
+                message = xml_escape(&message),
+                body = xml_escape(&format!(
+                    "{message}\nAlert: {alert_url}\nPlot: {perf_url}",
+                    alert_url = self.resource_url(Resource::Alert(alert.uuid)),
+                    perf_url = self.alert_perf_url(alert),
+                )),
+            ));
+        }
+        xml.push_str(&format!(
+            "\n      <system-out>{out}</system-out>",
+            out = xml_escape(&format!(
+                "{value} {units}\nPlot: {perf_url}",
+                value = report_measure.metric.value,
+                perf_url = self.perf_url(
+                    &result.benchmark,
+                    &report_measure.measure,
+                    report_measure.boundary.map(Into::into)
+                ),
+            )),
+        ));
+        xml.push_str("\n    </testcase>");
+    }
+
+    fn junit_alert(
+        &self,
+        result: &JsonReportResult,
+        report_measure: &JsonReportMeasure,
+    ) -> Option<&JsonAlert> {
+        self.alerts().iter().find(|alert| {
+            alert.iteration == result.iteration
+                && alert.benchmark.slug == result.benchmark.slug
+                && alert.threshold.measure.slug == report_measure.measure.slug
+        })
+    }
+
     pub fn html(&self, require_threshold: bool, id: Option<&str>) -> String {
         self.html_inner(require_threshold, id, false)
     }
@@ -790,6 +925,7 @@ impl ReportComment {
                 (self.json_report.start_time.into_inner() - DEFAULT_REPORT_HISTORY).into(),
             ),
             end_time: Some(self.json_report.end_time),
+            versions: None,
         };
         let mut query_string = vec![("report", Some(self.json_report.uuid.to_string()))];
         if boundary_limits.is_some_and(|bl| bl.lower) {
@@ -900,9 +1036,25 @@ impl Measure {
     }
 }
 
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 fn alert_status(alert: &JsonAlert) -> &str {
     match alert.status {
         AlertStatus::Active => "🔔",
+        AlertStatus::Resolved => "✅",
         AlertStatus::Dismissed | AlertStatus::Silenced => "🔕",
     }
 }
@@ -1159,8 +1311,14 @@ fn boundary_limits_map(
 #[cfg(test)]
 mod tests {
     use bencher_json::{
-        DateTime, JsonBranch, JsonHead, JsonProject, JsonReport, JsonReportCounts, JsonTestbed,
-        project::{Visibility, report::Adapter},
+        DateTime, JsonAlert, JsonBenchmark, JsonBoundary, JsonBranch, JsonHead, JsonMeasure,
+        JsonMetric, JsonProject, JsonReport, JsonReportCounts, JsonTestbed, JsonThreshold,
+        project::{
+            Visibility,
+            alert::AlertStatus,
+            boundary::BoundaryLimit,
+            report::{Adapter, Iteration, JsonReportMeasure, JsonReportResult},
+        },
     };
     use ordered_float::OrderedFloat;
 
@@ -1217,6 +1375,7 @@ mod tests {
             results: Some(Vec::new()),
             alerts: Some(Vec::new()),
             counts: JsonReportCounts::default(),
+            noisy: false,
             #[cfg(feature = "plus")]
             job: None,
             created: DateTime::TEST,
@@ -1224,9 +1383,13 @@ mod tests {
     }
 
     fn report_comment(visibility: Visibility) -> ReportComment {
+        report_comment_from(json_report(visibility))
+    }
+
+    fn report_comment_from(json_report: JsonReport) -> ReportComment {
         ReportComment::new(
             CONSOLE_URL.parse().unwrap(),
-            json_report(visibility),
+            json_report,
             SubAdapter {
                 build_time: false,
                 file_size: false,
@@ -1353,4 +1516,152 @@ mod tests {
             "unexpected value cell: {html}"
         );
     }
+
+    fn json_benchmark(
+        json_report: &JsonReport,
+        uuid: &str,
+        name: &str,
+        slug: &str,
+    ) -> JsonBenchmark {
+        JsonBenchmark {
+            uuid: uuid.parse().unwrap(),
+            project: json_report.project.uuid,
+            name: name.parse().unwrap(),
+            slug: slug.parse().unwrap(),
+            created: DateTime::TEST,
+            modified: DateTime::TEST,
+            archived: None,
+        }
+    }
+
+    fn json_report_measure(measure: &JsonMeasure, value: f64) -> JsonReportMeasure {
+        JsonReportMeasure {
+            measure: measure.clone(),
+            metric: JsonMetric {
+                uuid: "66666666-6666-6666-6666-666666666666".parse().unwrap(),
+                value: OrderedFloat(value),
+                lower_value: None,
+                upper_value: None,
+            },
+            threshold: None,
+            boundary: Some(JsonBoundary {
+                baseline: Some(OrderedFloat(100.0)),
+                lower_limit: None,
+                upper_limit: Some(OrderedFloat(150.0)),
+                p_value: None,
+            }),
+        }
+    }
+
+    // One passing and one failing Benchmark for the Latency Measure.
+    fn junit_report() -> JsonReport {
+        let mut json_report = json_report(Visibility::Public);
+        let measure = JsonMeasure {
+            uuid: "77777777-7777-7777-7777-777777777777".parse().unwrap(),
+            project: json_report.project.uuid,
+            name: "Latency".parse().unwrap(),
+            slug: "latency".parse().unwrap(),
+            units: "nanoseconds (ns)".parse().unwrap(),
+            created: DateTime::TEST,
+            modified: DateTime::TEST,
+            archived: None,
+        };
+        let fast = json_benchmark(
+            &json_report,
+            "88888888-8888-8888-8888-888888888888",
+            "bench fast",
+            "bench-fast",
+        );
+        let slow = json_benchmark(
+            &json_report,
+            "99999999-9999-9999-9999-999999999999",
+            "bench <slow>",
+            "bench-slow",
+        );
+        let slow_measure = json_report_measure(&measure, 200.0);
+        let alert = JsonAlert {
+            uuid: "aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa".parse().unwrap(),
+            report: json_report.uuid,
+            iteration: Iteration(0),
+            benchmark: slow.clone(),
+            metric: slow_measure.metric,
+            threshold: JsonThreshold {
+                uuid: "bbbbbbbb-bbbb-bbbb-bbbb-bbbbbbbbbbbb".parse().unwrap(),
+                project: json_report.project.uuid,
+                branch: json_report.branch.clone(),
+                testbed: json_report.testbed.clone(),
+                measure: measure.clone(),
+                model: None,
+                created: DateTime::TEST,
+                modified: DateTime::TEST,
+            },
+            boundary: slow_measure.boundary.unwrap(),
+            limit: BoundaryLimit::Upper,
+            status: AlertStatus::Active,
+            resolved_report: None,
+            created: DateTime::TEST,
+            modified: DateTime::TEST,
+        };
+        json_report.results = Some(vec![vec![
+            JsonReportResult {
+                iteration: Iteration(0),
+                benchmark: fast,
+                measures: vec![json_report_measure(&measure, 120.0)],
+            },
+            JsonReportResult {
+                iteration: Iteration(0),
+                benchmark: slow,
+                measures: vec![slow_measure],
+            },
+        ]]);
+        json_report.alerts = Some(vec![alert]);
+        json_report
+    }
+
+    #[test]
+    fn junit_no_results() {
+        let junit = report_comment(Visibility::Public).junit();
+        assert!(junit.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+        assert!(
+            junit.contains(
+                r#"<testsuites name="Bencher Report (My Project)" tests="0" failures="0">"#
+            ),
+            "unexpected junit: {junit}"
+        );
+        assert!(!junit.contains("<testsuite "), "unexpected junit: {junit}");
+    }
+
+    #[test]
+    fn junit_alert_failure() {
+        let junit = report_comment_from(junit_report()).junit();
+        assert!(
+            junit.contains(
+                r#"<testsuites name="Bencher Report (My Project)" tests="2" failures="1">"#
+            ),
+            "unexpected junit: {junit}"
+        );
+        assert!(
+            junit.contains(
+                r#"<testsuite name="My Project: main on localhost" tests="2" failures="1""#
+            ),
+            "unexpected junit: {junit}"
+        );
+        assert_eq!(junit.matches("<testcase ").count(), 2);
+        assert_eq!(junit.matches("<failure ").count(), 1);
+
+        let fast = junit
+            .find(r#"<testcase classname="bench fast" name="Latency">"#)
+            .expect("missing passing test case");
+        let slow = junit
+            .find(r#"<testcase classname="bench &lt;slow&gt;" name="Latency">"#)
+            .expect("missing failing test case");
+        let failure = junit
+            .find(r#"<failure message="200 nanoseconds (ns) is above the Upper Boundary Limit of 150 nanoseconds (ns)" type="upper_boundary">"#)
+            .expect("missing failure");
+        assert!(fast < slow && slow < failure, "unexpected junit: {junit}");
+        assert!(
+            junit.contains("https://bencher.example.com/perf/my-project/alerts/aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa"),
+            "unexpected junit: {junit}"
+        );
+    }
 }
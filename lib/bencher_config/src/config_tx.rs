@@ -18,16 +18,19 @@ use bencher_json::{
     },
 };
 use bencher_rbac::init_rbac;
-use bencher_schema::context::{ApiContext, Database, DbConnection};
 #[cfg(feature = "plus")]
 use bencher_schema::{
     context::RateLimiting,
     model::{
         runner::job::{reprocess_completed_jobs, spawn_heartbeat_timeout},
-        server::QueryServer,
+        server::{QueryServer, RegistryGc},
     },
     write_conn,
 };
+use bencher_schema::{
+    context::{ApiContext, Database, DbConnection},
+    model::project::{retention::ProjectRetention, webhook::WebhookDispatch},
+};
 use bencher_token::TokenKey;
 use diesel::{
     Connection as _,
@@ -44,7 +47,7 @@ use slog::{Logger, debug, error, info};
 
 #[cfg(feature = "plus")]
 use super::plus::Plus;
-use super::{Config, DEFAULT_BUSY_TIMEOUT, DEFAULT_CACHE_SIZE};
+use super::{Config, DEFAULT_BUSY_TIMEOUT, DEFAULT_CACHE_SIZE, DEFAULT_RETENTION_INTERVAL};
 
 const DATABASE_URL: &str = "DATABASE_URL";
 const SQLITE_TMPDIR: &str = "SQLITE_TMPDIR";
@@ -121,6 +124,13 @@ impl ConfigTx {
         }) = config;
 
         let request_body_max_bytes = server.request_body_max_bytes;
+        let retention_interval = std::time::Duration::from_secs(
+            database
+                .retention_interval
+                .unwrap_or(DEFAULT_RETENTION_INTERVAL)
+                .get()
+                .into(),
+        );
 
         debug!(log, "Creating internal configuration");
         let context = into_context(
@@ -176,6 +186,19 @@ impl ConfigTx {
         #[cfg(feature = "plus")]
         spawn_stats(log, server.app_private()).await?;
 
+        #[cfg(feature = "plus")]
+        spawn_registry_gc(log, server.app_private());
+
+        // Resume any webhook deliveries that were pending when the server last stopped
+        WebhookDispatch::resume(log, server.app_private()).await;
+
+        ProjectRetention::spawn(
+            log.clone(),
+            server.app_private().database.connection.clone(),
+            retention_interval,
+            server.app_private().shutdown.clone(),
+        );
+
         Ok(server)
     }
 }
@@ -313,6 +336,7 @@ async fn into_context(
         recaptcha_client,
         registry_url,
         oci_storage,
+        registry_gc_interval,
     } = Plus::new(log, &console_url, plus, &database.path).map_err(ConfigTxError::Plus)?;
 
     #[cfg(feature = "plus")]
@@ -361,8 +385,11 @@ async fn into_context(
         #[cfg(feature = "plus")]
         registry_url,
         #[cfg(feature = "plus")]
-        oci_storage,
+        oci_storage: Arc::new(oci_storage),
+        #[cfg(feature = "plus")]
+        registry_gc_interval,
         clock: bencher_json::Clock::System,
+        allow_insecure_webhooks: false,
         #[cfg(feature = "plus")]
         heartbeat_timeout,
         #[cfg(feature = "plus")]
@@ -371,7 +398,6 @@ async fn into_context(
         heartbeat_tasks: bencher_schema::context::HeartbeatTasks::new(),
         #[cfg(feature = "plus")]
         runner_update: bencher_schema::context::RunnerUpdate::new(runner_update_base_url),
-        #[cfg(feature = "plus")]
         shutdown: bencher_schema::context::CancellationToken::new(),
     })
 }
@@ -633,3 +659,16 @@ async fn spawn_stats(log: &Logger, context: &ApiContext) -> Result<(), ConfigTxE
         )
         .map_err(ConfigTxError::SpawnStats)
 }
+
+#[cfg(feature = "plus")]
+fn spawn_registry_gc(log: &Logger, context: &ApiContext) {
+    if let Some(interval) = context.registry_gc_interval {
+        RegistryGc::spawn(
+            log.clone(),
+            context.database.connection.clone(),
+            context.oci_storage.clone(),
+            interval,
+            context.shutdown.clone(),
+        );
+    }
+}
@@ -56,6 +56,12 @@ const DEFAULT_CACHE_SIZE: NonZeroU32 = match NonZeroU32::new(0x1_0000) {
     None => panic!("default cache size is zero"),
 };
 
+// Daily, in seconds
+const DEFAULT_RETENTION_INTERVAL: NonZeroU32 = match NonZeroU32::new(24 * 60 * 60) {
+    Some(retention_interval) => retention_interval,
+    None => panic!("default retention interval is zero"),
+};
+
 const DEFAULT_CONSOLE_URL_STR: &str = "http://localhost:3000";
 #[expect(clippy::panic, reason = "compile-time constant URL must be valid")]
 static DEFAULT_CONSOLE_URL: LazyLock<Url> = LazyLock::new(|| {
@@ -230,6 +236,7 @@ impl Default for Config {
                 data_store: None,
                 busy_timeout: None,
                 cache_size: None,
+                retention_interval: None,
             },
             smtp: None,
             logging: JsonLogging {
@@ -29,6 +29,7 @@ pub struct Plus {
     pub recaptcha_client: Option<RecaptchaClient>,
     pub registry_url: Url,
     pub oci_storage: OciStorage,
+    pub registry_gc_interval: Option<std::time::Duration>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -83,19 +84,23 @@ impl Plus {
                     None,
                 )
                 .map_err(PlusError::OciStorage)?,
+                registry_gc_interval: None,
             });
         };
 
         // Initialize registry storage - uses S3 if configured, otherwise local filesystem
-        let (registry_url, registry_data_store, upload_timeout, max_body_size) =
-            plus.registry.map_or((None, None, None, None), |registry| {
+        let (registry_url, registry_data_store, upload_timeout, max_body_size, gc_interval) = plus
+            .registry
+            .map_or((None, None, None, None, None), |registry| {
                 (
                     registry.url,
                     registry.data_store,
                     Some(registry.upload_timeout),
                     Some(registry.max_body_size),
+                    registry.gc_interval,
                 )
             });
+        let registry_gc_interval = gc_interval.map(std::time::Duration::from_secs);
         let registry_url = registry_url
             .map(|url| url.try_into().map_err(PlusError::RegistryUrl))
             .transpose()?
@@ -160,6 +165,7 @@ impl Plus {
                 recaptcha_client: None,
                 registry_url,
                 oci_storage,
+                registry_gc_interval,
             });
         };
 
@@ -191,6 +197,7 @@ impl Plus {
             recaptcha_client,
             registry_url,
             oci_storage,
+            registry_gc_interval,
         })
     }
 }
@@ -5,7 +5,8 @@ pub use bencher_valid::{
     BencherKey, BenchmarkName, Boundary, BranchName, CdfBoundary, DateTime, DateTimeMillis, Email,
     GitHash, Index, IntoResourceId, IqrBoundary, Jwt, Model, ModelTest, NameId, NonEmpty,
     PercentageBoundary, ProjectKey, ProjectKeyHash, ResourceId, ResourceName, SampleSize, Sanitize,
-    Search, Secret, Slug, Units, Url, UserKey, UserKeyHash, UserName, ValidError, Window,
+    Search, Secret, Slug, Units, Url, UserKey, UserKeyHash, UserName, ValidError, WebhookSecret,
+    Window,
 };
 #[cfg(feature = "plus")]
 pub use bencher_valid::{
@@ -65,14 +66,16 @@ pub use project::{
     JsonNewProject, JsonProject, JsonProjects, ProjectResourceId, ProjectSlug, ProjectUuid,
     alert::{AlertUuid, JsonAlert, JsonAlerts},
     benchmark::{
-        BenchmarkNameId, BenchmarkResourceId, BenchmarkSlug, BenchmarkUuid, JsonBenchmark,
-        JsonBenchmarks,
+        BenchmarkAliasUuid, BenchmarkNameId, BenchmarkResourceId, BenchmarkSlug, BenchmarkUuid,
+        JsonBenchmark, JsonBenchmarkAlias, JsonBenchmarkAliases, JsonBenchmarks,
+        JsonMergeBenchmarks, JsonNewBenchmarkAlias,
     },
     boundary::{BoundaryUuid, JsonBoundaries, JsonBoundary},
     branch::{
         BranchNameId, BranchResourceId, BranchSlug, BranchUuid, JsonBranch, JsonBranches,
         JsonNewBranch, JsonNewStartPoint,
     },
+    compare::{JsonCompare, JsonCompareQuery},
     head::{HeadUuid, JsonHead, JsonStartPoint, VersionUuid},
     key::{
         JsonNewProjectKey, JsonProjectKey, JsonProjectKeyCreated, JsonProjectKeys,
@@ -82,21 +85,33 @@ pub use project::{
         JsonMeasure, JsonMeasures, JsonNewMeasure, MeasureNameId, MeasureResourceId, MeasureSlug,
         MeasureUuid,
     },
+    member::{
+        JsonNewProjectMember, JsonProjectMember, JsonProjectMembers, JsonUpdateProjectMember,
+    },
     metric::{
         JsonMetric, JsonMetricsMap, JsonNewMetric, JsonOneMetric, JsonResultsMap, MetricUuid,
     },
     model::{JsonModel, ModelUuid},
-    perf::{JsonPerf, JsonPerfQuery, ReportBenchmarkUuid},
+    perf::{
+        JsonPerf, JsonPerfChangePoints, JsonPerfQuery, PerfImgFormat, PerfImgPanels,
+        ReportBenchmarkUuid,
+    },
     plot::{JsonNewPlot, JsonPlot, JsonPlots, PlotUuid},
     report::{
         Iteration, JsonNewReport, JsonReport, JsonReportAlertsCounts, JsonReportCounts,
         JsonReportIterationCounts, JsonReports, ReportUuid,
     },
+    retention::{JsonRetention, JsonRetentionReport, JsonUpdateRetention},
     testbed::{
         JsonNewTestbed, JsonTestbed, JsonTestbeds, TestbedNameId, TestbedResourceId, TestbedSlug,
         TestbedUuid,
     },
     threshold::{JsonNewThreshold, JsonThreshold, JsonThresholds, ThresholdUuid},
+    webhook::{
+        JsonNewWebhook, JsonUpdateWebhook, JsonWebhook, JsonWebhookCreated, JsonWebhookDeliveries,
+        JsonWebhookDelivery, JsonWebhookPayload, JsonWebhooks, WebhookDeliveryUuid, WebhookEvent,
+        WebhookUuid,
+    },
 };
 pub use run::JsonNewRun;
 #[cfg(feature = "plus")]
@@ -112,7 +127,7 @@ pub use spec::{
 };
 pub use system::{
     auth::{JsonAccept, JsonAuthAck, JsonAuthUser, JsonConfirm, JsonLogin, JsonSignup},
-    backup::{JsonBackup, JsonBackupCreated},
+    backup::{JsonBackup, JsonBackupCreated, JsonRestore, JsonRestored},
     config::{JsonConfig, JsonConsole},
     spec::JsonOpenApiSpec,
     version::JsonApiVersion,
@@ -120,6 +135,7 @@ pub use system::{
 #[cfg(feature = "plus")]
 pub use system::{
     auth::{JsonOAuth, JsonOAuthUrl, JsonOAuthUser},
+    registry::{JsonRegistryGc, JsonRegistryGcReport},
     server::{
         BooleanParam, JsonServer, JsonServerStats, SelfHostedStartup, SelfHostedStats, ServerUuid,
     },
@@ -27,12 +27,15 @@ pub struct JsonAlert {
     pub boundary: JsonBoundary,
     pub limit: BoundaryLimit,
     pub status: AlertStatus,
+    /// The report that resolved the alert, if it has been resolved.
+    pub resolved_report: Option<ReportUuid>,
     pub created: DateTime,
     pub modified: DateTime,
 }
 
 const ACTIVE_INT: i32 = 0;
 const DISMISSED_INT: i32 = 1;
+const RESOLVED_INT: i32 = 2;
 const SILENCED_INT: i32 = 10;
 
 #[typeshare::typeshare]
@@ -50,6 +53,8 @@ pub enum AlertStatus {
     Active = ACTIVE_INT,
     /// The alert has been dismissed by a user.
     Dismissed = DISMISSED_INT,
+    /// The alert has been resolved by later reports falling back within the boundary.
+    Resolved = RESOLVED_INT,
     /// The alert has been silenced by the system.
     Silenced = SILENCED_INT,
 }
@@ -65,7 +70,7 @@ impl From<UpdateAlertStatus> for AlertStatus {
 
 #[cfg(feature = "db")]
 mod alert_status {
-    use super::{ACTIVE_INT, AlertStatus, DISMISSED_INT, SILENCED_INT};
+    use super::{ACTIVE_INT, AlertStatus, DISMISSED_INT, RESOLVED_INT, SILENCED_INT};
 
     #[derive(Debug, thiserror::Error)]
     pub enum AlertStatusError {
@@ -85,6 +90,7 @@ mod alert_status {
             match self {
                 Self::Active => ACTIVE_INT.to_sql(out),
                 Self::Dismissed => DISMISSED_INT.to_sql(out),
+                Self::Resolved => RESOLVED_INT.to_sql(out),
                 Self::Silenced => SILENCED_INT.to_sql(out),
             }
         }
@@ -99,6 +105,7 @@ mod alert_status {
             match i32::from_sql(bytes)? {
                 ACTIVE_INT => Ok(Self::Active),
                 DISMISSED_INT => Ok(Self::Dismissed),
+                RESOLVED_INT => Ok(Self::Resolved),
                 SILENCED_INT => Ok(Self::Silenced),
                 value => Err(Box::new(AlertStatusError::Invalid(value))),
             }
@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 use crate::ProjectUuid;
 
 crate::typed_uuid::typed_uuid!(BenchmarkUuid);
+crate::typed_uuid::typed_uuid!(BenchmarkAliasUuid);
 crate::typed_slug::typed_slug!(BenchmarkSlug, BenchmarkName);
 
 /// An benchmark UUID or slug.
@@ -79,3 +80,46 @@ impl JsonUpdateBenchmark {
         name.is_some() || slug.is_some()
     }
 }
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonMergeBenchmarks {
+    /// The slugs or UUIDs for the benchmarks to merge into the target benchmark.
+    /// Their metrics, boundaries, and alerts are moved to the target benchmark,
+    /// their names become aliases of the target benchmark,
+    /// and then they are deleted.
+    pub benchmarks: Vec<BenchmarkResourceId>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewBenchmarkAlias {
+    /// The alias name for the benchmark.
+    /// Reports that use this name are recorded under the benchmark.
+    /// Maximum length is 1,024 characters.
+    pub name: BenchmarkName,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonBenchmarkAliases(pub Vec<JsonBenchmarkAlias>);
+
+crate::from_vec!(JsonBenchmarkAliases[JsonBenchmarkAlias]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonBenchmarkAlias {
+    pub uuid: BenchmarkAliasUuid,
+    pub benchmark: BenchmarkUuid,
+    pub name: BenchmarkName,
+    pub created: DateTime,
+}
+
+impl fmt::Display for JsonBenchmarkAlias {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
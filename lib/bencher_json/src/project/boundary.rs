@@ -18,6 +18,9 @@ pub struct JsonBoundary {
     pub baseline: Option<OrderedFloat<f64>>,
     pub lower_limit: Option<OrderedFloat<f64>>,
     pub upper_limit: Option<OrderedFloat<f64>>,
+    /// The p-value of the threshold model test, if it is a hypothesis test.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p_value: Option<OrderedFloat<f64>>,
 }
 
 const LOWER_BOOL: bool = false;
@@ -0,0 +1,263 @@
+use std::fmt;
+
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "plus")]
+use crate::SpecUuid;
+use crate::{
+    BranchNameId, GitHash, HeadUuid, JsonBenchmark, JsonBranch, JsonMeasure, JsonProject,
+    JsonTestbed, ReportUuid, SampleSize, TestbedNameId,
+    urlencoded::{UrlEncodedError, from_urlencoded, to_urlencoded},
+};
+
+/// The default number of most recent reports to compare for each side.
+pub const DEFAULT_COMPARE_REPORTS: u32 = 10;
+
+/// `JsonCompareQueryParams` is the actual query parameters accepted by the server.
+/// `JsonCompareQueryParams` should always be converted into `JsonCompareQuery` for full type level validation.
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompareQueryParams {
+    /// The UUID, slug, or name of the baseline branch.
+    pub baseline_branch: String,
+    /// The UUID of a baseline branch head.
+    /// If not provided, the current baseline branch head is used.
+    pub baseline_head: Option<HeadUuid>,
+    /// Only compare baseline reports for the given git hash.
+    pub baseline_hash: Option<GitHash>,
+    /// The UUID, slug, or name of the candidate branch.
+    pub candidate_branch: String,
+    /// The UUID of a candidate branch head.
+    /// If not provided, the current candidate branch head is used.
+    pub candidate_head: Option<HeadUuid>,
+    /// Only compare candidate reports for the given git hash.
+    pub candidate_hash: Option<GitHash>,
+    /// The UUID, slug, or name of the testbed.
+    pub testbed: String,
+    /// The UUID of a testbed spec.
+    /// Only reports run on this spec are compared.
+    /// If not provided, the current testbed spec is used, if there is one.
+    #[cfg(feature = "plus")]
+    pub spec: Option<SpecUuid>,
+    /// The number of most recent reports to compare for each side.
+    /// The default is 10.
+    pub reports: Option<SampleSize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct JsonCompareQuery {
+    pub baseline: JsonCompareHeadQuery,
+    pub candidate: JsonCompareHeadQuery,
+    pub testbed: TestbedNameId,
+    #[cfg(feature = "plus")]
+    pub spec: Option<SpecUuid>,
+    pub reports: Option<SampleSize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct JsonCompareHeadQuery {
+    pub branch: BranchNameId,
+    pub head: Option<HeadUuid>,
+    pub hash: Option<GitHash>,
+}
+
+impl TryFrom<JsonCompareQueryParams> for JsonCompareQuery {
+    type Error = UrlEncodedError;
+
+    fn try_from(query_params: JsonCompareQueryParams) -> Result<Self, Self::Error> {
+        let JsonCompareQueryParams {
+            baseline_branch,
+            baseline_head,
+            baseline_hash,
+            candidate_branch,
+            candidate_head,
+            candidate_hash,
+            testbed,
+            #[cfg(feature = "plus")]
+            spec,
+            reports,
+        } = query_params;
+
+        Ok(Self {
+            baseline: JsonCompareHeadQuery {
+                branch: from_urlencoded(&baseline_branch)?,
+                head: baseline_head,
+                hash: baseline_hash,
+            },
+            candidate: JsonCompareHeadQuery {
+                branch: from_urlencoded(&candidate_branch)?,
+                head: candidate_head,
+                hash: candidate_hash,
+            },
+            testbed: from_urlencoded(&testbed)?,
+            #[cfg(feature = "plus")]
+            spec,
+            reports,
+        })
+    }
+}
+
+impl JsonCompareQuery {
+    pub fn testbed(&self) -> String {
+        to_urlencoded(&self.testbed)
+    }
+
+    pub fn reports(&self) -> u32 {
+        self.reports.map_or(DEFAULT_COMPARE_REPORTS, u32::from)
+    }
+}
+
+impl JsonCompareHeadQuery {
+    pub fn branch(&self) -> String {
+        to_urlencoded(&self.branch)
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompare {
+    pub project: JsonProject,
+    pub testbed: JsonTestbed,
+    pub baseline: JsonCompareHead,
+    pub candidate: JsonCompareHead,
+    /// The confidence level for the change intervals and significance verdicts.
+    pub confidence: f64,
+    pub results: Vec<JsonCompareResult>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompareHead {
+    pub branch: JsonBranch,
+    /// The most recent reports used for any of the comparisons, newest first.
+    pub reports: Vec<ReportUuid>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompareResult {
+    pub benchmark: JsonBenchmark,
+    pub measure: JsonMeasure,
+    pub baseline: JsonCompareSample,
+    pub candidate: JsonCompareSample,
+    /// The relative change of the candidate mean from the baseline mean.
+    /// This is not set if the baseline mean is zero.
+    pub change: Option<f64>,
+    /// The lower bound of the confidence interval for the relative change.
+    pub lower_change: Option<f64>,
+    /// The upper bound of the confidence interval for the relative change.
+    pub upper_change: Option<f64>,
+    /// The two-sided p-value for the difference in means, using Welch's t-test.
+    pub p_value: Option<f64>,
+    pub verdict: CompareVerdict,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompareSample {
+    /// The number of reports in the sample.
+    /// The metrics for each report are reduced to their mean across iterations.
+    pub size: u32,
+    pub mean: f64,
+    /// The sample standard deviation.
+    /// This is not set if there are fewer than two reports.
+    pub std_dev: Option<f64>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum CompareVerdict {
+    /// The candidate mean is significantly greater than the baseline mean.
+    Increase,
+    /// The candidate mean is significantly less than the baseline mean.
+    Decrease,
+    /// There is no significant difference between the means.
+    NoChange,
+    /// There are too few reports on one or both sides to test for significance.
+    Inconclusive,
+}
+
+impl fmt::Display for CompareVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Increase => "increase",
+                Self::Decrease => "decrease",
+                Self::NoChange => "no change",
+                Self::Inconclusive => "inconclusive",
+            }
+        )
+    }
+}
+
+#[cfg(feature = "table")]
+pub mod table {
+    use std::fmt;
+
+    use tabled::{Table, Tabled};
+
+    use super::{CompareVerdict, JsonCompare};
+    use crate::{JsonBenchmark, JsonMeasure};
+
+    impl From<JsonCompare> for Table {
+        fn from(json_compare: JsonCompare) -> Self {
+            let compare_table = json_compare
+                .results
+                .into_iter()
+                .map(|result| CompareTable {
+                    benchmark: result.benchmark,
+                    measure: result.measure,
+                    baseline: result.baseline.mean,
+                    candidate: result.candidate.mean,
+                    change: Percent(result.change),
+                    lower_change: Percent(result.lower_change),
+                    upper_change: Percent(result.upper_change),
+                    verdict: result.verdict,
+                })
+                .collect::<Vec<_>>();
+            Self::new(compare_table)
+        }
+    }
+
+    #[derive(Tabled)]
+    pub struct CompareTable {
+        #[tabled(rename = "Benchmark")]
+        pub benchmark: JsonBenchmark,
+        #[tabled(rename = "Measure")]
+        pub measure: JsonMeasure,
+        #[tabled(rename = "Baseline Mean")]
+        pub baseline: f64,
+        #[tabled(rename = "Candidate Mean")]
+        pub candidate: f64,
+        #[tabled(rename = "Change")]
+        pub change: Percent,
+        #[tabled(rename = "Lower Change")]
+        pub lower_change: Percent,
+        #[tabled(rename = "Upper Change")]
+        pub upper_change: Percent,
+        #[tabled(rename = "Verdict")]
+        pub verdict: CompareVerdict,
+    }
+
+    pub struct Percent(Option<f64>);
+
+    impl fmt::Display for Percent {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            if let Some(change) = self.0 {
+                write!(f, "{:+.2}%", change * 100.0)
+            } else {
+                write!(f, "")
+            }
+        }
+    }
+}
@@ -74,6 +74,15 @@ pub mod default {
     );
 }
 
+pub mod throughput {
+    create_measure!(
+        ByteThroughput,
+        "Byte Throughput",
+        "byte-throughput",
+        "bytes / second (B/s)"
+    );
+}
+
 pub mod json {
     use bencher_valid::{BYTES, SECONDS};
 
@@ -94,6 +103,25 @@ pub mod dotnet {
         "total-operations",
         "operations"
     );
+    create_measure!(
+        CompletedWorkItems,
+        "Completed Work Items",
+        "completed-work-items",
+        "work items"
+    );
+    create_measure!(
+        LockContentions,
+        "Lock Contentions",
+        "lock-contentions",
+        "contentions"
+    );
+    create_measure!(Exceptions, "Exceptions", "exceptions", "exceptions");
+}
+
+pub mod google {
+    use bencher_valid::NANOSECONDS;
+
+    create_measure!(CpuTime, "CPU Time", "cpu-time", NANOSECONDS);
 }
 
 pub mod iai {
@@ -109,6 +137,13 @@ pub mod iai {
     );
 }
 
+pub mod perf {
+    create_measure!(Instructions, "Instructions", "instructions", "instructions");
+    create_measure!(Cycles, "Cycles", "cycles", "cycles");
+    create_measure!(BranchMisses, "Branch Misses", "branch-misses", "misses");
+    create_measure!(CacheMisses, "Cache Misses", "cache-misses", "misses");
+}
+
 pub mod noise {
     use bencher_valid::{DECIBELS, PERCENTAGE};
 
@@ -0,0 +1,53 @@
+use bencher_valid::{DateTime, Email, Slug, UserName};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::UserUuid;
+
+use super::ProjectRole;
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewProjectMember {
+    /// The email for the new member.
+    /// The user must already have a Bencher account.
+    pub email: Email,
+    /// The project role for the new member.
+    pub role: ProjectRole,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonProjectMembers(pub Vec<JsonProjectMember>);
+
+crate::from_vec!(JsonProjectMembers[JsonProjectMember]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonProjectMember {
+    /// The member UUID.
+    pub uuid: UserUuid,
+    /// The member user name.
+    pub name: UserName,
+    /// The member slug.
+    pub slug: Slug,
+    /// The member email.
+    pub email: Email,
+    /// The member project role.
+    pub role: ProjectRole,
+    /// The date time the member was created.
+    pub created: DateTime,
+    /// The date time the member was last modified.
+    pub modified: DateTime,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateProjectMember {
+    /// The new project role for the member.
+    pub role: Option<ProjectRole>,
+}
@@ -17,16 +17,20 @@ pub mod alert;
 pub mod benchmark;
 pub mod boundary;
 pub mod branch;
+pub mod compare;
 pub mod head;
 pub mod key;
 pub mod measure;
+pub mod member;
 pub mod metric;
 pub mod model;
 pub mod perf;
 pub mod plot;
 pub mod report;
+pub mod retention;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
 
 crate::typed_uuid::typed_uuid!(ProjectUuid);
 crate::typed_slug::typed_slug!(ProjectSlug, ResourceName);
@@ -335,15 +339,21 @@ pub const VIEWER_ROLE: &str = "viewer";
 pub const DEVELOPER_ROLE: &str = "developer";
 pub const MAINTAINER_ROLE: &str = "maintainer";
 
+#[typeshare::typeshare]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
 #[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Text))]
 #[serde(rename_all = "snake_case")]
 pub enum ProjectRole {
-    // TODO Team Management
-    // Viewer,
-    // Developer,
+    /// The project viewer role.
+    /// Viewers can view the project, even if it is private.
+    Viewer,
+    /// The project developer role.
+    /// Developers can also create, edit, and delete project resources.
+    Developer,
+    /// The project maintainer role.
+    /// Maintainers can also manage the project and its members.
     Maintainer,
 }
 
@@ -352,8 +362,8 @@ impl FromStr for ProjectRole {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            // TODO Team Management
-            // MEMBER_ROLE => Ok(Self::Member),
+            VIEWER_ROLE => Ok(Self::Viewer),
+            DEVELOPER_ROLE => Ok(Self::Developer),
             MAINTAINER_ROLE => Ok(Self::Maintainer),
             _ => Err(s.into()),
         }
@@ -366,8 +376,8 @@ impl Display for ProjectRole {
             f,
             "{}",
             match self {
-                // TODO Team Management
-                // Self::Member => MEMBER_ROLE,
+                Self::Viewer => VIEWER_ROLE,
+                Self::Developer => DEVELOPER_ROLE,
                 Self::Maintainer => MAINTAINER_ROLE,
             }
         )
@@ -375,8 +385,8 @@ impl Display for ProjectRole {
 }
 
 #[cfg(feature = "db")]
-mod organization_role {
-    use super::{MAINTAINER_ROLE, ProjectRole};
+mod project_role {
+    use super::{DEVELOPER_ROLE, MAINTAINER_ROLE, ProjectRole, VIEWER_ROLE};
 
     #[derive(Debug, thiserror::Error)]
     pub enum ProjectRoleError {
@@ -395,6 +405,8 @@ mod organization_role {
             out: &mut diesel::serialize::Output<'b, '_, DB>,
         ) -> diesel::serialize::Result {
             match self {
+                Self::Viewer => out.set_value(VIEWER_ROLE.to_owned()),
+                Self::Developer => out.set_value(DEVELOPER_ROLE.to_owned()),
                 Self::Maintainer => out.set_value(MAINTAINER_ROLE.to_owned()),
             }
             Ok(diesel::serialize::IsNull::No)
@@ -409,6 +421,8 @@ mod organization_role {
         fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
             let role = String::from_sql(bytes)?;
             match role.as_str() {
+                VIEWER_ROLE => Ok(Self::Viewer),
+                DEVELOPER_ROLE => Ok(Self::Developer),
                 MAINTAINER_ROLE => Ok(Self::Maintainer),
                 _ => Err(Box::new(ProjectRoleError::Invalid(role))),
             }
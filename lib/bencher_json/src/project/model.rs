@@ -16,6 +16,10 @@ pub struct JsonModel {
     pub window: Option<Window>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
+    pub confirm_outliers: Option<SampleSize>,
+    pub confirm_reports: Option<SampleSize>,
+    pub resolve_reports: Option<SampleSize>,
+    pub version_window: Option<SampleSize>,
     pub created: DateTime,
     pub replaced: Option<DateTime>,
 }
@@ -12,7 +12,7 @@ use crate::urlencoded::{
 };
 use crate::{
     BenchmarkUuid, BranchUuid, DateTime, DateTimeMillis, HeadUuid, JsonBenchmark, JsonBranch,
-    JsonMeasure, JsonProject, JsonTestbed, MeasureUuid, ReportUuid, TestbedUuid,
+    JsonMeasure, JsonProject, JsonTestbed, MeasureUuid, ReportUuid, SampleSize, TestbedUuid,
 };
 
 use super::alert::JsonPerfAlert;
@@ -50,6 +50,8 @@ pub struct JsonPerfQueryParams {
     pub start_time: Option<DateTimeMillis>,
     /// Search for metrics before the given date time in milliseconds.
     pub end_time: Option<DateTimeMillis>,
+    /// Search for metrics in the given number of most recent branch versions.
+    pub versions: Option<SampleSize>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -76,6 +78,100 @@ pub struct JsonPerfImgQueryParams {
     pub start_time: Option<DateTimeMillis>,
     /// Search for metrics before the given date time in milliseconds.
     pub end_time: Option<DateTimeMillis>,
+    /// Search for metrics in the given number of most recent branch versions.
+    pub versions: Option<SampleSize>,
+    /// The image format for the perf plot.
+    /// If not provided, the `Accept` header is used, defaulting to `jpeg`.
+    pub format: Option<PerfImgFormat>,
+    /// Draw a grid of panels, one for each `measure` or `benchmark`.
+    /// If not provided, all results are drawn in a single panel.
+    pub panels: Option<PerfImgPanels>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PerfImgFormat {
+    #[default]
+    Jpeg,
+    Svg,
+}
+
+impl PerfImgFormat {
+    pub const JPEG_CONTENT_TYPE: &'static str = "image/jpeg";
+    pub const SVG_CONTENT_TYPE: &'static str = "image/svg+xml";
+
+    /// Select the image format from an `Accept` header value.
+    /// SVG is only selected if it is explicitly accepted
+    /// and strictly preferred over JPEG, taking quality values into account.
+    /// JPEG is accepted by the most specific of `image/jpeg`, `image/*`, or `*/*`.
+    pub fn from_accept(accept: &str) -> Self {
+        let mut svg = None;
+        let mut jpeg = None;
+        let mut image = None;
+        let mut any = None;
+        for media_range in accept.split(',') {
+            let mut params = media_range.split(';');
+            let Some(media_type) = params.next().map(str::trim) else {
+                continue;
+            };
+            let Some(quality) = quality(params) else {
+                continue;
+            };
+            let preference = if media_type.eq_ignore_ascii_case(Self::SVG_CONTENT_TYPE) {
+                &mut svg
+            } else if media_type.eq_ignore_ascii_case(Self::JPEG_CONTENT_TYPE) {
+                &mut jpeg
+            } else if media_type.eq_ignore_ascii_case("image/*") {
+                &mut image
+            } else if media_type == "*/*" {
+                &mut any
+            } else {
+                continue;
+            };
+            *preference = Some(preference.map_or(quality, |q: f32| q.max(quality)));
+        }
+        let svg = svg.unwrap_or_default();
+        let jpeg = jpeg.or(image).or(any).unwrap_or_default();
+        if svg > 0.0 && svg > jpeg {
+            Self::Svg
+        } else {
+            Self::Jpeg
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Jpeg => Self::JPEG_CONTENT_TYPE,
+            Self::Svg => Self::SVG_CONTENT_TYPE,
+        }
+    }
+}
+
+/// The quality value from the parameters of a media range, defaulting to `1`.
+/// An invalid quality value returns `None`, and the media range should be ignored.
+fn quality(params: std::str::Split<'_, char>) -> Option<f32> {
+    for param in params {
+        let Some((name, value)) = param.split_once('=') else {
+            continue;
+        };
+        if name.trim().eq_ignore_ascii_case("q") {
+            return value
+                .trim()
+                .parse::<f32>()
+                .ok()
+                .filter(|q| (0.0..=1.0).contains(q));
+        }
+    }
+    Some(1.0)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PerfImgPanels {
+    Measure,
+    Benchmark,
 }
 
 impl From<JsonPerfImgQueryParams> for JsonPerfQueryParams {
@@ -90,6 +186,66 @@ impl From<JsonPerfImgQueryParams> for JsonPerfQueryParams {
             measures,
             start_time,
             end_time,
+            versions,
+            format: _,
+            panels: _,
+        } = query;
+        Self {
+            branches,
+            heads,
+            testbeds,
+            specs,
+            benchmarks,
+            measures,
+            start_time,
+            end_time,
+            versions,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfChangePointsQueryParams {
+    /// The penalty multiplier used for change point detection.
+    /// A larger penalty results in fewer change points.
+    /// If not provided, a penalty of `1.0` will be used.
+    pub penalty: Option<f64>,
+    /// A comma separated list of branch UUIDs to query.
+    pub branches: String,
+    /// An optional comma separated list of branch head UUIDs.
+    /// To not specify a particular branch head leave an empty entry in the list.
+    pub heads: Option<String>,
+    /// A comma separated list of testbed UUIDs to query.
+    pub testbeds: String,
+    /// An optional comma separated list of testbed spec UUIDs.
+    /// To not specify a particular testbed spec leave an empty entry in the list.
+    pub specs: Option<String>,
+    /// A comma separated list of benchmark UUIDs to query.
+    pub benchmarks: String,
+    /// A comma separated list of measure UUIDs to query.
+    pub measures: String,
+    /// Search for metrics after the given date time in milliseconds.
+    pub start_time: Option<DateTimeMillis>,
+    /// Search for metrics before the given date time in milliseconds.
+    pub end_time: Option<DateTimeMillis>,
+    /// Search for metrics in the given number of most recent branch versions.
+    pub versions: Option<SampleSize>,
+}
+
+impl From<JsonPerfChangePointsQueryParams> for JsonPerfQueryParams {
+    fn from(query: JsonPerfChangePointsQueryParams) -> Self {
+        let JsonPerfChangePointsQueryParams {
+            penalty: _,
+            branches,
+            heads,
+            testbeds,
+            specs,
+            benchmarks,
+            measures,
+            start_time,
+            end_time,
+            versions,
         } = query;
         Self {
             branches,
@@ -100,6 +256,7 @@ impl From<JsonPerfImgQueryParams> for JsonPerfQueryParams {
             measures,
             start_time,
             end_time,
+            versions,
         }
     }
 }
@@ -118,6 +275,7 @@ pub struct JsonPerfQuery {
     pub measures: Vec<MeasureUuid>,
     pub start_time: Option<DateTime>,
     pub end_time: Option<DateTime>,
+    pub versions: Option<SampleSize>,
 }
 
 impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
@@ -133,6 +291,7 @@ impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
             measures,
             start_time,
             end_time,
+            versions,
         } = query_params;
 
         if branches.is_empty() {
@@ -176,6 +335,7 @@ impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
             measures,
             start_time: start_time.map(Into::into),
             end_time: end_time.map(Into::into),
+            versions,
         })
     }
 }
@@ -245,7 +405,7 @@ impl JsonPerfQuery {
         serde_urlencoded::to_string(query).map_err(Into::into)
     }
 
-    fn urlencoded(&self) -> Result<[(&'static str, Option<String>); 8], UrlEncodedError> {
+    fn urlencoded(&self) -> Result<[(&'static str, Option<String>); 9], UrlEncodedError> {
         QUERY_KEYS
             .into_iter()
             .zip([
@@ -257,6 +417,7 @@ impl JsonPerfQuery {
                 Some(self.measures()),
                 self.start_time_str(),
                 self.end_time_str(),
+                self.versions_str(),
             ])
             .collect::<Vec<_>>()
             .try_into()
@@ -317,6 +478,10 @@ impl JsonPerfQuery {
     fn end_time_str(&self) -> Option<String> {
         self.end_time().as_ref().map(to_urlencoded)
     }
+
+    fn versions_str(&self) -> Option<String> {
+        self.versions.as_ref().map(to_urlencoded)
+    }
 }
 
 #[typeshare::typeshare]
@@ -332,6 +497,7 @@ pub enum PerfQueryKey {
     Measures,
     StartTime,
     EndTime,
+    Versions,
 }
 
 pub const BRANCHES: &str = "branches";
@@ -342,8 +508,9 @@ pub const BENCHMARKS: &str = "benchmarks";
 pub const MEASURES: &str = "measures";
 pub const START_TIME: &str = "start_time";
 pub const END_TIME: &str = "end_time";
-const QUERY_KEYS: [&str; 8] = [
-    BRANCHES, HEADS, TESTBEDS, SPECS, BENCHMARKS, MEASURES, START_TIME, END_TIME,
+pub const VERSIONS: &str = "versions";
+const QUERY_KEYS: [&str; 9] = [
+    BRANCHES, HEADS, TESTBEDS, SPECS, BENCHMARKS, MEASURES, START_TIME, END_TIME, VERSIONS,
 ];
 
 #[typeshare::typeshare]
@@ -383,6 +550,44 @@ pub struct JsonPerfMetric {
     pub alert: Option<JsonPerfAlert>,
 }
 
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfChangePoints {
+    pub project: JsonProject,
+    pub start_time: Option<DateTime>,
+    pub end_time: Option<DateTime>,
+    pub results: Vec<JsonPerfChangePointMetrics>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfChangePointMetrics {
+    pub branch: JsonBranch,
+    pub testbed: JsonTestbed,
+    pub benchmark: JsonBenchmark,
+    pub measure: JsonMeasure,
+    pub change_points: Vec<JsonPerfChangePoint>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfChangePoint {
+    /// The first report after the change point.
+    pub report: ReportUuid,
+    pub iteration: Iteration,
+    pub start_time: DateTime,
+    pub end_time: DateTime,
+    /// The branch version where the distribution shifted.
+    pub version: JsonVersion,
+    /// The mean of the metrics before the change point.
+    pub before: f64,
+    /// The mean of the metrics after the change point.
+    pub after: f64,
+}
+
 #[cfg(feature = "table")]
 pub mod table {
     use std::fmt;
@@ -486,3 +691,34 @@ pub mod table {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::PerfImgFormat;
+
+    #[test]
+    fn perf_img_format_from_accept() {
+        for (accept, expected) in [
+            ("image/svg+xml", PerfImgFormat::Svg),
+            ("IMAGE/SVG+XML", PerfImgFormat::Svg),
+            ("image/svg+xml, image/*;q=0.8", PerfImgFormat::Svg),
+            ("image/jpeg;q=0.5, image/svg+xml;q=0.9", PerfImgFormat::Svg),
+            ("image/svg+xml, */*;q=0.1", PerfImgFormat::Svg),
+            ("image/jpeg", PerfImgFormat::Jpeg),
+            ("*/*", PerfImgFormat::Jpeg),
+            ("", PerfImgFormat::Jpeg),
+            // A browser image request accepts both equally
+            (
+                "image/avif,image/webp,image/apng,image/svg+xml,image/*,*/*;q=0.8",
+                PerfImgFormat::Jpeg,
+            ),
+            ("image/svg+xml, image/jpeg", PerfImgFormat::Jpeg),
+            ("image/svg+xml;q=0.5, image/*;q=0.5", PerfImgFormat::Jpeg),
+            ("image/svg+xml;q=0", PerfImgFormat::Jpeg),
+            ("image/svg+xml;q=2", PerfImgFormat::Jpeg),
+            ("image/svg+xml;q=0.9, image/jpeg", PerfImgFormat::Jpeg),
+        ] {
+            assert_eq!(PerfImgFormat::from_accept(accept), expected, "{accept}");
+        }
+    }
+}
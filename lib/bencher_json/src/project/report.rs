@@ -54,6 +54,9 @@ pub struct JsonNewReport {
     pub results: Vec<String>,
     /// Settings for how to handle the report.
     pub settings: Option<JsonReportSettings>,
+    /// Results of the host noise probe that was run before the benchmarks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub noise: Option<JsonReportNoise>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -81,6 +84,28 @@ pub struct JsonReportSettings {
     /// Fold multiple results into a single result using the selected operation.
     /// This can be useful for taking the min, max, mean, or median of the benchmark results.
     pub fold: Option<JsonFold>,
+    /// Use bootstrap percentile confidence intervals for the lower and upper values.
+    /// This applies whenever the adapter has the raw samples for a benchmark
+    /// or when multiple iterations are folded into a single result.
+    pub bootstrap: Option<bool>,
+    /// Benchmark harness statistic to use for the value, lower value, and upper value.
+    /// Some benchmarking harnesses report many statistics, such as min, median, and IQR.
+    /// If set, this takes precedence over the `average` for those harnesses.
+    pub statistic: Option<JsonStatistic>,
+    /// Name parameterized benchmarks with their parameters (ex: `Method(N=1000, Mode=Fast)`).
+    /// Otherwise, all sets of parameters share the name of the benchmark.
+    pub params: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonReportNoise {
+    /// The host noise probe measures in Bencher Metric Format (BMF).
+    /// These are recorded along with the benchmark results.
+    pub results: String,
+    /// Whether the host was too noisy to trust the benchmark results.
+    /// Noisy reports are excluded from the historical metrics used to detect regressions.
+    pub noisy: Option<bool>,
 }
 
 const MAGIC_INT: i32 = 0;
@@ -92,6 +117,7 @@ const RUST_IAI_INT: i32 = 23;
 const RUST_GUNGRAUN_INT: i32 = 24;
 const RUST_GUNGRAUN_STDOUT_INT: i32 = 25;
 const RUST_GUNGRAUN_JSON_INT: i32 = 26;
+const RUST_CRITERION_JSON_INT: i32 = 27;
 const CPP_INT: i32 = 30;
 const CPP_GOOGLE_INT: i32 = 31;
 const CPP_CATCH2_INT: i32 = 32;
@@ -136,6 +162,7 @@ pub enum Adapter {
     RustGungraun = RUST_GUNGRAUN_INT,
     RustGungraunStdout = RUST_GUNGRAUN_STDOUT_INT,
     RustGungraunJson = RUST_GUNGRAUN_JSON_INT,
+    RustCriterionJson = RUST_CRITERION_JSON_INT,
     Cpp = CPP_INT,
     CppGoogle = CPP_GOOGLE_INT,
     CppCatch2 = CPP_CATCH2_INT,
@@ -185,6 +212,7 @@ impl Adapter {
             | Self::RustGungraun
             | Self::RustGungraunStdout
             | Self::RustGungraunJson
+            | Self::RustCriterionJson
             | Self::CppGoogle
             | Self::CppCatch2
             | Self::GoBench
@@ -214,6 +242,7 @@ impl fmt::Display for Adapter {
             Self::RustGungraun => write!(f, "rust_gungraun"),
             Self::RustGungraunStdout => write!(f, "rust_gungraun_stdout"),
             Self::RustGungraunJson => write!(f, "rust_gungraun_json"),
+            Self::RustCriterionJson => write!(f, "rust_criterion_json"),
             Self::Cpp => write!(f, "cpp"),
             Self::CppGoogle => write!(f, "cpp_google"),
             Self::CppCatch2 => write!(f, "cpp_catch2"),
@@ -247,8 +276,8 @@ mod adapter {
         DART_BENCHMARK_HARNESS_INT, DART_INT, GO_BENCH_INT, GO_INT, JAVA_INT, JAVA_JMH_INT,
         JS_BENCHMARK_INT, JS_INT, JS_TIME_INT, JS_VITEST_INT, JSON_INT, MAGIC_INT, PYTHON_ASV_INT,
         PYTHON_INT, PYTHON_PYTEST_INT, RUBY_BENCHMARK_INT, RUBY_INT, RUST_BENCH_INT,
-        RUST_CRITERION_INT, RUST_GUNGRAUN_INT, RUST_GUNGRAUN_JSON_INT, RUST_GUNGRAUN_STDOUT_INT,
-        RUST_IAI_INT, RUST_INT, SHELL_HYPERFINE_INT, SHELL_INT,
+        RUST_CRITERION_INT, RUST_CRITERION_JSON_INT, RUST_GUNGRAUN_INT, RUST_GUNGRAUN_JSON_INT,
+        RUST_GUNGRAUN_STDOUT_INT, RUST_IAI_INT, RUST_INT, SHELL_HYPERFINE_INT, SHELL_INT,
     };
 
     #[derive(Debug, thiserror::Error)]
@@ -276,6 +305,7 @@ mod adapter {
                 Self::RustGungraun => RUST_GUNGRAUN_INT.to_sql(out),
                 Self::RustGungraunStdout => RUST_GUNGRAUN_STDOUT_INT.to_sql(out),
                 Self::RustGungraunJson => RUST_GUNGRAUN_JSON_INT.to_sql(out),
+                Self::RustCriterionJson => RUST_CRITERION_JSON_INT.to_sql(out),
                 Self::Cpp => CPP_INT.to_sql(out),
                 Self::CppGoogle => CPP_GOOGLE_INT.to_sql(out),
                 Self::CppCatch2 => CPP_CATCH2_INT.to_sql(out),
@@ -318,6 +348,7 @@ mod adapter {
                 RUST_GUNGRAUN_INT => Ok(Self::RustGungraun),
                 RUST_GUNGRAUN_STDOUT_INT => Ok(Self::RustGungraunStdout),
                 RUST_GUNGRAUN_JSON_INT => Ok(Self::RustGungraunJson),
+                RUST_CRITERION_JSON_INT => Ok(Self::RustCriterionJson),
                 CPP_INT => Ok(Self::Cpp),
                 CPP_GOOGLE_INT => Ok(Self::CppGoogle),
                 CPP_CATCH2_INT => Ok(Self::CppCatch2),
@@ -365,6 +396,28 @@ pub enum JsonFold {
     Median,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonStatistic {
+    Mean,
+    Median,
+    Min,
+    Iqr,
+    P90,
+    P95,
+    Ops,
+}
+
+impl From<JsonAverage> for JsonStatistic {
+    fn from(average: JsonAverage) -> Self {
+        match average {
+            JsonAverage::Mean => Self::Mean,
+            JsonAverage::Median => Self::Median,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonReports(pub Vec<JsonReport>);
@@ -394,6 +447,10 @@ pub struct JsonReport {
     /// The report counts.
     #[serde(default)]
     pub counts: JsonReportCounts,
+    /// Whether the report was run on a host that was too noisy to trust its results.
+    /// Noisy reports are excluded from the historical metrics used to detect regressions.
+    #[serde(default)]
+    pub noisy: bool,
     #[cfg(feature = "plus")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub job: Option<JobUuid>,
@@ -0,0 +1,59 @@
+use bencher_valid::DateTime;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{BranchResourceId, BranchUuid, ProjectUuid};
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonRetention {
+    pub project: ProjectUuid,
+    /// Reports older than this many days are deleted.
+    /// If not set, reports are never deleted.
+    pub report_days: Option<u32>,
+    /// The default branch is exempt from `report_days`.
+    /// If the default branch is deleted, `report_days` is not applied until a new one is set.
+    pub default_branch: Option<BranchUuid>,
+    /// Metrics older than this many days are downsampled to a single daily median
+    /// for each branch, testbed, benchmark, and measure.
+    /// If not set, metrics are never downsampled.
+    pub downsample_days: Option<u32>,
+    /// The last time the retention policy was changed, if ever.
+    pub modified: Option<DateTime>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateRetention {
+    /// Delete reports older than this many days.
+    /// Must be greater than zero.
+    /// If not set, reports are never deleted.
+    pub report_days: Option<u32>,
+    /// The slug or UUID for the default branch, which is exempt from `report_days`.
+    /// Required if `report_days` is set.
+    pub default_branch: Option<BranchResourceId>,
+    /// Downsample metrics older than this many days to a single daily median
+    /// for each branch, testbed, benchmark, and measure.
+    /// The median metric is kept and all others for that day are deleted.
+    /// Must be greater than zero.
+    /// If not set, metrics are never downsampled.
+    pub downsample_days: Option<u32>,
+}
+
+/// The result of applying a retention policy
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonRetentionReport {
+    pub project: ProjectUuid,
+    /// Whether this was a dry run, where nothing was deleted.
+    pub dry_run: bool,
+    /// The number of reports deleted by `report_days`.
+    pub reports: u64,
+    /// The number of metrics deleted along with those reports.
+    pub report_metrics: u64,
+    /// The number of metrics deleted by `downsample_days`.
+    pub downsampled_metrics: u64,
+}
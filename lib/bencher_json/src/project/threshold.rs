@@ -153,6 +153,7 @@ pub struct JsonRemoveModel {
 }
 
 impl<'de> Deserialize<'de> for JsonUpdateThreshold {
+    #[expect(clippy::too_many_lines, reason = "one match arm per model field")]
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
@@ -163,6 +164,10 @@ impl<'de> Deserialize<'de> for JsonUpdateThreshold {
         const WINDOW_FIELD: &str = "window";
         const LOWER_BOUNDARY_FIELD: &str = "lower_boundary";
         const UPPER_BOUNDARY_FIELD: &str = "upper_boundary";
+        const CONFIRM_OUTLIERS_FIELD: &str = "confirm_outliers";
+        const CONFIRM_REPORTS_FIELD: &str = "confirm_reports";
+        const RESOLVE_REPORTS_FIELD: &str = "resolve_reports";
+        const VERSION_WINDOW_FIELD: &str = "version_window";
 
         const FIELDS: &[&str] = &[
             TEST_FIELD,
@@ -171,6 +176,10 @@ impl<'de> Deserialize<'de> for JsonUpdateThreshold {
             WINDOW_FIELD,
             LOWER_BOUNDARY_FIELD,
             UPPER_BOUNDARY_FIELD,
+            CONFIRM_OUTLIERS_FIELD,
+            CONFIRM_REPORTS_FIELD,
+            RESOLVE_REPORTS_FIELD,
+            VERSION_WINDOW_FIELD,
         ];
 
         #[derive(Deserialize)]
@@ -182,6 +191,10 @@ impl<'de> Deserialize<'de> for JsonUpdateThreshold {
             Window,
             LowerBoundary,
             UpperBoundary,
+            ConfirmOutliers,
+            ConfirmReports,
+            ResolveReports,
+            VersionWindow,
         }
 
         struct UpdateThresholdVisitor;
@@ -203,6 +216,10 @@ impl<'de> Deserialize<'de> for JsonUpdateThreshold {
                 let mut window = None;
                 let mut lower_boundary = None;
                 let mut upper_boundary = None;
+                let mut confirm_outliers = None;
+                let mut confirm_reports = None;
+                let mut resolve_reports = None;
+                let mut version_window = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -242,6 +259,30 @@ impl<'de> Deserialize<'de> for JsonUpdateThreshold {
                             }
                             upper_boundary = Some(map.next_value()?);
                         },
+                        Field::ConfirmOutliers => {
+                            if confirm_outliers.is_some() {
+                                return Err(de::Error::duplicate_field(CONFIRM_OUTLIERS_FIELD));
+                            }
+                            confirm_outliers = Some(map.next_value()?);
+                        },
+                        Field::ConfirmReports => {
+                            if confirm_reports.is_some() {
+                                return Err(de::Error::duplicate_field(CONFIRM_REPORTS_FIELD));
+                            }
+                            confirm_reports = Some(map.next_value()?);
+                        },
+                        Field::ResolveReports => {
+                            if resolve_reports.is_some() {
+                                return Err(de::Error::duplicate_field(RESOLVE_REPORTS_FIELD));
+                            }
+                            resolve_reports = Some(map.next_value()?);
+                        },
+                        Field::VersionWindow => {
+                            if version_window.is_some() {
+                                return Err(de::Error::duplicate_field(VERSION_WINDOW_FIELD));
+                            }
+                            version_window = Some(map.next_value()?);
+                        },
                     }
                 }
 
@@ -254,6 +295,10 @@ impl<'de> Deserialize<'de> for JsonUpdateThreshold {
                             window,
                             lower_boundary,
                             upper_boundary,
+                            confirm_outliers,
+                            confirm_reports,
+                            resolve_reports,
+                            version_window,
                         },
                     })),
                     Some(None) => Ok(Self::Value::Remove(JsonRemoveModel { test: () })),
@@ -0,0 +1,361 @@
+use bencher_valid::{DateTime, Url, WebhookSecret};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{JsonAlert, JsonReport, ProjectUuid};
+
+crate::typed_uuid::typed_uuid!(WebhookUuid);
+crate::typed_uuid::typed_uuid!(WebhookDeliveryUuid);
+
+/// The HTTP header with the event that triggered a webhook delivery
+pub const WEBHOOK_EVENT_HEADER: &str = "X-Bencher-Event";
+/// The HTTP header with the UUID of a webhook delivery
+pub const WEBHOOK_DELIVERY_HEADER: &str = "X-Bencher-Delivery";
+/// The HTTP header with the HMAC-SHA256 signature of a webhook payload
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Bencher-Signature-256";
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewWebhook {
+    /// The URL to send webhook payloads to.
+    /// Must be an `https` URL with a host that resolves to public addresses.
+    pub url: Url,
+    /// The events that trigger the webhook.
+    /// If not provided, the webhook is triggered by all events.
+    pub events: Option<Vec<WebhookEvent>>,
+    /// Whether the webhook is enabled.
+    /// If not provided, the webhook is enabled.
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhooks(pub Vec<JsonWebhook>);
+
+crate::from_vec!(JsonWebhooks[JsonWebhook]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhook {
+    pub uuid: WebhookUuid,
+    pub project: ProjectUuid,
+    pub url: Url,
+    pub events: Vec<WebhookEvent>,
+    pub enabled: bool,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhookCreated {
+    pub uuid: WebhookUuid,
+    pub project: ProjectUuid,
+    pub url: Url,
+    pub events: Vec<WebhookEvent>,
+    pub enabled: bool,
+    /// The secret used to sign webhook payloads. Only returned once, at creation.
+    /// Each payload is signed with HMAC-SHA256 using this secret,
+    /// and the hex encoded signature is sent in the `X-Bencher-Signature-256` header
+    /// prefixed with `sha256=`.
+    pub secret: WebhookSecret,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateWebhook {
+    /// The new URL to send webhook payloads to.
+    /// Must be an `https` URL with a host that resolves to public addresses.
+    pub url: Option<Url>,
+    /// The new events that trigger the webhook.
+    pub events: Option<Vec<WebhookEvent>>,
+    /// Enable or disable the webhook.
+    pub enabled: Option<bool>,
+}
+
+const ALERT_CREATED_INT: i32 = 0;
+const ALERT_DISMISSED_INT: i32 = 1;
+const REPORT_CREATED_INT: i32 = 10;
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
+#[repr(i32)]
+pub enum WebhookEvent {
+    /// An alert was created by a report.
+    #[serde(rename = "alert.created")]
+    #[display("alert.created")]
+    AlertCreated = ALERT_CREATED_INT,
+    /// An alert was dismissed by a user.
+    #[serde(rename = "alert.dismissed")]
+    #[display("alert.dismissed")]
+    AlertDismissed = ALERT_DISMISSED_INT,
+    /// A report was created and its results were processed.
+    #[serde(rename = "report.created")]
+    #[display("report.created")]
+    ReportCreated = REPORT_CREATED_INT,
+}
+
+impl WebhookEvent {
+    pub const ALL: [Self; 3] = [
+        Self::AlertCreated,
+        Self::AlertDismissed,
+        Self::ReportCreated,
+    ];
+}
+
+/// The events a webhook is subscribed to, stored as a JSON array
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Text))]
+#[serde(transparent)]
+pub struct WebhookEvents(Vec<WebhookEvent>);
+
+impl WebhookEvents {
+    /// Deduplicates the events, keeping them in a stable order.
+    /// Returns `None` if there are no events.
+    pub fn new(events: &[WebhookEvent]) -> Option<Self> {
+        let events = WebhookEvent::ALL
+            .into_iter()
+            .filter(|event| events.contains(event))
+            .collect::<Vec<_>>();
+        (!events.is_empty()).then_some(Self(events))
+    }
+
+    pub fn all() -> Self {
+        Self(WebhookEvent::ALL.to_vec())
+    }
+
+    pub fn contains(&self, event: WebhookEvent) -> bool {
+        self.0.contains(&event)
+    }
+}
+
+impl From<WebhookEvents> for Vec<WebhookEvent> {
+    fn from(events: WebhookEvents) -> Self {
+        events.0
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhookDeliveries(pub Vec<JsonWebhookDelivery>);
+
+crate::from_vec!(JsonWebhookDeliveries[JsonWebhookDelivery]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhookDelivery {
+    pub uuid: WebhookDeliveryUuid,
+    pub webhook: WebhookUuid,
+    pub event: WebhookEvent,
+    pub status: WebhookDeliveryStatus,
+    /// The number of delivery attempts made so far.
+    pub attempts: u32,
+    /// The HTTP status code of the response to the last attempt, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_status: Option<u16>,
+    /// The error from the last attempt, if it failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+const PENDING_INT: i32 = 0;
+const SUCCEEDED_INT: i32 = 1;
+const FAILED_INT: i32 = 2;
+
+#[typeshare::typeshare]
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, derive_more::Display, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum WebhookDeliveryStatus {
+    /// The delivery has not succeeded yet, and it will be retried.
+    #[default]
+    Pending = PENDING_INT,
+    /// The delivery received a successful (2xx) response.
+    Succeeded = SUCCEEDED_INT,
+    /// The delivery failed and it will not be retried.
+    Failed = FAILED_INT,
+}
+
+/// The JSON body sent to a webhook URL
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhookPayload {
+    /// The UUID of this delivery, also sent in the `X-Bencher-Delivery` header.
+    pub delivery: WebhookDeliveryUuid,
+    pub webhook: WebhookUuid,
+    pub project: ProjectUuid,
+    /// The event that triggered this delivery, also sent in the `X-Bencher-Event` header.
+    pub event: WebhookEvent,
+    pub data: JsonWebhookData,
+    pub created: DateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(untagged)]
+pub enum JsonWebhookData {
+    /// The alert for `alert.*` events.
+    Alert(Box<JsonAlert>),
+    /// The report for `report.*` events.
+    Report(Box<JsonReport>),
+}
+
+#[cfg(feature = "db")]
+mod db {
+    use super::{
+        ALERT_CREATED_INT, ALERT_DISMISSED_INT, FAILED_INT, PENDING_INT, REPORT_CREATED_INT,
+        SUCCEEDED_INT, WebhookDeliveryStatus, WebhookEvent, WebhookEvents,
+    };
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum WebhookError {
+        #[error("Invalid webhook event value: {0}")]
+        Event(i32),
+        #[error("Invalid webhook delivery status value: {0}")]
+        DeliveryStatus(i32),
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for WebhookEvent
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::AlertCreated => ALERT_CREATED_INT.to_sql(out),
+                Self::AlertDismissed => ALERT_DISMISSED_INT.to_sql(out),
+                Self::ReportCreated => REPORT_CREATED_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for WebhookEvent
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                ALERT_CREATED_INT => Ok(Self::AlertCreated),
+                ALERT_DISMISSED_INT => Ok(Self::AlertDismissed),
+                REPORT_CREATED_INT => Ok(Self::ReportCreated),
+                value => Err(Box::new(WebhookError::Event(value))),
+            }
+        }
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for WebhookDeliveryStatus
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::Pending => PENDING_INT.to_sql(out),
+                Self::Succeeded => SUCCEEDED_INT.to_sql(out),
+                Self::Failed => FAILED_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for WebhookDeliveryStatus
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                PENDING_INT => Ok(Self::Pending),
+                SUCCEEDED_INT => Ok(Self::Succeeded),
+                FAILED_INT => Ok(Self::Failed),
+                value => Err(Box::new(WebhookError::DeliveryStatus(value))),
+            }
+        }
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for WebhookEvents
+    where
+        DB: diesel::backend::Backend,
+        for<'a> String: diesel::serialize::ToSql<diesel::sql_types::Text, DB>
+            + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            let json = serde_json::to_string(self)?;
+            out.set_value(json);
+            Ok(diesel::serialize::IsNull::No)
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Text, DB> for WebhookEvents
+    where
+        DB: diesel::backend::Backend,
+        String: diesel::deserialize::FromSql<diesel::sql_types::Text, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            let json_str = String::from_sql(bytes)?;
+            let events: WebhookEvents = serde_json::from_str(&json_str)?;
+            Ok(events)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{WebhookEvent, WebhookEvents};
+
+    #[test]
+    fn webhook_event_serde() {
+        let json = serde_json::to_string(&WebhookEvent::AlertCreated).unwrap();
+        assert_eq!(json, "\"alert.created\"");
+        let event: WebhookEvent = serde_json::from_str("\"report.created\"").unwrap();
+        assert_eq!(event, WebhookEvent::ReportCreated);
+        assert_eq!(WebhookEvent::AlertDismissed.to_string(), "alert.dismissed");
+    }
+
+    #[test]
+    fn webhook_events_new() {
+        assert_eq!(WebhookEvents::new(&[]), None);
+        let events = WebhookEvents::new(&[
+            WebhookEvent::ReportCreated,
+            WebhookEvent::AlertCreated,
+            WebhookEvent::ReportCreated,
+        ])
+        .unwrap();
+        assert_eq!(
+            Vec::from(events.clone()),
+            vec![WebhookEvent::AlertCreated, WebhookEvent::ReportCreated]
+        );
+        assert!(events.contains(WebhookEvent::AlertCreated));
+        assert!(!events.contains(WebhookEvent::AlertDismissed));
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
     BranchNameId, ProjectResourceId, TestbedNameId,
     project::{
         branch::JsonUpdateStartPoint,
-        report::{JsonReportSettings, JsonReportThresholds, ReportIdempotencyKey},
+        report::{JsonReportNoise, JsonReportSettings, JsonReportThresholds, ReportIdempotencyKey},
     },
 };
 
@@ -69,6 +69,9 @@ pub struct JsonNewRun {
     pub results: Vec<String>,
     /// Settings for how to handle the results.
     pub settings: Option<JsonReportSettings>,
+    /// Results of the host noise probe that was run before the benchmarks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub noise: Option<JsonReportNoise>,
     /// Context for the report.
     pub context: Option<RunContext>,
     /// Runner job configuration. When present, the run is executed
@@ -95,6 +98,7 @@ impl From<JsonNewRun> for JsonNewReport {
             end_time,
             results,
             settings,
+            noise,
             context,
             #[cfg(feature = "plus")]
                 job: _,
@@ -131,6 +135,7 @@ impl From<JsonNewRun> for JsonNewReport {
             end_time,
             results,
             settings,
+            noise,
         }
     }
 }
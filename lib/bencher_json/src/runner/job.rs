@@ -8,9 +8,9 @@ use serde::{Deserialize, Serialize};
 
 use super::RunnerUuid;
 use super::job_status::JobStatus;
-use crate::ProjectUuid;
-use crate::project::report::{Iteration, JsonAverage, JsonFold};
+use crate::project::report::{Iteration, JsonAverage, JsonFold, JsonStatistic};
 use crate::spec::{JsonSpec, SpecResourceId};
+use crate::{JsonResultsMap, MeasureNameId, ProjectUuid};
 
 crate::typed_uuid::typed_uuid!(JobUuid);
 
@@ -63,6 +63,50 @@ pub struct JsonIterationOutput {
     #[typeshare(typescript(type = "Record<string, string> | undefined"))]
     #[cfg_attr(feature = "schema", schemars(with = "Option<HashMap<String, String>>"))]
     pub output: Option<BTreeMap<Utf8PathBuf, String>>,
+    /// Built-in measures collected by the runner (e.g. hardware counters),
+    /// merged into the results parsed from this iteration's output
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<JsonResultsMap>,
+    /// Why the runner dropped this iteration from the results, if it did
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dropped: Option<JsonDroppedIteration>,
+}
+
+/// An iteration the runner dropped from the results.
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonDroppedIteration {
+    /// Why the iteration was dropped
+    pub reason: JsonDropReason,
+    /// The trim measure value the iteration was ranked by (trimmed iterations only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
+}
+
+/// Why the runner dropped an iteration.
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonDropReason {
+    /// A discarded warm-up iteration
+    Warmup,
+    /// Trimmed as one of the lowest values of the trim measure
+    TrimLow,
+    /// Trimmed as one of the highest values of the trim measure
+    TrimHigh,
+}
+
+/// Outlier iteration trimming.
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonIterationTrim {
+    /// The measure to rank iterations by
+    pub measure: MeasureNameId,
+    /// The number of iterations to drop from each end (lowest and highest)
+    pub count: u32,
 }
 
 /// Job output stored in blob storage after job completion or failure.
@@ -90,6 +134,8 @@ struct JsonUncheckedNewRunJob {
     pub build_time: Option<bool>,
     pub file_size: Option<bool>,
     pub iter: Option<Iteration>,
+    pub warmup: Option<Iteration>,
+    pub trim: Option<JsonIterationTrim>,
     pub allow_failure: Option<bool>,
     pub backdate: Option<DateTime>,
 }
@@ -109,6 +155,8 @@ impl TryFrom<JsonUncheckedNewRunJob> for JsonNewRunJob {
             build_time,
             file_size,
             iter,
+            warmup,
+            trim,
             allow_failure,
             backdate,
         } = unchecked;
@@ -118,6 +166,7 @@ impl TryFrom<JsonUncheckedNewRunJob> for JsonNewRunJob {
             file_paths.as_ref(),
             env.as_ref(),
         )?;
+        validate_trim(iter, trim.as_ref())?;
         Ok(JsonNewRunJob {
             image,
             spec,
@@ -129,6 +178,8 @@ impl TryFrom<JsonUncheckedNewRunJob> for JsonNewRunJob {
             build_time,
             file_size,
             iter,
+            warmup,
+            trim,
             allow_failure,
             backdate,
         })
@@ -175,6 +226,12 @@ pub struct JsonNewRunJob {
     /// Number of benchmark iterations for the runner to execute
     #[serde(skip_serializing_if = "Option::is_none")]
     pub iter: Option<Iteration>,
+    /// Number of warm-up iterations for the runner to execute and discard first
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warmup: Option<Iteration>,
+    /// Trim outlier iterations before the results are reported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trim: Option<JsonIterationTrim>,
     /// Allow benchmark failure without short-circuiting iterations
     #[serde(skip_serializing_if = "Option::is_none")]
     pub allow_failure: Option<bool>,
@@ -234,6 +291,8 @@ pub enum JobConfigError {
     FilePathsTooLong(usize),
     #[error("env length {0} exceeds maximum {MAX_ENV_LEN}")]
     EnvTooLong(usize),
+    #[error("trimming {trim} iterations from each end leaves none of the {iter} iterations")]
+    TrimTooLarge { trim: u32, iter: u32 },
 }
 
 fn validate_collection_sizes(
@@ -265,6 +324,23 @@ fn validate_collection_sizes(
     Ok(())
 }
 
+fn validate_trim(
+    iter: Option<Iteration>,
+    trim: Option<&JsonIterationTrim>,
+) -> Result<(), JobConfigError> {
+    let Some(trim) = trim else {
+        return Ok(());
+    };
+    let iter = iter.map_or(1, u32::from);
+    if u64::from(trim.count) * 2 >= u64::from(iter) {
+        return Err(JobConfigError::TrimTooLarge {
+            trim: trim.count,
+            iter,
+        });
+    }
+    Ok(())
+}
+
 #[derive(Deserialize)]
 struct JsonUncheckedJobConfig {
     pub registry: Url,
@@ -280,7 +356,12 @@ struct JsonUncheckedJobConfig {
     pub file_size: Option<bool>,
     pub average: Option<JsonAverage>,
     pub iter: Option<Iteration>,
+    pub warmup: Option<Iteration>,
+    pub trim: Option<JsonIterationTrim>,
     pub fold: Option<JsonFold>,
+    pub bootstrap: Option<bool>,
+    pub statistic: Option<JsonStatistic>,
+    pub params: Option<bool>,
     pub allow_failure: Option<bool>,
     pub backdate: Option<DateTime>,
 }
@@ -303,7 +384,12 @@ impl TryFrom<JsonUncheckedJobConfig> for JsonJobConfig {
             file_size,
             average,
             iter,
+            warmup,
+            trim,
             fold,
+            bootstrap,
+            statistic,
+            params,
             allow_failure,
             backdate,
         } = unchecked;
@@ -313,6 +399,7 @@ impl TryFrom<JsonUncheckedJobConfig> for JsonJobConfig {
             file_paths.as_ref(),
             env.as_ref(),
         )?;
+        validate_trim(iter, trim.as_ref())?;
         Ok(JsonJobConfig {
             registry,
             project,
@@ -327,7 +414,12 @@ impl TryFrom<JsonUncheckedJobConfig> for JsonJobConfig {
             file_size,
             average,
             iter,
+            warmup,
+            trim,
             fold,
+            bootstrap,
+            statistic,
+            params,
             allow_failure,
             backdate,
         })
@@ -383,9 +475,24 @@ pub struct JsonJobConfig {
     /// Number of benchmark iterations for the runner to execute
     #[serde(skip_serializing_if = "Option::is_none")]
     pub iter: Option<Iteration>,
+    /// Number of warm-up iterations for the runner to execute and discard first
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warmup: Option<Iteration>,
+    /// Trim outlier iterations before the results are reported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trim: Option<JsonIterationTrim>,
     /// Fold operation for combining multiple iteration results
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fold: Option<JsonFold>,
+    /// Use bootstrap confidence intervals for the lower and upper values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bootstrap: Option<bool>,
+    /// Benchmark harness statistic to use for the value, lower value, and upper value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statistic: Option<JsonStatistic>,
+    /// Name parameterized benchmarks with their parameters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<bool>,
     /// Allow benchmark failure without short-circuiting iterations
     #[serde(skip_serializing_if = "Option::is_none")]
     pub allow_failure: Option<bool>,
@@ -409,6 +516,8 @@ mod tests {
                 stdout: Some("hello".into()),
                 stderr: Some("world".into()),
                 output: Some(output_files),
+                metrics: None,
+                dropped: None,
             }],
             error: Some("oops".into()),
         };
@@ -432,6 +541,8 @@ mod tests {
                 stdout: Some("stdout".into()),
                 stderr: Some("stderr".into()),
                 output: Some(BTreeMap::new()),
+                metrics: None,
+                dropped: None,
             }],
             error: None,
         };
@@ -454,6 +565,8 @@ mod tests {
                 stdout: Some("stdout".into()),
                 stderr: Some("stderr".into()),
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: Some("something broke".into()),
         };
@@ -496,6 +609,8 @@ mod tests {
                 stdout: None,
                 stderr: None,
                 output: Some(files),
+                metrics: None,
+                dropped: None,
             }],
             error: None,
         };
@@ -520,12 +635,16 @@ mod tests {
                     stdout: Some("iter1".into()),
                     stderr: None,
                     output: None,
+                    metrics: None,
+                    dropped: None,
                 },
                 JsonIterationOutput {
                     exit_code: 0,
                     stdout: Some("iter2".into()),
                     stderr: None,
                     output: None,
+                    metrics: None,
+                    dropped: None,
                 },
             ],
             error: None,
@@ -797,6 +916,43 @@ mod tests {
             Some(&r#"{"metric":42}"#.to_owned())
         );
     }
+
+    #[test]
+    fn dropped_iteration_round_trip() {
+        let json = r#"{"exit_code":0,"dropped":{"reason":"trim_high","value":12.5}}"#;
+        let output: JsonIterationOutput = serde_json::from_str(json).unwrap();
+        let dropped = output.dropped.unwrap();
+        assert_eq!(dropped.reason, JsonDropReason::TrimHigh);
+        assert_eq!(dropped.value, Some(12.5));
+        assert_eq!(serde_json::to_string(&output).unwrap(), json);
+
+        let json = r#"{"exit_code":0,"dropped":{"reason":"warmup"}}"#;
+        let output: JsonIterationOutput = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&output).unwrap(), json);
+    }
+
+    #[test]
+    fn new_run_job_warmup_and_trim() {
+        let json =
+            r#"{"image":"alpine:3.18","iter":5,"warmup":2,"trim":{"measure":"latency","count":2}}"#;
+        let job: JsonNewRunJob = serde_json::from_str(json).unwrap();
+        assert_eq!(job.warmup.map(u32::from), Some(2));
+        let trim = job.trim.unwrap();
+        assert_eq!(trim.measure.to_string(), "latency");
+        assert_eq!(trim.count, 2);
+    }
+
+    #[test]
+    fn new_run_job_trim_too_large() {
+        // Trimming 2 from each end of 4 iterations leaves nothing
+        let json = r#"{"image":"alpine:3.18","iter":4,"trim":{"measure":"latency","count":2}}"#;
+        let err = serde_json::from_str::<JsonNewRunJob>(json).unwrap_err();
+        assert!(err.to_string().contains("leaves none"), "{err}");
+
+        // Without `iter` there is only a single iteration
+        let json = r#"{"image":"alpine:3.18","trim":{"measure":"latency","count":1}}"#;
+        serde_json::from_str::<JsonNewRunJob>(json).unwrap_err();
+    }
 }
 
 #[cfg(feature = "db")]
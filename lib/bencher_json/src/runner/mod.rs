@@ -10,8 +10,9 @@ pub mod job_status;
 pub mod websocket;
 
 pub use job::{
-    DEFAULT_POLL_TIMEOUT, JobUuid, JsonClaimJob, JsonClaimedJob, JsonIterationOutput, JsonJob,
-    JsonJobConfig, JsonJobOutput, JsonJobs, JsonNewRunJob, MAX_POLL_TIMEOUT, MIN_POLL_TIMEOUT,
+    DEFAULT_POLL_TIMEOUT, JobUuid, JsonClaimJob, JsonClaimedJob, JsonDropReason,
+    JsonDroppedIteration, JsonIterationOutput, JsonIterationTrim, JsonJob, JsonJobConfig,
+    JsonJobOutput, JsonJobs, JsonNewRunJob, MAX_POLL_TIMEOUT, MIN_POLL_TIMEOUT,
 };
 pub use job_status::JobStatus;
 pub use websocket::{CloseReason, JsonRunnerMetadata, RunnerMessage, ServerMessage};
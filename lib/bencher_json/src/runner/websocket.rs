@@ -356,6 +356,8 @@ mod tests {
                 stdout: Some("hello".into()),
                 stderr: Some("world".into()),
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         };
         let json = serde_json::to_string(&msg).unwrap();
@@ -400,6 +402,8 @@ mod tests {
                 stdout: Some("out".into()),
                 stderr: Some("err".into()),
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: "something broke".into(),
         };
@@ -535,6 +539,8 @@ mod tests {
                 stdout: None,
                 stderr: None,
                 output: Some(output),
+                metrics: None,
+                dropped: None,
             }],
         };
         let json = serde_json::to_string(&msg).unwrap();
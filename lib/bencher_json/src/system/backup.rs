@@ -1,3 +1,5 @@
+use std::{fmt, num::NonZeroU32};
+
 use bencher_valid::DateTime;
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
@@ -10,23 +12,96 @@ pub struct JsonBackup {
     /// This operation runs first.
     pub compress: Option<bool>,
     /// Save the database backup to this data store.
+    /// This must match the data store in the server config.
     /// This operation runs second.
     pub data_store: Option<JsonDataStore>,
     /// Remove the local copy of the database backup.
     /// This operation runs third.
     #[serde(alias = "rm")]
     pub remove: Option<bool>,
+    /// Keep only this many of the most recent backups, pruning the rest.
+    /// Backups are pruned from the data store if one is set,
+    /// otherwise from the database directory.
+    /// This operation runs last.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keep: Option<NonZeroU32>,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum JsonDataStore {
+    /// AWS S3, addressed by access point ARN
     AwsS3,
+    /// Any S3-compatible object store, addressed path-style
+    S3,
+    /// A directory on the server's filesystem
+    Local,
+}
+
+impl fmt::Display for JsonDataStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::AwsS3 => "aws_s3",
+                Self::S3 => "s3",
+                Self::Local => "local",
+            }
+        )
+    }
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonBackupCreated {
     pub created: DateTime,
+    /// The file name of the backup.
+    pub file_name: String,
+    /// The file names of any older backups that were pruned.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pruned: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonRestore {
+    /// The file name of the backup to restore, as created by a server backup.
+    /// For example, `backup-bencher-2025-01-01-00-00-00.db.gz`.
+    pub file_name: String,
+    /// Download the backup from this data store.
+    /// This must match the data store in the server config.
+    /// If not set, the backup must already be in the database directory.
+    pub data_store: Option<JsonDataStore>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonRestored {
+    pub restored: DateTime,
+    /// The file name of a backup of the database taken just before the restore,
+    /// saved in the database directory.
+    pub previous: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{JsonBackup, JsonDataStore};
+
+    #[test]
+    fn backup_keep() {
+        let backup: JsonBackup =
+            serde_json::from_str(r#"{"data_store": "s3", "rm": true, "keep": 7}"#).unwrap();
+        assert_eq!(backup.data_store, Some(JsonDataStore::S3));
+        assert_eq!(backup.remove, Some(true));
+        assert_eq!(backup.keep.map(u32::from), Some(7));
+    }
+
+    #[test]
+    fn backup_keep_zero() {
+        serde_json::from_str::<JsonBackup>(r#"{"keep": 0}"#).unwrap_err();
+    }
 }
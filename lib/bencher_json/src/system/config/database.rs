@@ -4,6 +4,7 @@ use bencher_valid::{Sanitize, Secret};
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use url::Url;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -17,6 +18,9 @@ pub struct JsonDatabase {
     /// The database page cache size in KiB for the writer connection
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cache_size: Option<NonZeroU32>,
+    /// The interval in seconds between applying project retention policies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention_interval: Option<NonZeroU32>,
 }
 
 impl Sanitize for JsonDatabase {
@@ -36,6 +40,24 @@ pub enum DataStore {
         // https://docs.aws.amazon.com/AmazonS3/latest/userguide/using-access-points.html
         access_point: String,
     },
+    /// Any S3-compatible object store, such as `MinIO` or Cloudflare R2.
+    /// Objects are addressed path-style: `{endpoint}/{bucket}/{key}`.
+    S3 {
+        /// The S3 API endpoint, e.g. `http://minio.internal:9000`
+        endpoint: Url,
+        /// The bucket to store backups in
+        bucket: String,
+        /// An optional key prefix for backups within the bucket
+        #[serde(skip_serializing_if = "Option::is_none")]
+        prefix: Option<String>,
+        /// The signing region. Defaults to `us-east-1`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        region: Option<String>,
+        access_key_id: String,
+        secret_access_key: Secret,
+    },
+    /// A directory on the server's filesystem, such as a mounted volume.
+    Local { directory: PathBuf },
 }
 
 impl Sanitize for DataStore {
@@ -43,7 +65,11 @@ impl Sanitize for DataStore {
         match self {
             Self::AwsS3 {
                 secret_access_key, ..
+            }
+            | Self::S3 {
+                secret_access_key, ..
             } => secret_access_key.sanitize(),
+            Self::Local { .. } => {},
         }
     }
 }
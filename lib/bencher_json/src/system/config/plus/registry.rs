@@ -49,6 +49,12 @@ pub struct JsonRegistry {
     /// Defaults to 1 GiB (1,073,741,824 bytes).
     #[serde(default = "default_max_body_size")]
     pub max_body_size: u64,
+    /// Garbage collection interval in seconds.
+    /// If set, unreferenced manifests, blobs, and referrers
+    /// along with stale uploads are removed on this schedule.
+    /// Disabled by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gc_interval: Option<u64>,
 }
 
 fn default_upload_timeout() -> u64 {
@@ -2,6 +2,7 @@ pub mod auth;
 pub mod backup;
 pub mod config;
 pub mod payment;
+pub mod registry;
 pub mod server;
 pub mod spec;
 pub mod version;
@@ -0,0 +1,33 @@
+#![cfg(feature = "plus")]
+
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonRegistryGc {
+    /// Only report what would be removed, without removing anything.
+    pub dry_run: Option<bool>,
+}
+
+/// The result of a registry garbage collection
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonRegistryGcReport {
+    /// Whether this was a dry run.
+    /// If so, nothing was removed and the counts are what would have been removed.
+    pub dry_run: bool,
+    /// The number of repositories scanned
+    pub repositories: u64,
+    /// The number of unreferenced manifests removed
+    pub manifests: u64,
+    /// The number of unreferenced blobs removed
+    pub blobs: u64,
+    /// The number of dangling referrer links removed
+    pub referrers: u64,
+    /// The number of stale uploads removed
+    pub uploads: u64,
+    /// The total size in bytes of the manifests and blobs removed
+    pub bytes: u64,
+}
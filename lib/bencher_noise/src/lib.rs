@@ -6,7 +6,9 @@ mod score;
 
 use std::time::Duration;
 
-use benchmark::{cache, compute, io};
+use bencher_adapter::AdapterResults;
+use benchmark::{BenchmarkResult, cache, compute, io};
+use platform::PlatformMetrics;
 
 #[derive(Debug, Clone, Copy)]
 pub enum NoiseFormat {
@@ -29,6 +31,13 @@ pub enum NoiseError {
     SerializeResults(serde_json::Error),
 }
 
+/// The result of a noise probe: the composite noise score and the measures in BMF.
+#[derive(Debug)]
+pub struct NoiseProbe {
+    pub score: f64,
+    pub results: AdapterResults,
+}
+
 pub fn run_noise(
     duration: u64,
     format: NoiseFormat,
@@ -36,6 +45,72 @@ pub fn run_noise(
     stdout: &mut dyn std::io::Write,
     stderr: &mut dyn std::io::Write,
 ) -> Result<(), NoiseError> {
+    let measurement = measure(duration, quiet, stderr)?;
+
+    // Output results
+    match format {
+        NoiseFormat::Human => {
+            let output = report::format_report(
+                duration,
+                &measurement.compute,
+                &measurement.cache,
+                &measurement.io,
+                &measurement.platform,
+                measurement.score,
+            );
+            let _w = writeln!(stdout, "{output}");
+        },
+        NoiseFormat::Json => {
+            let bmf_results = measurement.bmf()?;
+            let _w = writeln!(
+                stdout,
+                "{}",
+                serde_json::to_string_pretty(&bmf_results).map_err(NoiseError::SerializeResults)?
+            );
+        },
+    }
+
+    Ok(())
+}
+
+/// Measure the environment noise and return the composite score along with the BMF results.
+pub fn probe_noise(
+    duration: u64,
+    quiet: bool,
+    stderr: &mut dyn std::io::Write,
+) -> Result<NoiseProbe, NoiseError> {
+    let measurement = measure(duration, quiet, stderr)?;
+    Ok(NoiseProbe {
+        score: measurement.score,
+        results: measurement.bmf()?,
+    })
+}
+
+struct Measurement {
+    compute: BenchmarkResult,
+    cache: BenchmarkResult,
+    io: BenchmarkResult,
+    platform: PlatformMetrics,
+    score: f64,
+}
+
+impl Measurement {
+    fn bmf(&self) -> Result<AdapterResults, NoiseError> {
+        bmf::build_bmf(
+            &self.compute,
+            &self.cache,
+            &self.io,
+            &self.platform,
+            self.score,
+        )
+    }
+}
+
+fn measure(
+    duration: u64,
+    quiet: bool,
+    stderr: &mut dyn std::io::Write,
+) -> Result<Measurement, NoiseError> {
     let log = !quiet;
     let total_duration = Duration::from_secs(duration);
 
@@ -94,34 +169,11 @@ pub fn run_noise(
         &platform_metrics,
     );
 
-    // Output results
-    match format {
-        NoiseFormat::Human => {
-            let output = report::format_report(
-                duration,
-                &compute_result,
-                &cache_result,
-                &io_result,
-                &platform_metrics,
-                noise_score,
-            );
-            let _w = writeln!(stdout, "{output}");
-        },
-        NoiseFormat::Json => {
-            let bmf_results = bmf::build_bmf(
-                &compute_result,
-                &cache_result,
-                &io_result,
-                &platform_metrics,
-                noise_score,
-            )?;
-            let _w = writeln!(
-                stdout,
-                "{}",
-                serde_json::to_string_pretty(&bmf_results).map_err(NoiseError::SerializeResults)?
-            );
-        },
-    }
-
-    Ok(())
+    Ok(Measurement {
+        compute: compute_result,
+        cache: cache_result,
+        io: io_result,
+        platform: platform_metrics,
+        score: noise_score,
+    })
 }
@@ -8,6 +8,8 @@ pub enum PlotError {
     IntError(#[from] std::num::TryFromIntError),
     #[error("Failed to draw plot: {0}")]
     BitMap(#[from] DrawingAreaErrorKind<BitMapBackendError>),
+    #[error("Failed to draw SVG plot: {0}")]
+    Svg(#[from] DrawingAreaErrorKind<std::io::Error>),
     #[error("Failed to generate image buffer")]
     ImageBuffer,
     #[error("Failed to generate image: {0}")]
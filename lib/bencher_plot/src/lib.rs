@@ -1,3 +1,6 @@
+// Enables the `bitmap_encoder` feature, so the wordmark is embedded in SVG plots as a PNG
+use plotters_svg as _;
+
 mod error;
 mod line;
 
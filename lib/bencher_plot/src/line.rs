@@ -1,12 +1,15 @@
 use crate::PlotError;
-use bencher_json::{JsonMeasure, Units};
+use bencher_json::{JsonMeasure, PerfImgPanels, Units};
 use bencher_json::{JsonPerf, project::perf::JsonPerfMetrics};
 use chrono::{DateTime, Duration, Utc};
 use image::{GenericImageView as _, ImageBuffer};
 use ordered_float::{FloatCore as _, OrderedFloat};
 use plotters::chart::{ChartContext, DualCoordChartContext};
 use plotters::coord::ranged1d::{DefaultFormatting, KeyPointHint};
-use plotters::prelude::{Cartesian2d, IntoLogRange as _, LogCoord, RangedDateTime};
+use plotters::prelude::{
+    Cartesian2d, DrawingAreaErrorKind, DrawingBackend, IntoLogRange as _, LogCoord, RangedDateTime,
+    SVGBackend,
+};
 use plotters::{
     coord::{Shift, types::RangedCoordf64},
     prelude::{
@@ -23,18 +26,15 @@ const IMG_WIDTH: u32 = 1024;
 const IMG_HEIGHT: u32 = 768;
 const TITLE_HEIGHT: u32 = 48;
 const PLOT_HEIGHT: u32 = 600;
-const KEY_HEIGHT: u32 = IMG_HEIGHT - PLOT_HEIGHT;
+const PANEL_TITLE_HEIGHT: u32 = 32;
 
 const MAX_TITLE_LEN: usize = 28;
 const X_LABELS: i64 = 5;
 const Y_LABELS: usize = 5;
 const DATE_TIME_FMT: &str = "%d %b %Y %H:%M:%S";
 
-// RGB is three units in size
-// https://docs.rs/image/latest/image/struct.Rgb.html
-const BUFFER_SIZE: usize = IMG_WIDTH as usize * IMG_HEIGHT as usize * 3;
-
 const MAX_LINES: usize = 10;
+const MAX_PANELS: usize = 16;
 
 pub const BENCHER_WORDMARK: &[u8; 4406] = include_bytes!("../wordmark.png");
 #[expect(clippy::expect_used, reason = "embedded PNG wordmark must be valid")]
@@ -50,6 +50,7 @@ static WORDMARK_ELEMENT: LazyLock<BitMapElement<(i32, i32)>> = LazyLock::new(||
 pub struct LinePlot {
     width: u32,
     height: u32,
+    panels: Option<PerfImgPanels>,
 }
 
 impl Default for LinePlot {
@@ -57,6 +58,7 @@ impl Default for LinePlot {
         Self {
             width: IMG_WIDTH,
             height: IMG_HEIGHT,
+            panels: None,
         }
     }
 }
@@ -66,56 +68,146 @@ impl LinePlot {
         Self::default()
     }
 
+    /// Draw a grid of small multiples, one panel for each measure or benchmark.
+    /// The panels share the same image size as a single plot, and only the first 16 panels are drawn.
+    #[must_use]
+    pub fn with_panels(mut self, panels: Option<PerfImgPanels>) -> Self {
+        self.panels = panels;
+        self
+    }
+
+    /// Draw the plot as a JPEG image
     pub fn draw(&self, title: Option<&str>, json_perf: &JsonPerf) -> Result<Vec<u8>, PlotError> {
-        let mut plot_buffer = vec![0; BUFFER_SIZE];
-        self.draw_inner(title, json_perf, &mut plot_buffer)?;
+        let panels = self.panels(json_perf);
+        let (width, height) = (self.width, self.height);
+        // RGB is three units in size
+        // https://docs.rs/image/latest/image/struct.Rgb.html
+        let buffer_size = usize::try_from(width)? * usize::try_from(height)? * 3;
+        let mut plot_buffer = vec![0; buffer_size];
+        {
+            let root_area =
+                BitMapBackend::with_buffer(&mut plot_buffer, (width, height)).into_drawing_area();
+            Self::draw_inner(title, json_perf, &panels, &root_area)?;
+        }
 
         let image_buffer: ImageBuffer<image::Rgb<u8>, Vec<u8>> =
-            ImageBuffer::from_vec(self.width, self.height, plot_buffer)
-                .ok_or(PlotError::ImageBuffer)?;
-        let mut image_cursor = Cursor::new(Vec::with_capacity(BUFFER_SIZE));
+            ImageBuffer::from_vec(width, height, plot_buffer).ok_or(PlotError::ImageBuffer)?;
+        let mut image_cursor = Cursor::new(Vec::with_capacity(buffer_size));
         image_buffer.write_to(&mut image_cursor, image::ImageFormat::Jpeg)?;
 
         Ok(image_cursor.into_inner())
     }
 
-    fn draw_inner(
-        &self,
+    /// Draw the plot as an SVG image
+    pub fn draw_svg(&self, title: Option<&str>, json_perf: &JsonPerf) -> Result<String, PlotError> {
+        let panels = self.panels(json_perf);
+        let (width, height) = (self.width, self.height);
+        let mut svg = String::new();
+        {
+            let root_area = SVGBackend::with_string(&mut svg, (width, height)).into_drawing_area();
+            Self::draw_inner(title, json_perf, &panels, &root_area)?;
+        }
+        Ok(svg)
+    }
+
+    fn draw_inner<DB>(
         title: Option<&str>,
         json_perf: &JsonPerf,
-        plot_buffer: &mut [u8],
-    ) -> Result<(), PlotError> {
-        let root_area =
-            BitMapBackend::with_buffer(plot_buffer, (self.width, self.height)).into_drawing_area();
-
-        let (header_area, plot_area) = Self::split_header(&root_area)?;
+        panels: &[Panel<'_>],
+        root_area: &Area<DB>,
+    ) -> Result<(), PlotError>
+    where
+        DB: DrawingBackend,
+        PlotError: From<DrawingAreaErrorKind<DB::ErrorType>>,
+    {
+        let (header_area, plot_area) = Self::split_header(root_area)?;
         Self::header(title, json_perf, &header_area)?;
 
-        // Marshal the perf data into a plot-able form
-        let perf_data = PerfData::new(json_perf);
-        let Some(perf_data) = perf_data else {
-            return Self::no_data_found(&root_area, &plot_area);
+        if let [
+            Panel {
+                caption: None,
+                results,
+            },
+        ] = panels
+        {
+            Self::panel(results, &plot_area)?;
+        } else {
+            let panel_areas = plot_area.split_evenly(grid(panels.len()));
+            for (panel, panel_area) in panels.iter().zip(panel_areas) {
+                let (caption_area, panel_plot_area) =
+                    panel_area.split_vertically(PANEL_TITLE_HEIGHT);
+                if let Some(caption) = panel.caption {
+                    caption_area
+                        .titled(caption, (FontFamily::Monospace, PANEL_TITLE_HEIGHT - 8))?;
+                }
+                Self::panel(&panel.results, &panel_plot_area)?;
+            }
+        }
+
+        root_area.present().map_err(Into::into)
+    }
+
+    /// Split the perf results into panels.
+    /// Without small multiples, or without any results, there is a single panel without a caption.
+    fn panels<'a>(&self, json_perf: &'a JsonPerf) -> Vec<Panel<'a>> {
+        let Some(panels) = self.panels else {
+            return vec![Panel {
+                caption: None,
+                results: json_perf.results.iter().collect(),
+            }];
         };
 
-        Self::plot(perf_data, &plot_area)?;
+        let mut grid: Vec<Panel<'a>> = Vec::new();
+        for result in &json_perf.results {
+            let same_panel = |panel: &&mut Panel<'a>| {
+                panel.results.first().is_some_and(|first| match panels {
+                    PerfImgPanels::Measure => first.measure.uuid == result.measure.uuid,
+                    PerfImgPanels::Benchmark => first.benchmark.uuid == result.benchmark.uuid,
+                })
+            };
+            if let Some(panel) = grid.iter_mut().find(same_panel) {
+                panel.results.push(result);
+            } else if grid.len() < MAX_PANELS {
+                let caption = match panels {
+                    PerfImgPanels::Measure => result.measure.name.as_ref(),
+                    PerfImgPanels::Benchmark => result.benchmark.name.as_ref(),
+                };
+                grid.push(Panel {
+                    caption: Some(caption),
+                    results: vec![result],
+                });
+            }
+        }
 
-        root_area.present().map_err(Into::into)
+        if grid.is_empty() {
+            grid.push(Panel {
+                caption: None,
+                results: Vec::new(),
+            });
+        }
+        grid
     }
 
-    fn split_header<'b>(
-        root_area: &DrawingArea<BitMapBackend<'b>, Shift>,
-    ) -> Result<(Area<'b>, Area<'b>), PlotError> {
+    fn split_header<DB>(root_area: &Area<DB>) -> Result<(Area<DB>, Area<DB>), PlotError>
+    where
+        DB: DrawingBackend,
+        PlotError: From<DrawingAreaErrorKind<DB::ErrorType>>,
+    {
         root_area.fill(&WHITE)?;
         // Bencher Wordmark
         root_area.draw(&*WORDMARK_ELEMENT)?;
         Ok(root_area.split_vertically(TITLE_HEIGHT))
     }
 
-    fn header(
+    fn header<DB>(
         title: Option<&str>,
         json_perf: &JsonPerf,
-        header_area: &Area<'_>,
-    ) -> Result<(), PlotError> {
+        header_area: &Area<DB>,
+    ) -> Result<(), PlotError>
+    where
+        DB: DrawingBackend,
+        PlotError: From<DrawingAreaErrorKind<DB::ErrorType>>,
+    {
         // Adaptive title sizing
         let title = title.unwrap_or(json_perf.project.name.as_ref());
         let title_len = title.len();
@@ -129,7 +221,23 @@ impl LinePlot {
         Ok(())
     }
 
-    fn no_data_found(root_area: &Area<'_>, plot_area: &Area<'_>) -> Result<(), PlotError> {
+    fn panel<DB>(results: &[&JsonPerfMetrics], plot_area: &Area<DB>) -> Result<(), PlotError>
+    where
+        DB: DrawingBackend,
+        PlotError: From<DrawingAreaErrorKind<DB::ErrorType>>,
+    {
+        // Marshal the perf data into a plot-able form
+        let Some(perf_data) = PerfData::new(results) else {
+            return Self::no_data_found(plot_area);
+        };
+        Self::plot(perf_data, plot_area)
+    }
+
+    fn no_data_found<DB>(plot_area: &Area<DB>) -> Result<(), PlotError>
+    where
+        DB: DrawingBackend,
+        PlotError: From<DrawingAreaErrorKind<DB::ErrorType>>,
+    {
         // Return an informative message if there is no perf data found
         let _chart_context = ChartBuilder::on(plot_area)
             .margin_top(TITLE_HEIGHT)
@@ -138,21 +246,41 @@ impl LinePlot {
                 (FontFamily::Monospace, 32),
             )
             .build_cartesian_2d(PerfData::default_x_range(), PerfData::default_y_range())?;
-
-        root_area.present().map_err(Into::into)
+        Ok(())
     }
 
-    fn plot(
-        perf_data: PerfData,
-        plot_area: &DrawingArea<BitMapBackend<'_>, Shift>,
-    ) -> Result<(), PlotError> {
-        let (plot_area, key_area) = plot_area.split_vertically(PLOT_HEIGHT);
+    fn plot<DB>(perf_data: PerfData, plot_area: &Area<DB>) -> Result<(), PlotError>
+    where
+        DB: DrawingBackend,
+        PlotError: From<DrawingAreaErrorKind<DB::ErrorType>>,
+    {
+        // Keep the same proportion between the plot and its key for any size of panel
+        let (width, height) = plot_area.dim_in_pixel();
+        #[expect(clippy::integer_division, reason = "pixel height")]
+        let plot_height =
+            u64::from(height) * u64::from(PLOT_HEIGHT) / u64::from(IMG_HEIGHT - TITLE_HEIGHT);
+        let (plot_area, key_area) = plot_area.split_vertically(u32::try_from(plot_height)?);
         let mut chart = Chart::new(&perf_data, &plot_area)?;
-        chart.layout_plot(&perf_data)?;
+        chart.layout_plot(&perf_data, width)?;
         chart.plot_lines(perf_data, &key_area)
     }
 }
 
+/// The perf results drawn in a single panel
+struct Panel<'a> {
+    caption: Option<&'a str>,
+    results: Vec<&'a JsonPerfMetrics>,
+}
+
+/// The rows and columns for a grid of panels, kept as square as possible
+fn grid(panels: usize) -> (usize, usize) {
+    let mut cols = 1;
+    while cols * cols < panels {
+        cols += 1;
+    }
+    (panels.div_ceil(cols), cols)
+}
+
 /// Ranged type that can support either linear or log scaling
 enum RangedCoord {
     Linear(RangedCoordf64),
@@ -206,29 +334,24 @@ impl From<Range<f64>> for RangedCoord {
     clippy::type_complexity,
     reason = "plotters chart types are inherently large and complex"
 )]
-enum Chart<'b> {
-    Single(
-        ChartContext<
-            'b,
-            BitMapBackend<'b>,
-            Cartesian2d<RangedDateTime<DateTime<Utc>>, RangedCoord>,
-        >,
-    ),
+enum Chart<'b, DB: DrawingBackend> {
+    Single(ChartContext<'b, DB, Cartesian2d<RangedDateTime<DateTime<Utc>>, RangedCoord>>),
     Dual(
         DualCoordChartContext<
             'b,
-            BitMapBackend<'b>,
+            DB,
             Cartesian2d<RangedDateTime<DateTime<Utc>>, RangedCoord>,
             Cartesian2d<RangedDateTime<DateTime<Utc>>, RangedCoord>,
         >,
     ),
 }
 
-impl<'b> Chart<'b> {
-    fn new(
-        perf_data: &PerfData,
-        plot_area: &DrawingArea<BitMapBackend<'b>, Shift>,
-    ) -> Result<Self, PlotError> {
+impl<'b, DB> Chart<'b, DB>
+where
+    DB: DrawingBackend,
+    PlotError: From<DrawingAreaErrorKind<DB::ErrorType>>,
+{
+    fn new(perf_data: &PerfData, plot_area: &'b Area<DB>) -> Result<Self, PlotError> {
         let left_y_range = RangedCoord::from(perf_data.left_y_range());
         let chart_context = ChartBuilder::on(plot_area)
             .x_label_area_size(40)
@@ -251,10 +374,15 @@ impl<'b> Chart<'b> {
         clippy::items_after_statements,
         reason = "helper fn defined near its only usage"
     )]
-    fn layout_plot(&mut self, perf_data: &PerfData) -> Result<(), PlotError> {
+    fn layout_plot(&mut self, perf_data: &PerfData, width: u32) -> Result<(), PlotError> {
         const AXIS_DESC_STYLE: (FontFamily, u32) = (FontFamily::Monospace, 20);
         const X_DESC: &str = "Benchmark Date and Time";
-        let x_labels = usize::try_from(X_LABELS)?;
+        // Fewer x labels fit in a narrower panel
+        #[expect(clippy::integer_division, reason = "whole number of labels")]
+        let x_labels = std::cmp::max(
+            usize::try_from(X_LABELS)? * usize::try_from(width)? / usize::try_from(IMG_WIDTH)?,
+            2,
+        );
         const X_LABEL_STYLE: (FontFamily, u32) = (FontFamily::Monospace, 16);
         #[expect(
             clippy::type_complexity,
@@ -317,12 +445,8 @@ impl<'b> Chart<'b> {
         Ok(())
     }
 
-    fn plot_lines(
-        &mut self,
-        perf_data: PerfData,
-        key_area: &DrawingArea<BitMapBackend<'_>, Shift>,
-    ) -> Result<(), PlotError> {
-        let plot_box = perf_data.plot_box()?;
+    fn plot_lines(&mut self, perf_data: PerfData, key_area: &Area<DB>) -> Result<(), PlotError> {
+        let plot_box = perf_data.plot_box(key_area.dim_in_pixel())?;
         let mut box_x_left = plot_box.x_left;
         for LineData {
             data,
@@ -391,7 +515,7 @@ impl<'b> Chart<'b> {
     }
 }
 
-type Area<'b> = DrawingArea<BitMapBackend<'b>, Shift>;
+type Area<DB> = DrawingArea<DB, Shift>;
 
 struct PerfData {
     lines: Vec<LineData>,
@@ -418,7 +542,7 @@ enum Anchor {
 }
 
 impl PerfData {
-    fn new(json_perf: &JsonPerf) -> Option<PerfData> {
+    fn new(results: &[&JsonPerfMetrics]) -> Option<PerfData> {
         let Extent {
             left_measure,
             right_measure,
@@ -429,7 +553,7 @@ impl PerfData {
             right_min_y,
             right_max_y,
             lines,
-        } = Extent::new(json_perf)?;
+        } = Extent::new(results)?;
 
         let (Some(min_x), Some(max_x), Some(left_min_y), Some(left_max_y)) =
             (min_x, max_x, left_min_y, left_max_y)
@@ -591,13 +715,14 @@ impl PerfData {
         u32::try_from(y_len).map_err(Into::into)
     }
 
-    fn plot_box(&self) -> Result<PlotBox, PlotError> {
+    fn plot_box(&self, (key_width, key_height): (u32, u32)) -> Result<PlotBox, PlotError> {
         const KEY_LEFT_MARGIN: usize = 48;
         const BOX_GAP: usize = 12;
         const BOX_HEIGHT: i32 = 24;
         const TEXT_START: i32 = BOX_HEIGHT + 4;
 
         let lines_len = self.lines.len();
+        let key_width = usize::try_from(key_width)?;
         let (box_x_left, box_width, box_gap) = if lines_len > 3 {
             const MIN_GAP: usize = 4;
             let extra_lines = lines_len - 4;
@@ -608,13 +733,19 @@ impl PerfData {
                 clippy::integer_division,
                 reason = "evenly dividing available width among legend boxes"
             )]
-            let width = (usize::try_from(IMG_WIDTH)? - box_x_left - box_gaps) / lines_len;
+            let width = key_width.saturating_sub(box_x_left + box_gaps) / lines_len;
             (box_x_left, width, box_gap)
         } else {
-            (KEY_LEFT_MARGIN, 256, BOX_GAP)
+            #[expect(
+                clippy::integer_division,
+                reason = "evenly dividing available width among legend boxes"
+            )]
+            let width = key_width.saturating_sub(KEY_LEFT_MARGIN + lines_len * BOX_GAP)
+                / std::cmp::max(lines_len, 1);
+            (KEY_LEFT_MARGIN, std::cmp::min(width, 256), BOX_GAP)
         };
 
-        let text_end = i32::try_from(KEY_HEIGHT)? - TEXT_START - 48;
+        let text_end = i32::try_from(key_height)? - TEXT_START;
         let text_width = u32::try_from(box_width)?;
 
         Ok(PlotBox {
@@ -642,8 +773,8 @@ struct Extent {
 }
 
 impl Extent {
-    fn new(json_perf: &JsonPerf) -> Option<Self> {
-        let (left_measure, right_measure) = Self::measures(json_perf)?;
+    fn new(results: &[&JsonPerfMetrics]) -> Option<Self> {
+        let (left_measure, right_measure) = Self::measures(results)?;
 
         let find_anchor = |measure: &JsonMeasure| -> Option<Anchor> {
             if measure.uuid == left_measure.uuid {
@@ -664,8 +795,7 @@ impl Extent {
         let mut right_min_y = None;
         let mut right_max_y = None;
 
-        let lines = json_perf
-            .results
+        let lines = results
             .iter()
             .take(MAX_LINES)
             .enumerate()
@@ -728,9 +858,9 @@ impl Extent {
         })
     }
 
-    fn measures(json_perf: &JsonPerf) -> Option<(JsonMeasure, Option<JsonMeasure>)> {
+    fn measures(results: &[&JsonPerfMetrics]) -> Option<(JsonMeasure, Option<JsonMeasure>)> {
         let mut json_measures: Vec<&JsonMeasure> = Vec::with_capacity(2);
-        for result in &json_perf.results {
+        for result in results {
             if !json_measures
                 .iter()
                 .any(|measure| measure.uuid == result.measure.uuid)
@@ -813,7 +943,7 @@ impl LineData {
 mod tests {
     use std::{fs::File, io::Write as _, sync::LazyLock};
 
-    use bencher_json::JsonPerf;
+    use bencher_json::{JsonPerf, PerfImgPanels};
 
     use crate::LinePlot;
 
@@ -843,6 +973,11 @@ mod tests {
         file.write_all(jpeg).unwrap();
     }
 
+    fn save_svg(svg: &str, name: &str) {
+        let mut file = File::create(format!("{name}.svg")).unwrap();
+        file.write_all(svg.as_bytes()).unwrap();
+    }
+
     #[test]
     fn plot() {
         let plot = LinePlot::new();
@@ -887,4 +1022,54 @@ mod tests {
         let plot_buffer = plot.draw(None, &json_perf).unwrap();
         save_jpeg(&plot_buffer, "empty");
     }
+
+    #[test]
+    fn plot_svg() {
+        let plot = LinePlot::new();
+        let svg = plot
+            .draw_svg(Some("Benchmark Adapter Comparison"), &JSON_PERF)
+            .unwrap();
+        assert!(svg.starts_with("<svg"));
+        save_svg(&svg, "perf");
+    }
+
+    #[test]
+    fn plot_panels() {
+        let plot = LinePlot::new().with_panels(Some(PerfImgPanels::Measure));
+        let plot_buffer = plot
+            .draw(Some("Benchmark Adapter Comparison"), &JSON_PERF_DUAL_AXES)
+            .unwrap();
+        save_jpeg(&plot_buffer, "perf_panels");
+    }
+
+    #[test]
+    fn plot_panels_svg() {
+        let plot = LinePlot::new().with_panels(Some(PerfImgPanels::Benchmark));
+        let svg = plot
+            .draw_svg(Some("Benchmark Adapter Comparison"), &JSON_PERF)
+            .unwrap();
+        assert!(svg.starts_with("<svg"));
+        save_svg(&svg, "perf_panels");
+    }
+
+    #[test]
+    fn plot_panels_empty() {
+        let plot = LinePlot::new().with_panels(Some(PerfImgPanels::Benchmark));
+        let mut json_perf = JSON_PERF.clone();
+        json_perf.results.clear();
+        let panels = plot.panels(&json_perf);
+        assert_eq!(panels.len(), 1);
+        assert!(panels.first().unwrap().caption.is_none());
+    }
+
+    #[test]
+    fn grid() {
+        assert_eq!(super::grid(1), (1, 1));
+        assert_eq!(super::grid(2), (1, 2));
+        assert_eq!(super::grid(3), (2, 2));
+        assert_eq!(super::grid(4), (2, 2));
+        assert_eq!(super::grid(5), (2, 3));
+        assert_eq!(super::grid(9), (3, 3));
+        assert_eq!(super::grid(16), (4, 4));
+    }
 }
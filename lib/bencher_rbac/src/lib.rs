@@ -349,4 +349,66 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[test]
+    fn rbac_project_roles() {
+        let oso = &*OSO;
+
+        let org_id = Uuid::new_v4();
+        let proj_id = Uuid::new_v4();
+        let org = Organization {
+            id: org_id.to_string(),
+        };
+        let proj = Project {
+            id: proj_id.to_string(),
+            organization_id: org_id.to_string(),
+        };
+        let other_proj = Project {
+            id: Uuid::new_v4().to_string(),
+            organization_id: org_id.to_string(),
+        };
+
+        let proj_user = |role: ProjRole| User {
+            admin: false,
+            locked: false,
+            organizations: HashMap::new(),
+            projects: literally::hmap! {
+                proj_id.to_string() => role
+            },
+        };
+
+        let all = [
+            ProjPerm::View,
+            ProjPerm::ViewRole,
+            ProjPerm::Create,
+            ProjPerm::Edit,
+            ProjPerm::Delete,
+            ProjPerm::Manage,
+            ProjPerm::CreateRole,
+            ProjPerm::EditRole,
+            ProjPerm::DeleteRole,
+        ];
+        for (role, allowed) in [
+            (ProjRole::Viewer, 2),
+            (ProjRole::Developer, 5),
+            (ProjRole::Maintainer, all.len()),
+        ] {
+            let user = proj_user(role);
+            for (index, permission) in all.into_iter().enumerate() {
+                assert_eq!(
+                    oso.is_allowed(user.clone(), permission, proj.clone())
+                        .unwrap(),
+                    index < allowed,
+                    "{role:?} {permission:?}"
+                );
+                // Project roles do not carry over to other projects in the organization
+                assert!(
+                    !oso.is_allowed(user.clone(), permission, other_proj.clone())
+                        .unwrap()
+                );
+            }
+            // Nor do they grant access to the organization itself
+            assert!(!oso.is_allowed(user, OrgPerm::View, org.clone()).unwrap());
+        }
+    }
 }
@@ -0,0 +1,2 @@
+ALTER TABLE model ADD COLUMN confirm_outliers BIGINT;
+ALTER TABLE model ADD COLUMN confirm_reports BIGINT;
@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS index_webhook_delivery_webhook_created;
+DROP TABLE IF EXISTS webhook_delivery;
+DROP INDEX IF EXISTS index_webhook_project_id;
+DROP TABLE IF EXISTS webhook;
@@ -0,0 +1,28 @@
+CREATE TABLE webhook (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    events TEXT NOT NULL,
+    enabled BOOLEAN NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE
+);
+CREATE INDEX index_webhook_project_id ON webhook(project_id);
+CREATE TABLE webhook_delivery (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    webhook_id INTEGER NOT NULL,
+    event INTEGER NOT NULL,
+    payload TEXT NOT NULL,
+    status INTEGER NOT NULL,
+    attempts INTEGER NOT NULL,
+    response_status INTEGER,
+    error TEXT,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (webhook_id) REFERENCES webhook (id) ON DELETE CASCADE
+);
+CREATE INDEX index_webhook_delivery_webhook_created ON webhook_delivery(webhook_id, created);
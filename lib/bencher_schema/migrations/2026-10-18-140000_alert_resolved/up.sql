@@ -0,0 +1,2 @@
+ALTER TABLE model ADD COLUMN resolve_reports BIGINT;
+ALTER TABLE alert ADD COLUMN resolved_report_id INTEGER REFERENCES report (id) ON DELETE SET NULL;
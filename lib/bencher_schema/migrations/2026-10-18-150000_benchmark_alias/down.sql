@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS index_benchmark_alias_benchmark_id;
+DROP TABLE IF EXISTS benchmark_alias;
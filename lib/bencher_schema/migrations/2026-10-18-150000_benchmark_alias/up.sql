@@ -0,0 +1,12 @@
+CREATE TABLE benchmark_alias (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    benchmark_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (benchmark_id) REFERENCES benchmark (id) ON DELETE CASCADE,
+    UNIQUE(project_id, name)
+);
+CREATE INDEX index_benchmark_alias_benchmark_id ON benchmark_alias(benchmark_id);
@@ -0,0 +1,9 @@
+CREATE TABLE project_retention (
+    project_id INTEGER PRIMARY KEY NOT NULL,
+    report_days BIGINT,
+    default_branch_id INTEGER,
+    downsample_days BIGINT,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (default_branch_id) REFERENCES branch (id) ON DELETE SET NULL
+);
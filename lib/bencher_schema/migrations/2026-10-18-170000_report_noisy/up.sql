@@ -0,0 +1 @@
+ALTER TABLE report ADD COLUMN noisy BOOLEAN NOT NULL DEFAULT FALSE;
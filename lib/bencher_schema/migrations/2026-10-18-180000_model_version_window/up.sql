@@ -0,0 +1 @@
+ALTER TABLE model ADD COLUMN version_window BIGINT;
@@ -0,0 +1,22 @@
+-- boundary: add p_value column
+ALTER TABLE boundary ADD COLUMN p_value DOUBLE;
+-- metric_boundary
+DROP VIEW IF EXISTS metric_boundary;
+CREATE VIEW metric_boundary AS
+SELECT metric.id AS metric_id,
+    metric.uuid AS metric_uuid,
+    metric.report_benchmark_id,
+    metric.measure_id,
+    metric.value,
+    metric.lower_value,
+    metric.upper_value,
+    boundary.id AS boundary_id,
+    boundary.uuid AS boundary_uuid,
+    boundary.threshold_id,
+    boundary.model_id,
+    boundary.baseline,
+    boundary.lower_limit,
+    boundary.upper_limit,
+    boundary.p_value
+FROM metric
+    LEFT OUTER JOIN boundary ON (boundary.metric_id = metric.id);
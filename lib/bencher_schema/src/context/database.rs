@@ -4,10 +4,14 @@ use std::{
     sync::Arc,
 };
 
-use bencher_json::{Secret, system::config::DataStore as DataStoreConfig};
+use bencher_json::{
+    Secret,
+    system::{backup::JsonDataStore, config::DataStore as DataStoreConfig},
+};
 use camino::Utf8PathBuf;
 use diesel::r2d2::{ConnectionManager, Pool, PooledConnection};
 use dropshot::HttpError;
+use url::Url;
 
 use crate::error::issue_error;
 
@@ -74,27 +78,45 @@ impl Database {
 }
 
 pub enum DataStore {
-    AwsS3(AwsS3),
+    AwsS3(S3Bucket),
+    S3(S3Bucket),
+    Local(LocalDirectory),
 }
 
-pub struct AwsS3 {
+/// An S3 bucket, either on AWS or any S3-compatible object store.
+pub struct S3Bucket {
     client: aws_sdk_s3::Client,
-    s3_arn: S3Arn,
+    /// The bucket name or access point ARN
+    bucket: String,
+    /// The key prefix for all objects
+    prefix: Option<String>,
+}
+
+/// A directory on the server's filesystem.
+pub struct LocalDirectory {
+    directory: PathBuf,
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum DataStoreError {
     #[error("Failed to configure data store: {0}")]
     DataStore(String),
-    #[error("Failed to use AWS S3: {0}")]
-    AwsS3(String),
+    #[error("Failed to use S3: {0}")]
+    S3(String),
     #[error("Failed to parse S3 ARN ({access_point}): {error}")]
     S3Arn {
         access_point: String,
         error: S3ArnError,
     },
+    #[error("Failed to use local data store ({path}): {error}")]
+    Local {
+        path: PathBuf,
+        error: std::io::Error,
+    },
 }
 
+const DEFAULT_S3_REGION: &str = "us-east-1";
+
 impl TryFrom<DataStoreConfig> for DataStore {
     type Error = DataStoreError;
 
@@ -104,34 +126,74 @@ impl TryFrom<DataStoreConfig> for DataStore {
                 access_key_id,
                 secret_access_key,
                 access_point,
-            } => AwsS3::new(access_key_id, secret_access_key, &access_point).map(Self::AwsS3),
+            } => {
+                S3Bucket::new_aws(access_key_id, secret_access_key, &access_point).map(Self::AwsS3)
+            },
+            DataStoreConfig::S3 {
+                endpoint,
+                bucket,
+                prefix,
+                region,
+                access_key_id,
+                secret_access_key,
+            } => Ok(Self::S3(S3Bucket::new_path_style(
+                access_key_id,
+                secret_access_key,
+                &endpoint,
+                bucket,
+                prefix,
+                region,
+            ))),
+            DataStoreConfig::Local { directory } => Ok(Self::Local(LocalDirectory { directory })),
         }
     }
 }
 
 impl DataStore {
+    pub fn kind(&self) -> JsonDataStore {
+        match self {
+            Self::AwsS3(_) => JsonDataStore::AwsS3,
+            Self::S3(_) => JsonDataStore::S3,
+            Self::Local(_) => JsonDataStore::Local,
+        }
+    }
+
     pub async fn backup(&self, source_path: &Path, file_name: &str) -> Result<(), DataStoreError> {
         match self {
-            Self::AwsS3(aws_s3) => aws_s3.backup(source_path, file_name).await,
+            Self::AwsS3(bucket) | Self::S3(bucket) => bucket.backup(source_path, file_name).await,
+            Self::Local(local) => local.backup(source_path, file_name).await,
+        }
+    }
+
+    /// List the file names of all backups that start with `name_prefix`.
+    pub async fn list(&self, name_prefix: &str) -> Result<Vec<String>, DataStoreError> {
+        match self {
+            Self::AwsS3(bucket) | Self::S3(bucket) => bucket.list(name_prefix).await,
+            Self::Local(local) => local.list(name_prefix).await,
+        }
+    }
+
+    pub async fn remove(&self, file_name: &str) -> Result<(), DataStoreError> {
+        match self {
+            Self::AwsS3(bucket) | Self::S3(bucket) => bucket.remove(file_name).await,
+            Self::Local(local) => local.remove(file_name).await,
+        }
+    }
+
+    pub async fn download(&self, file_name: &str, dest_path: &Path) -> Result<(), DataStoreError> {
+        match self {
+            Self::AwsS3(bucket) | Self::S3(bucket) => bucket.download(file_name, dest_path).await,
+            Self::Local(local) => local.download(file_name, dest_path).await,
         }
     }
 }
 
-impl AwsS3 {
-    fn new(
+impl S3Bucket {
+    fn new_aws(
         access_key_id: String,
         secret_access_key: Secret,
         access_point: &str,
     ) -> Result<Self, DataStoreError> {
-        let credentials = aws_credential_types::Credentials::new(
-            access_key_id,
-            secret_access_key,
-            None,
-            None,
-            "bencher",
-        );
-        let credentials_provider =
-            aws_credential_types::provider::SharedCredentialsProvider::new(credentials);
         let s3_arn: S3Arn = access_point
             .parse()
             .map_err(|error| DataStoreError::S3Arn {
@@ -140,36 +202,230 @@ impl AwsS3 {
             })?;
 
         let config = aws_sdk_s3::Config::builder()
-            .credentials_provider(credentials_provider)
+            .credentials_provider(credentials_provider(access_key_id, secret_access_key))
             .region(aws_sdk_s3::config::Region::new(s3_arn.region.clone()))
             .build();
         let client = aws_sdk_s3::Client::from_conf(config);
 
-        Ok(Self { client, s3_arn })
+        Ok(Self {
+            client,
+            bucket: s3_arn.bucket_arn(),
+            prefix: s3_arn.bucket_path,
+        })
     }
 
-    async fn backup(&self, source_path: &Path, file_name: &str) -> Result<(), DataStoreError> {
-        let key = if let Some(bucket_path) = &self.s3_arn.bucket_path {
-            Utf8PathBuf::from(bucket_path).join(file_name).to_string()
+    fn new_path_style(
+        access_key_id: String,
+        secret_access_key: Secret,
+        endpoint: &Url,
+        bucket: String,
+        prefix: Option<String>,
+        region: Option<String>,
+    ) -> Self {
+        let config = aws_sdk_s3::Config::builder()
+            .credentials_provider(credentials_provider(access_key_id, secret_access_key))
+            .region(aws_sdk_s3::config::Region::new(
+                region.unwrap_or_else(|| DEFAULT_S3_REGION.to_owned()),
+            ))
+            .endpoint_url(endpoint.as_str().trim_end_matches('/'))
+            // MinIO and most other S3-compatible stores do not support virtual-hosted buckets
+            .force_path_style(true)
+            .build();
+        let client = aws_sdk_s3::Client::from_conf(config);
+
+        Self {
+            client,
+            bucket,
+            prefix: prefix.filter(|prefix| !prefix.is_empty()),
+        }
+    }
+
+    fn key(&self, file_name: &str) -> String {
+        if let Some(prefix) = &self.prefix {
+            Utf8PathBuf::from(prefix).join(file_name).to_string()
         } else {
             file_name.to_owned()
+        }
+    }
+
+    /// The file name for a key, if it is directly under the prefix.
+    fn file_name<'k>(&self, key: &'k str) -> Option<&'k str> {
+        let file_name = if let Some(prefix) = &self.prefix {
+            key.strip_prefix(prefix.trim_end_matches('/'))?
+                .strip_prefix('/')?
+        } else {
+            key
         };
+        (!file_name.contains('/')).then_some(file_name)
+    }
 
+    async fn backup(&self, source_path: &Path, file_name: &str) -> Result<(), DataStoreError> {
         let body = aws_sdk_s3::primitives::ByteStream::from_path(source_path)
             .await
-            .map_err(|e| DataStoreError::AwsS3(e.to_string()))?;
+            .map_err(|e| DataStoreError::S3(e.to_string()))?;
 
         self.client
             .put_object()
-            .bucket(self.s3_arn.bucket_arn())
-            .key(key)
+            .bucket(&self.bucket)
+            .key(self.key(file_name))
             .body(body)
             .send()
             .await
-            .map_err(|e| DataStoreError::AwsS3(e.to_string()))?;
+            .map_err(|e| DataStoreError::S3(e.to_string()))?;
 
         Ok(())
     }
+
+    async fn list(&self, name_prefix: &str) -> Result<Vec<String>, DataStoreError> {
+        let prefix = self.key(name_prefix);
+        let mut file_names = Vec::new();
+        let mut continuation_token: Option<String> = None;
+
+        loop {
+            let mut request = self
+                .client
+                .list_objects_v2()
+                .bucket(&self.bucket)
+                .prefix(&prefix);
+
+            if let Some(token) = continuation_token.take() {
+                request = request.continuation_token(token);
+            }
+
+            let response = request
+                .send()
+                .await
+                .map_err(|e| DataStoreError::S3(e.to_string()))?;
+
+            for key in response
+                .contents
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|object| object.key)
+            {
+                if let Some(file_name) = self.file_name(&key) {
+                    file_names.push(file_name.to_owned());
+                }
+            }
+
+            if response.is_truncated == Some(true) {
+                continuation_token = response.next_continuation_token;
+            } else {
+                break;
+            }
+        }
+
+        Ok(file_names)
+    }
+
+    async fn remove(&self, file_name: &str) -> Result<(), DataStoreError> {
+        self.client
+            .delete_object()
+            .bucket(&self.bucket)
+            .key(self.key(file_name))
+            .send()
+            .await
+            .map_err(|e| DataStoreError::S3(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn download(&self, file_name: &str, dest_path: &Path) -> Result<(), DataStoreError> {
+        let response = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(self.key(file_name))
+            .send()
+            .await
+            .map_err(|e| DataStoreError::S3(e.to_string()))?;
+
+        let local_error = |error| DataStoreError::Local {
+            path: dest_path.to_owned(),
+            error,
+        };
+        let mut file = tokio::fs::File::create(dest_path)
+            .await
+            .map_err(local_error)?;
+        let mut body = response.body.into_async_read();
+        tokio::io::copy(&mut body, &mut file)
+            .await
+            .map_err(local_error)?;
+        file.sync_all().await.map_err(local_error)
+    }
+}
+
+fn credentials_provider(
+    access_key_id: String,
+    secret_access_key: Secret,
+) -> aws_credential_types::provider::SharedCredentialsProvider {
+    let credentials = aws_credential_types::Credentials::new(
+        access_key_id,
+        secret_access_key,
+        None,
+        None,
+        "bencher",
+    );
+    aws_credential_types::provider::SharedCredentialsProvider::new(credentials)
+}
+
+impl LocalDirectory {
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    fn local_error(path: &Path) -> impl FnOnce(std::io::Error) -> DataStoreError {
+        let path = path.to_owned();
+        move |error| DataStoreError::Local { path, error }
+    }
+
+    async fn backup(&self, source_path: &Path, file_name: &str) -> Result<(), DataStoreError> {
+        tokio::fs::create_dir_all(&self.directory)
+            .await
+            .map_err(Self::local_error(&self.directory))?;
+        let dest_path = self.directory.join(file_name);
+        tokio::fs::copy(source_path, &dest_path)
+            .await
+            .map_err(Self::local_error(&dest_path))?;
+        Ok(())
+    }
+
+    pub async fn list(&self, name_prefix: &str) -> Result<Vec<String>, DataStoreError> {
+        let mut file_names = Vec::new();
+        let mut entries = match tokio::fs::read_dir(&self.directory).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(file_names),
+            Err(e) => return Err(Self::local_error(&self.directory)(e)),
+        };
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(Self::local_error(&self.directory))?
+        {
+            let Ok(file_name) = entry.file_name().into_string() else {
+                continue;
+            };
+            if file_name.starts_with(name_prefix) && entry.path().is_file() {
+                file_names.push(file_name);
+            }
+        }
+        Ok(file_names)
+    }
+
+    pub async fn remove(&self, file_name: &str) -> Result<(), DataStoreError> {
+        let path = self.directory.join(file_name);
+        tokio::fs::remove_file(&path)
+            .await
+            .map_err(Self::local_error(&path))
+    }
+
+    async fn download(&self, file_name: &str, dest_path: &Path) -> Result<(), DataStoreError> {
+        let source_path = self.directory.join(file_name);
+        tokio::fs::copy(&source_path, dest_path)
+            .await
+            .map_err(Self::local_error(&source_path))?;
+        Ok(())
+    }
 }
 
 // https://docs.aws.amazon.com/IAM/latest/UserGuide/reference-arns.html
@@ -34,7 +34,7 @@ mod stats;
 
 #[cfg(feature = "plus")]
 use bencher_recaptcha::RecaptchaClient;
-pub use database::{DataStore, DataStoreError, Database, DbConnection};
+pub use database::{DataStore, DataStoreError, Database, DbConnection, LocalDirectory};
 #[cfg(feature = "plus")]
 pub use heartbeat_tasks::HeartbeatTasks;
 #[cfg(feature = "plus")]
@@ -49,7 +49,6 @@ pub use rbac::{Rbac, RbacError};
 pub use runner_update::RunnerUpdate;
 #[cfg(feature = "plus")]
 pub use stats::StatsSettings;
-#[cfg(feature = "plus")]
 pub use tokio_util::sync::CancellationToken;
 
 pub struct ApiContext {
@@ -80,8 +79,13 @@ pub struct ApiContext {
     #[cfg(feature = "plus")]
     pub registry_url: Url,
     #[cfg(feature = "plus")]
-    pub oci_storage: OciStorage,
+    pub oci_storage: Arc<OciStorage>,
+    /// How often to collect garbage from the container registry, if at all.
+    #[cfg(feature = "plus")]
+    pub registry_gc_interval: Option<std::time::Duration>,
     pub clock: bencher_json::Clock,
+    /// Allow webhooks to use `http` and non-public addresses (for testing only)
+    pub allow_insecure_webhooks: bool,
     #[cfg(feature = "plus")]
     pub heartbeat_timeout: std::time::Duration,
     #[cfg(feature = "plus")]
@@ -92,8 +96,7 @@ pub struct ApiContext {
     #[cfg(feature = "plus")]
     pub runner_update: RunnerUpdate,
     /// Cancellation signal tripped on graceful shutdown so long-lived handlers (the runner WebSocket
-    /// channel) can wind down and let `server.close()` complete.
-    #[cfg(feature = "plus")]
+    /// channel) and background tasks can wind down and let `server.close()` complete.
     pub shutdown: CancellationToken,
 }
 
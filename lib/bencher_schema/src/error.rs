@@ -27,12 +27,15 @@ pub enum BencherResource {
     HeadVersion,
     Testbed,
     Benchmark,
+    BenchmarkAlias,
     Measure,
     Metric,
     Threshold,
     Model,
     Boundary,
     Alert,
+    Webhook,
+    WebhookDelivery,
     Runner,
     RunnerSpec,
     Spec,
@@ -72,12 +75,15 @@ impl fmt::Display for BencherResource {
                 Self::HeadVersion => "Head Version",
                 Self::Testbed => "Testbed",
                 Self::Benchmark => "Benchmark",
+                Self::BenchmarkAlias => "Benchmark Alias",
                 Self::Measure => "Measure",
                 Self::Metric => "Metric",
                 Self::Threshold => "Threshold",
                 Self::Model => "Model",
                 Self::Boundary => "Boundary",
                 Self::Alert => "Alert",
+                Self::Webhook => "Webhook",
+                Self::WebhookDelivery => "Webhook Delivery",
                 Self::Runner => "Runner",
                 Self::RunnerSpec => "Runner Spec",
                 Self::Spec => "Spec",
@@ -52,3 +52,15 @@ pub fn run_migrations(database: &mut context::DbConnection) -> Result<(), Migrat
 
     Ok(())
 }
+
+/// The versions of every migration this server knows about.
+pub fn migration_versions() -> Result<Vec<String>, MigrationError> {
+    diesel::migration::MigrationSource::<diesel::sqlite::Sqlite>::migrations(&MIGRATIONS)
+        .map(|migrations| {
+            migrations
+                .iter()
+                .map(|migration| migration.name().version().to_string())
+                .collect()
+        })
+        .map_err(MigrationError::Migrations)
+}
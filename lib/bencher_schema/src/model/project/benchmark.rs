@@ -5,7 +5,15 @@ use bencher_json::{
 use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
 use dropshot::HttpError;
 
-use super::{ProjectId, QueryProject};
+use super::{
+    ProjectId, QueryProject,
+    benchmark_alias::{InsertBenchmarkAlias, QueryBenchmarkAlias},
+    plot::PlotId,
+};
+#[cfg(feature = "plus")]
+use super::{measure::MeasureId, series::upsert_series_last_seen, testbed::TestbedId};
+#[cfg(feature = "plus")]
+use crate::model::organization::OrganizationId;
 use crate::{
     auth_conn,
     context::{ApiContext, DbConnection},
@@ -90,6 +98,12 @@ impl QueryBenchmark {
             Err(e) => e,
         };
 
+        if let Some(benchmark) =
+            QueryBenchmarkAlias::benchmark_for_name_id(auth_conn!(context), project_id, benchmark)
+        {
+            return Ok(benchmark);
+        }
+
         let json_benchmark = match benchmark.clone() {
             NameId::Uuid(_) => return Err(http_error),
             NameId::Slug(slug) => JsonNewBenchmark {
@@ -131,6 +145,103 @@ impl QueryBenchmark {
         .map(|id| insert_benchmark.into_query(id))
     }
 
+    /// Merge the source benchmarks into this benchmark.
+    ///
+    /// All of the report benchmarks for the sources, and with them their metrics, boundaries, and alerts,
+    /// are moved to this benchmark.
+    /// The names of the sources become aliases of this benchmark, and the sources are deleted.
+    /// This must be run inside of a single write transaction.
+    pub fn merge(
+        &self,
+        conn: &mut DbConnection,
+        sources: &[QueryBenchmark],
+        now: DateTime,
+    ) -> diesel::QueryResult<()> {
+        for source in sources {
+            diesel::update(
+                schema::report_benchmark::table
+                    .filter(schema::report_benchmark::benchmark_id.eq(source.id)),
+            )
+            .set(schema::report_benchmark::benchmark_id.eq(self.id))
+            .execute(conn)?;
+
+            // A plot may already include this benchmark,
+            // in which case the source is simply dropped from the plot.
+            let plot_ids = schema::plot_benchmark::table
+                .filter(schema::plot_benchmark::benchmark_id.eq(self.id))
+                .select(schema::plot_benchmark::plot_id)
+                .load::<PlotId>(conn)?;
+            diesel::update(
+                schema::plot_benchmark::table
+                    .filter(schema::plot_benchmark::benchmark_id.eq(source.id))
+                    .filter(schema::plot_benchmark::plot_id.ne_all(plot_ids)),
+            )
+            .set(schema::plot_benchmark::benchmark_id.eq(self.id))
+            .execute(conn)?;
+
+            #[cfg(feature = "plus")]
+            self.merge_series_last_seen(conn, source)?;
+
+            diesel::update(
+                schema::benchmark_alias::table
+                    .filter(schema::benchmark_alias::benchmark_id.eq(source.id)),
+            )
+            .set(schema::benchmark_alias::benchmark_id.eq(self.id))
+            .execute(conn)?;
+            // A benchmark may have been created with the same name as an existing alias.
+            diesel::delete(
+                schema::benchmark_alias::table
+                    .filter(schema::benchmark_alias::project_id.eq(self.project_id))
+                    .filter(schema::benchmark_alias::name.eq(&source.name)),
+            )
+            .execute(conn)?;
+            let insert_alias = InsertBenchmarkAlias::new(self, source.name.clone(), now);
+            diesel::insert_into(schema::benchmark_alias::table)
+                .values(&insert_alias)
+                .execute(conn)?;
+
+            diesel::delete(schema::benchmark::table.filter(schema::benchmark::id.eq(source.id)))
+                .execute(conn)?;
+        }
+
+        diesel::update(schema::benchmark::table.filter(schema::benchmark::id.eq(self.id)))
+            .set(schema::benchmark::modified.eq(now))
+            .execute(conn)?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "plus")]
+    fn merge_series_last_seen(
+        &self,
+        conn: &mut DbConnection,
+        source: &QueryBenchmark,
+    ) -> diesel::QueryResult<()> {
+        let series = schema::series_last_seen::table
+            .filter(schema::series_last_seen::benchmark_id.eq(source.id))
+            .select((
+                schema::series_last_seen::organization_id,
+                schema::series_last_seen::project_id,
+                schema::series_last_seen::testbed_id,
+                schema::series_last_seen::measure_id,
+                schema::series_last_seen::last_seen,
+            ))
+            .load::<(OrganizationId, ProjectId, TestbedId, MeasureId, DateTime)>(conn)?;
+        for (organization_id, project_id, testbed_id, measure_id, last_seen) in series {
+            upsert_series_last_seen(
+                conn,
+                organization_id,
+                project_id,
+                testbed_id,
+                self.id,
+                measure_id,
+                last_seen,
+            )?;
+        }
+        // The source rows are removed when the source benchmark is deleted.
+        Ok(())
+    }
+
     pub fn into_json_for_project(self, project: &QueryProject) -> JsonBenchmark {
         let Self {
             uuid,
@@ -262,11 +373,14 @@ mod tests {
 
     use bencher_json::DateTime;
 
-    use super::BenchmarkId;
+    use super::{BenchmarkId, QueryBenchmark};
     use crate::{
         macros::sql::last_insert_rowid,
         schema,
-        test_util::{create_base_entities, create_benchmark, setup_test_db},
+        test_util::{
+            create_base_entities, create_benchmark, create_branch_with_head, create_report,
+            create_report_benchmark, create_testbed, create_version, setup_test_db,
+        },
     };
 
     #[test]
@@ -441,4 +555,184 @@ mod tests {
             .expect("Failed to query");
         assert!(archived.is_none());
     }
+
+    #[test]
+    fn benchmark_merge() {
+        let mut conn = setup_test_db();
+        let base = create_base_entities(&mut conn);
+        let branch = create_branch_with_head(
+            &mut conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000001",
+            "main",
+            "main",
+            "00000000-0000-0000-0000-000000000002",
+        );
+        let testbed_id = create_testbed(
+            &mut conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000003",
+            "localhost",
+            "localhost",
+        );
+        let version_id = create_version(
+            &mut conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000004",
+            1,
+            None,
+        );
+        let target_id = create_benchmark(
+            &mut conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000010",
+            "bench_new",
+            "bench-new",
+        );
+        let source_id = create_benchmark(
+            &mut conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000011",
+            "bench_old",
+            "bench-old",
+        );
+        let old_report_id = create_report(
+            &mut conn,
+            "00000000-0000-0000-0000-000000000020",
+            base.project_id,
+            branch.head_id,
+            version_id,
+            testbed_id,
+        );
+        create_report_benchmark(
+            &mut conn,
+            "00000000-0000-0000-0000-000000000030",
+            old_report_id,
+            0,
+            source_id,
+        );
+        let new_report_id = create_report(
+            &mut conn,
+            "00000000-0000-0000-0000-000000000021",
+            base.project_id,
+            branch.head_id,
+            version_id,
+            testbed_id,
+        );
+        create_report_benchmark(
+            &mut conn,
+            "00000000-0000-0000-0000-000000000031",
+            new_report_id,
+            0,
+            target_id,
+        );
+
+        let target = QueryBenchmark::get(&mut conn, target_id).expect("Failed to get target");
+        let source = QueryBenchmark::get(&mut conn, source_id).expect("Failed to get source");
+        conn.immediate_transaction(|conn| target.merge(conn, &[source], DateTime::TEST))
+            .expect("Failed to merge");
+
+        let report_benchmarks: i64 = schema::report_benchmark::table
+            .filter(schema::report_benchmark::benchmark_id.eq(target_id))
+            .count()
+            .get_result(&mut conn)
+            .expect("Failed to count report benchmarks");
+        assert_eq!(report_benchmarks, 2);
+        let sources: i64 = schema::benchmark::table
+            .filter(schema::benchmark::id.eq(source_id))
+            .count()
+            .get_result(&mut conn)
+            .expect("Failed to count benchmarks");
+        assert_eq!(sources, 0);
+        let alias: BenchmarkId = schema::benchmark_alias::table
+            .filter(schema::benchmark_alias::name.eq("bench_old"))
+            .select(schema::benchmark_alias::benchmark_id)
+            .first(&mut conn)
+            .expect("Failed to get alias");
+        assert_eq!(alias, target_id);
+    }
+
+    #[test]
+    fn benchmark_merge_conflict() {
+        let mut conn = setup_test_db();
+        let base = create_base_entities(&mut conn);
+        let branch = create_branch_with_head(
+            &mut conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000001",
+            "main",
+            "main",
+            "00000000-0000-0000-0000-000000000002",
+        );
+        let testbed_id = create_testbed(
+            &mut conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000003",
+            "localhost",
+            "localhost",
+        );
+        let version_id = create_version(
+            &mut conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000004",
+            1,
+            None,
+        );
+        let target_id = create_benchmark(
+            &mut conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000010",
+            "bench_new",
+            "bench-new",
+        );
+        let source_id = create_benchmark(
+            &mut conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000011",
+            "bench_old",
+            "bench-old",
+        );
+        // Both benchmarks are in the same report iteration
+        let report_id = create_report(
+            &mut conn,
+            "00000000-0000-0000-0000-000000000020",
+            base.project_id,
+            branch.head_id,
+            version_id,
+            testbed_id,
+        );
+        create_report_benchmark(
+            &mut conn,
+            "00000000-0000-0000-0000-000000000030",
+            report_id,
+            0,
+            source_id,
+        );
+        create_report_benchmark(
+            &mut conn,
+            "00000000-0000-0000-0000-000000000031",
+            report_id,
+            0,
+            target_id,
+        );
+
+        let target = QueryBenchmark::get(&mut conn, target_id).expect("Failed to get target");
+        let source = QueryBenchmark::get(&mut conn, source_id).expect("Failed to get source");
+        let result =
+            conn.immediate_transaction(|conn| target.merge(conn, &[source], DateTime::TEST));
+        assert!(result.is_err());
+
+        // Nothing is merged
+        let sources: i64 = schema::report_benchmark::table
+            .filter(schema::report_benchmark::benchmark_id.eq(source_id))
+            .count()
+            .get_result(&mut conn)
+            .expect("Failed to count report benchmarks");
+        assert_eq!(sources, 1);
+        let aliases: i64 = schema::benchmark_alias::table
+            .count()
+            .get_result(&mut conn)
+            .expect("Failed to count aliases");
+        assert_eq!(aliases, 0);
+    }
 }
@@ -0,0 +1,134 @@
+use bencher_json::{
+    BenchmarkAliasUuid, BenchmarkName, BenchmarkNameId, DateTime, JsonBenchmarkAlias, NameId,
+    project::benchmark::JsonNewBenchmarkAlias,
+};
+use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _, SelectableHelper as _};
+use dropshot::HttpError;
+
+use super::{
+    ProjectId,
+    benchmark::{BenchmarkId, QueryBenchmark},
+};
+use crate::{
+    context::DbConnection,
+    error::{BencherResource, assert_parentage, resource_not_found_err},
+    schema::{self, benchmark_alias as benchmark_alias_table},
+};
+
+crate::macros::typed_id::typed_id!(BenchmarkAliasId);
+
+#[derive(Debug, Clone, diesel::Queryable, diesel::Selectable)]
+#[diesel(table_name = benchmark_alias_table)]
+pub struct QueryBenchmarkAlias {
+    pub id: BenchmarkAliasId,
+    pub uuid: BenchmarkAliasUuid,
+    pub project_id: ProjectId,
+    pub benchmark_id: BenchmarkId,
+    pub name: BenchmarkName,
+    pub created: DateTime,
+}
+
+impl QueryBenchmarkAlias {
+    pub fn get_benchmark_alias(
+        conn: &mut DbConnection,
+        benchmark_id: BenchmarkId,
+        uuid: BenchmarkAliasUuid,
+    ) -> Result<Self, HttpError> {
+        schema::benchmark_alias::table
+            .filter(schema::benchmark_alias::benchmark_id.eq(benchmark_id))
+            .filter(schema::benchmark_alias::uuid.eq(uuid))
+            .first::<Self>(conn)
+            .map_err(resource_not_found_err!(
+                BenchmarkAlias,
+                (benchmark_id, &uuid)
+            ))
+    }
+
+    /// Find the benchmark that a name or slug is an alias for.
+    /// UUIDs are never aliases.
+    pub fn benchmark_for_name_id(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+        benchmark: &BenchmarkNameId,
+    ) -> Option<QueryBenchmark> {
+        let name: &str = match benchmark {
+            NameId::Uuid(_) => return None,
+            NameId::Slug(slug) => slug.as_ref(),
+            NameId::Name(name) => name.as_ref(),
+        };
+        schema::benchmark_alias::table
+            .inner_join(schema::benchmark::table)
+            .filter(schema::benchmark_alias::project_id.eq(project_id))
+            .filter(schema::benchmark_alias::name.eq(name))
+            .select(QueryBenchmark::as_select())
+            .first::<QueryBenchmark>(conn)
+            .ok()
+    }
+
+    pub fn into_json_for_benchmark(self, query_benchmark: &QueryBenchmark) -> JsonBenchmarkAlias {
+        let Self {
+            uuid,
+            benchmark_id,
+            name,
+            created,
+            ..
+        } = self;
+        assert_parentage(
+            BencherResource::Benchmark,
+            query_benchmark.id,
+            BencherResource::BenchmarkAlias,
+            benchmark_id,
+        );
+        JsonBenchmarkAlias {
+            uuid,
+            benchmark: query_benchmark.uuid,
+            name,
+            created,
+        }
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = benchmark_alias_table)]
+pub struct InsertBenchmarkAlias {
+    pub uuid: BenchmarkAliasUuid,
+    pub project_id: ProjectId,
+    pub benchmark_id: BenchmarkId,
+    pub name: BenchmarkName,
+    pub created: DateTime,
+}
+
+impl InsertBenchmarkAlias {
+    pub fn new(query_benchmark: &QueryBenchmark, name: BenchmarkName, now: DateTime) -> Self {
+        Self {
+            uuid: BenchmarkAliasUuid::new(),
+            project_id: query_benchmark.project_id,
+            benchmark_id: query_benchmark.id,
+            name,
+            created: now,
+        }
+    }
+
+    pub fn from_json(query_benchmark: &QueryBenchmark, alias: JsonNewBenchmarkAlias) -> Self {
+        let JsonNewBenchmarkAlias { name } = alias;
+        Self::new(query_benchmark, name, DateTime::now())
+    }
+
+    pub fn into_query(self, id: BenchmarkAliasId) -> QueryBenchmarkAlias {
+        let Self {
+            uuid,
+            project_id,
+            benchmark_id,
+            name,
+            created,
+        } = self;
+        QueryBenchmarkAlias {
+            id,
+            uuid,
+            project_id,
+            benchmark_id,
+            name,
+            created,
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use bencher_json::{
-    GitHash, VersionUuid,
+    GitHash, SampleSize, VersionUuid,
     project::head::{JsonVersion, VersionNumber},
 };
 use diesel::{
@@ -58,6 +58,23 @@ impl QueryVersion {
             .optional()
     }
 
+    /// Find the oldest version number within the most recent `versions` for the given branch head.
+    /// If the branch head has fewer versions, then there is no start to the window.
+    pub fn window_start(
+        conn: &mut DbConnection,
+        head_id: HeadId,
+        versions: SampleSize,
+    ) -> QueryResult<Option<VersionNumber>> {
+        schema::version::table
+            .inner_join(schema::head_version::table)
+            .filter(schema::head_version::head_id.eq(head_id))
+            .order(schema::version::number.desc())
+            .offset(i64::from(u32::from(versions)) - 1)
+            .select(schema::version::number)
+            .first::<VersionNumber>(conn)
+            .optional()
+    }
+
     pub fn into_json(self) -> JsonVersion {
         let Self { number, hash, .. } = self;
         JsonVersion { number, hash }
@@ -95,13 +95,27 @@ impl QueryMeasure {
 
         let json_measure = if let Some(measure) = built_in::default::Latency::from_str(measure_str)
             .or_else(|| built_in::default::Throughput::from_str(measure_str))
+            .or_else(|| built_in::throughput::ByteThroughput::from_str(measure_str))
             .or_else(|| built_in::json::BuildTime::from_str(measure_str))
             .or_else(|| built_in::json::FileSize::from_str(measure_str))
+            .or_else(|| built_in::dotnet::Allocated::from_str(measure_str))
+            .or_else(|| built_in::dotnet::Gen0Collects::from_str(measure_str))
+            .or_else(|| built_in::dotnet::Gen1Collects::from_str(measure_str))
+            .or_else(|| built_in::dotnet::Gen2Collects::from_str(measure_str))
+            .or_else(|| built_in::dotnet::TotalOperations::from_str(measure_str))
+            .or_else(|| built_in::dotnet::CompletedWorkItems::from_str(measure_str))
+            .or_else(|| built_in::dotnet::LockContentions::from_str(measure_str))
+            .or_else(|| built_in::dotnet::Exceptions::from_str(measure_str))
+            .or_else(|| built_in::google::CpuTime::from_str(measure_str))
             .or_else(|| built_in::iai::Instructions::from_str(measure_str))
             .or_else(|| built_in::iai::L1Accesses::from_str(measure_str))
             .or_else(|| built_in::iai::L2Accesses::from_str(measure_str))
             .or_else(|| built_in::iai::RamAccesses::from_str(measure_str))
             .or_else(|| built_in::iai::EstimatedCycles::from_str(measure_str))
+            // perf (instructions shares the iai measure)
+            .or_else(|| built_in::perf::Cycles::from_str(measure_str))
+            .or_else(|| built_in::perf::BranchMisses::from_str(measure_str))
+            .or_else(|| built_in::perf::CacheMisses::from_str(measure_str))
             // noise
             .or_else(|| built_in::noise::ComputeJitter::from_str(measure_str))
             .or_else(|| built_in::noise::CacheJitter::from_str(measure_str))
@@ -39,17 +39,21 @@ use crate::{
 use super::organization::OrganizationId;
 
 pub mod benchmark;
+pub mod benchmark_alias;
 pub mod branch;
 pub mod key;
 pub mod measure;
+pub mod member;
 pub mod metric;
 pub mod metric_boundary;
 pub mod plot;
 pub mod project_role;
 pub mod report;
+pub mod retention;
 pub mod series;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
 
 crate::macros::typed_id::typed_id!(ProjectId);
 
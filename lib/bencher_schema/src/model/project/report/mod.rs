@@ -2,14 +2,15 @@
 use bencher_json::runner::job::{JobUuid, JsonNewRunJob};
 use std::collections::HashSet;
 
+use bencher_adapter::AdapterResultsArray;
 use bencher_json::{
     DateTime, JsonNewReport, JsonReport, JsonReportAlertsCounts, JsonReportCounts,
     JsonReportIterationCounts, ReportUuid,
     project::{
         alert::AlertStatus,
         report::{
-            Adapter, Iteration, JsonReportAlerts, JsonReportMeasure, JsonReportResult,
-            JsonReportResults, JsonReportSettings, ReportIdempotencyKey,
+            Adapter, Iteration, JsonFold, JsonReportAlerts, JsonReportMeasure, JsonReportResult,
+            JsonReportResults, ReportIdempotencyKey,
         },
     },
 };
@@ -48,6 +49,7 @@ use crate::{
             measure::QueryMeasure,
             testbed::{QueryTestbed, ResolvedTestbed, TestbedId},
             threshold::{QueryThreshold, alert::QueryAlert, model::QueryModel},
+            webhook::WebhookDispatch,
         },
         user::{QueryUser, UserId, actor::ApiActor},
     },
@@ -116,6 +118,7 @@ pub struct QueryReport {
     pub start_time: DateTime,
     pub end_time: DateTime,
     pub created: DateTime,
+    pub noisy: bool,
 }
 
 impl QueryReport {
@@ -341,14 +344,21 @@ impl QueryReport {
 
         // Process and record the report results
         let results_array: Vec<&str> = json_report.results.iter().map(AsRef::as_ref).collect();
+        let mut results_array = ReportResults::convert(&results_array, adapter, &json_settings)?;
+        if let Some(noise) = &json_report.noise {
+            ReportResults::add_noise(
+                &mut results_array,
+                &noise.results,
+                json_settings.fold.is_some(),
+            )?;
+        }
         query_report
             .process_results(
                 log,
                 context,
                 branch_id,
-                &results_array,
-                adapter,
-                json_settings,
+                results_array,
+                json_settings.fold,
                 #[cfg(feature = "plus")]
                 plan_kind,
                 #[cfg(all(feature = "plus", feature = "otel"))]
@@ -359,7 +369,7 @@ impl QueryReport {
             .await?;
 
         // If the report was processed successfully, then return the report with the results
-        query_report
+        let json_report = query_report
             .finish_create(
                 log,
                 context,
@@ -367,7 +377,9 @@ impl QueryReport {
                 #[cfg(feature = "otel")]
                 create_start,
             )
-            .await
+            .await?;
+        WebhookDispatch::report_created(log, context, project_id, &json_report).await;
+        Ok(json_report)
     }
 
     /// If an idempotency key is provided, check for an existing report with the same key.
@@ -433,6 +445,7 @@ impl QueryReport {
             start_time,
             end_time,
             created,
+            noisy,
         } = self;
 
         let query_project = QueryProject::get(conn, project_id)?;
@@ -470,6 +483,7 @@ impl QueryReport {
             results,
             alerts,
             counts,
+            noisy,
             #[cfg(feature = "plus")]
             job,
             created,
@@ -486,9 +500,8 @@ impl QueryReport {
         log: &Logger,
         context: &ApiContext,
         branch_id: BranchId,
-        results: &[&str],
-        adapter: Adapter,
-        settings: JsonReportSettings,
+        results_array: AdapterResultsArray,
+        fold: Option<JsonFold>,
         #[cfg(feature = "plus")] plan_kind: PlanKind,
         #[cfg(all(feature = "plus", feature = "otel"))] priority: bencher_json::Priority,
         #[cfg(feature = "plus")] query_project: &QueryProject,
@@ -517,9 +530,8 @@ impl QueryReport {
             .process(
                 log,
                 context,
-                results,
-                adapter,
-                settings,
+                results_array,
+                fold,
                 #[cfg(feature = "plus")]
                 &mut usage,
             )
@@ -696,6 +708,10 @@ fn get_report_results(
                 schema::model::upper_boundary,
                 schema::model::created,
                 schema::model::replaced,
+                schema::model::confirm_outliers,
+                schema::model::confirm_reports,
+                schema::model::resolve_reports,
+                schema::model::version_window,
             )
         ).nullable(),
     ))
@@ -973,6 +989,7 @@ pub struct InsertReport {
     pub start_time: DateTime,
     pub end_time: DateTime,
     pub created: DateTime,
+    pub noisy: bool,
 }
 
 impl InsertReport {
@@ -1005,6 +1022,11 @@ impl InsertReport {
             start_time: report.start_time,
             end_time: report.end_time,
             created: now,
+            noisy: report
+                .noise
+                .as_ref()
+                .and_then(|noise| noise.noisy)
+                .unwrap_or_default(),
         }
     }
 }
@@ -0,0 +1,361 @@
+use bencher_json::project::boundary::BoundaryLimit;
+use dropshot::HttpError;
+
+use crate::{
+    context::DbConnection,
+    model::project::{benchmark::BenchmarkId, report::ReportId},
+};
+
+use super::previous::previous_reports;
+
+/// Check whether an outlier is confirmed by the threshold model.
+///
+/// The new Metric is an outlier, so it counts towards `confirm_outliers`.
+/// The remaining outliers must be found among the previous `confirm_reports - 1` Reports.
+/// A previous Report is an outlier if any of its Metrics was outside of the same side of its boundary limits.
+pub fn is_confirmed(
+    conn: &mut DbConnection,
+    detector: &super::Detector,
+    benchmark_id: BenchmarkId,
+    report_id: ReportId,
+    outlier: BoundaryLimit,
+) -> Result<bool, HttpError> {
+    let model = &detector.threshold.model;
+    let Some(confirm_outliers) = model.confirm_outliers else {
+        return Ok(true);
+    };
+    let confirm_outliers = u32::from(confirm_outliers);
+    if confirm_outliers <= 1 {
+        return Ok(true);
+    }
+    let confirm_reports = model
+        .confirm_reports
+        .map_or(confirm_outliers, u32::from)
+        .max(confirm_outliers);
+
+    let previous_outliers =
+        previous_reports(conn, detector, benchmark_id, report_id, confirm_reports - 1)?
+            .into_iter()
+            .filter(|previous| previous.is_outlier(outlier))
+            .count();
+
+    // Count the new Metric as the first outlier.
+    Ok(u32::try_from(previous_outliers)
+        .unwrap_or(u32::MAX)
+        .saturating_add(1)
+        >= confirm_outliers)
+}
+
+#[cfg(test)]
+mod tests {
+    use bencher_json::project::boundary::BoundaryLimit;
+    use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+
+    use crate::{
+        context::DbConnection,
+        model::project::{benchmark::BenchmarkId, report::ReportId, threshold::model::ModelId},
+        schema,
+        test_util::{
+            create_base_entities, create_benchmark, create_boundary, create_branch_with_head,
+            create_head_version, create_measure, create_metric, create_model, create_report,
+            create_report_benchmark, create_testbed, create_threshold, create_version,
+            setup_test_db,
+        },
+    };
+
+    use super::{super::Detector, is_confirmed};
+
+    const UPPER_LIMIT: f64 = 50.0;
+
+    fn set_confirm(
+        conn: &mut DbConnection,
+        model_id: ModelId,
+        confirm_outliers: Option<i64>,
+        confirm_reports: Option<i64>,
+    ) {
+        diesel::update(schema::model::table.filter(schema::model::id.eq(model_id)))
+            .set((
+                schema::model::confirm_outliers.eq(confirm_outliers),
+                schema::model::confirm_reports.eq(confirm_reports),
+            ))
+            .execute(conn)
+            .expect("Failed to update model");
+    }
+
+    /// Create a threshold with the given confirmation
+    /// and one previous report per slice of iteration values, oldest first,
+    /// followed by the current report.
+    #[expect(clippy::too_many_lines, reason = "test data setup")]
+    fn setup_confirm(
+        conn: &mut DbConnection,
+        confirm_outliers: Option<i64>,
+        confirm_reports: Option<i64>,
+        reports: &[&[f64]],
+    ) -> (Detector, BenchmarkId, ReportId) {
+        let base = create_base_entities(conn);
+        let branch = create_branch_with_head(
+            conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000010",
+            "main",
+            "main",
+            "00000000-0000-0000-0000-000000000011",
+        );
+        let testbed = create_testbed(
+            conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000020",
+            "localhost",
+            "localhost",
+        );
+        let measure = create_measure(
+            conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000030",
+            "latency",
+            "latency",
+        );
+        let threshold_id = create_threshold(
+            conn,
+            base.project_id,
+            branch.branch_id,
+            testbed,
+            measure,
+            "00000000-0000-0000-0000-000000000040",
+        );
+        let model_id = create_model(
+            conn,
+            threshold_id,
+            "00000000-0000-0000-0000-000000000050",
+            0,
+        );
+        set_confirm(conn, model_id, confirm_outliers, confirm_reports);
+        let benchmark_id = create_benchmark(
+            conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000060",
+            "bench1",
+            "bench1",
+        );
+
+        for (number, values) in (1..).zip(reports) {
+            let version_id = create_version(
+                conn,
+                base.project_id,
+                &format!("00000000-0000-0000-0001-{number:012}"),
+                number,
+                None,
+            );
+            create_head_version(conn, branch.head_id, version_id);
+            let report_id = create_report(
+                conn,
+                &format!("00000000-0000-0000-0002-{number:012}"),
+                base.project_id,
+                branch.head_id,
+                version_id,
+                testbed,
+            );
+            for (iteration, &value) in (0..).zip(*values) {
+                let report_benchmark_id = create_report_benchmark(
+                    conn,
+                    &format!("00000000-0000-0000-0003-{number:08}{iteration:04}"),
+                    report_id,
+                    iteration,
+                    benchmark_id,
+                );
+                let metric_id = create_metric(
+                    conn,
+                    &format!("00000000-0000-0000-0004-{number:08}{iteration:04}"),
+                    report_benchmark_id,
+                    measure,
+                    value,
+                );
+                let boundary_id = create_boundary(
+                    conn,
+                    &format!("00000000-0000-0000-0005-{number:08}{iteration:04}"),
+                    metric_id,
+                    threshold_id,
+                    model_id,
+                );
+                diesel::update(
+                    schema::boundary::table.filter(schema::boundary::id.eq(boundary_id)),
+                )
+                .set(schema::boundary::upper_limit.eq(UPPER_LIMIT))
+                .execute(conn)
+                .expect("Failed to update boundary");
+            }
+        }
+
+        // The current report, which is not yet complete
+        let number = i32::try_from(reports.len()).expect("Too many reports") + 1;
+        let version_id = create_version(
+            conn,
+            base.project_id,
+            &format!("00000000-0000-0000-0001-{number:012}"),
+            number,
+            None,
+        );
+        create_head_version(conn, branch.head_id, version_id);
+        let report_id = create_report(
+            conn,
+            &format!("00000000-0000-0000-0002-{number:012}"),
+            base.project_id,
+            branch.head_id,
+            version_id,
+            testbed,
+        );
+
+        let detector = Detector::new(
+            conn,
+            branch.branch_id,
+            branch.head_id,
+            testbed,
+            None,
+            measure,
+        )
+        .expect("Failed to create detector");
+        (detector, benchmark_id, report_id)
+    }
+
+    #[test]
+    fn confirm_without_confirmation() {
+        let mut conn = setup_test_db();
+        let (detector, benchmark_id, report_id) =
+            setup_confirm(&mut conn, None, None, &[&[10.0], &[10.0]]);
+        assert!(
+            is_confirmed(
+                &mut conn,
+                &detector,
+                benchmark_id,
+                report_id,
+                BoundaryLimit::Upper
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn confirm_consecutive_outliers() {
+        let mut conn = setup_test_db();
+        let (detector, benchmark_id, report_id) =
+            setup_confirm(&mut conn, Some(2), None, &[&[100.0], &[10.0]]);
+        assert!(
+            !is_confirmed(
+                &mut conn,
+                &detector,
+                benchmark_id,
+                report_id,
+                BoundaryLimit::Upper
+            )
+            .unwrap()
+        );
+
+        let mut conn = setup_test_db();
+        let (detector, benchmark_id, report_id) =
+            setup_confirm(&mut conn, Some(2), None, &[&[10.0], &[100.0]]);
+        assert!(
+            is_confirmed(
+                &mut conn,
+                &detector,
+                benchmark_id,
+                report_id,
+                BoundaryLimit::Upper
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn confirm_outliers_of_reports() {
+        let mut conn = setup_test_db();
+        let (detector, benchmark_id, report_id) = setup_confirm(
+            &mut conn,
+            Some(3),
+            Some(4),
+            &[&[100.0], &[100.0], &[10.0], &[100.0]],
+        );
+        // Only the three most recent previous reports are considered.
+        assert!(
+            is_confirmed(
+                &mut conn,
+                &detector,
+                benchmark_id,
+                report_id,
+                BoundaryLimit::Upper
+            )
+            .unwrap()
+        );
+
+        let mut conn = setup_test_db();
+        let (detector, benchmark_id, report_id) = setup_confirm(
+            &mut conn,
+            Some(3),
+            Some(4),
+            &[&[100.0], &[10.0], &[10.0], &[100.0]],
+        );
+        assert!(
+            !is_confirmed(
+                &mut conn,
+                &detector,
+                benchmark_id,
+                report_id,
+                BoundaryLimit::Upper
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn confirm_multiple_iterations() {
+        // An outlier in any iteration makes the previous report an outlier.
+        let mut conn = setup_test_db();
+        let (detector, benchmark_id, report_id) =
+            setup_confirm(&mut conn, Some(2), None, &[&[100.0, 10.0, 10.0]]);
+        assert!(
+            is_confirmed(
+                &mut conn,
+                &detector,
+                benchmark_id,
+                report_id,
+                BoundaryLimit::Upper
+            )
+            .unwrap()
+        );
+
+        // The iterations of the latest report do not count as separate reports.
+        let mut conn = setup_test_db();
+        let (detector, benchmark_id, report_id) = setup_confirm(
+            &mut conn,
+            Some(3),
+            Some(3),
+            &[&[10.0], &[100.0], &[100.0, 10.0, 10.0]],
+        );
+        assert!(
+            is_confirmed(
+                &mut conn,
+                &detector,
+                benchmark_id,
+                report_id,
+                BoundaryLimit::Upper
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn confirm_same_side_only() {
+        let mut conn = setup_test_db();
+        let (detector, benchmark_id, report_id) =
+            setup_confirm(&mut conn, Some(2), None, &[&[100.0]]);
+        assert!(
+            !is_confirmed(
+                &mut conn,
+                &detector,
+                benchmark_id,
+                report_id,
+                BoundaryLimit::Lower
+            )
+            .unwrap()
+        );
+    }
+}
@@ -5,7 +5,10 @@ use dropshot::HttpError;
 use slog::{Logger, warn};
 
 use crate::{
-    context::DbConnection, error::not_found_error, model::project::benchmark::BenchmarkId, schema,
+    context::DbConnection,
+    error::not_found_error,
+    model::project::{benchmark::BenchmarkId, branch::version::QueryVersion},
+    schema,
 };
 
 pub fn metrics_data(
@@ -35,6 +38,8 @@ pub fn metrics_data(
         .filter(schema::testbed::id.eq(detector.testbed_id))
         .filter(schema::benchmark::id.eq(benchmark_id))
         .filter(schema::metric::measure_id.eq(detector.measure_id))
+        // Reports from a noisy host are not trusted as a baseline
+        .filter(schema::report::noisy.eq(false))
         .into_boxed();
 
     if let Some(spec_id) = detector.spec_id {
@@ -54,6 +59,13 @@ pub fn metrics_data(
             );
         }
     }
+    if let Some(version_window) = model.version_window
+        && let Some(start_number) =
+            QueryVersion::window_start(conn, detector.head_id, version_window)
+                .map_err(not_found_error)?
+    {
+        query = query.filter(schema::version::number.ge(start_number));
+    }
 
     let mut query = query.order((
         schema::version::number.desc(),
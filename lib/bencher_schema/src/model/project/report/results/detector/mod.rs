@@ -11,17 +11,23 @@ use crate::{
         benchmark::BenchmarkId,
         branch::{BranchId, head::HeadId},
         measure::MeasureId,
+        report::ReportId,
         testbed::TestbedId,
     },
 };
 
+mod confirm;
 pub mod data;
 mod prepared;
+mod previous;
+mod resolve;
 pub mod threshold;
 
 pub use prepared::PreparedDetection;
 
+use confirm::is_confirmed;
 use data::metrics_data;
+use resolve::resolved_alerts;
 use threshold::Threshold;
 
 #[derive(Debug, Clone)]
@@ -60,6 +66,7 @@ impl Detector {
         log: &Logger,
         conn: &mut DbConnection,
         benchmark_id: BenchmarkId,
+        report_id: ReportId,
         metric_value: f64,
         ignore_benchmark: bool,
     ) -> Result<PreparedDetection, HttpError> {
@@ -78,6 +85,23 @@ impl Detector {
         )
         .map_err(bad_request_error)?;
 
+        // Only keep the outlier if it has been confirmed by enough recent outliers.
+        let outlier = match boundary.outlier {
+            Some(outlier) if is_confirmed(conn, self, benchmark_id, report_id, outlier)? => {
+                Some(outlier)
+            },
+            Some(_) | None => None,
+        };
+
+        // Resolve any active alerts once enough recent Metrics are back within the boundary.
+        let is_within = boundary.outlier.is_none()
+            && (boundary.limits.lower.is_some() || boundary.limits.upper.is_some());
+        let resolve_alerts = if ignore_benchmark {
+            Vec::new()
+        } else {
+            resolved_alerts(conn, self, benchmark_id, report_id, is_within)?
+        };
+
         Ok(PreparedDetection {
             threshold_id: self.threshold.id,
             model_id: self.threshold.model.id,
@@ -85,8 +109,10 @@ impl Detector {
             baseline: boundary.limits.baseline,
             lower_limit: boundary.limits.lower.map(Into::into),
             upper_limit: boundary.limits.upper.map(Into::into),
-            outlier: boundary.outlier,
+            p_value: boundary.p_value,
+            outlier,
             ignore_benchmark,
+            resolve_alerts,
         })
     }
 }
@@ -1,14 +1,15 @@
-use bencher_json::{BoundaryUuid, project::boundary::BoundaryLimit};
-use diesel::RunQueryDsl as _;
+use bencher_json::{BoundaryUuid, DateTime, project::boundary::BoundaryLimit};
+use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
 
 use crate::macros::sql::last_insert_rowid;
 use crate::{
     context::DbConnection,
     model::project::{
         metric::MetricId,
+        report::ReportId,
         threshold::{
             ThresholdId,
-            alert::InsertAlert,
+            alert::{AlertId, InsertAlert, UpdateAlert},
             boundary::{BoundaryId, InsertBoundary},
             model::ModelId,
         },
@@ -25,14 +26,22 @@ pub struct PreparedDetection {
     pub baseline: Option<f64>,
     pub lower_limit: Option<f64>,
     pub upper_limit: Option<f64>,
+    pub p_value: Option<f64>,
     pub outlier: Option<BoundaryLimit>,
     pub ignore_benchmark: bool,
+    pub resolve_alerts: Vec<AlertId>,
 }
 
 impl PreparedDetection {
     /// Write this prepared detection (boundary + optional alert) into the database
     /// using the provided connection (expected to be within a transaction).
-    pub fn write(self, conn: &mut DbConnection, metric_id: MetricId) -> diesel::QueryResult<()> {
+    /// Any alerts resolved by the detection are linked to the given report.
+    pub fn write(
+        self,
+        conn: &mut DbConnection,
+        report_id: ReportId,
+        metric_id: MetricId,
+    ) -> diesel::QueryResult<()> {
         let Self {
             threshold_id,
             model_id,
@@ -40,8 +49,10 @@ impl PreparedDetection {
             baseline,
             lower_limit,
             upper_limit,
+            p_value,
             outlier,
             ignore_benchmark,
+            resolve_alerts,
         } = self;
 
         let insert_boundary = InsertBoundary {
@@ -52,6 +63,7 @@ impl PreparedDetection {
             baseline,
             lower_limit,
             upper_limit,
+            p_value,
         };
 
         diesel::insert_into(schema::boundary::table)
@@ -64,36 +76,48 @@ impl PreparedDetection {
             InsertAlert::insert(conn, boundary_id, boundary_limit)?;
         }
 
+        if !resolve_alerts.is_empty() {
+            let resolved_alert = UpdateAlert::resolve(report_id, DateTime::now());
+            diesel::update(schema::alert::table.filter(schema::alert::id.eq_any(&resolve_alerts)))
+                .set(&resolved_alert)
+                .execute(conn)?;
+        }
+
         Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use bencher_json::{BoundaryUuid, project::boundary::BoundaryLimit};
+    use bencher_json::{
+        BoundaryUuid,
+        project::{alert::AlertStatus, boundary::BoundaryLimit},
+    };
     use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
 
     use crate::{
         context::DbConnection,
         model::project::{
             metric::MetricId,
+            report::ReportId,
             threshold::{ThresholdId, model::ModelId},
         },
         schema,
         test_util::{
-            create_base_entities, create_benchmark, create_branch_with_head, create_head_version,
-            create_measure, create_metric, create_model, create_report, create_report_benchmark,
-            create_testbed, create_threshold, create_version, setup_test_db,
+            create_alert, create_base_entities, create_benchmark, create_boundary,
+            create_branch_with_head, create_head_version, create_measure, create_metric,
+            create_model, create_report, create_report_benchmark, create_testbed, create_threshold,
+            create_version, get_alert_status, setup_test_db,
         },
     };
 
     use super::PreparedDetection;
 
     /// Set up the full entity chain needed for `PreparedDetection::write` tests.
-    /// Returns `(threshold_id, model_id, metric_id)`.
+    /// Returns `(threshold_id, model_id, report_id, metric_id)`.
     fn setup_prepared_detection_entities(
         conn: &mut DbConnection,
-    ) -> (ThresholdId, ModelId, MetricId) {
+    ) -> (ThresholdId, ModelId, ReportId, MetricId) {
         let base = create_base_entities(conn);
         let branch = create_branch_with_head(
             conn,
@@ -169,13 +193,14 @@ mod tests {
             100.0,
         );
 
-        (threshold_id, model_id, metric_id)
+        (threshold_id, model_id, report_id, metric_id)
     }
 
     #[test]
     fn prepared_detection_write_inserts_boundary() {
         let mut conn = setup_test_db();
-        let (threshold_id, model_id, metric_id) = setup_prepared_detection_entities(&mut conn);
+        let (threshold_id, model_id, report_id, metric_id) =
+            setup_prepared_detection_entities(&mut conn);
 
         let detection = PreparedDetection {
             threshold_id,
@@ -184,11 +209,13 @@ mod tests {
             baseline: Some(50.0),
             lower_limit: Some(10.0),
             upper_limit: Some(90.0),
+            p_value: Some(0.25),
             outlier: None,
             ignore_benchmark: false,
+            resolve_alerts: Vec::new(),
         };
 
-        conn.immediate_transaction(|conn| detection.write(conn, metric_id))
+        conn.immediate_transaction(|conn| detection.write(conn, report_id, metric_id))
             .expect("Failed to write detection");
 
         // Assert 1 boundary row exists with correct fields
@@ -201,6 +228,14 @@ mod tests {
             .expect("Failed to count boundaries");
         assert_eq!(boundary_count, 1);
 
+        // Assert the p-value of the model test is stored on the boundary
+        let p_value: Option<f64> = schema::boundary::table
+            .filter(schema::boundary::metric_id.eq(metric_id))
+            .select(schema::boundary::p_value)
+            .first(&mut conn)
+            .expect("Failed to get boundary p-value");
+        assert_eq!(p_value, Some(0.25));
+
         // Assert 0 alert rows exist
         let alert_count: i64 = schema::alert::table
             .count()
@@ -212,7 +247,8 @@ mod tests {
     #[test]
     fn prepared_detection_write_creates_alert_on_outlier() {
         let mut conn = setup_test_db();
-        let (threshold_id, model_id, metric_id) = setup_prepared_detection_entities(&mut conn);
+        let (threshold_id, model_id, report_id, metric_id) =
+            setup_prepared_detection_entities(&mut conn);
 
         let detection = PreparedDetection {
             threshold_id,
@@ -221,11 +257,13 @@ mod tests {
             baseline: Some(50.0),
             lower_limit: Some(10.0),
             upper_limit: Some(90.0),
+            p_value: None,
             outlier: Some(BoundaryLimit::Upper),
             ignore_benchmark: false,
+            resolve_alerts: Vec::new(),
         };
 
-        conn.immediate_transaction(|conn| detection.write(conn, metric_id))
+        conn.immediate_transaction(|conn| detection.write(conn, report_id, metric_id))
             .expect("Failed to write detection");
 
         // Assert 1 boundary exists
@@ -247,7 +285,8 @@ mod tests {
     #[test]
     fn prepared_detection_write_skips_alert_when_ignore_benchmark() {
         let mut conn = setup_test_db();
-        let (threshold_id, model_id, metric_id) = setup_prepared_detection_entities(&mut conn);
+        let (threshold_id, model_id, report_id, metric_id) =
+            setup_prepared_detection_entities(&mut conn);
 
         let detection = PreparedDetection {
             threshold_id,
@@ -256,11 +295,13 @@ mod tests {
             baseline: Some(50.0),
             lower_limit: Some(10.0),
             upper_limit: Some(90.0),
+            p_value: None,
             outlier: Some(BoundaryLimit::Upper),
             ignore_benchmark: true,
+            resolve_alerts: Vec::new(),
         };
 
-        conn.immediate_transaction(|conn| detection.write(conn, metric_id))
+        conn.immediate_transaction(|conn| detection.write(conn, report_id, metric_id))
             .expect("Failed to write detection");
 
         // Assert 1 boundary exists
@@ -278,4 +319,73 @@ mod tests {
             .expect("Failed to count alerts");
         assert_eq!(alert_count, 0);
     }
+
+    #[test]
+    fn prepared_detection_write_resolves_alerts() {
+        let mut conn = setup_test_db();
+        let (threshold_id, model_id, report_id, metric_id) =
+            setup_prepared_detection_entities(&mut conn);
+        let boundary_id = create_boundary(
+            &mut conn,
+            "00000000-0000-0000-0000-0000000000b0",
+            metric_id,
+            threshold_id,
+            model_id,
+        );
+        let alert_id = create_alert(
+            &mut conn,
+            "00000000-0000-0000-0000-0000000000c0",
+            boundary_id,
+            BoundaryLimit::Upper,
+            AlertStatus::Active,
+        );
+
+        let detection = PreparedDetection {
+            threshold_id,
+            model_id,
+            boundary_uuid: BoundaryUuid::new(),
+            baseline: Some(50.0),
+            lower_limit: Some(10.0),
+            upper_limit: Some(90.0),
+            p_value: None,
+            outlier: None,
+            ignore_benchmark: false,
+            resolve_alerts: vec![alert_id],
+        };
+
+        // The alert is resolved by a later Metric.
+        let (benchmark_id, measure_id) = schema::metric::table
+            .filter(schema::metric::id.eq(metric_id))
+            .inner_join(schema::report_benchmark::table)
+            .select((
+                schema::report_benchmark::benchmark_id,
+                schema::metric::measure_id,
+            ))
+            .first(&mut conn)
+            .expect("Failed to get metric");
+        let report_benchmark_id = create_report_benchmark(
+            &mut conn,
+            "00000000-0000-0000-0000-000000000091",
+            report_id,
+            1,
+            benchmark_id,
+        );
+        let resolve_metric_id = create_metric(
+            &mut conn,
+            "00000000-0000-0000-0000-0000000000a1",
+            report_benchmark_id,
+            measure_id,
+            50.0,
+        );
+        conn.immediate_transaction(|conn| detection.write(conn, report_id, resolve_metric_id))
+            .expect("Failed to write detection");
+
+        assert_eq!(get_alert_status(&mut conn, alert_id), AlertStatus::Resolved);
+        let resolved_report_id: Option<ReportId> = schema::alert::table
+            .filter(schema::alert::id.eq(alert_id))
+            .select(schema::alert::resolved_report_id)
+            .first(&mut conn)
+            .expect("Failed to get resolved report");
+        assert_eq!(resolved_report_id, Some(report_id));
+    }
 }
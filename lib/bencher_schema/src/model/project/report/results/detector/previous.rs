@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use bencher_json::project::boundary::BoundaryLimit;
+use diesel::{ExpressionMethods as _, JoinOnDsl as _, QueryDsl as _, RunQueryDsl as _};
+use dropshot::HttpError;
+
+use crate::{
+    context::DbConnection,
+    error::not_found_error,
+    model::project::{benchmark::BenchmarkId, report::ReportId},
+    schema,
+};
+
+/// A previous Metric value along with its boundary limits.
+pub struct PreviousMetric {
+    pub value: f64,
+    pub lower_limit: Option<f64>,
+    pub upper_limit: Option<f64>,
+}
+
+impl PreviousMetric {
+    /// Whether the Metric was within its boundary limits.
+    /// A Metric without any boundary limits was not checked, so it is not within them.
+    pub fn is_within(&self) -> bool {
+        if self.lower_limit.is_none() && self.upper_limit.is_none() {
+            return false;
+        }
+        self.lower_limit.is_none_or(|limit| self.value >= limit)
+            && self.upper_limit.is_none_or(|limit| self.value <= limit)
+    }
+
+    /// Whether the Metric was outside of the given side of its boundary limits.
+    pub fn is_outlier(&self, outlier: BoundaryLimit) -> bool {
+        match outlier {
+            BoundaryLimit::Lower => self.lower_limit.is_some_and(|limit| self.value < limit),
+            BoundaryLimit::Upper => self.upper_limit.is_some_and(|limit| self.value > limit),
+        }
+    }
+}
+
+/// The Metrics with a boundary from a previous Report, one per iteration.
+pub struct PreviousReport {
+    pub metrics: Vec<PreviousMetric>,
+}
+
+impl PreviousReport {
+    /// Whether every Metric in the Report was within its boundary limits.
+    pub fn is_within(&self) -> bool {
+        !self.metrics.is_empty() && self.metrics.iter().all(PreviousMetric::is_within)
+    }
+
+    /// Whether any Metric in the Report was outside of the given side of its boundary limits.
+    pub fn is_outlier(&self, outlier: BoundaryLimit) -> bool {
+        self.metrics.iter().any(|metric| metric.is_outlier(outlier))
+    }
+}
+
+/// Get up to `limit` of the most recent Reports with a boundary for the detector's threshold,
+/// newest first, not including the current Report or any Reports from a noisy host.
+///
+/// Reports are counted, not Metrics, so a Report with many iterations still counts once.
+pub fn previous_reports(
+    conn: &mut DbConnection,
+    detector: &super::Detector,
+    benchmark_id: BenchmarkId,
+    report_id: ReportId,
+    limit: u32,
+) -> Result<Vec<PreviousReport>, HttpError> {
+    if limit == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut query = schema::boundary::table
+        .inner_join(
+            schema::metric::table.inner_join(schema::report_benchmark::table.inner_join(
+                schema::report::table.inner_join(schema::version::table.inner_join(
+                    schema::head_version::table.inner_join(
+                        schema::head::table.on(schema::head_version::head_id.eq(schema::head::id)),
+                    ),
+                )),
+            )),
+        )
+        .filter(schema::boundary::threshold_id.eq(detector.threshold.id))
+        .filter(schema::head::id.eq(detector.head_id))
+        .filter(schema::report::id.ne(report_id))
+        // Reports from a noisy host are not trusted to confirm or resolve an alert
+        .filter(schema::report::noisy.eq(false))
+        .filter(schema::report::testbed_id.eq(detector.testbed_id))
+        .filter(schema::report_benchmark::benchmark_id.eq(benchmark_id))
+        .filter(schema::metric::measure_id.eq(detector.measure_id))
+        .into_boxed();
+
+    if let Some(spec_id) = detector.spec_id {
+        query = query.filter(schema::report::spec_id.eq(spec_id));
+    }
+
+    let report_ids = query
+        .select(schema::report::id)
+        .distinct()
+        .order((
+            schema::version::number.desc(),
+            schema::report::start_time.desc(),
+        ))
+        .limit(i64::from(limit))
+        .load::<ReportId>(conn)
+        .map_err(not_found_error)?;
+
+    let mut metrics = schema::boundary::table
+        .inner_join(schema::metric::table.inner_join(schema::report_benchmark::table))
+        .filter(schema::boundary::threshold_id.eq(detector.threshold.id))
+        .filter(schema::report_benchmark::report_id.eq_any(&report_ids))
+        .filter(schema::report_benchmark::benchmark_id.eq(benchmark_id))
+        .filter(schema::metric::measure_id.eq(detector.measure_id))
+        .select((
+            schema::report_benchmark::report_id,
+            schema::metric::value,
+            schema::boundary::lower_limit,
+            schema::boundary::upper_limit,
+        ))
+        .load::<(ReportId, f64, Option<f64>, Option<f64>)>(conn)
+        .map_err(not_found_error)?
+        .into_iter()
+        .fold(
+            HashMap::<ReportId, Vec<PreviousMetric>>::new(),
+            |mut metrics, (report_id, value, lower_limit, upper_limit)| {
+                metrics.entry(report_id).or_default().push(PreviousMetric {
+                    value,
+                    lower_limit,
+                    upper_limit,
+                });
+                metrics
+            },
+        );
+
+    Ok(report_ids
+        .into_iter()
+        .map(|report_id| PreviousReport {
+            metrics: metrics.remove(&report_id).unwrap_or_default(),
+        })
+        .collect())
+}
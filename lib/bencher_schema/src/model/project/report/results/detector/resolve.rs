@@ -0,0 +1,360 @@
+use bencher_json::project::alert::AlertStatus;
+use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+use dropshot::HttpError;
+
+use crate::{
+    context::DbConnection,
+    error::not_found_error,
+    model::project::{benchmark::BenchmarkId, report::ReportId, threshold::alert::AlertId},
+    schema,
+};
+
+use super::previous::previous_reports;
+
+/// Get the active Alerts that are resolved by the new Metric.
+///
+/// The new Metric must be within its boundary limits, and its Report counts towards `resolve_reports`.
+/// Every Metric in the previous `resolve_reports - 1` Reports must also have been within its boundary limits.
+/// Every active Alert for the same benchmark before them is then resolved.
+pub fn resolved_alerts(
+    conn: &mut DbConnection,
+    detector: &super::Detector,
+    benchmark_id: BenchmarkId,
+    report_id: ReportId,
+    is_within: bool,
+) -> Result<Vec<AlertId>, HttpError> {
+    let Some(resolve_reports) = detector.threshold.model.resolve_reports else {
+        return Ok(Vec::new());
+    };
+    if !is_within {
+        return Ok(Vec::new());
+    }
+
+    let previous_count = u32::from(resolve_reports) - 1;
+    let previous = previous_reports(conn, detector, benchmark_id, report_id, previous_count)?;
+    if u32::try_from(previous.len()).unwrap_or(u32::MAX) < previous_count
+        || !previous
+            .iter()
+            .all(super::previous::PreviousReport::is_within)
+    {
+        return Ok(Vec::new());
+    }
+
+    let mut query = schema::alert::table
+        .inner_join(
+            schema::boundary::table.inner_join(
+                schema::metric::table
+                    .inner_join(schema::report_benchmark::table.inner_join(schema::report::table)),
+            ),
+        )
+        .filter(schema::alert::status.eq(AlertStatus::Active))
+        .filter(schema::boundary::threshold_id.eq(detector.threshold.id))
+        .filter(schema::report::head_id.eq(detector.head_id))
+        .filter(schema::report::testbed_id.eq(detector.testbed_id))
+        .filter(schema::report_benchmark::benchmark_id.eq(benchmark_id))
+        .filter(schema::metric::measure_id.eq(detector.measure_id))
+        .into_boxed();
+
+    if let Some(spec_id) = detector.spec_id {
+        query = query.filter(schema::report::spec_id.eq(spec_id));
+    }
+
+    query
+        .select(schema::alert::id)
+        .load::<AlertId>(conn)
+        .map_err(not_found_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use bencher_json::project::{alert::AlertStatus, boundary::BoundaryLimit};
+    use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+
+    use crate::{
+        context::DbConnection,
+        model::project::{benchmark::BenchmarkId, report::ReportId, threshold::alert::AlertId},
+        schema,
+        test_util::{
+            create_alert, create_base_entities, create_benchmark, create_boundary,
+            create_branch_with_head, create_head_version, create_measure, create_metric,
+            create_model, create_report, create_report_benchmark, create_testbed, create_threshold,
+            create_version, setup_test_db,
+        },
+    };
+
+    use super::{super::Detector, resolved_alerts};
+
+    const UPPER_LIMIT: f64 = 50.0;
+
+    /// Create a threshold with the given resolution
+    /// and one previous report per slice of iteration values, oldest first,
+    /// followed by the current report.
+    /// An active Alert is created for the first value.
+    #[expect(clippy::too_many_lines, reason = "test data setup")]
+    fn setup_resolve(
+        conn: &mut DbConnection,
+        resolve_reports: Option<i64>,
+        reports: &[&[f64]],
+    ) -> (Detector, BenchmarkId, ReportId, AlertId) {
+        let base = create_base_entities(conn);
+        let branch = create_branch_with_head(
+            conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000010",
+            "main",
+            "main",
+            "00000000-0000-0000-0000-000000000011",
+        );
+        let testbed = create_testbed(
+            conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000020",
+            "localhost",
+            "localhost",
+        );
+        let measure = create_measure(
+            conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000030",
+            "latency",
+            "latency",
+        );
+        let threshold_id = create_threshold(
+            conn,
+            base.project_id,
+            branch.branch_id,
+            testbed,
+            measure,
+            "00000000-0000-0000-0000-000000000040",
+        );
+        let model_id = create_model(
+            conn,
+            threshold_id,
+            "00000000-0000-0000-0000-000000000050",
+            0,
+        );
+        diesel::update(schema::model::table.filter(schema::model::id.eq(model_id)))
+            .set(schema::model::resolve_reports.eq(resolve_reports))
+            .execute(conn)
+            .expect("Failed to update model");
+        let benchmark_id = create_benchmark(
+            conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000060",
+            "bench1",
+            "bench1",
+        );
+
+        let mut alert_id = None;
+        for (number, values) in (1..).zip(reports) {
+            let version_id = create_version(
+                conn,
+                base.project_id,
+                &format!("00000000-0000-0000-0001-{number:012}"),
+                number,
+                None,
+            );
+            create_head_version(conn, branch.head_id, version_id);
+            let report_id = create_report(
+                conn,
+                &format!("00000000-0000-0000-0002-{number:012}"),
+                base.project_id,
+                branch.head_id,
+                version_id,
+                testbed,
+            );
+            for (iteration, &value) in (0..).zip(*values) {
+                let report_benchmark_id = create_report_benchmark(
+                    conn,
+                    &format!("00000000-0000-0000-0003-{number:08}{iteration:04}"),
+                    report_id,
+                    iteration,
+                    benchmark_id,
+                );
+                let metric_id = create_metric(
+                    conn,
+                    &format!("00000000-0000-0000-0004-{number:08}{iteration:04}"),
+                    report_benchmark_id,
+                    measure,
+                    value,
+                );
+                let boundary_id = create_boundary(
+                    conn,
+                    &format!("00000000-0000-0000-0005-{number:08}{iteration:04}"),
+                    metric_id,
+                    threshold_id,
+                    model_id,
+                );
+                diesel::update(
+                    schema::boundary::table.filter(schema::boundary::id.eq(boundary_id)),
+                )
+                .set(schema::boundary::upper_limit.eq(UPPER_LIMIT))
+                .execute(conn)
+                .expect("Failed to update boundary");
+                if alert_id.is_none() {
+                    alert_id = Some(create_alert(
+                        conn,
+                        "00000000-0000-0000-0000-000000000070",
+                        boundary_id,
+                        BoundaryLimit::Upper,
+                        AlertStatus::Active,
+                    ));
+                }
+            }
+        }
+
+        // The current report, which is not yet complete
+        let number = i32::try_from(reports.len()).expect("Too many reports") + 1;
+        let version_id = create_version(
+            conn,
+            base.project_id,
+            &format!("00000000-0000-0000-0001-{number:012}"),
+            number,
+            None,
+        );
+        create_head_version(conn, branch.head_id, version_id);
+        let report_id = create_report(
+            conn,
+            &format!("00000000-0000-0000-0002-{number:012}"),
+            base.project_id,
+            branch.head_id,
+            version_id,
+            testbed,
+        );
+
+        let detector = Detector::new(
+            conn,
+            branch.branch_id,
+            branch.head_id,
+            testbed,
+            None,
+            measure,
+        )
+        .expect("Failed to create detector");
+        (
+            detector,
+            benchmark_id,
+            report_id,
+            alert_id.expect("No values"),
+        )
+    }
+
+    #[test]
+    fn resolve_without_resolution() {
+        let mut conn = setup_test_db();
+        let (detector, benchmark_id, report_id, _) =
+            setup_resolve(&mut conn, None, &[&[100.0], &[10.0], &[10.0]]);
+        assert!(
+            resolved_alerts(&mut conn, &detector, benchmark_id, report_id, true)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn resolve_consecutive_within() {
+        let mut conn = setup_test_db();
+        let (detector, benchmark_id, report_id, alert_id) =
+            setup_resolve(&mut conn, Some(3), &[&[100.0], &[10.0], &[10.0]]);
+        assert_eq!(
+            resolved_alerts(&mut conn, &detector, benchmark_id, report_id, true).unwrap(),
+            vec![alert_id]
+        );
+        // The new Metric must also be within its boundary limits.
+        assert!(
+            resolved_alerts(&mut conn, &detector, benchmark_id, report_id, false)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn resolve_not_enough_within() {
+        let mut conn = setup_test_db();
+        let (detector, benchmark_id, report_id, _) =
+            setup_resolve(&mut conn, Some(3), &[&[100.0], &[100.0], &[10.0]]);
+        assert!(
+            resolved_alerts(&mut conn, &detector, benchmark_id, report_id, true)
+                .unwrap()
+                .is_empty()
+        );
+
+        let mut conn = setup_test_db();
+        let (detector, benchmark_id, report_id, _) =
+            setup_resolve(&mut conn, Some(4), &[&[100.0], &[10.0], &[10.0]]);
+        assert!(
+            resolved_alerts(&mut conn, &detector, benchmark_id, report_id, true)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn resolve_multiple_iterations() {
+        // Every iteration of the previous reports must be within the boundary.
+        let mut conn = setup_test_db();
+        let (detector, benchmark_id, report_id, alert_id) = setup_resolve(
+            &mut conn,
+            Some(3),
+            &[&[100.0], &[10.0, 10.0, 10.0], &[10.0, 10.0, 10.0]],
+        );
+        assert_eq!(
+            resolved_alerts(&mut conn, &detector, benchmark_id, report_id, true).unwrap(),
+            vec![alert_id]
+        );
+
+        // The iterations of the latest report do not count as separate reports.
+        let mut conn = setup_test_db();
+        let (detector, benchmark_id, report_id, _) = setup_resolve(
+            &mut conn,
+            Some(3),
+            &[&[100.0], &[100.0, 10.0, 10.0], &[10.0, 10.0, 10.0]],
+        );
+        assert!(
+            resolved_alerts(&mut conn, &detector, benchmark_id, report_id, true)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn resolve_skips_noisy() {
+        let mut conn = setup_test_db();
+        let (detector, benchmark_id, report_id, alert_id) =
+            setup_resolve(&mut conn, Some(2), &[&[100.0], &[10.0], &[10.0], &[100.0]]);
+        assert!(
+            resolved_alerts(&mut conn, &detector, benchmark_id, report_id, true)
+                .unwrap()
+                .is_empty()
+        );
+
+        // A report from a noisy host does not count towards resolving an alert.
+        diesel::update(
+            schema::report::table
+                .filter(schema::report::uuid.eq("00000000-0000-0000-0002-000000000004")),
+        )
+        .set(schema::report::noisy.eq(true))
+        .execute(&mut conn)
+        .expect("Failed to update report");
+        assert_eq!(
+            resolved_alerts(&mut conn, &detector, benchmark_id, report_id, true).unwrap(),
+            vec![alert_id]
+        );
+    }
+
+    #[test]
+    fn resolve_only_active() {
+        let mut conn = setup_test_db();
+        let (detector, benchmark_id, report_id, alert_id) =
+            setup_resolve(&mut conn, Some(1), &[&[100.0]]);
+        diesel::update(schema::alert::table.filter(schema::alert::id.eq(alert_id)))
+            .set(schema::alert::status.eq(AlertStatus::Dismissed))
+            .execute(&mut conn)
+            .expect("Failed to update alert");
+        assert!(
+            resolved_alerts(&mut conn, &detector, benchmark_id, report_id, true)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
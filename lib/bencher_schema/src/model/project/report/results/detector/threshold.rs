@@ -33,6 +33,10 @@ pub struct ThresholdModel {
     pub window: Option<Window>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
+    pub confirm_outliers: Option<SampleSize>,
+    pub confirm_reports: Option<SampleSize>,
+    pub resolve_reports: Option<SampleSize>,
+    pub version_window: Option<SampleSize>,
 }
 
 impl Threshold {
@@ -61,6 +65,10 @@ impl Threshold {
                     window,
                     lower_boundary,
                     upper_boundary,
+                    confirm_outliers,
+                    confirm_reports,
+                    resolve_reports,
+                    version_window,
                     ..
                 } = query_model;
                 let model = ThresholdModel {
@@ -71,6 +79,10 @@ impl Threshold {
                     window,
                     lower_boundary,
                     upper_boundary,
+                    confirm_outliers,
+                    confirm_reports,
+                    resolve_reports,
+                    version_window,
                 };
                 Self {
                     id: threshold_id,
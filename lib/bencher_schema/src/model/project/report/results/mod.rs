@@ -1,12 +1,12 @@
 use std::collections::HashMap;
 
 use bencher_adapter::{
-    AdapterResults, AdapterResultsArray, Settings as AdapterSettings,
+    Adaptable as _, AdapterResults, AdapterResultsArray, Settings as AdapterSettings,
     results::adapter_metrics::AdapterMetrics,
 };
 use bencher_json::{
     BenchmarkName, BenchmarkNameId, JsonNewMetric, MeasureNameId, Slug,
-    project::report::{Adapter, Iteration, JsonReportSettings},
+    project::report::{Adapter, Iteration, JsonFold, JsonReportSettings},
 };
 use diesel::RunQueryDsl as _;
 use dropshot::HttpError;
@@ -95,6 +95,54 @@ impl ReportResults {
         }
     }
 
+    /// Convert raw benchmark results into per-iteration results with the adapter.
+    pub fn convert(
+        results_array: &[&str],
+        adapter: Adapter,
+        settings: &JsonReportSettings,
+    ) -> Result<AdapterResultsArray, HttpError> {
+        let adapter_settings = AdapterSettings::new(
+            settings.average,
+            settings.bootstrap.unwrap_or_default(),
+            settings.statistic,
+            settings.params.unwrap_or_default(),
+        );
+        AdapterResultsArray::new(results_array, adapter, adapter_settings).map_err(|e| {
+            bad_request_error(format!(
+                "Failed to convert results with adapter ({adapter} | {settings:?}): {e}\n\nAre you sure {adapter} is the right adapter?\nRead more about adapters here: https://bencher.dev/docs/explanation/adapters/"
+            ))
+        })
+    }
+
+    /// Add the host noise probe results, which are always in Bencher Metric Format (BMF).
+    ///
+    /// The probe only runs once, before any of the iterations.
+    /// When folding, the probe results are added to every iteration so that they fold to themselves.
+    /// Otherwise, they are only added to the first iteration.
+    pub fn add_noise(
+        results_array: &mut AdapterResultsArray,
+        noise: &str,
+        fold: bool,
+    ) -> Result<(), HttpError> {
+        let noise_results = Adapter::Json
+            .convert(noise, AdapterSettings::default())
+            .ok_or_else(|| {
+                bad_request_error(format!(
+                    "Failed to convert noise probe results as Bencher Metric Format (BMF): {noise}"
+                ))
+            })?;
+        if fold && !results_array.inner.is_empty() {
+            for results in &mut results_array.inner {
+                results.inner.extend(noise_results.inner.clone());
+            }
+        } else if let Some(results) = results_array.inner.first_mut() {
+            results.inner.extend(noise_results.inner);
+        } else {
+            results_array.inner.push(noise_results);
+        }
+        Ok(())
+    }
+
     /// Process report results by iterating over each result set sequentially.
     ///
     /// The sequential per-iteration processing is load-bearing:
@@ -106,23 +154,14 @@ impl ReportResults {
         &mut self,
         log: &Logger,
         context: &ApiContext,
-        results_array: &[&str],
-        adapter: Adapter,
-        settings: JsonReportSettings,
+        results_array: AdapterResultsArray,
+        fold: Option<JsonFold>,
         #[cfg(feature = "plus")] usage: &mut u32,
     ) -> Result<(), HttpError> {
         #[cfg(feature = "otel")]
         let process_start = context.clock.now();
 
-        let adapter_settings = AdapterSettings::new(settings.average);
-        let results_array = AdapterResultsArray::new(results_array, adapter, adapter_settings)
-            .map_err(|e| {
-                bad_request_error(format!(
-                    "Failed to convert results with adapter ({adapter} | {settings:?}): {e}\n\nAre you sure {adapter} is the right adapter?\nRead more about adapters here: https://bencher.dev/docs/explanation/adapters/"
-                ))
-            })?;
-
-        if let Some(fold) = settings.fold {
+        if let Some(fold) = fold {
             let results = results_array.fold(fold);
             self.results(
                 log,
@@ -220,7 +259,7 @@ impl ReportResults {
                     // If there's a prepared detection, write boundary + optional alert
                     if let Some(prepared_detection) = prepared_metric.detection {
                         let metric_id = diesel::select(last_insert_rowid()).get_result(conn)?;
-                        prepared_detection.write(conn, metric_id)?;
+                        prepared_detection.write(conn, self.report_id, metric_id)?;
                     }
                 }
             }
@@ -298,6 +337,7 @@ impl ReportResults {
                     log,
                     auth_conn!(context),
                     benchmark_id,
+                    self.report_id,
                     metric.value.into(),
                     ignore_benchmark,
                 )?)
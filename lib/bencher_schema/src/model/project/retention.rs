@@ -0,0 +1,915 @@
+//! Per-project retention policy.
+//!
+//! A retention policy deletes old reports (`report_days`), except for those on the project's
+//! default branch, and downsamples old metrics to a single daily median (`downsample_days`).
+//! Old reports are only deleted while there is a default branch to keep,
+//! and metrics with an alert are never downsampled.
+//! Policies are applied on a schedule by [`ProjectRetention::spawn`], and the same code path
+//! with `dry_run` set reports what would be deleted without deleting anything.
+//!
+//! Downsampling works through one day at a time and deletion happens in bounded chunks,
+//! releasing the writer connection in between so other writers can interleave,
+//! the same as deleting a single report.
+//! A failure part way through leaves some of the condemned rows in place until the next run,
+//! which is safe because every step is idempotent.
+//! Versions are kept even if all of their reports are deleted,
+//! so the version numbers for each branch never change.
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
+
+use bencher_json::{
+    DateTime, JsonRetention, JsonRetentionReport, ProjectUuid,
+    project::retention::JsonUpdateRetention,
+};
+use diesel::{ExpressionMethods as _, OptionalExtension as _, QueryDsl as _, RunQueryDsl as _};
+use dropshot::HttpError;
+use slog::Logger;
+use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
+
+use super::{
+    ProjectId, QueryProject,
+    branch::{BranchId, QueryBranch},
+    report::ReportId,
+};
+use crate::{
+    context::DbConnection,
+    error::{bad_request_error, resource_not_found_err},
+    model::{
+        project::{
+            benchmark::BenchmarkId, measure::MeasureId, metric::MetricId,
+            report::report_benchmark::ReportBenchmarkId, testbed::TestbedId,
+        },
+        spec::SpecId,
+    },
+    schema::{self, project_retention as project_retention_table},
+};
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+const DELETE_CHUNK_SIZE: usize = 1_000;
+
+#[derive(Debug, Clone, diesel::Queryable, diesel::Insertable, diesel::AsChangeset)]
+#[diesel(table_name = project_retention_table)]
+#[diesel(treat_none_as_null = true)]
+pub struct QueryRetention {
+    pub project_id: ProjectId,
+    pub report_days: Option<i64>,
+    pub default_branch_id: Option<BranchId>,
+    pub downsample_days: Option<i64>,
+    pub modified: DateTime,
+}
+
+impl QueryRetention {
+    pub fn get(conn: &mut DbConnection, project_id: ProjectId) -> Result<Option<Self>, HttpError> {
+        schema::project_retention::table
+            .filter(schema::project_retention::project_id.eq(project_id))
+            .first::<Self>(conn)
+            .optional()
+            .map_err(resource_not_found_err!(Project, project_id))
+    }
+
+    pub fn from_json(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+        json_retention: JsonUpdateRetention,
+        now: DateTime,
+    ) -> Result<Self, HttpError> {
+        let JsonUpdateRetention {
+            report_days,
+            default_branch,
+            downsample_days,
+        } = json_retention;
+        let default_branch_id = default_branch
+            .map(|branch| QueryBranch::from_resource_id(conn, project_id, &branch))
+            .transpose()?
+            .map(|branch| branch.id);
+        if report_days.is_some() && default_branch_id.is_none() {
+            return Err(bad_request_error(
+                "A default branch is required to delete old reports",
+            ));
+        }
+        Ok(Self {
+            project_id,
+            report_days: report_days.map(days).transpose()?,
+            default_branch_id,
+            downsample_days: downsample_days.map(days).transpose()?,
+            modified: now,
+        })
+    }
+
+    /// Convert an optional retention policy into JSON.
+    /// A project without a retention policy keeps everything.
+    pub fn into_json(
+        retention: Option<&Self>,
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+    ) -> Result<JsonRetention, HttpError> {
+        let Some(retention) = retention else {
+            return Ok(JsonRetention {
+                project: query_project.uuid,
+                report_days: None,
+                default_branch: None,
+                downsample_days: None,
+                modified: None,
+            });
+        };
+        let default_branch = retention
+            .default_branch_id
+            .map(|branch_id| QueryBranch::get_uuid(conn, branch_id))
+            .transpose()?;
+        Ok(JsonRetention {
+            project: query_project.uuid,
+            report_days: retention
+                .report_days
+                .and_then(|days| u32::try_from(days).ok()),
+            default_branch,
+            downsample_days: retention
+                .downsample_days
+                .and_then(|days| u32::try_from(days).ok()),
+            modified: Some(retention.modified),
+        })
+    }
+}
+
+fn days(days: u32) -> Result<i64, HttpError> {
+    if days == 0 {
+        Err(bad_request_error(
+            "Retention days must be greater than zero",
+        ))
+    } else {
+        Ok(i64::from(days))
+    }
+}
+
+pub struct ProjectRetention;
+
+impl ProjectRetention {
+    /// Apply a project's retention policy as of `now`.
+    ///
+    /// If `dry_run` is set, nothing is deleted and the report counts what would be deleted.
+    pub async fn run(
+        connection: &Mutex<DbConnection>,
+        project_uuid: ProjectUuid,
+        retention: &QueryRetention,
+        now: DateTime,
+        dry_run: bool,
+    ) -> diesel::QueryResult<JsonRetentionReport> {
+        // If the default branch has since been deleted,
+        // there is no way to tell which reports must be kept, so none are deleted.
+        let report_ids = if let (Some(report_days), Some(default_branch_id)) =
+            (retention.report_days, retention.default_branch_id)
+        {
+            let cutoff = now.timestamp() - report_days * SECONDS_PER_DAY;
+            expired_reports(
+                &mut *connection.lock().await,
+                retention.project_id,
+                default_branch_id,
+                cutoff,
+            )?
+        } else {
+            Vec::new()
+        };
+        let mut report_metrics = 0;
+        for chunk in report_ids.chunks(DELETE_CHUNK_SIZE) {
+            report_metrics += count_report_metrics(&mut *connection.lock().await, chunk)?;
+        }
+
+        let mut metric_ids = Vec::new();
+        if let Some(downsample_days) = retention.downsample_days {
+            let cutoff = now.timestamp() - downsample_days * SECONDS_PER_DAY;
+            let excluded_reports = report_ids.iter().copied().collect::<HashSet<_>>();
+            let mut start_time = i64::MIN;
+            loop {
+                let next_day = next_day(
+                    &mut *connection.lock().await,
+                    retention.project_id,
+                    start_time,
+                    cutoff,
+                )?;
+                let Some(day) = next_day else {
+                    break;
+                };
+                metric_ids.extend(downsampled_metrics(
+                    &mut *connection.lock().await,
+                    retention.project_id,
+                    day,
+                    cutoff,
+                    &excluded_reports,
+                )?);
+                start_time = (day + 1) * SECONDS_PER_DAY;
+            }
+        }
+
+        if !dry_run {
+            for chunk in report_ids.chunks(DELETE_CHUNK_SIZE) {
+                delete_reports(&mut *connection.lock().await, chunk)?;
+            }
+            for chunk in metric_ids.chunks(DELETE_CHUNK_SIZE) {
+                delete_metrics(&mut *connection.lock().await, chunk)?;
+            }
+        }
+
+        Ok(JsonRetentionReport {
+            project: project_uuid,
+            dry_run,
+            reports: u64::try_from(report_ids.len()).unwrap_or(u64::MAX),
+            report_metrics,
+            downsampled_metrics: u64::try_from(metric_ids.len()).unwrap_or(u64::MAX),
+        })
+    }
+
+    /// Apply the retention policy for every project that has one.
+    /// A project that fails is logged and skipped, so it never holds back the others.
+    pub async fn run_all(
+        log: &Logger,
+        connection: &Mutex<DbConnection>,
+        now: DateTime,
+    ) -> diesel::QueryResult<()> {
+        let retentions = schema::project_retention::table
+            .inner_join(schema::project::table)
+            .filter(schema::project::deleted.is_null())
+            .select((
+                schema::project::uuid,
+                (
+                    schema::project_retention::project_id,
+                    schema::project_retention::report_days,
+                    schema::project_retention::default_branch_id,
+                    schema::project_retention::downsample_days,
+                    schema::project_retention::modified,
+                ),
+            ))
+            .order(schema::project_retention::project_id)
+            .load::<(ProjectUuid, QueryRetention)>(&mut *connection.lock().await)?;
+        for (project_uuid, retention) in retentions {
+            let report = match Self::run(connection, project_uuid, &retention, now, false).await {
+                Ok(report) => report,
+                Err(e) => {
+                    slog::error!(
+                        log,
+                        "Failed to apply retention policy for project {project_uuid}: {e}"
+                    );
+                    continue;
+                },
+            };
+            if report.reports > 0 || report.downsampled_metrics > 0 {
+                slog::info!(
+                    log,
+                    "Applied retention policy for project {project_uuid}: deleted {} reports ({} metrics) and downsampled {} metrics",
+                    report.reports,
+                    report.report_metrics,
+                    report.downsampled_metrics
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply all retention policies every `interval` until shutdown.
+    pub fn spawn(
+        log: Logger,
+        connection: Arc<Mutex<DbConnection>>,
+        interval: Duration,
+        shutdown: CancellationToken,
+    ) {
+        slog::info!(
+            log,
+            "Scheduling project retention policies every {} seconds",
+            interval.as_secs()
+        );
+        tokio::spawn(async move {
+            while shutdown
+                .run_until_cancelled(tokio::time::sleep(interval))
+                .await
+                .is_some()
+            {
+                // Stopping part way through is safe, as every step is idempotent.
+                if let Some(Err(e)) = shutdown
+                    .run_until_cancelled(Self::run_all(&log, &connection, DateTime::now()))
+                    .await
+                {
+                    slog::error!(log, "Scheduled project retention failed: {e}");
+                }
+            }
+        });
+    }
+}
+
+/// Reports that ended before the cutoff, except for those on the default branch
+fn expired_reports(
+    conn: &mut DbConnection,
+    project_id: ProjectId,
+    default_branch_id: BranchId,
+    cutoff: i64,
+) -> diesel::QueryResult<Vec<ReportId>> {
+    schema::report::table
+        .inner_join(schema::head::table)
+        .filter(schema::report::project_id.eq(project_id))
+        .filter(schema::report::end_time.lt(cutoff))
+        .filter(schema::head::branch_id.ne(default_branch_id))
+        .select(schema::report::id)
+        .order(schema::report::id.asc())
+        .load(conn)
+}
+
+fn count_report_metrics(
+    conn: &mut DbConnection,
+    report_ids: &[ReportId],
+) -> diesel::QueryResult<u64> {
+    schema::metric::table
+        .inner_join(schema::report_benchmark::table)
+        .filter(schema::report_benchmark::report_id.eq_any(report_ids))
+        .count()
+        .get_result::<i64>(conn)
+        .map(|count| u64::try_from(count).unwrap_or_default())
+}
+
+type Series = (BranchId, TestbedId, Option<SpecId>, BenchmarkId, MeasureId);
+
+/// The next day, at or after the start time and before the cutoff, with any reports
+fn next_day(
+    conn: &mut DbConnection,
+    project_id: ProjectId,
+    start_time: i64,
+    cutoff: i64,
+) -> diesel::QueryResult<Option<i64>> {
+    schema::report::table
+        .filter(schema::report::project_id.eq(project_id))
+        .filter(schema::report::end_time.ge(start_time))
+        .filter(schema::report::end_time.lt(cutoff))
+        .select(diesel::dsl::min(schema::report::end_time))
+        .get_result::<Option<i64>>(conn)
+        .map(|end_time| end_time.map(|end_time| end_time.div_euclid(SECONDS_PER_DAY)))
+}
+
+/// Metrics on the given day that ended before the cutoff and are not the daily median for their series.
+/// Metrics in the excluded reports are ignored, as those reports are about to be deleted.
+/// Metrics with an alert are always kept, and they are not counted towards the median.
+fn downsampled_metrics(
+    conn: &mut DbConnection,
+    project_id: ProjectId,
+    day: i64,
+    cutoff: i64,
+    excluded_reports: &HashSet<ReportId>,
+) -> diesel::QueryResult<Vec<MetricId>> {
+    let start_time = day * SECONDS_PER_DAY;
+    let end_time = (start_time + SECONDS_PER_DAY).min(cutoff);
+    let metrics = schema::metric::table
+        .inner_join(
+            schema::report_benchmark::table
+                .inner_join(schema::report::table.inner_join(schema::head::table)),
+        )
+        .filter(schema::report::project_id.eq(project_id))
+        .filter(schema::report::end_time.ge(start_time))
+        .filter(schema::report::end_time.lt(end_time))
+        .filter(diesel::dsl::not(diesel::dsl::exists(
+            schema::alert::table
+                .inner_join(schema::boundary::table)
+                .filter(schema::boundary::metric_id.eq(schema::metric::id)),
+        )))
+        .select((
+            schema::metric::id,
+            schema::head::branch_id,
+            schema::report::testbed_id,
+            schema::report::spec_id,
+            schema::report_benchmark::benchmark_id,
+            schema::metric::measure_id,
+            schema::metric::value,
+            schema::report::id,
+        ))
+        .load::<(
+            MetricId,
+            BranchId,
+            TestbedId,
+            Option<SpecId>,
+            BenchmarkId,
+            MeasureId,
+            f64,
+            ReportId,
+        )>(conn)?;
+
+    let mut series: HashMap<Series, Vec<(f64, MetricId)>> = HashMap::new();
+    for (metric_id, branch_id, testbed_id, spec_id, benchmark_id, measure_id, value, report_id) in
+        metrics
+    {
+        if excluded_reports.contains(&report_id) {
+            continue;
+        }
+        series
+            .entry((branch_id, testbed_id, spec_id, benchmark_id, measure_id))
+            .or_default()
+            .push((value, metric_id));
+    }
+
+    let mut metric_ids = Vec::new();
+    for mut day in series.into_values() {
+        if day.len() < 2 {
+            continue;
+        }
+        day.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        // Keep the lower median, so the kept value is always a real metric
+        #[expect(clippy::integer_division, reason = "lower median")]
+        let median = (day.len() - 1) / 2;
+        metric_ids.extend(
+            day.into_iter()
+                .enumerate()
+                .filter_map(|(index, (_, metric_id))| (index != median).then_some(metric_id)),
+        );
+    }
+    Ok(metric_ids)
+}
+
+fn delete_reports(conn: &mut DbConnection, report_ids: &[ReportId]) -> diesel::QueryResult<()> {
+    diesel::delete(schema::report::table.filter(schema::report::id.eq_any(report_ids)))
+        .execute(conn)
+        .map(|_| ())
+}
+
+/// Delete the metrics, along with any report benchmarks and reports left without metrics.
+/// Any metrics that have gained an alert since they were selected are kept.
+fn delete_metrics(conn: &mut DbConnection, metric_ids: &[MetricId]) -> diesel::QueryResult<()> {
+    conn.immediate_transaction(|conn| {
+        let metrics = schema::metric::table
+            .filter(schema::metric::id.eq_any(metric_ids))
+            .filter(diesel::dsl::not(diesel::dsl::exists(
+                schema::alert::table
+                    .inner_join(schema::boundary::table)
+                    .filter(schema::boundary::metric_id.eq(schema::metric::id)),
+            )))
+            .select((schema::metric::id, schema::metric::report_benchmark_id))
+            .load::<(MetricId, ReportBenchmarkId)>(conn)?;
+        let (metric_ids, report_benchmark_ids): (Vec<_>, HashSet<_>) = metrics.into_iter().unzip();
+        let report_benchmark_ids = report_benchmark_ids.into_iter().collect::<Vec<_>>();
+        diesel::delete(schema::metric::table.filter(schema::metric::id.eq_any(&metric_ids)))
+            .execute(conn)?;
+
+        let empty_report_benchmarks = || {
+            schema::report_benchmark::table
+                .filter(schema::report_benchmark::id.eq_any(&report_benchmark_ids))
+                .filter(diesel::dsl::not(diesel::dsl::exists(
+                    schema::metric::table.filter(
+                        schema::metric::report_benchmark_id.eq(schema::report_benchmark::id),
+                    ),
+                )))
+        };
+        let report_ids = empty_report_benchmarks()
+            .select(schema::report_benchmark::report_id)
+            .distinct()
+            .load::<ReportId>(conn)?;
+        diesel::delete(empty_report_benchmarks()).execute(conn)?;
+
+        diesel::delete(
+            schema::report::table
+                .filter(schema::report::id.eq_any(&report_ids))
+                .filter(diesel::dsl::not(diesel::dsl::exists(
+                    schema::report_benchmark::table
+                        .filter(schema::report_benchmark::report_id.eq(schema::report::id)),
+                ))),
+        )
+        .execute(conn)
+        .map(|_| ())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bencher_json::{
+        DateTime, ProjectUuid,
+        project::{alert::AlertStatus, boundary::BoundaryLimit, retention::JsonUpdateRetention},
+    };
+    use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+    use tokio::sync::Mutex;
+
+    use crate::{
+        context::DbConnection,
+        model::project::{
+            ProjectId,
+            benchmark::BenchmarkId,
+            branch::{BranchId, head::HeadId},
+            measure::MeasureId,
+            metric::MetricId,
+            report::ReportId,
+            testbed::TestbedId,
+        },
+        schema,
+        test_util::{
+            create_alert, create_base_entities, create_benchmark, create_boundary,
+            create_branch_with_head, create_head_version, create_measure, create_metric,
+            create_model, create_report, create_report_benchmark, create_testbed, create_threshold,
+            create_version, setup_test_db,
+        },
+    };
+
+    use super::{ProjectRetention, QueryRetention, SECONDS_PER_DAY};
+
+    struct Setup {
+        project_id: ProjectId,
+        main: BranchId,
+        main_head: HeadId,
+        feature_head: HeadId,
+        testbed_id: TestbedId,
+        benchmark_id: BenchmarkId,
+        measure_id: MeasureId,
+    }
+
+    fn setup(conn: &mut DbConnection) -> Setup {
+        let base = create_base_entities(conn);
+        let main = create_branch_with_head(
+            conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000010",
+            "main",
+            "main",
+            "00000000-0000-0000-0000-000000000011",
+        );
+        let feature = create_branch_with_head(
+            conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000012",
+            "feature",
+            "feature",
+            "00000000-0000-0000-0000-000000000013",
+        );
+        let testbed_id = create_testbed(
+            conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000020",
+            "localhost",
+            "localhost",
+        );
+        let measure_id = create_measure(
+            conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000030",
+            "latency",
+            "latency",
+        );
+        let benchmark_id = create_benchmark(
+            conn,
+            base.project_id,
+            "00000000-0000-0000-0000-000000000040",
+            "bench1",
+            "bench1",
+        );
+        Setup {
+            project_id: base.project_id,
+            main: main.branch_id,
+            main_head: main.head_id,
+            feature_head: feature.head_id,
+            testbed_id,
+            benchmark_id,
+            measure_id,
+        }
+    }
+
+    /// Create a report with a single metric that ended `offset` seconds after the test time.
+    fn add_report(
+        conn: &mut DbConnection,
+        setup: &Setup,
+        head_id: HeadId,
+        number: i32,
+        offset: i64,
+        value: f64,
+    ) -> ReportId {
+        let version_id = create_version(
+            conn,
+            setup.project_id,
+            &format!("00000000-0000-0000-0001-{number:012}"),
+            number,
+            None,
+        );
+        create_head_version(conn, head_id, version_id);
+        let report_id = create_report(
+            conn,
+            &format!("00000000-0000-0000-0002-{number:012}"),
+            setup.project_id,
+            head_id,
+            version_id,
+            setup.testbed_id,
+        );
+        diesel::update(schema::report::table.filter(schema::report::id.eq(report_id)))
+            .set(schema::report::end_time.eq(DateTime::TEST.timestamp() + offset))
+            .execute(conn)
+            .expect("Failed to update report");
+        let report_benchmark_id = create_report_benchmark(
+            conn,
+            &format!("00000000-0000-0000-0003-{number:012}"),
+            report_id,
+            0,
+            setup.benchmark_id,
+        );
+        create_metric(
+            conn,
+            &format!("00000000-0000-0000-0004-{number:012}"),
+            report_benchmark_id,
+            setup.measure_id,
+            value,
+        );
+        report_id
+    }
+
+    fn now() -> DateTime {
+        DateTime::try_from(DateTime::TEST.timestamp() + 100 * SECONDS_PER_DAY)
+            .expect("Invalid date time")
+    }
+
+    fn report_ids(conn: &mut DbConnection) -> Vec<ReportId> {
+        schema::report::table
+            .order(schema::report::id)
+            .select(schema::report::id)
+            .load(conn)
+            .expect("Failed to load reports")
+    }
+
+    fn metric_values(conn: &mut DbConnection) -> Vec<f64> {
+        schema::metric::table
+            .order(schema::metric::id)
+            .select(schema::metric::value)
+            .load(conn)
+            .expect("Failed to load metrics")
+    }
+
+    #[tokio::test]
+    async fn retention_expires_reports_except_default_branch() {
+        let mut conn = setup_test_db();
+        let setup = setup(&mut conn);
+        let main_old = add_report(&mut conn, &setup, setup.main_head, 1, 0, 1.0);
+        add_report(&mut conn, &setup, setup.feature_head, 2, 0, 2.0);
+        let feature_new = add_report(
+            &mut conn,
+            &setup,
+            setup.feature_head,
+            3,
+            90 * SECONDS_PER_DAY,
+            3.0,
+        );
+        let retention = QueryRetention {
+            project_id: setup.project_id,
+            report_days: Some(30),
+            default_branch_id: Some(setup.main),
+            downsample_days: None,
+            modified: DateTime::TEST,
+        };
+
+        let connection = Mutex::new(conn);
+        let report =
+            ProjectRetention::run(&connection, ProjectUuid::new(), &retention, now(), false)
+                .await
+                .expect("Failed to apply retention");
+        assert_eq!(report.reports, 1);
+        assert_eq!(report.report_metrics, 1);
+        assert_eq!(report.downsampled_metrics, 0);
+
+        let mut conn = connection.into_inner();
+        assert_eq!(report_ids(&mut conn), vec![main_old, feature_new]);
+        assert_eq!(metric_values(&mut conn), vec![1.0, 3.0]);
+    }
+
+    #[tokio::test]
+    async fn retention_requires_default_branch() {
+        let mut conn = setup_test_db();
+        let setup = setup(&mut conn);
+        let main_old = add_report(&mut conn, &setup, setup.main_head, 1, 0, 1.0);
+        let feature_old = add_report(&mut conn, &setup, setup.feature_head, 2, 0, 2.0);
+
+        let json_retention = JsonUpdateRetention {
+            report_days: Some(30),
+            ..Default::default()
+        };
+        QueryRetention::from_json(&mut conn, setup.project_id, json_retention, DateTime::TEST)
+            .expect_err("A default branch should be required");
+
+        // A policy whose default branch has been deleted never deletes any reports.
+        let retention = QueryRetention {
+            project_id: setup.project_id,
+            report_days: Some(30),
+            default_branch_id: None,
+            downsample_days: None,
+            modified: DateTime::TEST,
+        };
+        let connection = Mutex::new(conn);
+        let report =
+            ProjectRetention::run(&connection, ProjectUuid::new(), &retention, now(), false)
+                .await
+                .expect("Failed to apply retention");
+        assert_eq!(report.reports, 0);
+        assert_eq!(report.report_metrics, 0);
+
+        let mut conn = connection.into_inner();
+        assert_eq!(report_ids(&mut conn), vec![main_old, feature_old]);
+    }
+
+    #[tokio::test]
+    async fn retention_downsamples_to_daily_median() {
+        let mut conn = setup_test_db();
+        let setup = setup(&mut conn);
+        add_report(&mut conn, &setup, setup.main_head, 1, 0, 1.0);
+        let median = add_report(&mut conn, &setup, setup.main_head, 2, 60, 3.0);
+        add_report(&mut conn, &setup, setup.main_head, 3, 120, 5.0);
+        let next_day = add_report(&mut conn, &setup, setup.main_head, 4, SECONDS_PER_DAY, 7.0);
+        let recent = add_report(
+            &mut conn,
+            &setup,
+            setup.main_head,
+            5,
+            90 * SECONDS_PER_DAY,
+            9.0,
+        );
+        let also_recent = add_report(
+            &mut conn,
+            &setup,
+            setup.main_head,
+            6,
+            90 * SECONDS_PER_DAY,
+            11.0,
+        );
+        let retention = QueryRetention {
+            project_id: setup.project_id,
+            report_days: None,
+            default_branch_id: None,
+            downsample_days: Some(30),
+            modified: DateTime::TEST,
+        };
+
+        let connection = Mutex::new(conn);
+        let report =
+            ProjectRetention::run(&connection, ProjectUuid::new(), &retention, now(), false)
+                .await
+                .expect("Failed to apply retention");
+        assert_eq!(report.reports, 0);
+        assert_eq!(report.downsampled_metrics, 2);
+
+        // Reports left without metrics are removed along with them.
+        let mut conn = connection.into_inner();
+        assert_eq!(
+            report_ids(&mut conn),
+            vec![median, next_day, recent, also_recent]
+        );
+        assert_eq!(metric_values(&mut conn), vec![3.0, 7.0, 9.0, 11.0]);
+    }
+
+    #[tokio::test]
+    async fn retention_downsample_keeps_alerts() {
+        let mut conn = setup_test_db();
+        let setup = setup(&mut conn);
+        let alerted = add_report(&mut conn, &setup, setup.main_head, 1, 0, 1.0);
+        let median = add_report(&mut conn, &setup, setup.main_head, 2, 60, 3.0);
+        add_report(&mut conn, &setup, setup.main_head, 3, 120, 5.0);
+
+        let threshold_id = create_threshold(
+            &mut conn,
+            setup.project_id,
+            setup.main,
+            setup.testbed_id,
+            setup.measure_id,
+            "00000000-0000-0000-0000-000000000050",
+        );
+        let model_id = create_model(
+            &mut conn,
+            threshold_id,
+            "00000000-0000-0000-0000-000000000051",
+            0,
+        );
+        let metric_id: MetricId = schema::metric::table
+            .filter(schema::metric::value.eq(1.0))
+            .select(schema::metric::id)
+            .first(&mut conn)
+            .expect("Failed to get metric");
+        let boundary_id = create_boundary(
+            &mut conn,
+            "00000000-0000-0000-0000-000000000052",
+            metric_id,
+            threshold_id,
+            model_id,
+        );
+        create_alert(
+            &mut conn,
+            "00000000-0000-0000-0000-000000000053",
+            boundary_id,
+            BoundaryLimit::Lower,
+            AlertStatus::Active,
+        );
+        let retention = QueryRetention {
+            project_id: setup.project_id,
+            report_days: None,
+            default_branch_id: None,
+            downsample_days: Some(30),
+            modified: DateTime::TEST,
+        };
+
+        let connection = Mutex::new(conn);
+        let report =
+            ProjectRetention::run(&connection, ProjectUuid::new(), &retention, now(), false)
+                .await
+                .expect("Failed to apply retention");
+        // The alerted metric is kept, and the median is taken from the others.
+        assert_eq!(report.downsampled_metrics, 1);
+
+        let mut conn = connection.into_inner();
+        assert_eq!(report_ids(&mut conn), vec![alerted, median]);
+        assert_eq!(metric_values(&mut conn), vec![1.0, 3.0]);
+        assert_eq!(
+            schema::alert::table
+                .count()
+                .get_result::<i64>(&mut conn)
+                .expect("Failed to count alerts"),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn retention_dry_run() {
+        let mut conn = setup_test_db();
+        let setup = setup(&mut conn);
+        add_report(&mut conn, &setup, setup.feature_head, 1, 0, 1.0);
+        add_report(&mut conn, &setup, setup.main_head, 2, 0, 2.0);
+        add_report(&mut conn, &setup, setup.main_head, 3, 60, 4.0);
+        let retention = QueryRetention {
+            project_id: setup.project_id,
+            report_days: Some(30),
+            default_branch_id: Some(setup.main),
+            downsample_days: Some(30),
+            modified: DateTime::TEST,
+        };
+
+        let connection = Mutex::new(conn);
+        let report =
+            ProjectRetention::run(&connection, ProjectUuid::new(), &retention, now(), true)
+                .await
+                .expect("Failed to apply retention");
+        assert!(report.dry_run);
+        assert_eq!(report.reports, 1);
+        assert_eq!(report.report_metrics, 1);
+        // The expired feature branch report is not also counted as downsampled.
+        assert_eq!(report.downsampled_metrics, 1);
+
+        let mut conn = connection.into_inner();
+        assert_eq!(report_ids(&mut conn).len(), 3);
+        assert_eq!(metric_values(&mut conn).len(), 3);
+    }
+
+    #[tokio::test]
+    async fn retention_run_all_skips_failed_project() {
+        let mut conn = setup_test_db();
+        let setup = setup(&mut conn);
+        let organization_id: i32 = schema::project::table
+            .filter(schema::project::id.eq(setup.project_id))
+            .select(schema::project::organization_id)
+            .first(&mut conn)
+            .expect("Failed to get organization id");
+        diesel::insert_into(schema::project::table)
+            .values((
+                schema::project::uuid.eq("00000000-0000-0000-0000-000000000003"),
+                schema::project::organization_id.eq(organization_id),
+                schema::project::name.eq("Other Project"),
+                schema::project::slug.eq("other-project"),
+                schema::project::visibility.eq(0),
+                schema::project::created.eq(DateTime::TEST),
+                schema::project::modified.eq(DateTime::TEST),
+            ))
+            .execute(&mut conn)
+            .expect("Failed to insert project");
+        let other = Setup {
+            project_id: schema::project::table
+                .filter(schema::project::slug.eq("other-project"))
+                .select(schema::project::id)
+                .first(&mut conn)
+                .expect("Failed to get project id"),
+            ..setup
+        };
+        let failed_old = add_report(&mut conn, &setup, setup.feature_head, 1, 0, 1.0);
+        add_report(&mut conn, &other, other.feature_head, 2, 0, 2.0);
+        for project_id in [setup.project_id, other.project_id] {
+            diesel::insert_into(schema::project_retention::table)
+                .values((
+                    schema::project_retention::project_id.eq(project_id),
+                    schema::project_retention::report_days.eq(Some(30)),
+                    schema::project_retention::default_branch_id.eq(Some(setup.main)),
+                    schema::project_retention::modified.eq(DateTime::TEST),
+                ))
+                .execute(&mut conn)
+                .expect("Failed to insert retention");
+        }
+        // Applying the policy fails for the first project
+        diesel::sql_query(format!(
+            "CREATE TRIGGER fail_retention BEFORE DELETE ON report WHEN OLD.project_id = {} \
+             BEGIN SELECT RAISE(ABORT, 'retention failed'); END",
+            setup.project_id
+        ))
+        .execute(&mut conn)
+        .expect("Failed to create trigger");
+
+        let log = slog::Logger::root(slog::Discard, slog::o!());
+        let connection = Mutex::new(conn);
+        ProjectRetention::run_all(&log, &connection, now())
+            .await
+            .expect("Failed to apply retention");
+
+        // The other project still has its policy applied
+        let mut conn = connection.into_inner();
+        assert_eq!(report_ids(&mut conn), vec![failed_old]);
+    }
+}
@@ -23,6 +23,7 @@ use crate::{
             benchmark::QueryBenchmark,
             branch::{head::HeadId, version::VersionId},
             metric::QueryMetric,
+            report::{QueryReport, ReportId},
         },
         spec::SpecId,
     },
@@ -40,6 +41,7 @@ pub struct QueryAlert {
     pub boundary_limit: BoundaryLimit,
     pub status: AlertStatus,
     pub modified: DateTime,
+    pub resolved_report_id: Option<ReportId>,
 }
 
 impl QueryAlert {
@@ -175,8 +177,12 @@ impl QueryAlert {
             boundary_limit,
             status,
             modified,
+            resolved_report_id,
             ..
         } = self;
+        let resolved_report = resolved_report_id
+            .map(|report_id| QueryReport::get_uuid(conn, report_id))
+            .transpose()?;
         let threshold = QueryThreshold::get_alert_json(
             conn,
             query_boundary.threshold_id,
@@ -195,6 +201,7 @@ impl QueryAlert {
             boundary: query_boundary.into_json(),
             limit: boundary_limit,
             status,
+            resolved_report,
             created,
             modified,
         })
@@ -225,6 +232,7 @@ pub struct InsertAlert {
     pub boundary_limit: BoundaryLimit,
     pub status: AlertStatus,
     pub modified: DateTime,
+    pub resolved_report_id: Option<ReportId>,
 }
 
 impl InsertAlert {
@@ -241,6 +249,7 @@ impl InsertAlert {
             boundary_limit,
             status: AlertStatus::default(),
             modified: DateTime::now(),
+            resolved_report_id: None,
         };
 
         diesel::insert_into(schema::alert::table)
@@ -255,13 +264,16 @@ impl InsertAlert {
 #[diesel(table_name = alert_table)]
 pub struct UpdateAlert {
     pub status: Option<AlertStatus>,
+    pub resolved_report_id: Option<Option<ReportId>>,
     pub modified: DateTime,
 }
 
 impl UpdateAlert {
+    /// A user status change also clears the report that resolved the alert, if any.
     pub fn status_change(status: Option<UpdateAlertStatus>, now: DateTime) -> Self {
         Self {
             status: status.map(Into::into),
+            resolved_report_id: status.map(|_| None),
             modified: now,
         }
     }
@@ -269,6 +281,15 @@ impl UpdateAlert {
     pub fn silence(now: DateTime) -> Self {
         Self {
             status: Some(AlertStatus::Silenced),
+            resolved_report_id: None,
+            modified: now,
+        }
+    }
+
+    pub fn resolve(report_id: ReportId, now: DateTime) -> Self {
+        Self {
+            status: Some(AlertStatus::Resolved),
+            resolved_report_id: Some(Some(report_id)),
             modified: now,
         }
     }
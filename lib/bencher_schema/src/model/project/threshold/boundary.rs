@@ -26,6 +26,7 @@ pub struct QueryBoundary {
     pub baseline: Option<f64>,
     pub lower_limit: Option<f64>,
     pub upper_limit: Option<f64>,
+    pub p_value: Option<f64>,
 }
 
 impl QueryBoundary {
@@ -45,6 +46,7 @@ impl QueryBoundary {
             baseline: self.baseline.map(Into::into),
             lower_limit: self.lower_limit.map(Into::into),
             upper_limit: self.upper_limit.map(Into::into),
+            p_value: self.p_value.map(Into::into),
         }
     }
 }
@@ -59,4 +61,5 @@ pub struct InsertBoundary {
     pub baseline: Option<f64>,
     pub lower_limit: Option<f64>,
     pub upper_limit: Option<f64>,
+    pub p_value: Option<f64>,
 }
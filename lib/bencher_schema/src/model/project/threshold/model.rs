@@ -32,6 +32,10 @@ pub struct QueryModel {
     pub upper_boundary: Option<Boundary>,
     pub created: DateTime,
     pub replaced: Option<DateTime>,
+    pub confirm_outliers: Option<SampleSize>,
+    pub confirm_reports: Option<SampleSize>,
+    pub resolve_reports: Option<SampleSize>,
+    pub version_window: Option<SampleSize>,
 }
 
 impl QueryModel {
@@ -63,6 +67,10 @@ impl QueryModel {
             window,
             lower_boundary,
             upper_boundary,
+            confirm_outliers,
+            confirm_reports,
+            resolve_reports,
+            version_window,
             ..
         } = self;
         Model {
@@ -72,6 +80,10 @@ impl QueryModel {
             window,
             lower_boundary,
             upper_boundary,
+            confirm_outliers,
+            confirm_reports,
+            resolve_reports,
+            version_window,
         }
     }
 
@@ -87,6 +99,10 @@ impl QueryModel {
             upper_boundary,
             created,
             replaced,
+            confirm_outliers,
+            confirm_reports,
+            resolve_reports,
+            version_window,
             ..
         } = self;
         assert_parentage(
@@ -103,6 +119,10 @@ impl QueryModel {
             window,
             lower_boundary,
             upper_boundary,
+            confirm_outliers,
+            confirm_reports,
+            resolve_reports,
+            version_window,
             created,
             replaced,
         }
@@ -122,6 +142,10 @@ pub struct InsertModel {
     pub upper_boundary: Option<Boundary>,
     pub created: DateTime,
     pub replaced: Option<DateTime>,
+    pub confirm_outliers: Option<SampleSize>,
+    pub confirm_reports: Option<SampleSize>,
+    pub resolve_reports: Option<SampleSize>,
+    pub version_window: Option<SampleSize>,
 }
 
 impl InsertModel {
@@ -171,6 +195,10 @@ impl InsertModel {
             window,
             lower_boundary,
             upper_boundary,
+            confirm_outliers,
+            confirm_reports,
+            resolve_reports,
+            version_window,
         } = model;
         Self {
             uuid: ModelUuid::new(),
@@ -183,6 +211,10 @@ impl InsertModel {
             upper_boundary,
             created: DateTime::now(),
             replaced: None,
+            confirm_outliers,
+            confirm_reports,
+            resolve_reports,
+            version_window,
         }
     }
 
@@ -196,6 +228,10 @@ impl InsertModel {
             upper_boundary,
             created,
             replaced,
+            confirm_outliers,
+            confirm_reports,
+            resolve_reports,
+            version_window,
             ..
         } = query_model;
         Self {
@@ -209,6 +245,10 @@ impl InsertModel {
             upper_boundary,
             created,
             replaced,
+            confirm_outliers,
+            confirm_reports,
+            resolve_reports,
+            version_window,
         }
     }
 }
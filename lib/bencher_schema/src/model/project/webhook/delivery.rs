@@ -0,0 +1,111 @@
+use bencher_json::{
+    DateTime, JsonWebhookDelivery, WebhookDeliveryUuid, WebhookEvent,
+    project::webhook::WebhookDeliveryStatus,
+};
+
+use crate::{
+    error::{BencherResource, assert_parentage},
+    schema::webhook_delivery as webhook_delivery_table,
+};
+
+use super::{QueryWebhook, WebhookId};
+
+crate::macros::typed_id::typed_id!(WebhookDeliveryId);
+
+#[derive(Debug, Clone, diesel::Queryable)]
+pub struct QueryWebhookDelivery {
+    pub id: WebhookDeliveryId,
+    pub uuid: WebhookDeliveryUuid,
+    pub webhook_id: WebhookId,
+    pub event: WebhookEvent,
+    pub payload: String,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    pub error: Option<String>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryWebhookDelivery {
+    pub fn into_json_for_webhook(self, query_webhook: &QueryWebhook) -> JsonWebhookDelivery {
+        let Self {
+            id: _,
+            uuid,
+            webhook_id,
+            event,
+            payload: _,
+            status,
+            attempts,
+            response_status,
+            error,
+            created,
+            modified,
+        } = self;
+        assert_parentage(
+            BencherResource::Webhook,
+            query_webhook.id,
+            BencherResource::WebhookDelivery,
+            webhook_id,
+        );
+        JsonWebhookDelivery {
+            uuid,
+            webhook: query_webhook.uuid,
+            event,
+            status,
+            attempts: u32::try_from(attempts).unwrap_or_default(),
+            response_status: response_status.and_then(|status| u16::try_from(status).ok()),
+            error,
+            created,
+            modified,
+        }
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = webhook_delivery_table)]
+pub struct InsertWebhookDelivery {
+    pub uuid: WebhookDeliveryUuid,
+    pub webhook_id: WebhookId,
+    pub event: WebhookEvent,
+    pub payload: String,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    pub error: Option<String>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl InsertWebhookDelivery {
+    pub fn new(
+        uuid: WebhookDeliveryUuid,
+        webhook_id: WebhookId,
+        event: WebhookEvent,
+        payload: String,
+        now: DateTime,
+    ) -> Self {
+        Self {
+            uuid,
+            webhook_id,
+            event,
+            payload,
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            response_status: None,
+            error: None,
+            created: now,
+            modified: now,
+        }
+    }
+}
+
+#[derive(Debug, Clone, diesel::AsChangeset)]
+#[diesel(table_name = webhook_delivery_table, treat_none_as_null = true)]
+pub struct UpdateWebhookDelivery {
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    pub error: Option<String>,
+    pub modified: DateTime,
+}
@@ -0,0 +1,413 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use bencher_json::{
+    Clock, JsonAlert, JsonReport, ProjectUuid, Url, WebhookDeliveryUuid, WebhookEvent,
+    project::webhook::{
+        JsonWebhookData, JsonWebhookPayload, WEBHOOK_DELIVERY_HEADER, WEBHOOK_EVENT_HEADER,
+        WEBHOOK_SIGNATURE_HEADER, WebhookDeliveryStatus,
+    },
+};
+use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+use hmac::{Hmac, KeyInit as _, Mac as _};
+use sha2::Sha256;
+use slog::Logger;
+use tokio::sync::Mutex;
+
+use crate::{
+    context::{ApiContext, DbConnection},
+    model::project::ProjectId,
+    schema,
+};
+
+use super::{
+    QueryWebhook, WebhookTarget,
+    delivery::{InsertWebhookDelivery, QueryWebhookDelivery, UpdateWebhookDelivery},
+};
+
+/// The maximum number of attempts made for a single delivery.
+pub const MAX_ATTEMPTS: u32 = 5;
+const INITIAL_BACKOFF: Duration = Duration::from_secs(2);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// Pending deliveries older than this are expired instead of resumed at startup.
+const RESUME_WINDOW: chrono::TimeDelta = chrono::TimeDelta::days(1);
+const SIGNATURE_PREFIX: &str = "sha256=";
+
+/// Sends project events to the webhooks that are subscribed to them.
+///
+/// Deliveries are recorded in the database and sent in the background,
+/// so a failing webhook never fails the request that triggered it.
+pub struct WebhookDispatch;
+
+impl WebhookDispatch {
+    /// Send a `report.created` event for the report
+    /// and an `alert.created` event for each of its alerts.
+    pub async fn report_created(
+        log: &Logger,
+        context: &ApiContext,
+        project_id: ProjectId,
+        json_report: &JsonReport,
+    ) {
+        let project_uuid = json_report.project.uuid;
+        let alerts = json_report.alerts.clone().unwrap_or_default();
+        Self::send(
+            log,
+            context,
+            project_id,
+            project_uuid,
+            WebhookEvent::ReportCreated,
+            JsonWebhookData::Report(Box::new(json_report.clone())),
+        )
+        .await;
+        for alert in alerts {
+            Self::send(
+                log,
+                context,
+                project_id,
+                project_uuid,
+                WebhookEvent::AlertCreated,
+                JsonWebhookData::Alert(Box::new(alert)),
+            )
+            .await;
+        }
+    }
+
+    /// Send an `alert.dismissed` event for the alert.
+    pub async fn alert_dismissed(
+        log: &Logger,
+        context: &ApiContext,
+        project_id: ProjectId,
+        project_uuid: ProjectUuid,
+        json_alert: JsonAlert,
+    ) {
+        Self::send(
+            log,
+            context,
+            project_id,
+            project_uuid,
+            WebhookEvent::AlertDismissed,
+            JsonWebhookData::Alert(Box::new(json_alert)),
+        )
+        .await;
+    }
+
+    async fn send(
+        log: &Logger,
+        context: &ApiContext,
+        project_id: ProjectId,
+        project_uuid: ProjectUuid,
+        event: WebhookEvent,
+        data: JsonWebhookData,
+    ) {
+        let webhooks = match subscribed_webhooks(
+            &mut *context.database.connection.lock().await,
+            project_id,
+            event,
+        ) {
+            Ok(webhooks) => webhooks,
+            Err(e) => {
+                slog::error!(
+                    log,
+                    "Failed to get webhooks for project ({project_id}): {e}"
+                );
+                return;
+            },
+        };
+
+        for query_webhook in webhooks {
+            let now = context.clock.now();
+            let payload = JsonWebhookPayload {
+                delivery: WebhookDeliveryUuid::new(),
+                webhook: query_webhook.uuid,
+                project: project_uuid,
+                event,
+                data: data.clone(),
+                created: now,
+            };
+            let body = match serde_json::to_string(&payload) {
+                Ok(body) => body,
+                Err(e) => {
+                    slog::error!(log, "Failed to serialize webhook payload: {e}");
+                    continue;
+                },
+            };
+            let insert_delivery = InsertWebhookDelivery::new(
+                payload.delivery,
+                query_webhook.id,
+                event,
+                body.clone(),
+                now,
+            );
+            if let Err(e) = diesel::insert_into(schema::webhook_delivery::table)
+                .values(&insert_delivery)
+                .execute(&mut *context.database.connection.lock().await)
+            {
+                slog::error!(
+                    log,
+                    "Failed to record delivery for webhook ({}): {e}",
+                    query_webhook.uuid
+                );
+                continue;
+            }
+
+            let delivery = Delivery {
+                log: log.clone(),
+                connection: context.database.connection.clone(),
+                clock: context.clock.clone(),
+                allow_insecure: context.allow_insecure_webhooks,
+                uuid: payload.delivery,
+                event,
+                url: query_webhook.url,
+                signature: sign(query_webhook.secret.as_ref(), body.as_bytes()),
+                body,
+                attempts: 0,
+            };
+            tokio::spawn(delivery.run());
+        }
+    }
+
+    /// Resume the deliveries that were still pending when the server last stopped.
+    ///
+    /// Recent deliveries to enabled webhooks are sent again with their remaining attempts,
+    /// and all others are marked as failed, so no delivery is left pending forever.
+    pub async fn resume(log: &Logger, context: &ApiContext) {
+        let pending = match pending_deliveries(&mut *context.database.connection.lock().await) {
+            Ok(pending) => pending,
+            Err(e) => {
+                slog::error!(log, "Failed to get pending webhook deliveries: {e}");
+                return;
+            },
+        };
+
+        let cutoff = (context.clock.now() - RESUME_WINDOW).timestamp();
+        for (query_delivery, query_webhook) in pending {
+            let attempts = u32::try_from(query_delivery.attempts).unwrap_or(MAX_ATTEMPTS);
+            let delivery = Delivery {
+                log: log.clone(),
+                connection: context.database.connection.clone(),
+                clock: context.clock.clone(),
+                allow_insecure: context.allow_insecure_webhooks,
+                uuid: query_delivery.uuid,
+                event: query_delivery.event,
+                url: query_webhook.url,
+                signature: sign(
+                    query_webhook.secret.as_ref(),
+                    query_delivery.payload.as_bytes(),
+                ),
+                body: query_delivery.payload,
+                attempts,
+            };
+            let is_expired = query_delivery.created.timestamp() < cutoff;
+            if !query_webhook.enabled || is_expired || attempts >= MAX_ATTEMPTS {
+                delivery
+                    .record(
+                        WebhookDeliveryStatus::Failed,
+                        attempts,
+                        query_delivery.response_status,
+                        Some("Delivery expired before it could be sent".to_owned()),
+                    )
+                    .await;
+            } else {
+                slog::info!(log, "Resuming webhook delivery ({})", delivery.uuid);
+                tokio::spawn(delivery.run());
+            }
+        }
+    }
+}
+
+fn pending_deliveries(
+    conn: &mut DbConnection,
+) -> diesel::QueryResult<Vec<(QueryWebhookDelivery, QueryWebhook)>> {
+    schema::webhook_delivery::table
+        .inner_join(schema::webhook::table)
+        .filter(schema::webhook_delivery::status.eq(WebhookDeliveryStatus::Pending))
+        .select((
+            schema::webhook_delivery::all_columns,
+            schema::webhook::all_columns,
+        ))
+        .load(conn)
+}
+
+fn subscribed_webhooks(
+    conn: &mut DbConnection,
+    project_id: ProjectId,
+    event: WebhookEvent,
+) -> diesel::QueryResult<Vec<QueryWebhook>> {
+    Ok(schema::webhook::table
+        .filter(schema::webhook::project_id.eq(project_id))
+        .filter(schema::webhook::enabled.eq(true))
+        .load::<QueryWebhook>(conn)?
+        .into_iter()
+        .filter(|webhook| webhook.events.contains(event))
+        .collect())
+}
+
+struct Delivery {
+    log: Logger,
+    connection: Arc<Mutex<DbConnection>>,
+    clock: Clock,
+    allow_insecure: bool,
+    uuid: WebhookDeliveryUuid,
+    event: WebhookEvent,
+    url: Url,
+    signature: String,
+    body: String,
+    /// The number of attempts already made
+    attempts: u32,
+}
+
+impl Delivery {
+    async fn run(self) {
+        // Check the URL again at send time, since the host may now resolve elsewhere
+        let target = match WebhookTarget::resolve(&self.url, self.allow_insecure).await {
+            Ok(target) => target,
+            Err(e) => {
+                self.record(
+                    WebhookDeliveryStatus::Failed,
+                    self.attempts,
+                    None,
+                    Some(e.to_string()),
+                )
+                .await;
+                return;
+            },
+        };
+        // Pin the request to the checked addresses
+        let client = match reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .redirect(reqwest::redirect::Policy::none())
+            .resolve_to_addrs(&target.host, &target.addrs)
+            .build()
+        {
+            Ok(client) => client,
+            Err(e) => {
+                self.record(
+                    WebhookDeliveryStatus::Failed,
+                    self.attempts,
+                    None,
+                    Some(e.to_string()),
+                )
+                .await;
+                return;
+            },
+        };
+
+        for attempt in (self.attempts + 1)..=MAX_ATTEMPTS {
+            let (response_status, error) = match client
+                .post(target.url.clone())
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .header(WEBHOOK_EVENT_HEADER, self.event.to_string())
+                .header(WEBHOOK_DELIVERY_HEADER, self.uuid.to_string())
+                .header(WEBHOOK_SIGNATURE_HEADER, &self.signature)
+                .body(self.body.clone())
+                .send()
+                .await
+            {
+                Ok(response) if response.status().is_success() => {
+                    let status = i32::from(response.status().as_u16());
+                    self.record(
+                        WebhookDeliveryStatus::Succeeded,
+                        attempt,
+                        Some(status),
+                        None,
+                    )
+                    .await;
+                    return;
+                },
+                Ok(response) => {
+                    let status = response.status();
+                    (
+                        Some(i32::from(status.as_u16())),
+                        format!("Webhook responded with {status}"),
+                    )
+                },
+                Err(e) => (None, e.to_string()),
+            };
+
+            let status = if attempt < MAX_ATTEMPTS {
+                WebhookDeliveryStatus::Pending
+            } else {
+                WebhookDeliveryStatus::Failed
+            };
+            slog::warn!(
+                self.log,
+                "Webhook delivery ({}) attempt {attempt} failed: {error}",
+                self.uuid
+            );
+            self.record(status, attempt, response_status, Some(error))
+                .await;
+            if attempt < MAX_ATTEMPTS {
+                tokio::time::sleep(backoff(attempt)).await;
+            }
+        }
+    }
+
+    async fn record(
+        &self,
+        status: WebhookDeliveryStatus,
+        attempts: u32,
+        response_status: Option<i32>,
+        error: Option<String>,
+    ) {
+        let update_delivery = UpdateWebhookDelivery {
+            status,
+            attempts: i32::try_from(attempts).unwrap_or(i32::MAX),
+            response_status,
+            error,
+            modified: self.clock.now(),
+        };
+        if let Err(e) = diesel::update(
+            schema::webhook_delivery::table.filter(schema::webhook_delivery::uuid.eq(self.uuid)),
+        )
+        .set(&update_delivery)
+        .execute(&mut *self.connection.lock().await)
+        {
+            slog::error!(
+                self.log,
+                "Failed to update webhook delivery ({}): {e}",
+                self.uuid
+            );
+        }
+    }
+}
+
+/// The delay before the next attempt, doubling after each failed attempt.
+fn backoff(attempt: u32) -> Duration {
+    INITIAL_BACKOFF.saturating_mul(1 << attempt.saturating_sub(1).min(16))
+}
+
+/// The value of the signature header: the hex encoded HMAC-SHA256 of the body.
+fn sign(secret: &str, body: &[u8]) -> String {
+    #[expect(clippy::expect_used, reason = "HMAC accepts keys of any length")]
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    format!(
+        "{SIGNATURE_PREFIX}{}",
+        hex::encode(mac.finalize().into_bytes())
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{backoff, sign};
+
+    #[test]
+    fn sign_known_value() {
+        // RFC 4231 test case 2
+        assert_eq!(
+            sign("Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn backoff_doubles() {
+        assert_eq!(backoff(1), Duration::from_secs(2));
+        assert_eq!(backoff(2), Duration::from_secs(4));
+        assert_eq!(backoff(3), Duration::from_secs(8));
+        assert_eq!(backoff(4), Duration::from_secs(16));
+    }
+}
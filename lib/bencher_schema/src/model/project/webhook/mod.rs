@@ -0,0 +1,204 @@
+use bencher_json::{
+    DateTime, JsonNewWebhook, JsonUpdateWebhook, JsonWebhook, JsonWebhookCreated, ProjectUuid, Url,
+    WebhookSecret, WebhookUuid, project::webhook::WebhookEvents,
+};
+use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+use dropshot::HttpError;
+
+use crate::{
+    context::DbConnection,
+    error::{BencherResource, assert_parentage, bad_request_error, resource_not_found_err},
+    macros::fn_get::{fn_get, fn_get_id, fn_get_uuid},
+    schema::{self, webhook as webhook_table},
+};
+
+use super::{ProjectId, QueryProject};
+
+pub mod delivery;
+mod dispatch;
+mod target;
+
+pub use dispatch::{MAX_ATTEMPTS, WebhookDispatch};
+pub use target::{WebhookTarget, WebhookTargetError};
+
+crate::macros::typed_id::typed_id!(WebhookId);
+
+#[derive(Debug, Clone, diesel::Queryable)]
+pub struct QueryWebhook {
+    pub id: WebhookId,
+    pub uuid: WebhookUuid,
+    pub project_id: ProjectId,
+    pub url: Url,
+    pub secret: WebhookSecret,
+    pub events: WebhookEvents,
+    pub enabled: bool,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryWebhook {
+    fn_get!(webhook, WebhookId);
+    fn_get_id!(webhook, WebhookId, WebhookUuid);
+    fn_get_uuid!(webhook, WebhookId, WebhookUuid);
+
+    pub fn get_project_webhook(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+        uuid: WebhookUuid,
+    ) -> Result<Self, HttpError> {
+        schema::webhook::table
+            .filter(schema::webhook::project_id.eq(project_id))
+            .filter(schema::webhook::uuid.eq(uuid))
+            .first::<QueryWebhook>(conn)
+            .map_err(resource_not_found_err!(Webhook, (project_id, &uuid)))
+    }
+
+    pub fn into_json(self, conn: &mut DbConnection) -> Result<JsonWebhook, HttpError> {
+        let query_project = QueryProject::get(conn, self.project_id)?;
+        Ok(self.into_json_inner(query_project.uuid))
+    }
+
+    pub fn into_json_for_project(self, query_project: &QueryProject) -> JsonWebhook {
+        assert_parentage(
+            BencherResource::Project,
+            query_project.id,
+            BencherResource::Webhook,
+            self.project_id,
+        );
+        self.into_json_inner(query_project.uuid)
+    }
+
+    fn into_json_inner(self, project_uuid: ProjectUuid) -> JsonWebhook {
+        let Self {
+            id: _,
+            uuid,
+            project_id: _,
+            url,
+            secret: _,
+            events,
+            enabled,
+            created,
+            modified,
+        } = self;
+        JsonWebhook {
+            uuid,
+            project: project_uuid,
+            url,
+            events: events.into(),
+            enabled,
+            created,
+            modified,
+        }
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = webhook_table)]
+pub struct InsertWebhook {
+    pub uuid: WebhookUuid,
+    pub project_id: ProjectId,
+    pub url: Url,
+    pub secret: WebhookSecret,
+    pub events: WebhookEvents,
+    pub enabled: bool,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl InsertWebhook {
+    pub async fn from_json(
+        project_id: ProjectId,
+        json_webhook: JsonNewWebhook,
+        now: DateTime,
+        allow_insecure: bool,
+    ) -> Result<Self, HttpError> {
+        let JsonNewWebhook {
+            url,
+            events,
+            enabled,
+        } = json_webhook;
+        validate_url(&url, allow_insecure).await?;
+        let events = events
+            .as_deref()
+            .map(validate_events)
+            .transpose()?
+            .unwrap_or_else(WebhookEvents::all);
+        Ok(Self {
+            uuid: WebhookUuid::new(),
+            project_id,
+            url,
+            secret: WebhookSecret::generate(),
+            events,
+            enabled: enabled.unwrap_or(true),
+            created: now,
+            modified: now,
+        })
+    }
+
+    pub fn into_json(self, project_uuid: ProjectUuid) -> JsonWebhookCreated {
+        let Self {
+            uuid,
+            project_id: _,
+            url,
+            secret,
+            events,
+            enabled,
+            created,
+            modified,
+        } = self;
+        JsonWebhookCreated {
+            uuid,
+            project: project_uuid,
+            url,
+            events: events.into(),
+            enabled,
+            secret,
+            created,
+            modified,
+        }
+    }
+}
+
+#[derive(Debug, Clone, diesel::AsChangeset)]
+#[diesel(table_name = webhook_table)]
+pub struct UpdateWebhook {
+    pub url: Option<Url>,
+    pub events: Option<WebhookEvents>,
+    pub enabled: Option<bool>,
+    pub modified: DateTime,
+}
+
+impl UpdateWebhook {
+    pub async fn from_json(
+        json_webhook: JsonUpdateWebhook,
+        now: DateTime,
+        allow_insecure: bool,
+    ) -> Result<Self, HttpError> {
+        let JsonUpdateWebhook {
+            url,
+            events,
+            enabled,
+        } = json_webhook;
+        if let Some(url) = &url {
+            validate_url(url, allow_insecure).await?;
+        }
+        Ok(Self {
+            url,
+            events: events.as_deref().map(validate_events).transpose()?,
+            enabled,
+            modified: now,
+        })
+    }
+}
+
+async fn validate_url(url: &Url, allow_insecure: bool) -> Result<(), HttpError> {
+    WebhookTarget::resolve(url, allow_insecure)
+        .await
+        .map(|_| ())
+        .map_err(bad_request_error)
+}
+
+fn validate_events(events: &[bencher_json::WebhookEvent]) -> Result<WebhookEvents, HttpError> {
+    WebhookEvents::new(events)
+        .ok_or_else(|| bad_request_error("A webhook must have at least one event"))
+}
@@ -0,0 +1,204 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use bencher_json::Url;
+
+const HTTPS_SCHEME: &str = "https";
+const HTTP_SCHEME: &str = "http";
+
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookTargetError {
+    #[error("Invalid webhook URL ({url}): {error}")]
+    Parse { url: String, error: url::ParseError },
+    #[error("Webhook URL must use https, not {0}")]
+    Scheme(String),
+    #[error("Webhook URL ({0}) must have a host")]
+    Host(String),
+    #[error("Failed to resolve webhook host ({host}): {error}")]
+    Resolve { host: String, error: std::io::Error },
+    #[error("Webhook host ({0}) did not resolve to any addresses")]
+    NoAddresses(String),
+    #[error("Webhook host ({host}) resolves to a non-public address ({ip})")]
+    NonPublic { host: String, ip: IpAddr },
+}
+
+/// A webhook URL that has been checked to be safe to send requests to.
+///
+/// The URL must use `https`, and every address its host resolves to must be public,
+/// so a webhook can never be used to reach the API server's own network.
+/// Requests should be pinned to the resolved `addrs`,
+/// so the host cannot be re-resolved to a different address in between.
+#[derive(Debug)]
+pub struct WebhookTarget {
+    pub url: url::Url,
+    pub host: String,
+    pub addrs: Vec<SocketAddr>,
+}
+
+impl WebhookTarget {
+    /// Resolve and check a webhook URL.
+    /// If `allow_insecure` is set, `http` and non-public addresses are allowed (for testing only).
+    pub async fn resolve(url: &Url, allow_insecure: bool) -> Result<Self, WebhookTargetError> {
+        let url = url::Url::parse(url.as_ref()).map_err(|error| WebhookTargetError::Parse {
+            url: url.to_string(),
+            error,
+        })?;
+        let scheme = url.scheme();
+        let is_allowed_scheme = scheme == HTTPS_SCHEME || (allow_insecure && scheme == HTTP_SCHEME);
+        if !is_allowed_scheme {
+            return Err(WebhookTargetError::Scheme(scheme.to_owned()));
+        }
+
+        let host = url
+            .host_str()
+            .ok_or_else(|| WebhookTargetError::Host(url.to_string()))?
+            .to_owned();
+        let port = url
+            .port_or_known_default()
+            .ok_or_else(|| WebhookTargetError::Host(url.to_string()))?;
+        let addrs = match url.host() {
+            Some(url::Host::Ipv4(ip)) => vec![SocketAddr::new(ip.into(), port)],
+            Some(url::Host::Ipv6(ip)) => vec![SocketAddr::new(ip.into(), port)],
+            Some(url::Host::Domain(domain)) => tokio::net::lookup_host((domain, port))
+                .await
+                .map_err(|error| WebhookTargetError::Resolve {
+                    host: host.clone(),
+                    error,
+                })?
+                .collect(),
+            None => return Err(WebhookTargetError::Host(url.to_string())),
+        };
+        if addrs.is_empty() {
+            return Err(WebhookTargetError::NoAddresses(host));
+        }
+        if !allow_insecure && let Some(addr) = addrs.iter().find(|addr| !is_public(addr.ip())) {
+            return Err(WebhookTargetError::NonPublic {
+                host,
+                ip: addr.ip(),
+            });
+        }
+
+        Ok(Self { url, host, addrs })
+    }
+}
+
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => is_public_v6(ip),
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation()
+        // "This network" 0.0.0.0/8
+        || a == 0
+        // Shared address space (carrier-grade NAT) 100.64.0.0/10
+        || (a == 100 && (b & 0b1100_0000) == 64)
+        // IETF protocol assignments 192.0.0.0/24
+        || (a == 192 && b == 0 && c == 0)
+        // Benchmarking 198.18.0.0/15
+        || (a == 198 && (b & 0b1111_1110) == 18)
+        // Reserved 240.0.0.0/4
+        || a >= 240)
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    if let Some(ipv4) = ip.to_ipv4_mapped() {
+        return is_public_v4(ipv4);
+    }
+    let [s0, s1, s2, s3, s4, s5, _, _] = ip.segments();
+    let [.., a, b, c, d] = ip.octets();
+    // NAT64 64:ff9b::/96 embeds an IPv4 address
+    if [s0, s1, s2, s3, s4, s5] == [0x64, 0xff9b, 0, 0, 0, 0] {
+        return is_public_v4(Ipv4Addr::new(a, b, c, d));
+    }
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        || ip.is_unique_local()
+        || ip.is_unicast_link_local()
+        // IPv4-compatible (deprecated) ::/96
+        || [s0, s1, s2, s3, s4, s5] == [0; 6]
+        // Documentation 2001:db8::/32
+        || (s0 == 0x2001 && s1 == 0x0db8))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use bencher_json::Url;
+
+    use super::{WebhookTarget, WebhookTargetError, is_public};
+
+    #[test]
+    fn public_addresses() {
+        for ip in ["93.184.216.34", "1.1.1.1", "2606:4700:4700::1111"] {
+            let ip: IpAddr = ip.parse().unwrap();
+            assert!(is_public(ip), "{ip}");
+        }
+    }
+
+    #[test]
+    fn non_public_addresses() {
+        for ip in [
+            "0.0.0.0",
+            "127.0.0.1",
+            "10.0.0.1",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "198.18.0.1",
+            "255.255.255.255",
+            "::",
+            "::1",
+            "fc00::1",
+            "fd12:3456::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+            "::ffff:169.254.169.254",
+            "64:ff9b::a00:1",
+        ] {
+            let ip: IpAddr = ip.parse().unwrap();
+            assert!(!is_public(ip), "{ip}");
+        }
+    }
+
+    #[tokio::test]
+    async fn resolve_rejects_insecure() {
+        let url: Url = "http://93.184.216.34/hook".parse().unwrap();
+        assert!(matches!(
+            WebhookTarget::resolve(&url, false).await,
+            Err(WebhookTargetError::Scheme(_))
+        ));
+
+        for url in [
+            "https://127.0.0.1/hook",
+            "https://[::1]/hook",
+            "https://169.254.169.254/latest/meta-data",
+            "https://localhost/hook",
+        ] {
+            let url: Url = url.parse().unwrap();
+            assert!(
+                matches!(
+                    WebhookTarget::resolve(&url, false).await,
+                    Err(WebhookTargetError::NonPublic { .. })
+                ),
+                "{url}"
+            );
+        }
+
+        let url: Url = "http://127.0.0.1:8080/hook".parse().unwrap();
+        let target = WebhookTarget::resolve(&url, true).await.unwrap();
+        assert_eq!(target.host, "127.0.0.1");
+        assert_eq!(target.addrs, vec!["127.0.0.1:8080".parse().unwrap()]);
+    }
+}
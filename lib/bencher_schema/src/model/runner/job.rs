@@ -1,8 +1,11 @@
 use std::sync::Arc;
 
+use bencher_adapter::{AdapterResults, AdapterResultsArray};
 use bencher_json::{
     DateTime, ImageDigest, JobStatus, JobUuid, JsonJob, JsonJobConfig, Priority, Timeout,
-    project::report::JsonReportSettings, runner::JsonIterationOutput, runner::job::JsonNewRunJob,
+    project::report::{Adapter, JsonReportSettings},
+    runner::JsonIterationOutput,
+    runner::job::JsonNewRunJob,
 };
 use diesel::{
     BoolExpressionMethods as _, ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _,
@@ -22,7 +25,8 @@ use crate::{
         organization::{OrganizationId, plan::PlanKind},
         project::{
             QueryProject,
-            report::{QueryReport, ReportId},
+            report::{QueryReport, ReportId, ReportMode, results::ReportResults},
+            webhook::WebhookDispatch,
         },
         runner::{QueryRunner, RunnerId, SourceIp},
         spec::{QuerySpec, SpecId},
@@ -104,7 +108,6 @@ impl QueryJob {
     ///
     /// Looks up the report and branch, parses benchmark output via the adapter,
     /// creates metrics/alerts, checks plan usage, and updates the report timestamps.
-    #[expect(clippy::too_many_lines, reason = "sequential job processing steps")]
     pub async fn process_results(
         &self,
         log: &Logger,
@@ -139,35 +142,16 @@ impl QueryJob {
         )
         .await?;
 
-        // Build results array from per-iteration output.
-        // File output takes precedence (mirrors CLI CommandToFile mode):
-        // each file's contents = one result string for the adapter.
-        // Otherwise fall back to stdout (mirrors CLI Command mode).
-        //
-        // All iterations are flattened into a single Vec<String>, matching
-        // the CLI's local behavior (services/cli/src/bencher/sub/run/mod.rs).
-        // Without fold: each string becomes a separate enumerated iteration.
-        // With fold: all strings are combined via the fold operation.
-        let results_strings: Vec<String> = results
-            .into_iter()
-            .flat_map(|r| {
-                if let Some(output) = r.output {
-                    output.into_values().collect::<Vec<_>>()
-                } else if let Some(stdout) = r.stdout {
-                    vec![stdout]
-                } else {
-                    Vec::new()
-                }
-            })
-            .collect();
-        let results_array: Vec<&str> = results_strings.iter().map(AsRef::as_ref).collect();
-
         // Build settings from job config
         let settings = JsonReportSettings {
             adapter: Some(query_report.adapter),
             average: self.config.average,
             fold: self.config.fold,
+            bootstrap: self.config.bootstrap,
+            statistic: self.config.statistic,
+            params: self.config.params,
         };
+        let results_array = job_results_array(results, query_report.adapter, settings)?;
 
         // Process results (adapter parsing, metrics, alerts, usage)
         query_report
@@ -175,9 +159,8 @@ impl QueryJob {
                 log,
                 context,
                 branch_id,
-                &results_array,
-                query_report.adapter,
-                settings,
+                results_array,
+                settings.fold,
                 plan_kind,
                 #[cfg(feature = "otel")]
                 self.priority,
@@ -238,6 +221,14 @@ impl QueryJob {
             );
         }
 
+        // Now that the report has its results, notify any webhooks
+        match processed_report_json(log, context, report_id).await {
+            Ok(json_report) => {
+                WebhookDispatch::report_created(log, context, query_project.id, &json_report).await;
+            },
+            Err(e) => slog::error!(log, "Failed to get report ({report_id}) for webhooks: {e}"),
+        }
+
         Ok(())
     }
 
@@ -314,6 +305,19 @@ impl InsertJob {
     }
 }
 
+async fn processed_report_json(
+    log: &Logger,
+    context: &ApiContext,
+    report_id: ReportId,
+) -> Result<bencher_json::JsonReport, HttpError> {
+    let conn = auth_conn!(context);
+    schema::report::table
+        .filter(schema::report::id.eq(report_id))
+        .first::<QueryReport>(conn)
+        .map_err(resource_not_found_err!(Report, report_id))?
+        .into_json(log, conn, ReportMode::Full)
+}
+
 /// Pre-validated job that is ready to be inserted once a report ID is available.
 ///
 /// This separates async validation (registry checks, OCI digest resolution) from
@@ -381,7 +385,12 @@ impl PendingInsertJob {
             file_size: new_run_job.file_size,
             average: settings.average,
             iter: new_run_job.iter,
+            warmup: new_run_job.warmup,
+            trim: new_run_job.trim,
             fold: settings.fold,
+            bootstrap: settings.bootstrap,
+            statistic: settings.statistic,
+            params: settings.params,
             allow_failure: new_run_job.allow_failure,
             backdate: new_run_job.backdate,
         };
@@ -494,12 +503,61 @@ fn insert_job_duration(
     Ok(())
 }
 
+/// Convert per-iteration job output into adapter results.
+///
+/// File output takes precedence (mirrors CLI `CommandToFile` mode):
+/// each file's contents = one result string for the adapter.
+/// Otherwise fall back to stdout (mirrors CLI Command mode).
+///
+/// All iterations are flattened into a single results array, matching
+/// the CLI's local behavior (services/cli/src/bencher/sub/run/mod.rs).
+/// Without fold: each string becomes a separate enumerated iteration.
+/// With fold: all strings are combined via the fold operation.
+///
+/// Built-in measures collected by the runner are merged into the first
+/// result of their iteration, or become a result of their own when the
+/// iteration produced no output to parse.
+///
+/// Iterations the runner dropped (warm-ups and trimmed outliers) are
+/// kept in the job output for reference but not reported.
+fn job_results_array(
+    results: Vec<JsonIterationOutput>,
+    adapter: Adapter,
+    settings: JsonReportSettings,
+) -> Result<AdapterResultsArray, HttpError> {
+    // Start empty, carrying the adapter settings used when folding.
+    let mut results_array = ReportResults::convert(&[], adapter, &settings)?;
+    for iteration in results.into_iter().filter(|r| r.dropped.is_none()) {
+        let results_strings = if let Some(output) = iteration.output {
+            output.into_values().collect::<Vec<_>>()
+        } else if let Some(stdout) = iteration.stdout {
+            vec![stdout]
+        } else {
+            Vec::new()
+        };
+        let results_strs: Vec<&str> = results_strings.iter().map(AsRef::as_ref).collect();
+        let mut iteration_array = ReportResults::convert(&results_strs, adapter, &settings)?.inner;
+        if let Some(metrics) = iteration.metrics {
+            let runner_results = AdapterResults::from_results_map(metrics);
+            if let Some(first) = iteration_array.first_mut() {
+                *first = std::mem::take(first) + runner_results;
+            } else {
+                iteration_array.push(runner_results);
+            }
+        }
+        results_array.inner.extend(iteration_array);
+    }
+    Ok(results_array)
+}
+
 #[cfg(test)]
 mod tests {
     use bencher_json::{DateTime, Entitlements, PlanLevel};
     use diesel::QueryDsl as _;
     use pretty_assertions::assert_eq;
 
+    use bencher_json::runner::{JsonDropReason, JsonDroppedIteration};
+
     use super::*;
     use crate::{
         macros::sql::last_insert_rowid,
@@ -798,6 +856,79 @@ mod tests {
         // First write wins
         assert_eq!(duration, 100);
     }
+
+    fn iteration_output(stdout: Option<&str>, metrics: Option<&str>) -> JsonIterationOutput {
+        JsonIterationOutput {
+            exit_code: 0,
+            stdout: stdout.map(ToOwned::to_owned),
+            stderr: None,
+            output: None,
+            metrics: metrics.map(|metrics| serde_json::from_str(metrics).unwrap()),
+            dropped: None,
+        }
+    }
+
+    #[test]
+    fn job_results_array_without_metrics() {
+        let results = vec![
+            iteration_output(Some(r#"{"bench": {"latency": {"value": 1.0}}}"#), None),
+            iteration_output(Some(r#"{"bench": {"latency": {"value": 2.0}}}"#), None),
+        ];
+        let results_array =
+            job_results_array(results, Adapter::Json, JsonReportSettings::default()).unwrap();
+        assert_eq!(results_array.inner.len(), 2);
+    }
+
+    #[test]
+    fn job_results_array_merges_runner_metrics() {
+        let metrics =
+            r#"{"job": {"instructions": {"value": 1000.0}, "cycles": {"value": 2000.0}}}"#;
+        let results = vec![
+            iteration_output(
+                Some(r#"{"bench": {"latency": {"value": 1.0}}}"#),
+                Some(metrics),
+            ),
+            // No output to parse: the runner metrics are the whole iteration
+            iteration_output(None, Some(metrics)),
+        ];
+        let results_array =
+            job_results_array(results, Adapter::Json, JsonReportSettings::default()).unwrap();
+        assert_eq!(results_array.inner.len(), 2);
+
+        let first = results_array.inner.first().unwrap();
+        assert_eq!(first.inner.len(), 2);
+        let job_name = bencher_json::BenchmarkNameId::new_name("job".parse().unwrap());
+        let job = &first.inner[&job_name];
+        assert_eq!(job.inner.len(), 2);
+
+        let second = &results_array.inner[1];
+        assert_eq!(second.inner.len(), 1);
+        assert!(second.inner.contains_key(&job_name));
+    }
+
+    #[test]
+    fn job_results_array_skips_dropped() {
+        let mut warmup = iteration_output(Some(r#"{"warmup": {"latency": {"value": 9.0}}}"#), None);
+        warmup.dropped = Some(JsonDroppedIteration {
+            reason: JsonDropReason::Warmup,
+            value: None,
+        });
+        let results = vec![
+            warmup,
+            iteration_output(Some(r#"{"bench": {"latency": {"value": 1.0}}}"#), None),
+        ];
+        let results_array =
+            job_results_array(results, Adapter::Json, JsonReportSettings::default()).unwrap();
+        assert_eq!(results_array.inner.len(), 1);
+        let warmup_name = bencher_json::BenchmarkNameId::new_name("warmup".parse().unwrap());
+        assert!(!results_array.inner[0].inner.contains_key(&warmup_name));
+    }
+
+    #[test]
+    fn job_results_array_bad_output() {
+        let results = vec![iteration_output(Some("not json"), None)];
+        job_results_array(results, Adapter::Json, JsonReportSettings::default()).unwrap_err();
+    }
 }
 
 #[derive(Debug, Default, diesel::AsChangeset)]
@@ -1,14 +1,18 @@
-use std::{ffi::OsStr, path::PathBuf};
+use std::{
+    ffi::OsStr,
+    num::NonZeroU32,
+    path::{Path, PathBuf},
+};
 
 use async_compression::tokio::write::GzipEncoder;
 use bencher_json::{DateTime, JsonBackup, JsonBackupCreated, system::backup::JsonDataStore};
-use chrono::Utc;
+use chrono::{NaiveDateTime, Utc};
 use tokio::{
     fs::remove_file,
     io::{AsyncReadExt as _, AsyncWriteExt as _, BufReader, BufWriter},
 };
 
-use crate::context::DataStore;
+use crate::context::{DataStore, LocalDirectory};
 
 // https://www.sqlite.org/pgszchng2016.html
 const SQLITE_PAGE_SIZE: usize = 4096;
@@ -16,6 +20,9 @@ const PAUSE_BETWEEN_PAGES: std::time::Duration = std::time::Duration::from_milli
 // We want the backup to be done in a reasonable time, ~10 minutes.
 // Sqlite page size * 10 minutes * 60 seconds * 10 iterations of 100 milliseconds pauses per second
 const PAGES_PER_STEP_COEFFICIENT: usize = SQLITE_PAGE_SIZE * 10 * 60 * 10;
+const BACKUP_DATE_TIME_FORMAT: &str = "%Y-%m-%d-%H-%M-%S";
+// The length of a timestamp in `BACKUP_DATE_TIME_FORMAT`, e.g. `2025-01-01-00-00-00`
+const BACKUP_DATE_TIME_LEN: usize = 19;
 
 pub struct ServerBackup {
     pub file_path: PathBuf,
@@ -62,6 +69,15 @@ pub enum ServerBackupError {
     DataStore(crate::context::DataStoreError),
     #[error("No data store")]
     NoDataStore,
+    #[error(
+        "Requested data store ({requested}) does not match the server data store ({configured})"
+    )]
+    DataStoreMismatch {
+        requested: JsonDataStore,
+        configured: JsonDataStore,
+    },
+    #[error("Failed to prune old backups: {0}")]
+    Prune(crate::context::DataStoreError),
 }
 
 impl ServerBackup {
@@ -70,6 +86,14 @@ impl ServerBackup {
         data_store: Option<&DataStore>,
         json_backup: JsonBackup,
     ) -> Result<JsonBackupCreated, ServerBackupError> {
+        // Check the data store before doing any work
+        let data_store = json_backup
+            .data_store
+            .map(|requested| select_data_store(data_store, requested))
+            .transpose()?;
+        let name_prefix = backup_name_prefix(&file_path);
+        let database_dir = database_dir(&file_path);
+
         // Create a database backup
         let Self {
             file_path: backup_file_path,
@@ -87,16 +111,12 @@ impl ServerBackup {
             (backup_file_path, backup_file_name)
         };
 
-        // Store the database backup in AWS S3
-        if let Some(JsonDataStore::AwsS3) = json_backup.data_store {
-            if let Some(data_store) = data_store {
-                data_store
-                    .backup(&source_path, &file_name)
-                    .await
-                    .map_err(ServerBackupError::DataStore)?;
-            } else {
-                return Err(ServerBackupError::NoDataStore);
-            }
+        // Store the database backup in the data store
+        if let Some(data_store) = data_store {
+            data_store
+                .backup(&source_path, &file_name)
+                .await
+                .map_err(ServerBackupError::DataStore)?;
         }
 
         // Remove the remaining database backup
@@ -110,22 +130,37 @@ impl ServerBackup {
             })?;
         }
 
-        Ok(JsonBackupCreated { created })
+        // Prune older backups from wherever they are kept
+        let pruned = if let Some(keep) = json_backup.keep {
+            let local;
+            let store = if let Some(data_store) = data_store {
+                data_store
+            } else {
+                local = DataStore::Local(LocalDirectory::new(database_dir));
+                &local
+            };
+            prune_backups(store, &name_prefix, keep).await?
+        } else {
+            Vec::new()
+        };
+
+        Ok(JsonBackupCreated {
+            created,
+            file_name,
+            pruned,
+        })
     }
 
     pub fn backup_database(file_path: &PathBuf) -> Result<Self, ServerBackupError> {
-        let file_stem = file_path
-            .file_stem()
-            .unwrap_or_else(|| OsStr::new("bencher"))
-            .to_string_lossy();
         let file_extension = file_path
             .extension()
             .unwrap_or_else(|| OsStr::new("db"))
             .to_string_lossy();
         let date_time = Utc::now();
         let file_name = format!(
-            "backup-{file_stem}-{}.{file_extension}",
-            date_time.format("%Y-%m-%d-%H-%M-%S")
+            "{}{}.{file_extension}",
+            backup_name_prefix(file_path),
+            date_time.format(BACKUP_DATE_TIME_FORMAT)
         );
         let mut backup_file_path = file_path.clone();
         backup_file_path.set_file_name(&file_name);
@@ -140,6 +175,88 @@ impl ServerBackup {
     }
 }
 
+/// Get the data store for a backup or restore,
+/// checking that it is the one the server is configured with.
+pub(super) fn select_data_store(
+    data_store: Option<&DataStore>,
+    requested: JsonDataStore,
+) -> Result<&DataStore, ServerBackupError> {
+    let data_store = data_store.ok_or(ServerBackupError::NoDataStore)?;
+    let configured = data_store.kind();
+    if requested == configured {
+        Ok(data_store)
+    } else {
+        Err(ServerBackupError::DataStoreMismatch {
+            requested,
+            configured,
+        })
+    }
+}
+
+/// The directory that holds the database and its local backups.
+pub(super) fn database_dir(file_path: &Path) -> PathBuf {
+    match file_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_owned(),
+        _ => PathBuf::from("."),
+    }
+}
+
+/// Every backup of a database starts with this prefix, followed by its timestamp.
+fn backup_name_prefix(file_path: &Path) -> String {
+    let file_stem = file_path
+        .file_stem()
+        .unwrap_or_else(|| OsStr::new("bencher"))
+        .to_string_lossy();
+    format!("backup-{file_stem}-")
+}
+
+async fn prune_backups(
+    data_store: &DataStore,
+    name_prefix: &str,
+    keep: NonZeroU32,
+) -> Result<Vec<String>, ServerBackupError> {
+    let file_names = data_store
+        .list(name_prefix)
+        .await
+        .map_err(ServerBackupError::Prune)?;
+    let pruned = backups_to_prune(file_names, name_prefix, keep);
+    for file_name in &pruned {
+        data_store
+            .remove(file_name)
+            .await
+            .map_err(ServerBackupError::Prune)?;
+    }
+    Ok(pruned)
+}
+
+/// Pick all but the `keep` most recent backups.
+///
+/// Only file names that are the prefix followed by a backup timestamp are considered,
+/// so another database whose name shares the prefix is never pruned.
+fn backups_to_prune(file_names: Vec<String>, name_prefix: &str, keep: NonZeroU32) -> Vec<String> {
+    let mut backups = file_names
+        .into_iter()
+        .filter_map(|file_name| {
+            let date_time = file_name
+                .strip_prefix(name_prefix)
+                .and_then(|suffix| suffix.get(..BACKUP_DATE_TIME_LEN))
+                .and_then(|date_time| {
+                    NaiveDateTime::parse_from_str(date_time, BACKUP_DATE_TIME_FORMAT).ok()
+                })?;
+            Some((date_time, file_name))
+        })
+        .collect::<Vec<_>>();
+    // Oldest first
+    backups.sort_unstable();
+    let keep = usize::try_from(u32::from(keep)).unwrap_or(usize::MAX);
+    let prune = backups.len().saturating_sub(keep);
+    backups.truncate(prune);
+    backups
+        .into_iter()
+        .map(|(_, file_name)| file_name)
+        .collect()
+}
+
 fn run_online_backup(src: &PathBuf, dest: &PathBuf) -> Result<(), ServerBackupError> {
     // Get the total size of the source database
     let src_size = src
@@ -202,7 +319,7 @@ async fn compress_database(
         }
 
         encoder
-            .write_all(&data_buffer)
+            .write_all(data_buffer.get(..data_size).unwrap_or_default())
             .await
             .map_err(ServerBackupError::WriteZipFile)?;
     }
@@ -217,3 +334,115 @@ async fn compress_database(
 
     Ok((compress_file_path, compress_file_name))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroU32, path::Path};
+
+    use bencher_json::system::backup::JsonDataStore;
+
+    use super::{
+        ServerBackupError, backup_name_prefix, backups_to_prune, database_dir, prune_backups,
+        select_data_store,
+    };
+    use crate::context::{DataStore, LocalDirectory};
+
+    const PREFIX: &str = "backup-bencher-";
+
+    fn keep(keep: u32) -> NonZeroU32 {
+        NonZeroU32::new(keep).unwrap()
+    }
+
+    #[test]
+    fn name_prefix() {
+        assert_eq!(backup_name_prefix(Path::new("data/bencher.db")), PREFIX);
+        assert_eq!(
+            database_dir(Path::new("data/bencher.db")),
+            Path::new("data")
+        );
+        assert_eq!(database_dir(Path::new("bencher.db")), Path::new("."));
+    }
+
+    #[test]
+    fn prune_oldest() {
+        let file_names = vec![
+            "backup-bencher-2025-03-01-00-00-00.db.gz".to_owned(),
+            "backup-bencher-2025-01-01-00-00-00.db.gz".to_owned(),
+            "backup-bencher-2025-02-01-00-00-00.db".to_owned(),
+            "backup-bencher-2024-12-31-23-59-59.db.gz".to_owned(),
+        ];
+        assert_eq!(
+            backups_to_prune(file_names, PREFIX, keep(2)),
+            [
+                "backup-bencher-2024-12-31-23-59-59.db.gz",
+                "backup-bencher-2025-01-01-00-00-00.db.gz",
+            ]
+        );
+    }
+
+    #[test]
+    fn prune_nothing() {
+        let file_names = vec!["backup-bencher-2025-01-01-00-00-00.db".to_owned()];
+        assert!(backups_to_prune(file_names, PREFIX, keep(1)).is_empty());
+    }
+
+    #[test]
+    fn prune_ignores_other_databases() {
+        let file_names = vec![
+            "backup-bencher-2025-01-01-00-00-00.db".to_owned(),
+            "backup-bencher-old-2020-01-01-00-00-00.db".to_owned(),
+            "backup-bencher-notes.txt".to_owned(),
+            "backup-bencher-2025-02-01-00-00-00.db".to_owned(),
+        ];
+        assert_eq!(
+            backups_to_prune(file_names, PREFIX, keep(1)),
+            ["backup-bencher-2025-01-01-00-00-00.db"]
+        );
+    }
+
+    #[test]
+    fn select_data_store_mismatch() {
+        let local = DataStore::Local(LocalDirectory::new("backups".into()));
+        assert!(matches!(
+            select_data_store(None, JsonDataStore::Local),
+            Err(ServerBackupError::NoDataStore)
+        ));
+        assert!(matches!(
+            select_data_store(Some(&local), JsonDataStore::S3),
+            Err(ServerBackupError::DataStoreMismatch {
+                requested: JsonDataStore::S3,
+                configured: JsonDataStore::Local,
+            })
+        ));
+        select_data_store(Some(&local), JsonDataStore::Local).unwrap();
+    }
+
+    #[tokio::test]
+    async fn prune_local_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        for file_name in [
+            "backup-bencher-2025-01-01-00-00-00.db.gz",
+            "backup-bencher-2025-02-01-00-00-00.db.gz",
+            "backup-bencher-2025-03-01-00-00-00.db.gz",
+            "bencher.db",
+        ] {
+            std::fs::write(dir.path().join(file_name), b"").unwrap();
+        }
+        let local = DataStore::Local(LocalDirectory::new(dir.path().to_owned()));
+
+        let pruned = prune_backups(&local, PREFIX, keep(1)).await.unwrap();
+        assert_eq!(
+            pruned,
+            [
+                "backup-bencher-2025-01-01-00-00-00.db.gz",
+                "backup-bencher-2025-02-01-00-00-00.db.gz",
+            ]
+        );
+        let mut remaining = local.list("").await.unwrap();
+        remaining.sort();
+        assert_eq!(
+            remaining,
+            ["backup-bencher-2025-03-01-00-00-00.db.gz", "bencher.db"]
+        );
+    }
+}
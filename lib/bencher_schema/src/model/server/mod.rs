@@ -1,6 +1,8 @@
 mod backup;
 mod plus;
+mod restore;
 
 pub use backup::{ServerBackup, ServerBackupError};
 #[cfg(feature = "plus")]
-pub use plus::{QueryServer, ServerId};
+pub use plus::{QueryServer, RegistryGc, RegistryGcError, ServerId};
+pub use restore::{ServerRestore, ServerRestoreError};
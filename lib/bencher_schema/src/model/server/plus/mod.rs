@@ -26,8 +26,11 @@ use crate::{
     schema::{self, server as server_table},
 };
 
+mod registry;
 mod stats;
 
+pub use registry::{RegistryGc, RegistryGcError};
+
 crate::macros::typed_id::typed_id!(ServerId);
 
 const SERVER_ID: ServerId = ServerId(1);
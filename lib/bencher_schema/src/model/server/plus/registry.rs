@@ -0,0 +1,87 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use bencher_json::{JobStatus, JsonRegistryGcReport};
+use bencher_oci_storage::{Digest, GcRoots, OciStorage, OciStorageError};
+use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+use slog::Logger;
+use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
+
+use crate::{context::DbConnection, model::runner::QueryJob, schema};
+
+pub struct RegistryGc;
+
+#[derive(Debug, thiserror::Error)]
+pub enum RegistryGcError {
+    #[error("Failed to query unfinished jobs: {0}")]
+    Jobs(diesel::result::Error),
+    #[error("Failed to collect registry garbage: {0}")]
+    Storage(OciStorageError),
+}
+
+impl RegistryGc {
+    /// Collect garbage from the container registry.
+    ///
+    /// The images of any jobs that have yet to finish are always kept,
+    /// even if they are no longer tagged.
+    pub async fn run(
+        connection: &Mutex<DbConnection>,
+        oci_storage: &OciStorage,
+        dry_run: bool,
+    ) -> Result<JsonRegistryGcReport, RegistryGcError> {
+        let roots =
+            unfinished_job_images(&mut *connection.lock().await).map_err(RegistryGcError::Jobs)?;
+        oci_storage
+            .garbage_collector()
+            .run(&roots, dry_run)
+            .await
+            .map_err(RegistryGcError::Storage)
+    }
+
+    /// Collect garbage from the container registry every `interval` until shutdown.
+    pub fn spawn(
+        log: Logger,
+        connection: Arc<Mutex<DbConnection>>,
+        oci_storage: Arc<OciStorage>,
+        interval: Duration,
+        shutdown: CancellationToken,
+    ) {
+        slog::info!(
+            log,
+            "Scheduling registry garbage collection every {} seconds",
+            interval.as_secs()
+        );
+        tokio::spawn(async move {
+            while shutdown
+                .run_until_cancelled(tokio::time::sleep(interval))
+                .await
+                .is_some()
+            {
+                if let Err(e) = Self::run(&connection, &oci_storage, false).await {
+                    slog::error!(log, "Scheduled registry garbage collection failed: {e}");
+                }
+            }
+        });
+    }
+}
+
+/// The images of all pending, claimed, and running jobs, by project
+fn unfinished_job_images(conn: &mut DbConnection) -> Result<GcRoots, diesel::result::Error> {
+    let jobs = schema::job::table
+        .filter(schema::job::status.eq_any([
+            JobStatus::Pending,
+            JobStatus::Claimed,
+            JobStatus::Running,
+        ]))
+        .load::<QueryJob>(conn)?;
+
+    let mut roots = GcRoots::new();
+    for job in jobs {
+        // Job image digests are validated on creation
+        if let Ok(digest) = job.config.digest.as_ref().parse::<Digest>() {
+            roots.entry(job.config.project).or_default().insert(digest);
+        }
+    }
+    Ok(roots)
+}
@@ -0,0 +1,400 @@
+use std::path::{Path, PathBuf};
+
+use async_compression::tokio::bufread::GzipDecoder;
+use bencher_json::{JsonRestore, JsonRestored};
+use chrono::Utc;
+use tokio::io::BufReader;
+
+use super::backup::{ServerBackup, ServerBackupError, database_dir, select_data_store};
+use crate::context::{DataStore, DataStoreError, Database};
+
+const GZIP_EXTENSION: &str = ".gz";
+const BACKUP_PREFIX: &str = "backup-";
+const DOWNLOAD_PREFIX: &str = "download-";
+// The table diesel uses to track applied migrations
+const MIGRATIONS_TABLE: &str = "__diesel_schema_migrations";
+// How long to wait before retrying if the database is busy
+const RETRY_PAUSE: std::time::Duration = std::time::Duration::from_millis(100);
+
+pub struct ServerRestore;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ServerRestoreError {
+    #[error(
+        "Invalid backup file name ({0}): expected a file name like `backup-bencher-2025-01-01-00-00-00.db`"
+    )]
+    FileName(String),
+    #[error("Backup file not found: {0}")]
+    NotFound(PathBuf),
+    #[error("{0}")]
+    Backup(ServerBackupError),
+    #[error("Failed to download backup: {0}")]
+    Download(DataStoreError),
+    #[error("Failed to stage backup ({path}): {error}")]
+    Stage {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    #[error("Failed to open backup database ({path}): {error}")]
+    Open {
+        path: PathBuf,
+        error: rusqlite::Error,
+    },
+    #[error("Failed to check backup database integrity: {0}")]
+    IntegrityCheck(rusqlite::Error),
+    #[error("Backup database is corrupt: {0}")]
+    Corrupt(String),
+    #[error("Backup is not a Bencher database: {0}")]
+    NotBencher(rusqlite::Error),
+    #[error("Failed to list known migrations: {0}")]
+    KnownMigrations(crate::MigrationError),
+    #[error("Backup is from a newer version of Bencher with unknown migrations: {0:?}")]
+    NewerThanServer(Vec<String>),
+    #[error("Failed to open database ({path}): {error}")]
+    OpenDatabase {
+        path: PathBuf,
+        error: rusqlite::Error,
+    },
+    #[error("Failed to restore backup into database: {0}")]
+    Restore(rusqlite::Error),
+    #[error("Failed to run database migrations after restore: {0}")]
+    Migrations(crate::MigrationError),
+    #[error("Failed to join restore task: {0}")]
+    Join(tokio::task::JoinError),
+}
+
+impl ServerRestore {
+    /// Restore the database from a backup.
+    ///
+    /// The backup is fetched from the data store into its own download file
+    /// (or found in the database directory),
+    /// decompressed into a scratch copy, and verified: it must pass an integrity check
+    /// and only have migrations this server knows about.
+    /// A backup of the current database is taken before anything is changed.
+    /// The backup is then copied into the live database with the `SQLite` online backup API,
+    /// while holding the write connection, so open connections and any replication
+    /// see the restore as an ordinary write.
+    /// Finally, any pending migrations are run on the restored database.
+    pub async fn run(
+        database: &Database,
+        json_restore: JsonRestore,
+    ) -> Result<JsonRestored, ServerRestoreError> {
+        let JsonRestore {
+            file_name,
+            data_store,
+        } = json_restore;
+        validate_file_name(&file_name)?;
+
+        let database_dir = database_dir(&database.path);
+        let (backup_path, download_path) = if let Some(requested) = data_store {
+            let data_store = select_data_store(database.data_store.as_ref(), requested)
+                .map_err(ServerRestoreError::Backup)?;
+            let download_path = download_backup(data_store, &file_name, &database_dir).await?;
+            (download_path.clone(), Some(download_path))
+        } else {
+            let backup_path = database_dir.join(&file_name);
+            if !backup_path.is_file() {
+                return Err(ServerRestoreError::NotFound(backup_path));
+            }
+            (backup_path, None)
+        };
+
+        // Always verify and restore from a scratch copy,
+        // so the backup itself is never modified by opening it.
+        let stage_path = database_dir.join(format!(
+            "restore-{}",
+            file_name.trim_end_matches(GZIP_EXTENSION)
+        ));
+        let result = Self::restore_staged(database, &backup_path, &stage_path).await;
+        // Best effort cleanup of the scratch copy and any download
+        let _rm = tokio::fs::remove_file(&stage_path).await;
+        if let Some(download_path) = download_path {
+            let _rm = tokio::fs::remove_file(&download_path).await;
+        }
+        result
+    }
+
+    async fn restore_staged(
+        database: &Database,
+        backup_path: &Path,
+        stage_path: &Path,
+    ) -> Result<JsonRestored, ServerRestoreError> {
+        stage_backup(backup_path, stage_path).await?;
+
+        let verify_path = stage_path.to_owned();
+        tokio::task::spawn_blocking(move || verify_backup(&verify_path))
+            .await
+            .map_err(ServerRestoreError::Join)??;
+
+        // Hold the write connection from the copy of the current database through the swap,
+        // so no writes are lost in between
+        let mut connection = database.connection.lock().await;
+
+        // Keep a copy of the current database in case the restore needs to be undone
+        let current_path = database.path.clone();
+        let previous =
+            tokio::task::spawn_blocking(move || ServerBackup::backup_database(&current_path))
+                .await
+                .map_err(ServerRestoreError::Join)?
+                .map_err(ServerRestoreError::Backup)?;
+
+        let source_path = stage_path.to_owned();
+        let dest_path = database.path.clone();
+        let busy_timeout = std::time::Duration::from_millis(database.busy_timeout.into());
+        tokio::task::spawn_blocking(move || {
+            copy_into_database(&source_path, &dest_path, busy_timeout)
+        })
+        .await
+        .map_err(ServerRestoreError::Join)??;
+        crate::run_migrations(&mut connection).map_err(ServerRestoreError::Migrations)?;
+        drop(connection);
+
+        Ok(JsonRestored {
+            restored: Utc::now().into(),
+            previous: previous.file_name,
+        })
+    }
+}
+
+/// Only allow plain backup file names, so a restore can never reach outside the database directory.
+fn validate_file_name(file_name: &str) -> Result<(), ServerRestoreError> {
+    let is_plain = Path::new(file_name)
+        .file_name()
+        .is_some_and(|name| name == file_name);
+    if is_plain && file_name.starts_with(BACKUP_PREFIX) {
+        Ok(())
+    } else {
+        Err(ServerRestoreError::FileName(file_name.to_owned()))
+    }
+}
+
+/// Download the backup from the data store into the database directory.
+/// The download never uses the backup's own file name,
+/// as the data store may be a local directory that is the database directory,
+/// and copying a file onto itself truncates it.
+async fn download_backup(
+    data_store: &DataStore,
+    file_name: &str,
+    database_dir: &Path,
+) -> Result<PathBuf, ServerRestoreError> {
+    let download_path = database_dir.join(format!("{DOWNLOAD_PREFIX}{file_name}"));
+    if let Err(e) = data_store.download(file_name, &download_path).await {
+        let _rm = tokio::fs::remove_file(&download_path).await;
+        return Err(ServerRestoreError::Download(e));
+    }
+    Ok(download_path)
+}
+
+/// Copy the backup to the scratch path, decompressing it if needed.
+async fn stage_backup(backup_path: &Path, stage_path: &Path) -> Result<(), ServerRestoreError> {
+    let stage_error = |path: &Path| {
+        let path = path.to_owned();
+        move |error| ServerRestoreError::Stage { path, error }
+    };
+
+    let is_compressed = backup_path
+        .extension()
+        .is_some_and(|extension| extension == GZIP_EXTENSION.trim_start_matches('.'));
+    if is_compressed {
+        let backup_file = tokio::fs::File::open(backup_path)
+            .await
+            .map_err(stage_error(backup_path))?;
+        let mut decoder = GzipDecoder::new(BufReader::new(backup_file));
+        let mut stage_file = tokio::fs::File::create(stage_path)
+            .await
+            .map_err(stage_error(stage_path))?;
+        tokio::io::copy(&mut decoder, &mut stage_file)
+            .await
+            .map_err(stage_error(backup_path))?;
+        stage_file
+            .sync_all()
+            .await
+            .map_err(stage_error(stage_path))?;
+    } else {
+        tokio::fs::copy(backup_path, stage_path)
+            .await
+            .map_err(stage_error(backup_path))?;
+    }
+    Ok(())
+}
+
+fn verify_backup(path: &Path) -> Result<(), ServerRestoreError> {
+    let connection =
+        rusqlite::Connection::open(path).map_err(|error| ServerRestoreError::Open {
+            path: path.to_owned(),
+            error,
+        })?;
+
+    // https://www.sqlite.org/pragma.html#pragma_integrity_check
+    let problems = connection
+        .prepare("PRAGMA integrity_check")
+        .and_then(|mut statement| {
+            statement
+                .query_map([], |row| row.get::<_, String>(0))?
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .map_err(ServerRestoreError::IntegrityCheck)?;
+    if problems != ["ok"] {
+        return Err(ServerRestoreError::Corrupt(problems.join("; ")));
+    }
+
+    let applied = connection
+        .prepare(&format!("SELECT version FROM {MIGRATIONS_TABLE}"))
+        .and_then(|mut statement| {
+            statement
+                .query_map([], |row| row.get::<_, String>(0))?
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .map_err(ServerRestoreError::NotBencher)?;
+    let known = crate::migration_versions().map_err(ServerRestoreError::KnownMigrations)?;
+    let unknown = unknown_migrations(applied, &known);
+    if unknown.is_empty() {
+        Ok(())
+    } else {
+        Err(ServerRestoreError::NewerThanServer(unknown))
+    }
+}
+
+fn unknown_migrations(applied: Vec<String>, known: &[String]) -> Vec<String> {
+    applied
+        .into_iter()
+        .filter(|version| !known.contains(version))
+        .collect()
+}
+
+fn copy_into_database(
+    source_path: &Path,
+    dest_path: &Path,
+    busy_timeout: std::time::Duration,
+) -> Result<(), ServerRestoreError> {
+    let source =
+        rusqlite::Connection::open(source_path).map_err(|error| ServerRestoreError::Open {
+            path: source_path.to_owned(),
+            error,
+        })?;
+    let open_database_error = |error| ServerRestoreError::OpenDatabase {
+        path: dest_path.to_owned(),
+        error,
+    };
+    let mut dest = rusqlite::Connection::open(dest_path).map_err(open_database_error)?;
+    dest.busy_timeout(busy_timeout)
+        .map_err(open_database_error)?;
+    let backup =
+        rusqlite::backup::Backup::new(&source, &mut dest).map_err(ServerRestoreError::Restore)?;
+    // Copy every page in a single step, so readers never see a partial restore
+    backup
+        .run_to_completion(i32::MAX, RETRY_PAUSE, None)
+        .map_err(ServerRestoreError::Restore)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ServerRestoreError, download_backup, unknown_migrations, validate_file_name, verify_backup,
+    };
+    use crate::context::{DataStore, LocalDirectory};
+
+    #[test]
+    fn file_name_plain_backup() {
+        validate_file_name("backup-bencher-2025-01-01-00-00-00.db").unwrap();
+        validate_file_name("backup-bencher-2025-01-01-00-00-00.db.gz").unwrap();
+    }
+
+    #[test]
+    fn file_name_rejects_paths() {
+        for file_name in [
+            "",
+            "bencher.db",
+            "../backup-bencher.db",
+            "backup-bencher/../../etc/passwd",
+            "/tmp/backup-bencher.db",
+        ] {
+            assert!(
+                matches!(
+                    validate_file_name(file_name),
+                    Err(ServerRestoreError::FileName(_))
+                ),
+                "{file_name}"
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_migrations_newer() {
+        let known = ["1".to_owned(), "2".to_owned()];
+        assert!(unknown_migrations(vec!["1".into(), "2".into()], &known).is_empty());
+        assert_eq!(
+            unknown_migrations(vec!["1".into(), "3".into()], &known),
+            ["3"]
+        );
+    }
+
+    #[test]
+    fn verify_not_bencher() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("backup-other.db");
+        rusqlite::Connection::open(&path)
+            .unwrap()
+            .execute_batch("CREATE TABLE other (id INTEGER)")
+            .unwrap();
+        assert!(matches!(
+            verify_backup(&path),
+            Err(ServerRestoreError::NotBencher(_))
+        ));
+    }
+
+    #[test]
+    fn verify_bencher() {
+        use diesel::Connection as _;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("backup-bencher.db");
+        let mut connection =
+            crate::context::DbConnection::establish(path.to_str().unwrap()).unwrap();
+        crate::run_migrations(&mut connection).unwrap();
+        drop(connection);
+        verify_backup(&path).unwrap();
+    }
+
+    #[test]
+    fn verify_corrupt() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("backup-bencher.db");
+        std::fs::write(
+            &path,
+            b"not a database at all, but long enough to look like a header",
+        )
+        .unwrap();
+        verify_backup(&path).unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn download_from_database_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_name = "backup-bencher-2025-01-01-00-00-00.db.gz";
+        let backup_path = dir.path().join(file_name);
+        std::fs::write(&backup_path, b"backup").unwrap();
+        // The local data store is the database directory
+        let local = DataStore::Local(LocalDirectory::new(dir.path().to_owned()));
+
+        let download_path = download_backup(&local, file_name, dir.path())
+            .await
+            .unwrap();
+        assert_ne!(download_path, backup_path);
+        assert_eq!(std::fs::read(&download_path).unwrap(), b"backup");
+        // The backup itself is left untouched
+        assert_eq!(std::fs::read(&backup_path).unwrap(), b"backup");
+    }
+
+    #[tokio::test]
+    async fn download_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let local = DataStore::Local(LocalDirectory::new(dir.path().to_owned()));
+
+        assert!(matches!(
+            download_backup(&local, "backup-missing.db", dir.path()).await,
+            Err(ServerRestoreError::Download(_))
+        ));
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+}
@@ -8,6 +8,7 @@ diesel::table! {
         boundary_limit -> Bool,
         status -> Integer,
         modified -> BigInt,
+        resolved_report_id -> Nullable<Integer>,
     }
 }
 
@@ -24,6 +25,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    benchmark_alias (id) {
+        id -> Integer,
+        uuid -> Text,
+        project_id -> Integer,
+        benchmark_id -> Integer,
+        name -> Text,
+        created -> BigInt,
+    }
+}
+
 diesel::table! {
     boundary (id) {
         id -> Integer,
@@ -34,6 +46,7 @@ diesel::table! {
         baseline -> Nullable<Double>,
         lower_limit -> Nullable<Double>,
         upper_limit -> Nullable<Double>,
+        p_value -> Nullable<Double>,
     }
 }
 
@@ -146,6 +159,10 @@ diesel::table! {
         upper_boundary -> Nullable<Double>,
         created -> BigInt,
         replaced -> Nullable<BigInt>,
+        confirm_outliers -> Nullable<BigInt>,
+        confirm_reports -> Nullable<BigInt>,
+        resolve_reports -> Nullable<BigInt>,
+        version_window -> Nullable<BigInt>,
     }
 }
 
@@ -265,6 +282,16 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    project_retention (project_id) {
+        project_id -> Integer,
+        report_days -> Nullable<BigInt>,
+        default_branch_id -> Nullable<Integer>,
+        downsample_days -> Nullable<BigInt>,
+        modified -> BigInt,
+    }
+}
+
 diesel::table! {
     project_role (id) {
         id -> Integer,
@@ -291,6 +318,7 @@ diesel::table! {
         start_time -> BigInt,
         end_time -> BigInt,
         created -> BigInt,
+        noisy -> Bool,
     }
 }
 
@@ -453,8 +481,40 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    webhook (id) {
+        id -> Integer,
+        uuid -> Text,
+        project_id -> Integer,
+        url -> Text,
+        secret -> Text,
+        events -> Text,
+        enabled -> Bool,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
+diesel::table! {
+    webhook_delivery (id) {
+        id -> Integer,
+        uuid -> Text,
+        webhook_id -> Integer,
+        event -> Integer,
+        payload -> Text,
+        status -> Integer,
+        attempts -> Integer,
+        response_status -> Nullable<Integer>,
+        error -> Nullable<Text>,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
 diesel::joinable!(alert -> boundary (boundary_id));
 diesel::joinable!(benchmark -> project (project_id));
+diesel::joinable!(benchmark_alias -> benchmark (benchmark_id));
+diesel::joinable!(benchmark_alias -> project (project_id));
 diesel::joinable!(boundary -> metric (metric_id));
 diesel::joinable!(boundary -> model (model_id));
 diesel::joinable!(boundary -> threshold (threshold_id));
@@ -483,6 +543,8 @@ diesel::joinable!(plot_testbed -> testbed (testbed_id));
 diesel::joinable!(project -> organization (organization_id));
 diesel::joinable!(project_key -> project (project_id));
 diesel::joinable!(project_key -> user (creator_id));
+diesel::joinable!(project_retention -> branch (default_branch_id));
+diesel::joinable!(project_retention -> project (project_id));
 diesel::joinable!(project_role -> project (project_id));
 diesel::joinable!(project_role -> user (user_id));
 diesel::joinable!(report -> head (head_id));
@@ -510,10 +572,13 @@ diesel::joinable!(threshold -> testbed (testbed_id));
 diesel::joinable!(token -> user (user_id));
 diesel::joinable!(user_key -> user (user_id));
 diesel::joinable!(version -> project (project_id));
+diesel::joinable!(webhook -> project (project_id));
+diesel::joinable!(webhook_delivery -> webhook (webhook_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     alert,
     benchmark,
+    benchmark_alias,
     boundary,
     branch,
     head,
@@ -534,6 +599,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     plot_testbed,
     project,
     project_key,
+    project_retention,
     project_role,
     report,
     report_benchmark,
@@ -549,4 +615,6 @@ diesel::allow_tables_to_appear_in_same_query!(
     user,
     user_key,
     version,
+    webhook,
+    webhook_delivery,
 );
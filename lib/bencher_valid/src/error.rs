@@ -112,6 +112,8 @@ pub enum ValidError {
     UserKey(String),
     #[error("Failed to validate user key hash: {0}")]
     UserKeyHash(String),
+    #[error("Failed to validate webhook secret: {0}")]
+    WebhookSecret(String),
     #[error(
         "Failed to validate Bencher API key: {0}. Expected prefix `bencher_user_` or `bencher_run_`."
     )]
@@ -184,12 +186,30 @@ pub enum ValidError {
     Boundaries { lower: Boundary, upper: Boundary },
     #[error("Invalid model, no boundary provided")]
     NoBoundary,
+    #[error(
+        "Invalid model, confirmation outliers ({outliers}) is greater than confirmation reports ({reports})"
+    )]
+    Confirm {
+        outliers: SampleSize,
+        reports: SampleSize,
+    },
+    #[error("Invalid model, confirmation reports ({0}) requires confirmation outliers")]
+    ConfirmReports(SampleSize),
+    #[error(
+        "Invalid model, includes both a sampling window ({window}) and a version window ({version_window})"
+    )]
+    Windows {
+        window: Window,
+        version_window: SampleSize,
+    },
     #[error("Invalid static model, includes a minimum sample size: {0}")]
     StaticMinSampleSize(SampleSize),
     #[error("Invalid static model, includes a maximum sample size: {0}")]
     StaticMaxSampleSize(SampleSize),
     #[error("Invalid static model, includes a sampling window: {0}")]
     StaticWindow(Window),
+    #[error("Invalid static model, includes a version window: {0}")]
+    StaticVersionWindow(SampleSize),
     #[error("Invalid percentage boundary: {0}")]
     PercentageBoundary(f64),
     #[error("Invalid statistical boundary: {0}")]
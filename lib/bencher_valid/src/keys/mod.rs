@@ -3,12 +3,14 @@ pub mod project_key;
 pub mod project_key_hash;
 pub mod user_key;
 pub mod user_key_hash;
+pub mod webhook_secret;
 
 pub use bencher_key::BencherKey;
 pub use project_key::ProjectKey;
 pub use project_key_hash::ProjectKeyHash;
 pub use user_key::UserKey;
 pub use user_key_hash::UserKeyHash;
+pub use webhook_secret::WebhookSecret;
 
 /// ~178 bits of entropy.
 /// <https://github.blog/engineering/platform-security/behind-githubs-new-authentication-token-formats/>
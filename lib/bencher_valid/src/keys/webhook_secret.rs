@@ -0,0 +1,59 @@
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
+use serde::{Deserialize, Serialize};
+
+/// The secret used to sign webhook payloads.
+///
+/// Unlike API keys, webhook secrets are stored as plaintext,
+/// as the server must be able to sign every delivery with them.
+#[typeshare::typeshare]
+#[derive(Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Text))]
+#[serde(try_from = "String")]
+pub struct WebhookSecret(String);
+
+crate::keys::api_key_impl!(
+    WebhookSecret,
+    prefix = "bencher_whsec_",
+    error = WebhookSecret
+);
+
+// The `Display` implementation is sanitized in release builds,
+// so the secret is stored using its unsanitized string value instead.
+#[cfg(feature = "db")]
+mod db {
+    use super::WebhookSecret;
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for WebhookSecret
+    where
+        DB: diesel::backend::Backend,
+        for<'a> String: diesel::serialize::ToSql<diesel::sql_types::Text, DB>
+            + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            out.set_value(self.0.clone());
+            Ok(diesel::serialize::IsNull::No)
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Text, DB> for WebhookSecret
+    where
+        DB: diesel::backend::Backend,
+        String: diesel::deserialize::FromSql<diesel::sql_types::Text, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            String::from_sql(bytes)?.try_into().map_err(Into::into)
+        }
+    }
+}
+
+crate::keys::api_key_tests!(
+    WebhookSecret,
+    sample = "bencher_whsec_aB3xY9mN2pQ7rS4tU8vW1zK5jL0fGh",
+);
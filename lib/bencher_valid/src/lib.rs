@@ -41,7 +41,7 @@ use error::REGEX_ERROR;
 pub use error::ValidError;
 pub use index::Index;
 pub use jwt::Jwt;
-pub use keys::{BencherKey, ProjectKey, ProjectKeyHash, UserKey, UserKeyHash};
+pub use keys::{BencherKey, ProjectKey, ProjectKeyHash, UserKey, UserKeyHash, WebhookSecret};
 pub use model::{
     Model,
     boundary::{Boundary, CdfBoundary, IqrBoundary, PercentageBoundary},
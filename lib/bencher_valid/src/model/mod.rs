@@ -39,6 +39,24 @@ pub struct Model {
     /// The upper boundary used to calculate the upper boundary limit.
     /// The requirements for this field depend on which `test` is selected.
     pub upper_boundary: Option<Boundary>,
+    /// The number of outliers required to generate an Alert.
+    /// The new Metric being tested must be an outlier and is counted towards this number.
+    /// If not set, an Alert is generated for every outlier.
+    pub confirm_outliers: Option<SampleSize>,
+    /// The number of most recent reports in which to look for `confirm_outliers`,
+    /// including the report for the new Metric being tested.
+    /// If not set, the `confirm_outliers` must be consecutive.
+    /// Requires `confirm_outliers` to be set.
+    pub confirm_reports: Option<SampleSize>,
+    /// The number of consecutive reports that must fall back within the boundary limits
+    /// to automatically resolve an active Alert.
+    /// If not set, Alerts are never automatically resolved.
+    pub resolve_reports: Option<SampleSize>,
+    /// The window of most recent branch versions for samples used to perform the test,
+    /// including the version for the new Metric being tested.
+    /// Samples from older versions will be omitted.
+    /// This is an alternative to the `window` of time, and the two cannot be used together.
+    pub version_window: Option<SampleSize>,
 }
 
 impl Model {
@@ -50,6 +68,10 @@ impl Model {
             window: None,
             lower_boundary: Some(Boundary::NINETY_NINE),
             upper_boundary: None,
+            confirm_outliers: None,
+            confirm_reports: None,
+            resolve_reports: None,
+            version_window: None,
         }
     }
 
@@ -61,6 +83,10 @@ impl Model {
             window: None,
             lower_boundary: None,
             upper_boundary: Some(Boundary::NINETY_NINE),
+            confirm_outliers: None,
+            confirm_reports: None,
+            resolve_reports: None,
+            version_window: None,
         }
     }
 
@@ -77,15 +103,29 @@ pub fn validate_model(model: Model) -> Result<(), ValidError> {
         window,
         lower_boundary,
         upper_boundary,
+        confirm_outliers,
+        confirm_reports,
+        resolve_reports: _,
+        version_window,
     } = model;
 
     validate_test_sample_size(test, min_sample_size, max_sample_size)?;
+    validate_confirm(confirm_outliers, confirm_reports)?;
+    if let (Some(window), Some(version_window)) = (window, version_window) {
+        return Err(ValidError::Windows {
+            window,
+            version_window,
+        });
+    }
 
     match test {
         ModelTest::Static => {
             if let Some(&window) = window.as_ref() {
                 return Err(ValidError::StaticWindow(window));
             }
+            if let Some(version_window) = version_window {
+                return Err(ValidError::StaticVersionWindow(version_window));
+            }
 
             match (lower_boundary.as_ref(), upper_boundary.as_ref()) {
                 (Some(&lower), Some(&upper)) => {
@@ -103,7 +143,7 @@ pub fn validate_model(model: Model) -> Result<(), ValidError> {
             validate_sample_size(min_sample_size, max_sample_size)?;
             validate_boundary::<PercentageBoundary>(lower_boundary, upper_boundary)
         },
-        ModelTest::ZScore | ModelTest::TTest | ModelTest::LogNormal => {
+        ModelTest::ZScore | ModelTest::TTest | ModelTest::LogNormal | ModelTest::MannWhitney => {
             validate_sample_size(min_sample_size, max_sample_size)?;
             validate_boundary::<CdfBoundary>(lower_boundary, upper_boundary)
         },
@@ -139,7 +179,8 @@ fn validate_test_sample_size(
         | ModelTest::TTest
         | ModelTest::LogNormal
         | ModelTest::Iqr
-        | ModelTest::DeltaIqr => SampleSize::TWO,
+        | ModelTest::DeltaIqr
+        | ModelTest::MannWhitney => SampleSize::TWO,
     };
     for sample_size in [min_sample_size, max_sample_size].into_iter().flatten() {
         if sample_size < test_min {
@@ -166,6 +207,20 @@ fn validate_sample_size(
     }
 }
 
+fn validate_confirm(
+    confirm_outliers: Option<SampleSize>,
+    confirm_reports: Option<SampleSize>,
+) -> Result<(), ValidError> {
+    let Some(reports) = confirm_reports else {
+        return Ok(());
+    };
+    match confirm_outliers {
+        Some(outliers) if outliers > reports => Err(ValidError::Confirm { outliers, reports }),
+        Some(_) => Ok(()),
+        None => Err(ValidError::ConfirmReports(reports)),
+    }
+}
+
 fn validate_boundary<B>(lower: Option<Boundary>, upper: Option<Boundary>) -> Result<(), ValidError>
 where
     B: TryFrom<Boundary, Error = ValidError>,
@@ -196,7 +251,7 @@ pub fn is_valid_model(model: &str) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Boundary, ModelTest, SampleSize, ValidError};
+    use crate::{Boundary, ModelTest, SampleSize, ValidError, Window};
 
     use super::{Model, validate_model, validate_test_sample_size};
 
@@ -211,6 +266,10 @@ mod tests {
             window: None,
             lower_boundary: None,
             upper_boundary: Some(Boundary::NINETY_NINE),
+            confirm_outliers: None,
+            confirm_reports: None,
+            resolve_reports: None,
+            version_window: None,
         }
     }
 
@@ -225,6 +284,10 @@ mod tests {
             window: None,
             lower_boundary: None,
             upper_boundary: Some(Boundary::NINETY_NINE),
+            confirm_outliers: None,
+            confirm_reports: None,
+            resolve_reports: None,
+            version_window: None,
         }
     }
 
@@ -370,4 +433,86 @@ mod tests {
             "unexpected error: {err:?}",
         );
     }
+
+    #[test]
+    fn confirm_outliers_within_reports() {
+        let model = Model {
+            confirm_outliers: Some(SampleSize::TWO),
+            confirm_reports: Some(SampleSize::THIRTY),
+            ..t_test_model(None, None)
+        };
+        validate_model(model).unwrap();
+        let model = Model {
+            confirm_outliers: Some(SampleSize::TWO),
+            confirm_reports: None,
+            ..t_test_model(None, None)
+        };
+        validate_model(model).unwrap();
+    }
+
+    #[test]
+    fn confirm_outliers_greater_than_reports_rejected() {
+        let model = Model {
+            confirm_outliers: Some(SampleSize::THIRTY),
+            confirm_reports: Some(SampleSize::TWO),
+            ..t_test_model(None, None)
+        };
+        let err = validate_model(model).unwrap_err();
+        assert!(
+            matches!(err, ValidError::Confirm { .. }),
+            "unexpected error: {err:?}",
+        );
+    }
+
+    #[test]
+    fn confirm_reports_without_outliers_rejected() {
+        let model = Model {
+            confirm_outliers: None,
+            confirm_reports: Some(SampleSize::TWO),
+            ..t_test_model(None, None)
+        };
+        let err = validate_model(model).unwrap_err();
+        assert!(
+            matches!(err, ValidError::ConfirmReports(_)),
+            "unexpected error: {err:?}",
+        );
+    }
+
+    #[test]
+    fn version_window() {
+        let model = Model {
+            version_window: Some(SampleSize::THIRTY),
+            ..t_test_model(None, None)
+        };
+        validate_model(model).unwrap();
+    }
+
+    #[test]
+    fn version_window_with_window_rejected() {
+        let model = Model {
+            window: Some(Window::THIRTY),
+            version_window: Some(SampleSize::THIRTY),
+            ..t_test_model(None, None)
+        };
+        let err = validate_model(model).unwrap_err();
+        assert!(
+            matches!(err, ValidError::Windows { .. }),
+            "unexpected error: {err:?}",
+        );
+    }
+
+    #[test]
+    fn static_version_window_rejected() {
+        let model = Model {
+            test: ModelTest::Static,
+            max_sample_size: None,
+            version_window: Some(SampleSize::THIRTY),
+            ..t_test_model(None, None)
+        };
+        let err = validate_model(model).unwrap_err();
+        assert!(
+            matches!(err, ValidError::StaticVersionWindow(_)),
+            "unexpected error: {err:?}",
+        );
+    }
 }
@@ -9,6 +9,7 @@ use rmcp::model::{CallToolResult, Tool};
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde_json::Value;
+use slog::Logger;
 
 use super::{input_schema, json_result, list_result, parse_input};
 
@@ -86,11 +87,12 @@ pub fn update_tool(name: &'static str) -> Tool {
 }
 
 pub async fn update(
+    log: &Logger,
     context: &ApiContext,
     api_actor: &ApiActor,
     arguments: Value,
 ) -> Result<CallToolResult, HttpError> {
     let UpdateAlertInput { path, alert } = parse_input(arguments)?;
-    let json = patch_inner(context, api_actor, path, alert).await?;
+    let json = patch_inner(log, context, api_actor, path, alert).await?;
     json_result(&json)
 }
@@ -227,7 +227,7 @@ impl McpTool {
             Self::UpdateThreshold => Box::pin(thresholds::update(context, &api_actor, arguments)),
             Self::ListAlerts => Box::pin(alerts::list(context, &api_actor, arguments)),
             Self::ViewAlert => Box::pin(alerts::view(context, &api_actor, arguments)),
-            Self::UpdateAlert => Box::pin(alerts::update(context, &api_actor, arguments)),
+            Self::UpdateAlert => Box::pin(alerts::update(log, context, &api_actor, arguments)),
             Self::ViewMetric => Box::pin(metrics::view(context, &api_actor, arguments)),
             Self::QueryPerf => Box::pin(perf::query(log, context, &api_actor, arguments)),
             Self::PerfImage => Box::pin(perf::image(log, context, &api_actor, arguments)),
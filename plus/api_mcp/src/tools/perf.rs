@@ -37,7 +37,8 @@ pub fn query_tool(name: &'static str) -> Tool {
          This is the primary tool for investigating performance history and regressions. \
          `branches`, `testbeds`, `benchmarks`, and `measures` are comma-separated lists of UUIDs; \
          use the corresponding list tools to find them. \
-         `start_time` and `end_time` bound the results in milliseconds since epoch.",
+         `start_time` and `end_time` bound the results in milliseconds since epoch, \
+         and `versions` limits the results to the most recent branch versions.",
         input_schema::<QueryPerfInput>(),
     )
 }
@@ -57,8 +58,10 @@ pub async fn query(
 pub fn image_tool(name: &'static str) -> Tool {
     Tool::new(
         name,
-        "Render a perf plot image (JPEG) for a project. \
-         Takes the same query arguments as `query_perf`, plus an optional `title`.",
+        "Render a perf plot image for a project. \
+         Takes the same query arguments as `query_perf`, plus an optional `title`, \
+         an optional `format` (`jpeg` or `svg`, defaulting to `jpeg`), \
+         and optional `panels` (`measure` or `benchmark`) to draw one panel per measure or benchmark.",
         input_schema::<PerfImageInput>(),
     )
 }
@@ -71,12 +74,24 @@ pub async fn image(
 ) -> Result<CallToolResult, HttpError> {
     let PerfImageInput { path, mut query } = parse_input(arguments)?;
     let title = query.title.take();
+    let format = query.format.unwrap_or_default();
+    let panels = query.panels;
     let query_params: JsonPerfQueryParams = query.into();
     let query: JsonPerfQuery = query_params.try_into().map_err(bad_request_error)?;
-    let jpeg = img::get_inner(log, context, path, title.as_deref(), query, api_actor).await?;
-    let data = STANDARD.encode(jpeg);
+    let img = img::get_inner(
+        log,
+        context,
+        path,
+        title.as_deref(),
+        format,
+        panels,
+        query,
+        api_actor,
+    )
+    .await?;
+    let data = STANDARD.encode(img);
     Ok(CallToolResult::success(vec![ContentBlock::image(
         data,
-        "image/jpeg",
+        format.content_type(),
     )]))
 }
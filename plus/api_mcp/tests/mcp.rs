@@ -373,6 +373,45 @@ async fn mcp_submit_run_project_key() {
     assert_eq!(content["total_count"], 1);
 }
 
+// The perf image tool renders the requested image format
+#[tokio::test]
+async fn mcp_perf_image_format() {
+    let server = TestServer::new().await;
+    let user = server.signup("MCP Image", "mcp-image@bencher.dev").await;
+    let org = server.create_org(&user, "MCP Image Org").await;
+    let project = server
+        .create_project(&user, &org, "MCP Image Project")
+        .await;
+    let project_slug: &str = project.slug.as_ref();
+
+    let uuid = "00000000-0000-0000-0000-000000000000";
+    for (format, mime_type) in [
+        (None, "image/jpeg"),
+        (Some("jpeg"), "image/jpeg"),
+        (Some("svg"), "image/svg+xml"),
+    ] {
+        let mut arguments = json!({
+            "project": project_slug,
+            "branches": uuid,
+            "testbeds": uuid,
+            "benchmarks": uuid,
+            "measures": uuid,
+        });
+        if let Some(format) = format {
+            arguments["format"] = json!(format);
+        }
+        let result = mcp_result(
+            &server,
+            Some(&user.token),
+            &call_tool_request("perf_image", &arguments),
+        )
+        .await;
+        assert_ne!(result["isError"], true, "Unexpected tool error: {result}");
+        assert_eq!(result["content"][0]["type"], "image");
+        assert_eq!(result["content"][0]["mimeType"], mime_type);
+    }
+}
+
 // Anonymous requests cannot read a private project
 #[tokio::test]
 async fn mcp_anonymous_private_project() {
@@ -2744,6 +2744,8 @@ async fn reprocess_completed_jobs_malformed_output() {
             stdout: Some("this is not valid benchmark output at all!!!".to_owned()),
             stderr: None,
             output: None,
+            metrics: None,
+            dropped: None,
         }],
         error: None,
     };
@@ -241,6 +241,8 @@ async fn channel_lifecycle_completed() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         },
     )
@@ -320,6 +322,8 @@ async fn channel_completed_records_job_duration() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         },
     )
@@ -366,6 +370,8 @@ async fn channel_lifecycle_failed() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: "segfault".to_owned(),
         },
@@ -660,6 +666,8 @@ async fn channel_lifecycle_with_full_spec() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         },
     )
@@ -832,6 +840,8 @@ async fn channel_completed_before_running() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         },
     )
@@ -862,6 +872,8 @@ async fn channel_failed_from_claimed() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: "command not found".to_owned(),
         },
@@ -904,6 +916,8 @@ async fn channel_completed_ack_no_close() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         },
     )
@@ -938,6 +952,8 @@ async fn channel_failed_ack_no_close() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: "test failure".to_owned(),
         },
@@ -1004,6 +1020,8 @@ async fn channel_completed_with_output() {
                 stdout: Some("line of output\n".into()),
                 stderr: None,
                 output: Some(output),
+                metrics: None,
+                dropped: None,
             }],
         },
     )
@@ -1065,6 +1083,8 @@ async fn channel_completed_overrides_failed() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         },
     )
@@ -1103,6 +1123,8 @@ async fn channel_failed_with_output() {
                 stdout: Some("partial output\n".into()),
                 stderr: Some("error output\n".into()),
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: "benchmark crashed".to_owned(),
         },
@@ -1138,6 +1160,8 @@ async fn channel_completed_with_stderr_only() {
                 stdout: None,
                 stderr: Some("warning: benchmark variance high\n".into()),
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         },
     )
@@ -1173,6 +1197,8 @@ async fn channel_completed_result_processing_failure() {
                 stdout: Some("this is not valid benchmark output".into()),
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         },
     )
@@ -1218,18 +1244,24 @@ async fn channel_completed_multiple_iterations() {
                     stdout: None,
                     stderr: None,
                     output: None,
+                    metrics: None,
+                    dropped: None,
                 },
                 JsonIterationOutput {
                     exit_code: 0,
                     stdout: None,
                     stderr: None,
                     output: None,
+                    metrics: None,
+                    dropped: None,
                 },
                 JsonIterationOutput {
                     exit_code: 0,
                     stdout: None,
                     stderr: None,
                     output: None,
+                    metrics: None,
+                    dropped: None,
                 },
             ],
         },
@@ -1280,12 +1312,16 @@ async fn channel_completed_multiple_iterations_with_file_output() {
                     stdout: None,
                     stderr: None,
                     output: Some(output1),
+                    metrics: None,
+                    dropped: None,
                 },
                 JsonIterationOutput {
                     exit_code: 0,
                     stdout: None,
                     stderr: None,
                     output: Some(output2),
+                    metrics: None,
+                    dropped: None,
                 },
             ],
         },
@@ -1325,12 +1361,16 @@ async fn channel_failed_multiple_iterations() {
                     stdout: None,
                     stderr: None,
                     output: None,
+                    metrics: None,
+                    dropped: None,
                 },
                 JsonIterationOutput {
                     exit_code: 1,
                     stdout: None,
                     stderr: Some("benchmark crashed on iteration 2\n".into()),
                     output: None,
+                    metrics: None,
+                    dropped: None,
                 },
             ],
             error: "iteration 2 failed".to_owned(),
@@ -1602,6 +1642,8 @@ async fn channel_completed_after_concurrent_cancel() {
                 stdout: Some("benchmark results\n".into()),
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         },
     )
@@ -1646,6 +1688,8 @@ async fn channel_completed_after_concurrent_failure() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         },
     )
@@ -1688,6 +1732,8 @@ async fn channel_completed_idempotent_duplicate() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         },
     )
@@ -1730,6 +1776,8 @@ async fn channel_failed_after_concurrent_cancel() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: "benchmark crashed".to_owned(),
         },
@@ -1774,6 +1822,8 @@ async fn channel_failed_after_concurrent_completion() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: "killed".to_owned(),
         },
@@ -1817,6 +1867,8 @@ async fn channel_failed_idempotent_duplicate() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: "crash".to_owned(),
         },
@@ -1852,6 +1904,8 @@ async fn channel_completed_rejects_non_terminal_unexpected_state() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         },
     )
@@ -2153,6 +2207,8 @@ async fn channel_multi_job_cycle() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         },
     )
@@ -2203,6 +2259,8 @@ async fn channel_multi_job_cycle() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         },
     )
@@ -2261,6 +2319,8 @@ async fn channel_completed_during_idle() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         },
     )
@@ -2296,6 +2356,8 @@ async fn channel_completed_during_idle_idempotent() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         },
     )
@@ -2319,6 +2381,8 @@ async fn channel_completed_during_idle_idempotent() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         },
     )
@@ -2373,6 +2437,8 @@ async fn channel_failed_during_idle() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: "segfault on reconnect".to_owned(),
         },
@@ -2460,6 +2526,8 @@ async fn channel_completed_wrong_job_uuid_during_idle() {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         },
     )
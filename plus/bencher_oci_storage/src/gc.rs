@@ -0,0 +1,657 @@
+//! OCI Registry Garbage Collection
+//!
+//! A mark-and-sweep collector over the manifests, blobs, and referrers of each repository.
+//!
+//! ## Mark
+//! The roots of a repository are:
+//! - every manifest that a tag points to
+//! - every manifest stored within the grace period (the upload timeout),
+//!   so an image that is still being pushed is never collected out from under its client
+//! - any extra roots given by the caller, such as the images of jobs that have yet to run
+//!
+//! From the roots, indexes mark their child manifests,
+//! image manifests mark their config and layer blobs,
+//! and any referrer whose subject is marked is itself marked.
+//!
+//! ## Sweep
+//! Unmarked manifests are removed first, then unmarked blobs older than the grace period,
+//! and finally any referrer links left dangling.
+//! If a marked manifest can not be parsed, its references are unknown,
+//! so nothing in that repository is removed.
+//!
+//! Stale uploads are expired at the end of every collection.
+
+use std::collections::{HashMap, HashSet};
+
+use bencher_json::{JsonRegistryGcReport, ProjectUuid, oci::Manifest};
+use slog::{info, warn};
+
+use crate::storage::{OciStorage, OciStorageError};
+use crate::types::{Digest, Tag};
+
+/// Extra manifests to keep per repository, in addition to those that are tagged
+pub type GcRoots = HashMap<ProjectUuid, HashSet<Digest>>;
+
+/// A content-addressed object in a repository
+pub(crate) struct StoredObject {
+    pub digest: Digest,
+    /// Size in bytes
+    pub size: u64,
+    /// Unix timestamp of the last modification, if known
+    pub modified: Option<i64>,
+}
+
+/// A link from a subject manifest to one of its referrers
+pub(crate) struct ReferrerLink {
+    pub subject: Digest,
+    pub referrer: Digest,
+    /// Unix timestamp of the last modification, if known
+    pub modified: Option<i64>,
+}
+
+#[derive(Debug, Default)]
+struct Marked {
+    manifests: HashSet<Digest>,
+    blobs: HashSet<Digest>,
+}
+
+/// The manifests and blobs directly referenced by a manifest
+#[derive(Debug, Default, PartialEq, Eq)]
+struct References {
+    manifests: Vec<Digest>,
+    blobs: Vec<Digest>,
+}
+
+impl References {
+    /// Parses the references out of a manifest.
+    ///
+    /// Returns `None` if the manifest or any of its digests are invalid.
+    fn parse(content: &[u8]) -> Option<Self> {
+        fn parse_digests(digests: impl Iterator<Item = impl AsRef<str>>) -> Option<Vec<Digest>> {
+            digests.map(|digest| digest.as_ref().parse().ok()).collect()
+        }
+
+        Some(match Manifest::from_bytes(content).ok()? {
+            Manifest::OciImageManifest(manifest) => Self {
+                manifests: Vec::new(),
+                blobs: parse_digests(
+                    std::iter::once(&manifest.config)
+                        .chain(&manifest.layers)
+                        .map(|descriptor| descriptor.digest.as_str()),
+                )?,
+            },
+            Manifest::DockerManifestV2(manifest) => Self {
+                manifests: Vec::new(),
+                blobs: parse_digests(
+                    std::iter::once(&manifest.config)
+                        .chain(&manifest.layers)
+                        .map(|descriptor| descriptor.digest.as_str()),
+                )?,
+            },
+            Manifest::OciImageIndex(index) => Self {
+                manifests: parse_digests(
+                    index
+                        .manifests
+                        .iter()
+                        .map(|descriptor| descriptor.digest.as_str()),
+                )?,
+                blobs: Vec::new(),
+            },
+            Manifest::DockerManifestList(list) => Self {
+                manifests: parse_digests(
+                    list.manifests
+                        .iter()
+                        .map(|descriptor| descriptor.digest.as_str()),
+                )?,
+                blobs: Vec::new(),
+            },
+        })
+    }
+}
+
+/// Whether an object was last modified before the grace period.
+/// Objects with an unknown modification time are never considered expired.
+fn is_expired(modified: Option<i64>, now: i64, grace_period: i64) -> bool {
+    modified.is_some_and(|modified| now.saturating_sub(modified) > grace_period)
+}
+
+/// Thin view type for registry garbage collection.
+pub struct GarbageCollector<'a>(&'a OciStorage);
+
+impl<'a> GarbageCollector<'a> {
+    pub(crate) fn new(storage: &'a OciStorage) -> Self {
+        Self(storage)
+    }
+
+    /// Removes all manifests, blobs, and referrer links that are no longer reachable
+    /// from a tag or from the given `roots`, along with any stale uploads.
+    ///
+    /// If `dry_run` is set, nothing is removed and the report counts what would have been.
+    pub async fn run(
+        &self,
+        roots: &GcRoots,
+        dry_run: bool,
+    ) -> Result<JsonRegistryGcReport, OciStorageError> {
+        let mut report = JsonRegistryGcReport {
+            dry_run,
+            ..Default::default()
+        };
+
+        for repository in self.list_repositories().await? {
+            self.collect_repository(&repository, roots.get(&repository), &mut report)
+                .await?;
+            report.repositories += 1;
+        }
+        report.uploads = self.cleanup_stale_uploads(dry_run).await;
+
+        info!(self.0.log(), "Registry garbage collection complete"; "report" => ?report);
+        Ok(report)
+    }
+
+    async fn collect_repository(
+        &self,
+        repository: &ProjectUuid,
+        extra_roots: Option<&HashSet<Digest>>,
+        report: &mut JsonRegistryGcReport,
+    ) -> Result<(), OciStorageError> {
+        // Filesystem and S3 timestamps are both in the OS wall-clock time domain
+        let (_, now) = self.0.clock().timestamps();
+        let grace_period = i64::try_from(self.0.upload_timeout()).unwrap_or(i64::MAX);
+
+        let manifests = self.list_manifests(repository).await?;
+        let stored = manifests
+            .iter()
+            .map(|manifest| manifest.digest.clone())
+            .collect::<HashSet<_>>();
+        let links = self.list_referrer_links(repository).await?;
+
+        let mut roots = self.tagged_manifests(repository).await?;
+        roots.extend(
+            manifests
+                .iter()
+                .filter(|manifest| !is_expired(manifest.modified, now, grace_period))
+                .map(|manifest| manifest.digest.clone()),
+        );
+        roots.extend(extra_roots.into_iter().flatten().cloned());
+
+        let Some(marked) = self.mark(repository, &stored, &links, roots).await? else {
+            warn!(
+                self.0.log(),
+                "Skipping garbage collection for repository with an unreadable manifest";
+                "repository" => %repository
+            );
+            return Ok(());
+        };
+
+        for manifest in manifests
+            .iter()
+            .filter(|manifest| !marked.manifests.contains(&manifest.digest))
+        {
+            if !report.dry_run {
+                self.0.delete_manifest(repository, &manifest.digest).await?;
+            }
+            report.manifests += 1;
+            report.bytes = report.bytes.saturating_add(manifest.size);
+        }
+
+        for blob in self.list_blobs(repository).await? {
+            if marked.blobs.contains(&blob.digest) || !is_expired(blob.modified, now, grace_period)
+            {
+                continue;
+            }
+            if !report.dry_run {
+                self.0.delete_blob(repository, &blob.digest).await?;
+            }
+            report.blobs += 1;
+            report.bytes = report.bytes.saturating_add(blob.size);
+        }
+
+        for link in links.iter().filter(|link| {
+            !marked.manifests.contains(&link.referrer)
+                && is_expired(link.modified, now, grace_period)
+        }) {
+            if !report.dry_run {
+                self.delete_referrer_link(repository, &link.subject, &link.referrer)
+                    .await?;
+            }
+            report.referrers += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Resolves every tag in a repository to its manifest digest
+    async fn tagged_manifests(
+        &self,
+        repository: &ProjectUuid,
+    ) -> Result<HashSet<Digest>, OciStorageError> {
+        let mut digests = HashSet::new();
+        for tag in self.0.list_tags(repository, None, None).await?.tags {
+            let tag = tag
+                .parse::<Tag>()
+                .map_err(|e| OciStorageError::InvalidContent(e.to_string()))?;
+            match self.0.resolve_tag(repository, &tag).await {
+                Ok(digest) => {
+                    digests.insert(digest);
+                },
+                // The tag was removed since it was listed
+                Err(OciStorageError::ManifestNotFound(_)) => {},
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(digests)
+    }
+
+    /// Marks every manifest and blob reachable from the roots.
+    ///
+    /// Returns `None` if a reachable manifest can not be parsed.
+    async fn mark(
+        &self,
+        repository: &ProjectUuid,
+        stored: &HashSet<Digest>,
+        links: &[ReferrerLink],
+        roots: HashSet<Digest>,
+    ) -> Result<Option<Marked>, OciStorageError> {
+        let mut marked = Marked::default();
+        let mut queue = roots.into_iter().collect::<Vec<_>>();
+        loop {
+            while let Some(digest) = queue.pop() {
+                if !stored.contains(&digest) || !marked.manifests.insert(digest.clone()) {
+                    continue;
+                }
+                let content = match self.0.get_manifest_by_digest(repository, &digest).await {
+                    Ok(content) => content,
+                    // The manifest was removed since it was listed
+                    Err(OciStorageError::ManifestNotFound(_)) => continue,
+                    Err(e) => return Err(e),
+                };
+                let Some(references) = References::parse(&content) else {
+                    return Ok(None);
+                };
+                queue.extend(references.manifests);
+                marked.blobs.extend(references.blobs);
+            }
+
+            // Referrers are only reachable through their subject
+            queue.extend(
+                links
+                    .iter()
+                    .filter(|link| {
+                        marked.manifests.contains(&link.subject)
+                            && !marked.manifests.contains(&link.referrer)
+                            && stored.contains(&link.referrer)
+                    })
+                    .map(|link| link.referrer.clone()),
+            );
+            if queue.is_empty() {
+                return Ok(Some(marked));
+            }
+        }
+    }
+
+    // ==================== Backend Dispatch ====================
+
+    async fn list_repositories(&self) -> Result<Vec<ProjectUuid>, OciStorageError> {
+        match self.0 {
+            OciStorage::S3(s3) => s3.list_repositories().await,
+            OciStorage::Local(local) => local.list_repositories().await,
+        }
+    }
+
+    async fn list_manifests(
+        &self,
+        repository: &ProjectUuid,
+    ) -> Result<Vec<StoredObject>, OciStorageError> {
+        match self.0 {
+            OciStorage::S3(s3) => s3.list_manifests(repository).await,
+            OciStorage::Local(local) => local.list_manifests(repository).await,
+        }
+    }
+
+    async fn list_blobs(
+        &self,
+        repository: &ProjectUuid,
+    ) -> Result<Vec<StoredObject>, OciStorageError> {
+        match self.0 {
+            OciStorage::S3(s3) => s3.list_blobs(repository).await,
+            OciStorage::Local(local) => local.list_blobs(repository).await,
+        }
+    }
+
+    async fn list_referrer_links(
+        &self,
+        repository: &ProjectUuid,
+    ) -> Result<Vec<ReferrerLink>, OciStorageError> {
+        match self.0 {
+            OciStorage::S3(s3) => s3.list_referrer_links(repository).await,
+            OciStorage::Local(local) => local.list_referrer_links(repository).await,
+        }
+    }
+
+    async fn delete_referrer_link(
+        &self,
+        repository: &ProjectUuid,
+        subject_digest: &Digest,
+        referrer_digest: &Digest,
+    ) -> Result<(), OciStorageError> {
+        match self.0 {
+            OciStorage::S3(s3) => {
+                s3.delete_referrer_link(repository, subject_digest, referrer_digest)
+                    .await
+            },
+            OciStorage::Local(local) => {
+                local
+                    .delete_referrer_link(repository, subject_digest, referrer_digest)
+                    .await
+            },
+        }
+    }
+
+    async fn cleanup_stale_uploads(&self, dry_run: bool) -> u64 {
+        match self.0 {
+            OciStorage::S3(s3) => s3.cleanup_stale_uploads(dry_run).await,
+            OciStorage::Local(local) => local.cleanup_stale_uploads(dry_run).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::path::Path;
+
+    use bencher_json::ProjectUuid;
+    use bytes::Bytes;
+
+    use super::{GcRoots, References};
+    use crate::storage::OciStorage;
+    use crate::types::{Digest, Tag};
+
+    fn test_storage(tmp: &tempfile::TempDir) -> OciStorage {
+        let log = slog::Logger::root(slog::Discard, slog::o!());
+        OciStorage::try_from_config(log, None, &tmp.path().join("bencher.db"), None, None, None)
+            .unwrap()
+    }
+
+    fn test_repository() -> ProjectUuid {
+        "00000000-0000-0000-0000-000000000001".parse().unwrap()
+    }
+
+    async fn put_blob(storage: &OciStorage, repository: &ProjectUuid, data: &[u8]) -> Digest {
+        let digest = Digest::from_sha256_bytes(data);
+        let upload_id = storage.start_upload(repository).await.unwrap();
+        storage
+            .append_upload(&upload_id, Bytes::copy_from_slice(data))
+            .await
+            .unwrap();
+        storage.complete_upload(&upload_id, &digest).await.unwrap()
+    }
+
+    async fn put_manifest(
+        storage: &OciStorage,
+        repository: &ProjectUuid,
+        json: &serde_json::Value,
+        tag: Option<&str>,
+    ) -> Digest {
+        let content = json.to_string();
+        let manifest = bencher_json::oci::Manifest::from_bytes(content.as_bytes()).unwrap();
+        let tag = tag.map(|tag| tag.parse::<Tag>().unwrap());
+        storage
+            .put_manifest(repository, Bytes::from(content), tag.as_ref(), &manifest)
+            .await
+            .unwrap()
+    }
+
+    fn image_manifest(config: &Digest, layers: &[&Digest]) -> serde_json::Value {
+        serde_json::json!({
+            "schemaVersion": 2,
+            "mediaType": "application/vnd.oci.image.manifest.v1+json",
+            "config": {
+                "mediaType": "application/vnd.oci.image.config.v1+json",
+                "digest": config.as_str(),
+                "size": 1
+            },
+            "layers": layers.iter().map(|layer| serde_json::json!({
+                "mediaType": "application/vnd.oci.image.layer.v1.tar+gzip",
+                "digest": layer.as_str(),
+                "size": 1
+            })).collect::<Vec<_>>()
+        })
+    }
+
+    fn referrer_manifest(config: &Digest, subject: &Digest) -> serde_json::Value {
+        let mut manifest = image_manifest(config, &[]);
+        manifest["subject"] = serde_json::json!({
+            "mediaType": "application/vnd.oci.image.manifest.v1+json",
+            "digest": subject.as_str(),
+            "size": 1
+        });
+        manifest
+    }
+
+    /// Backdate every file in the registry so it is past the grace period
+    fn age_registry(dir: &Path) {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                age_registry(&path);
+            } else {
+                std::fs::File::options()
+                    .write(true)
+                    .open(&path)
+                    .unwrap()
+                    .set_modified(std::time::UNIX_EPOCH)
+                    .unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn references_image_manifest() {
+        let config = Digest::from_sha256_bytes(b"config");
+        let layer = Digest::from_sha256_bytes(b"layer");
+        let content = image_manifest(&config, &[&layer]).to_string();
+        assert_eq!(
+            References::parse(content.as_bytes()),
+            Some(References {
+                manifests: Vec::new(),
+                blobs: vec![config, layer],
+            })
+        );
+    }
+
+    #[test]
+    fn references_image_index() {
+        let child = Digest::from_sha256_bytes(b"child");
+        let content = serde_json::json!({
+            "schemaVersion": 2,
+            "mediaType": "application/vnd.oci.image.index.v1+json",
+            "manifests": [{
+                "mediaType": "application/vnd.oci.image.manifest.v1+json",
+                "digest": child.as_str(),
+                "size": 1
+            }]
+        })
+        .to_string();
+        assert_eq!(
+            References::parse(content.as_bytes()),
+            Some(References {
+                manifests: vec![child],
+                blobs: Vec::new(),
+            })
+        );
+    }
+
+    #[test]
+    fn references_invalid() {
+        assert_eq!(References::parse(b"not a manifest"), None);
+    }
+
+    #[tokio::test]
+    async fn gc_removes_unreferenced() {
+        let tmp = tempfile::tempdir().unwrap();
+        let storage = test_storage(&tmp);
+        let repo = test_repository();
+
+        let config = put_blob(&storage, &repo, b"config").await;
+        let layer = put_blob(&storage, &repo, b"layer").await;
+        let tagged = put_manifest(
+            &storage,
+            &repo,
+            &image_manifest(&config, &[&layer]),
+            Some("latest"),
+        )
+        .await;
+        let old_layer = put_blob(&storage, &repo, b"old layer").await;
+        let untagged = put_manifest(
+            &storage,
+            &repo,
+            &image_manifest(&config, &[&old_layer]),
+            None,
+        )
+        .await;
+        let orphan = put_blob(&storage, &repo, b"orphan").await;
+        age_registry(&tmp.path().join("registry"));
+
+        let gc = storage.garbage_collector();
+        let report = gc.run(&GcRoots::new(), true).await.unwrap();
+        assert!(report.dry_run);
+        assert_eq!(report.repositories, 1);
+        assert_eq!(report.manifests, 1);
+        assert_eq!(report.blobs, 2);
+        // Nothing is removed by a dry run
+        assert!(storage.manifest_exists(&repo, &untagged).await.unwrap());
+        assert!(storage.blob_exists(&repo, &orphan).await.unwrap());
+
+        let report = gc.run(&GcRoots::new(), false).await.unwrap();
+        assert!(!report.dry_run);
+        assert_eq!(report.manifests, 1);
+        assert_eq!(report.blobs, 2);
+        assert!(storage.manifest_exists(&repo, &tagged).await.unwrap());
+        assert!(!storage.manifest_exists(&repo, &untagged).await.unwrap());
+        for blob in [&config, &layer] {
+            assert!(storage.blob_exists(&repo, blob).await.unwrap());
+        }
+        for blob in [&old_layer, &orphan] {
+            assert!(!storage.blob_exists(&repo, blob).await.unwrap());
+        }
+
+        // A second run has nothing left to collect
+        let report = gc.run(&GcRoots::new(), false).await.unwrap();
+        assert_eq!((report.manifests, report.blobs), (0, 0));
+    }
+
+    #[tokio::test]
+    async fn gc_keeps_recent() {
+        let tmp = tempfile::tempdir().unwrap();
+        let storage = test_storage(&tmp);
+        let repo = test_repository();
+
+        let config = put_blob(&storage, &repo, b"config").await;
+        let untagged = put_manifest(&storage, &repo, &image_manifest(&config, &[]), None).await;
+        let orphan = put_blob(&storage, &repo, b"orphan").await;
+
+        let report = storage
+            .garbage_collector()
+            .run(&GcRoots::new(), false)
+            .await
+            .unwrap();
+        assert_eq!((report.manifests, report.blobs), (0, 0));
+        assert!(storage.manifest_exists(&repo, &untagged).await.unwrap());
+        assert!(storage.blob_exists(&repo, &orphan).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn gc_keeps_extra_roots() {
+        let tmp = tempfile::tempdir().unwrap();
+        let storage = test_storage(&tmp);
+        let repo = test_repository();
+
+        let config = put_blob(&storage, &repo, b"config").await;
+        let untagged = put_manifest(&storage, &repo, &image_manifest(&config, &[]), None).await;
+        age_registry(&tmp.path().join("registry"));
+
+        let roots = GcRoots::from([(repo, HashSet::from([untagged.clone()]))]);
+        let report = storage
+            .garbage_collector()
+            .run(&roots, false)
+            .await
+            .unwrap();
+        assert_eq!((report.manifests, report.blobs), (0, 0));
+        assert!(storage.manifest_exists(&repo, &untagged).await.unwrap());
+        assert!(storage.blob_exists(&repo, &config).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn gc_referrers_follow_subject() {
+        let tmp = tempfile::tempdir().unwrap();
+        let storage = test_storage(&tmp);
+        let repo = test_repository();
+
+        let config = put_blob(&storage, &repo, b"config").await;
+        let signature = put_blob(&storage, &repo, b"signature").await;
+        let tagged = put_manifest(
+            &storage,
+            &repo,
+            &image_manifest(&config, &[]),
+            Some("latest"),
+        )
+        .await;
+        let live_referrer = put_manifest(
+            &storage,
+            &repo,
+            &referrer_manifest(&signature, &tagged),
+            None,
+        )
+        .await;
+        let old_config = put_blob(&storage, &repo, b"old config").await;
+        let untagged = put_manifest(&storage, &repo, &image_manifest(&old_config, &[]), None).await;
+        let dead_referrer = put_manifest(
+            &storage,
+            &repo,
+            &referrer_manifest(&signature, &untagged),
+            None,
+        )
+        .await;
+        age_registry(&tmp.path().join("registry"));
+
+        let report = storage
+            .garbage_collector()
+            .run(&GcRoots::new(), false)
+            .await
+            .unwrap();
+        assert_eq!(report.manifests, 2);
+        assert_eq!(report.blobs, 1);
+        assert_eq!(report.referrers, 1);
+        assert!(
+            storage
+                .manifest_exists(&repo, &live_referrer)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !storage
+                .manifest_exists(&repo, &dead_referrer)
+                .await
+                .unwrap()
+        );
+        assert!(!storage.manifest_exists(&repo, &untagged).await.unwrap());
+        assert!(storage.blob_exists(&repo, &signature).await.unwrap());
+        assert!(!storage.blob_exists(&repo, &old_config).await.unwrap());
+        assert_eq!(
+            storage
+                .list_referrers(&repo, &tagged, None)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(
+            storage
+                .list_referrers(&repo, &untagged, None)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
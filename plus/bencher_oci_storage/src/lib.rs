@@ -10,12 +10,18 @@
 //! Two storage backends are supported:
 //! - **S3**: For production deployments with scalability and cross-instance consistency
 //! - **Local**: For development and single-instance deployments (stores files next to database)
+//!
+//! ## Garbage Collection
+//!
+//! Unreferenced manifests, blobs, and referrers are reclaimed by a mark-and-sweep collector,
+//! which also expires stale uploads.
 
 // Reference dev-dependency used only in integration tests to silence unused_crate_dependencies warning
 #[cfg(test)]
 use reqwest as _;
 
 mod error;
+mod gc;
 mod job_output;
 mod local;
 mod storage;
@@ -25,6 +31,7 @@ pub use bencher_json::Clock;
 pub use bencher_json::ProjectUuid;
 pub use bencher_json::system::config::DEFAULT_MAX_BODY_SIZE;
 pub use error::OciError;
+pub use gc::{GarbageCollector, GcRoots};
 pub use job_output::JobOutput;
 pub use local::OciLocalStorage;
 pub use storage::{
@@ -21,6 +21,7 @@ use tokio_util::io::ReaderStream;
 
 use bencher_json::ProjectUuid;
 
+use crate::gc::{ReferrerLink, StoredObject};
 use crate::storage::OciStorageError;
 use crate::types::{Digest, UploadId};
 
@@ -156,6 +157,19 @@ impl OciLocalStorage {
         self.max_body_size
     }
 
+    /// Returns the configured upload timeout in seconds
+    pub(crate) fn upload_timeout(&self) -> u64 {
+        self.upload_timeout
+    }
+
+    pub(crate) fn log(&self) -> &Logger {
+        &self.log
+    }
+
+    pub(crate) fn clock(&self) -> &crate::Clock {
+        &self.clock
+    }
+
     // ==================== Path Generation ====================
 
     /// Returns the directory for uploads
@@ -542,10 +556,24 @@ impl OciLocalStorage {
         let clock = self.clock.clone();
 
         tokio::spawn(async move {
-            cleanup_stale_uploads_local(&log, &uploads_dir, upload_timeout, clock).await;
+            cleanup_stale_uploads_local(&log, &uploads_dir, upload_timeout, clock, false).await;
         });
     }
 
+    /// Cleans up all stale uploads that have exceeded the timeout, returning how many there were.
+    ///
+    /// If `dry_run` is set, stale uploads are only counted.
+    pub(crate) async fn cleanup_stale_uploads(&self, dry_run: bool) -> u64 {
+        cleanup_stale_uploads_local(
+            &self.log,
+            &self.uploads_dir(),
+            self.upload_timeout,
+            self.clock.clone(),
+            dry_run,
+        )
+        .await
+    }
+
     // ==================== Blob Operations ====================
 
     /// Checks if a blob exists
@@ -957,26 +985,184 @@ impl OciLocalStorage {
 
         Ok(referrers)
     }
+
+    // ==================== Garbage Collection ====================
+
+    /// Lists all repositories in the registry
+    pub(crate) async fn list_repositories(&self) -> Result<Vec<ProjectUuid>, OciStorageError> {
+        let mut repositories = Vec::new();
+        for entry in read_dir_entries(&self.base_dir).await? {
+            if let Some(repository) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<ProjectUuid>().ok())
+            {
+                repositories.push(repository);
+            }
+        }
+        Ok(repositories)
+    }
+
+    /// Lists all manifests stored in a repository
+    pub(crate) async fn list_manifests(
+        &self,
+        repository: &ProjectUuid,
+    ) -> Result<Vec<StoredObject>, OciStorageError> {
+        list_digest_files(&self.repository_dir(repository).join("manifests")).await
+    }
+
+    /// Lists all blobs stored in a repository
+    pub(crate) async fn list_blobs(
+        &self,
+        repository: &ProjectUuid,
+    ) -> Result<Vec<StoredObject>, OciStorageError> {
+        list_digest_files(&self.repository_dir(repository).join("blobs")).await
+    }
+
+    /// Lists all referrer links stored in a repository
+    pub(crate) async fn list_referrer_links(
+        &self,
+        repository: &ProjectUuid,
+    ) -> Result<Vec<ReferrerLink>, OciStorageError> {
+        let referrers_dir = self.repository_dir(repository).join("referrers");
+        let mut links = Vec::new();
+        for algorithm in read_dir_entries(&referrers_dir).await? {
+            for subject in read_dir_entries(&algorithm.path()).await? {
+                let Ok(subject_digest) = format!(
+                    "{}:{}",
+                    algorithm.file_name().to_string_lossy(),
+                    subject.file_name().to_string_lossy()
+                )
+                .parse::<Digest>() else {
+                    continue;
+                };
+                for referrer in read_dir_entries(&subject.path()).await? {
+                    let Some(referrer_digest) = referrer
+                        .file_name()
+                        .to_str()
+                        .and_then(|name| name.split_once('-'))
+                        .and_then(|(alg, hex)| format!("{alg}:{hex}").parse::<Digest>().ok())
+                    else {
+                        continue;
+                    };
+                    let modified = referrer
+                        .metadata()
+                        .await
+                        .ok()
+                        .as_ref()
+                        .and_then(modified_secs);
+                    links.push(ReferrerLink {
+                        subject: subject_digest.clone(),
+                        referrer: referrer_digest,
+                        modified,
+                    });
+                }
+            }
+        }
+        Ok(links)
+    }
+
+    /// Deletes a referrer link
+    pub(crate) async fn delete_referrer_link(
+        &self,
+        repository: &ProjectUuid,
+        subject_digest: &Digest,
+        referrer_digest: &Digest,
+    ) -> Result<(), OciStorageError> {
+        let path = self.referrer_path(repository, subject_digest, referrer_digest);
+        match fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(OciStorageError::LocalStorage(format!(
+                "Failed to delete referrer: {e}"
+            ))),
+        }
+    }
+}
+
+/// Reads all entries in a directory, treating a missing directory as empty
+async fn read_dir_entries(path: &Path) -> Result<Vec<fs::DirEntry>, OciStorageError> {
+    let mut entries = match fs::read_dir(path).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => {
+            return Err(OciStorageError::LocalStorage(format!(
+                "Failed to read directory {}: {e}",
+                path.display()
+            )));
+        },
+    };
+    let mut dir_entries = Vec::new();
+    while let Some(entry) = entries.next_entry().await.map_err(|e| {
+        OciStorageError::LocalStorage(format!("Failed to read directory entry: {e}"))
+    })? {
+        dir_entries.push(entry);
+    }
+    Ok(dir_entries)
+}
+
+/// Lists content-addressed files stored as `{algorithm}/{hex}` under the given directory
+async fn list_digest_files(dir: &Path) -> Result<Vec<StoredObject>, OciStorageError> {
+    let mut objects = Vec::new();
+    for algorithm in read_dir_entries(dir).await? {
+        for file in read_dir_entries(&algorithm.path()).await? {
+            let Ok(digest) = format!(
+                "{}:{}",
+                algorithm.file_name().to_string_lossy(),
+                file.file_name().to_string_lossy()
+            )
+            .parse::<Digest>() else {
+                continue;
+            };
+            // The file may have been removed since it was listed
+            let Ok(metadata) = file.metadata().await else {
+                continue;
+            };
+            objects.push(StoredObject {
+                digest,
+                size: metadata.len(),
+                modified: modified_secs(&metadata),
+            });
+        }
+    }
+    Ok(objects)
+}
+
+/// Returns the modification time of a file as a Unix timestamp in seconds
+fn modified_secs(metadata: &std::fs::Metadata) -> Option<i64> {
+    let modified = metadata.modified().ok()?;
+    Some(
+        i64::try_from(
+            modified
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+        )
+        .unwrap_or(i64::MAX),
+    )
 }
 
 /// Cleans up all stale uploads in the given uploads directory.
 ///
 /// This is a standalone async function that can be spawned as a background task.
 /// Individual upload cleanup failures are logged but don't stop processing.
+/// Returns the number of stale uploads removed (or found, for a dry run).
 async fn cleanup_stale_uploads_local(
     log: &Logger,
     uploads_dir: &Path,
     upload_timeout: u64,
     clock: crate::Clock,
-) {
+    dry_run: bool,
+) -> u64 {
     let Ok(mut entries) = fs::read_dir(uploads_dir).await else {
         // Directory doesn't exist or can't be read - nothing to clean up
-        return;
+        return 0;
     };
 
     let (now, os_now) = clock.timestamps();
     let timeout_secs = i64::try_from(upload_timeout).unwrap_or(i64::MAX);
 
+    let mut removed = 0;
     loop {
         match entries.next_entry().await {
             Ok(Some(entry)) => {
@@ -1010,8 +1196,15 @@ async fn cleanup_stale_uploads_local(
                 };
 
                 // Remove stale uploads
-                if is_stale && let Err(e) = fs::remove_dir_all(entry.path()).await {
+                if !is_stale {
+                    continue;
+                }
+                if dry_run {
+                    removed += 1;
+                } else if let Err(e) = fs::remove_dir_all(entry.path()).await {
                     error!(log, "Failed to remove stale upload"; "upload_id" => &upload_id_str, "error" => %e);
+                } else {
+                    removed += 1;
                 }
             },
             Ok(None) => break,
@@ -1020,6 +1213,7 @@ async fn cleanup_stale_uploads_local(
             },
         }
     }
+    removed
 }
 
 /// Check whether a directory entry is stale based on its filesystem metadata.
@@ -1031,16 +1225,9 @@ async fn dir_is_stale(entry: &fs::DirEntry, now: i64, timeout_secs: i64) -> bool
         // Can't read metadata — skip rather than risk deleting an active upload
         return false;
     };
-    let Ok(modified) = metadata.modified() else {
+    let Some(modified_secs) = modified_secs(&metadata) else {
         return false;
     };
-    let modified_secs = i64::try_from(
-        modified
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs(),
-    )
-    .unwrap_or(i64::MAX);
     let dir_age = now.saturating_sub(modified_secs);
     dir_age > timeout_secs
 }
@@ -1252,7 +1439,15 @@ mod tests {
 
         // Run cleanup with 1-second timeout
         let log = Logger::root(slog::Discard, slog::o!());
-        cleanup_stale_uploads_local(&log, &storage.uploads_dir(), 1, crate::Clock::System).await;
+        let removed = cleanup_stale_uploads_local(
+            &log,
+            &storage.uploads_dir(),
+            1,
+            crate::Clock::System,
+            false,
+        )
+        .await;
+        assert_eq!(removed, 1);
 
         // Upload dir should be gone
         let upload_dir = storage.upload_dir(&upload_id);
@@ -1279,6 +1474,8 @@ mod tests {
                 stdout: Some("hello stdout".into()),
                 stderr: Some("hello stderr".into()),
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: None,
         };
@@ -1318,6 +1515,8 @@ mod tests {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: None,
         };
@@ -1345,6 +1544,8 @@ mod tests {
                 stdout: Some("first".into()),
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: None,
         };
@@ -1356,6 +1557,8 @@ mod tests {
                 stdout: Some("second".into()),
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: None,
         };
@@ -1383,6 +1586,8 @@ mod tests {
                 stdout: Some("full stdout".into()),
                 stderr: Some("full stderr".into()),
                 output: Some(file_outputs),
+                metrics: None,
+                dropped: None,
             }],
             error: None,
         };
@@ -1411,6 +1616,8 @@ mod tests {
                 stdout: Some("fail stdout".into()),
                 stderr: Some("fail stderr".into()),
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: Some("something went wrong".into()),
         };
@@ -1438,6 +1645,8 @@ mod tests {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: None,
         };
@@ -1466,6 +1675,8 @@ mod tests {
                 stdout: Some("project1".into()),
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: None,
         };
@@ -1475,6 +1686,8 @@ mod tests {
                 stdout: Some("project2".into()),
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: None,
         };
@@ -1502,6 +1715,8 @@ mod tests {
                 stdout: Some("only project1".into()),
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: None,
         };
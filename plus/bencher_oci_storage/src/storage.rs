@@ -40,6 +40,7 @@ use thiserror::Error;
 
 use bencher_json::Clock;
 
+use crate::gc::{ReferrerLink, StoredObject};
 use crate::local::{LocalBlobBody, OciLocalStorage};
 use crate::types::{Digest, UploadId};
 
@@ -327,6 +328,11 @@ impl OciStorage {
         crate::job_output::JobOutput::new(self)
     }
 
+    /// Returns a view type for registry garbage collection.
+    pub fn garbage_collector(&self) -> crate::gc::GarbageCollector<'_> {
+        crate::gc::GarbageCollector::new(self)
+    }
+
     /// Returns the configured maximum body size in bytes
     pub fn max_body_size(&self) -> u64 {
         match self {
@@ -335,6 +341,28 @@ impl OciStorage {
         }
     }
 
+    /// Returns the configured upload timeout in seconds
+    pub(crate) fn upload_timeout(&self) -> u64 {
+        match self {
+            Self::S3(s3) => s3.upload_timeout,
+            Self::Local(local) => local.upload_timeout(),
+        }
+    }
+
+    pub(crate) fn log(&self) -> &Logger {
+        match self {
+            Self::S3(s3) => &s3.log,
+            Self::Local(local) => local.log(),
+        }
+    }
+
+    pub(crate) fn clock(&self) -> &Clock {
+        match self {
+            Self::S3(s3) => &s3.clock,
+            Self::Local(local) => local.clock(),
+        }
+    }
+
     // ==================== Upload Operations ====================
 
     /// Starts a new upload session
@@ -1043,10 +1071,25 @@ impl OciS3Storage {
         let clock = self.clock.clone();
 
         tokio::spawn(async move {
-            cleanup_stale_uploads_s3(&log, client, config, upload_timeout, clock).await;
+            cleanup_stale_uploads_s3(&log, client, config, upload_timeout, clock, false).await;
         });
     }
 
+    /// Cleans up all stale uploads that have exceeded the timeout, returning how many there were.
+    ///
+    /// If `dry_run` is set, stale uploads are only counted.
+    pub(crate) async fn cleanup_stale_uploads(&self, dry_run: bool) -> u64 {
+        cleanup_stale_uploads_s3(
+            &self.log,
+            self.client.clone(),
+            self.config.clone(),
+            self.upload_timeout,
+            self.clock.clone(),
+            dry_run,
+        )
+        .await
+    }
+
     // ==================== Upload Operations ====================
 
     /// Starts a new upload session using S3 multipart upload
@@ -1979,6 +2022,190 @@ impl OciS3Storage {
         Ok(referrers)
     }
 
+    // ==================== Garbage Collection ====================
+
+    /// Lists all objects under the given prefix
+    async fn list_objects(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<aws_sdk_s3::types::Object>, OciStorageError> {
+        let mut objects = Vec::new();
+        let mut continuation_token: Option<String> = None;
+
+        loop {
+            let mut request = self
+                .client
+                .list_objects_v2()
+                .bucket(&self.config.bucket_arn)
+                .prefix(prefix);
+
+            if let Some(token) = continuation_token.take() {
+                request = request.continuation_token(token);
+            }
+
+            let response = request
+                .send()
+                .await
+                .map_err(|e| OciStorageError::S3(e.to_string()))?;
+
+            if let Some(contents) = response.contents {
+                objects.extend(contents);
+            }
+
+            if response.is_truncated == Some(true) {
+                continuation_token = response.next_continuation_token;
+            } else {
+                break;
+            }
+        }
+
+        Ok(objects)
+    }
+
+    /// Lists all repositories in the registry
+    pub(crate) async fn list_repositories(&self) -> Result<Vec<ProjectUuid>, OciStorageError> {
+        let prefix = match &self.config.prefix {
+            Some(prefix) => format!("{prefix}/"),
+            None => String::new(),
+        };
+
+        let mut repositories = Vec::new();
+        let mut continuation_token: Option<String> = None;
+
+        loop {
+            let mut request = self
+                .client
+                .list_objects_v2()
+                .bucket(&self.config.bucket_arn)
+                .prefix(&prefix)
+                .delimiter("/");
+
+            if let Some(token) = continuation_token.take() {
+                request = request.continuation_token(token);
+            }
+
+            let response = request
+                .send()
+                .await
+                .map_err(|e| OciStorageError::S3(e.to_string()))?;
+
+            for common_prefix in response.common_prefixes.unwrap_or_default() {
+                if let Some(repository) = common_prefix
+                    .prefix
+                    .as_deref()
+                    .and_then(|p| p.strip_prefix(&prefix))
+                    .and_then(|p| p.trim_end_matches('/').parse::<ProjectUuid>().ok())
+                {
+                    repositories.push(repository);
+                }
+            }
+
+            if response.is_truncated == Some(true) {
+                continuation_token = response.next_continuation_token;
+            } else {
+                break;
+            }
+        }
+
+        Ok(repositories)
+    }
+
+    /// Lists content-addressed objects stored as `{algorithm}/{hex}` under the given prefix
+    async fn list_digest_objects(
+        &self,
+        prefix: &str,
+    ) -> Result<Vec<StoredObject>, OciStorageError> {
+        Ok(self
+            .list_objects(prefix)
+            .await?
+            .into_iter()
+            .filter_map(|object| {
+                let digest = object
+                    .key
+                    .as_deref()
+                    .and_then(|key| key.strip_prefix(prefix))
+                    .and_then(|rest| rest.split_once('/'))
+                    .and_then(|(alg, hex)| format!("{alg}:{hex}").parse::<Digest>().ok())?;
+                Some(StoredObject {
+                    digest,
+                    size: object
+                        .size
+                        .and_then(|size| u64::try_from(size).ok())
+                        .unwrap_or_default(),
+                    modified: object.last_modified.map(|lm| lm.secs()),
+                })
+            })
+            .collect())
+    }
+
+    /// Lists all manifests stored in a repository
+    pub(crate) async fn list_manifests(
+        &self,
+        repository: &ProjectUuid,
+    ) -> Result<Vec<StoredObject>, OciStorageError> {
+        self.list_digest_objects(&format!("{}/manifests/", self.key_prefix(repository)))
+            .await
+    }
+
+    /// Lists all blobs stored in a repository
+    pub(crate) async fn list_blobs(
+        &self,
+        repository: &ProjectUuid,
+    ) -> Result<Vec<StoredObject>, OciStorageError> {
+        self.list_digest_objects(&format!("{}/blobs/", self.key_prefix(repository)))
+            .await
+    }
+
+    /// Lists all referrer links stored in a repository
+    pub(crate) async fn list_referrer_links(
+        &self,
+        repository: &ProjectUuid,
+    ) -> Result<Vec<ReferrerLink>, OciStorageError> {
+        let prefix = format!("{}/referrers/", self.key_prefix(repository));
+        Ok(self
+            .list_objects(&prefix)
+            .await?
+            .into_iter()
+            .filter_map(|object| {
+                // Format: {alg}/{subject_hex}/{alg}-{referrer_hex}
+                let mut parts = object.key.as_deref()?.strip_prefix(&prefix)?.split('/');
+                let (Some(alg), Some(subject_hex), Some(referrer), None) =
+                    (parts.next(), parts.next(), parts.next(), parts.next())
+                else {
+                    return None;
+                };
+                let subject = format!("{alg}:{subject_hex}").parse::<Digest>().ok()?;
+                let referrer = referrer
+                    .split_once('-')
+                    .and_then(|(alg, hex)| format!("{alg}:{hex}").parse::<Digest>().ok())?;
+                Some(ReferrerLink {
+                    subject,
+                    referrer,
+                    modified: object.last_modified.map(|lm| lm.secs()),
+                })
+            })
+            .collect())
+    }
+
+    /// Deletes a referrer link
+    pub(crate) async fn delete_referrer_link(
+        &self,
+        repository: &ProjectUuid,
+        subject_digest: &Digest,
+        referrer_digest: &Digest,
+    ) -> Result<(), OciStorageError> {
+        let key = self.referrer_key(repository, subject_digest, referrer_digest);
+        self.client
+            .delete_object()
+            .bucket(&self.config.bucket_arn)
+            .key(&key)
+            .send()
+            .await
+            .map_err(|e| OciStorageError::S3(e.to_string()))?;
+
+        Ok(())
+    }
+
     // ==================== Job Output ====================
 
     /// S3 key for a job output blob.
@@ -2158,6 +2385,7 @@ impl S3Arn {
 /// Cleans up all stale uploads in S3 that have exceeded the timeout.
 ///
 /// This is a standalone async function that can be spawned as a background task.
+/// Returns the number of stale uploads removed (or found, for a dry run).
 #[expect(
     clippy::too_many_lines,
     clippy::cognitive_complexity,
@@ -2169,7 +2397,8 @@ async fn cleanup_stale_uploads_s3(
     config: OciStorageConfig,
     upload_timeout: u64,
     clock: Clock,
-) {
+    dry_run: bool,
+) -> u64 {
     let global_prefix = match &config.prefix {
         Some(prefix) => format!("{prefix}/_uploads"),
         None => "_uploads".to_owned(),
@@ -2201,7 +2430,7 @@ async fn cleanup_stale_uploads_s3(
                 "stale_upload: list prefixes",
                 &"S3 list request failed",
             );
-            return;
+            return 0;
         };
 
         if let Some(prefixes) = response.common_prefixes {
@@ -2218,6 +2447,7 @@ async fn cleanup_stale_uploads_s3(
     let (now, os_now) = clock.timestamps();
     let timeout_secs = i64::try_from(upload_timeout).unwrap_or(i64::MAX);
 
+    let mut removed = 0;
     for prefix in all_prefixes {
         let Some(prefix_str) = prefix.prefix else {
             continue;
@@ -2280,6 +2510,11 @@ async fn cleanup_stale_uploads_s3(
         };
 
         if is_stale {
+            removed += 1;
+            if dry_run {
+                continue;
+            }
+
             // Abort the S3 multipart upload if we have the upload ID
             let data_key = format!("{global_prefix}/{upload_id}/data");
             if let Some(state) = &state
@@ -2356,6 +2591,7 @@ async fn cleanup_stale_uploads_s3(
             }
         }
     }
+    removed
 }
 
 /// Check whether an S3 prefix with no valid state.json is stale by inspecting
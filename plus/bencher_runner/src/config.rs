@@ -10,6 +10,10 @@ use crate::cpu::CpuLayout;
 use crate::log_level::SandboxLogLevel;
 
 /// Configuration for a benchmark run.
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "each bool is an independent run option"
+)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     /// OCI image source - either a local path or registry reference.
@@ -140,6 +144,14 @@ pub struct Config {
     #[serde(skip)]
     pub cpu_layout: Option<CpuLayout>,
 
+    /// Collect hardware performance counters for the run.
+    ///
+    /// Counters are opened on the run's cgroup, so they require CPU
+    /// isolation and `perf_event_paranoid <= 0` (or `CAP_PERFMON`).
+    /// This field is not serialized.
+    #[serde(skip)]
+    pub perf_counters: bool,
+
     /// Sandbox process log level (used by Firecracker; ignored in non-sandboxed mode). This field is not serialized.
     #[serde(skip)]
     pub sandbox_log_level: SandboxLogLevel,
@@ -253,6 +265,7 @@ impl Config {
             grace_period: default_grace_period(),
             registry_scheme: RegistryScheme::default(),
             cpu_layout: None,
+            perf_counters: false,
             sandbox_log_level: SandboxLogLevel::default(),
             sandbox: None,
         }
@@ -454,6 +467,13 @@ impl Config {
         self.cpu_layout = Some(layout);
         self
     }
+
+    /// Enable or disable hardware performance counters.
+    #[must_use]
+    pub fn with_perf_counters(mut self, perf_counters: bool) -> Self {
+        self.perf_counters = perf_counters;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -476,6 +496,7 @@ mod tests {
         assert!(config.cmd.is_none());
         assert!(config.env.is_none());
         assert!(config.cpu_layout.is_none());
+        assert!(!config.perf_counters);
     }
 
     #[test]
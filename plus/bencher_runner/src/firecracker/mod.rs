@@ -70,6 +70,8 @@ pub struct FirecrackerJobConfig {
     pub work_dir: Utf8PathBuf,
     /// Optional CPU layout for core isolation via cpuset.
     pub cpu_layout: Option<CpuLayout>,
+    /// Collect hardware performance counters on the VM's cgroup.
+    pub perf_counters: bool,
     /// Firecracker process log level.
     pub log_level: SandboxLogLevel,
     /// Maximum number of output files to decode.
@@ -140,6 +142,25 @@ pub fn run_firecracker(
         None
     };
 
+    // Count the whole VM (VMM, boot, and benchmark) on the benchmark cores.
+    // Opened before the VMM joins the cgroup so nothing goes uncounted.
+    let perf = if !config.perf_counters {
+        None
+    } else if let (Some(cg), Some(layout)) = (&cgroup, &config.cpu_layout) {
+        match crate::perf::PerfCounters::open(cg.path(), &layout.benchmark) {
+            Ok(perf) => Some(perf),
+            Err(e) => {
+                eprintln!("Warning: failed to open hardware performance counters: {e}");
+                None
+            },
+        }
+    } else {
+        eprintln!(
+            "Warning: hardware performance counters require CPU isolation with a cgroup; skipping"
+        );
+        None
+    };
+
     // Step 1: Start Firecracker process
     println!("Starting Firecracker process...");
     let housekeeping_cores = config
@@ -235,6 +256,7 @@ pub fn run_firecracker(
                 cgroup: cgroup
                     .as_ref()
                     .and_then(|cg| metrics::read_cgroup_metrics(cg.path())),
+                perf: perf.as_ref().map(crate::perf::PerfCounters::read),
             };
             if let Some(line) = metrics::format_metrics(&run_metrics) {
                 eprintln!("{line}");
@@ -244,6 +266,7 @@ pub fn run_firecracker(
         },
     };
     let elapsed = start_time.elapsed();
+    let perf_counts = perf.as_ref().map(crate::perf::PerfCounters::read);
 
     // Step 6: Output metrics to stderr
     let run_metrics = RunMetrics {
@@ -253,6 +276,7 @@ pub fn run_firecracker(
         cgroup: cgroup
             .as_ref()
             .and_then(|cg| metrics::read_cgroup_metrics(cg.path())),
+        perf: perf_counts,
     };
     if let Some(line) = metrics::format_metrics(&run_metrics) {
         eprintln!("{line}");
@@ -283,6 +307,7 @@ pub fn run_firecracker(
         stdout: results.stdout,
         stderr: results.stderr,
         output_files,
+        perf_counts,
     })
 }
 
@@ -376,6 +401,7 @@ mod tests {
             stdout: "hello".to_owned(),
             stderr: "warnings".to_owned(),
             output_files: Some(files),
+            perf_counts: None,
         };
         assert_eq!(output.exit_code, 42);
         assert_eq!(output.stdout, "hello");
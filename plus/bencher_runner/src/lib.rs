@@ -42,6 +42,8 @@ mod local_isolation;
 mod log_level;
 #[cfg(feature = "plus")]
 pub mod metrics;
+#[cfg(all(feature = "plus", target_os = "linux"))]
+mod perf;
 #[cfg(feature = "plus")]
 mod run;
 #[cfg(feature = "plus")]
@@ -97,8 +97,11 @@ pub fn local_execute(
     // Isolate the benchmark on the benchmark cores (cgroup cpuset with a
     // CPU affinity fallback), mirroring the Firecracker path. Best-effort:
     // failures degrade to no isolation with a warning.
-    let isolation = LocalIsolation::prepare(config.cpu_layout.as_ref());
+    let mut isolation = LocalIsolation::prepare(config.cpu_layout.as_ref());
     isolation.configure_command(&mut cmd);
+    if config.perf_counters {
+        isolation.start_perf_counters();
+    }
 
     let start = Instant::now();
     // No metrics are emitted on spawn failure: nothing ran, so there is
@@ -125,6 +128,7 @@ pub fn local_execute(
         },
     };
     emit_run_metrics(start.elapsed(), false, &isolation);
+    let perf_counts = isolation.read_perf();
 
     // Collect output files from the unpacked rootfs
     let output_files = collect_output_files(
@@ -138,6 +142,7 @@ pub fn local_execute(
         stdout: output.stdout,
         stderr: output.stderr,
         output_files,
+        perf_counts,
     })
 }
 
@@ -151,6 +156,7 @@ fn emit_run_metrics(elapsed: Duration, timed_out: bool, isolation: &LocalIsolati
         timed_out,
         transport: "local".to_owned(),
         cgroup: isolation.read_metrics(),
+        perf: isolation.read_perf(),
     };
     if let Some(line) = metrics::format_metrics(&run_metrics) {
         eprintln!("{line}");
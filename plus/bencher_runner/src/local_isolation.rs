@@ -21,7 +21,7 @@
 use std::process::Command;
 
 use crate::cpu::CpuLayout;
-use crate::metrics::CgroupMetrics;
+use crate::metrics::{CgroupMetrics, PerfCounts};
 
 // ---------------------------------------------------------------------------
 // Linux implementation
@@ -30,6 +30,9 @@ use crate::metrics::CgroupMetrics;
 /// CPU isolation state for a single non-sandboxed run.
 #[cfg(target_os = "linux")]
 pub(crate) struct LocalIsolation {
+    /// Hardware counters on the cgroup, once started. Declared first so
+    /// they are closed before the cgroup is removed.
+    perf: Option<crate::perf::PerfCounters>,
     /// Per-run cgroup; `None` when cgroup setup failed or was skipped.
     /// Dropping this removes the cgroup.
     cgroup: Option<crate::jail::CgroupManager>,
@@ -50,6 +53,7 @@ impl LocalIsolation {
     pub(crate) fn prepare(layout: Option<&CpuLayout>) -> Self {
         let Some(layout) = layout.filter(|layout| layout.has_isolation()) else {
             return Self {
+                perf: None,
                 cgroup: None,
                 procs: None,
                 benchmark: Vec::new(),
@@ -99,6 +103,7 @@ impl LocalIsolation {
         }
 
         Self {
+            perf: None,
             cgroup,
             procs,
             benchmark,
@@ -164,6 +169,29 @@ impl LocalIsolation {
             .and_then(|cgroup| crate::metrics::read_cgroup_metrics(cgroup.path()))
     }
 
+    /// Start hardware performance counters on the run's cgroup.
+    ///
+    /// Must be called before the benchmark is spawned so its whole
+    /// lifetime is counted. Best-effort: without a cgroup, or when the
+    /// counters cannot be opened, it warns and the run has no counts.
+    pub(crate) fn start_perf_counters(&mut self) {
+        let Some(cgroup) = &self.cgroup else {
+            eprintln!(
+                "Warning: hardware performance counters require CPU isolation with a cgroup; skipping"
+            );
+            return;
+        };
+        match crate::perf::PerfCounters::open(cgroup.path(), &self.benchmark) {
+            Ok(perf) => self.perf = Some(perf),
+            Err(e) => eprintln!("Warning: failed to open hardware performance counters: {e}"),
+        }
+    }
+
+    /// Read the hardware performance counters, if they were started.
+    pub(crate) fn read_perf(&self) -> Option<PerfCounts> {
+        self.perf.as_ref().map(crate::perf::PerfCounters::read)
+    }
+
     /// SIGKILL every process left in the run's cgroup (best-effort).
     ///
     /// On timeout or cancellation only the direct child is killed by the
@@ -200,6 +228,12 @@ impl LocalIsolation {
         None
     }
 
+    pub(crate) fn start_perf_counters(&mut self) {}
+
+    pub(crate) fn read_perf(&self) -> Option<PerfCounts> {
+        None
+    }
+
     pub(crate) fn kill_all(&self) {}
 }
 
@@ -233,6 +267,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn perf_counters_without_cgroup_are_skipped() {
+        let mut isolation = LocalIsolation::prepare(None);
+        isolation.start_perf_counters();
+        assert!(isolation.read_perf().is_none());
+    }
+
     #[test]
     fn noop_isolation_leaves_command_spawnable() {
         let isolation = LocalIsolation::prepare(None);
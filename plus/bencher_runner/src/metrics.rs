@@ -22,6 +22,10 @@ pub struct RunMetrics {
     /// Cgroup resource usage (if available).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cgroup: Option<CgroupMetrics>,
+
+    /// Hardware performance counters (if enabled and available).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub perf: Option<PerfCounts>,
 }
 
 /// Resource metrics from cgroup v2.
@@ -44,6 +48,28 @@ pub struct CgroupMetrics {
     pub memory_peak_bytes: Option<u64>,
 }
 
+/// Hardware performance counter totals for a run.
+///
+/// A counter is `None` when the host could not count that event.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PerfCounts {
+    /// Retired instructions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<u64>,
+
+    /// CPU cycles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycles: Option<u64>,
+
+    /// Mispredicted branches.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch_misses: Option<u64>,
+
+    /// Last level cache misses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_misses: Option<u64>,
+}
+
 /// Read cgroup metrics from the given cgroup path.
 ///
 /// Reads `cpu.stat` and `memory.peak` from the cgroup directory.
@@ -274,6 +300,12 @@ mod tests {
                 cpu_system_us: Some(400),
                 memory_peak_bytes: Some(2048),
             }),
+            perf: Some(PerfCounts {
+                instructions: Some(1_000_000),
+                cycles: Some(2_000_000),
+                branch_misses: None,
+                cache_misses: Some(10),
+            }),
         };
         let formatted = format_metrics(&metrics).unwrap();
         assert!(formatted.starts_with("---BENCHER_METRICS:"));
@@ -290,6 +322,9 @@ mod tests {
         assert!(!parsed.timed_out);
         assert_eq!(parsed.transport, "vsock");
         assert_eq!(parsed.cgroup.as_ref().unwrap().cpu_usage_us, Some(1000));
+        let perf = parsed.perf.unwrap();
+        assert_eq!(perf.instructions, Some(1_000_000));
+        assert_eq!(perf.branch_misses, None);
     }
 
     #[test]
@@ -299,10 +334,12 @@ mod tests {
             timed_out: true,
             transport: "vsock".to_owned(),
             cgroup: None,
+            perf: None,
         };
         let formatted = format_metrics(&metrics).unwrap();
-        // cgroup should be absent from JSON (skip_serializing_if)
+        // cgroup and perf should be absent from JSON (skip_serializing_if)
         assert!(!formatted.contains("\"cgroup\""));
+        assert!(!formatted.contains("\"perf\""));
         assert!(formatted.contains("\"timed_out\":true"));
     }
 }
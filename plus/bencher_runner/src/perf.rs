@@ -0,0 +1,320 @@
+//! Hardware performance counters for a run.
+//!
+//! Counters are opened in cgroup mode (`PERF_FLAG_PID_CGROUP`): one event
+//! group per benchmark core, counting only while a task of the run's
+//! cgroup is scheduled there. This covers every process the benchmark
+//! spawns without racing the spawn itself, and works the same for the
+//! local child and the Firecracker VMM. Opening counters on another
+//! task's cgroup needs `CAP_PERFMON` or `perf_event_paranoid <= 0`.
+//!
+//! Everything here is best-effort: an event the CPU or hypervisor does
+//! not expose is skipped and reported as missing.
+
+use std::os::fd::RawFd;
+
+use camino::Utf8Path;
+
+use crate::metrics::PerfCounts;
+
+/// Failure to open hardware performance counters.
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum PerfError {
+    #[error("No benchmark cores to count on")]
+    NoCpus,
+    #[error("Failed to open cgroup {path} for perf counters: {source}")]
+    Cgroup {
+        path: camino::Utf8PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Failed to open the {event} counter on CPU {cpu}: {source}")]
+    Open {
+        event: &'static str,
+        cpu: usize,
+        source: std::io::Error,
+    },
+}
+
+/// A hardware event, as `perf_event_attr.config` for `PERF_TYPE_HARDWARE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HardwareEvent {
+    Instructions,
+    Cycles,
+    BranchMisses,
+    CacheMisses,
+}
+
+impl HardwareEvent {
+    /// The group leader comes first: a group is only scheduled when its
+    /// leader is, so instructions is never silently missing on its own.
+    const ALL: [Self; 4] = [
+        Self::Instructions,
+        Self::Cycles,
+        Self::BranchMisses,
+        Self::CacheMisses,
+    ];
+
+    fn config(self) -> u64 {
+        match self {
+            Self::Cycles => 0,
+            Self::Instructions => 1,
+            Self::CacheMisses => 3,
+            Self::BranchMisses => 5,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Instructions => "instructions",
+            Self::Cycles => "cycles",
+            Self::BranchMisses => "branch-misses",
+            Self::CacheMisses => "cache-misses",
+        }
+    }
+
+    fn set(self, counts: &mut PerfCounts, value: u64) {
+        let count = match self {
+            Self::Instructions => &mut counts.instructions,
+            Self::Cycles => &mut counts.cycles,
+            Self::BranchMisses => &mut counts.branch_misses,
+            Self::CacheMisses => &mut counts.cache_misses,
+        };
+        *count = Some(count.unwrap_or_default().saturating_add(value));
+    }
+}
+
+/// Scale a raw count for the time the event was multiplexed off the PMU.
+///
+/// Returns `None` when the event never ran, since any estimate would be
+/// made up.
+#[expect(
+    clippy::integer_division,
+    reason = "counts are whole events; truncation is below the noise"
+)]
+fn scale(value: u64, time_enabled: u64, time_running: u64) -> Option<u64> {
+    if time_running == 0 {
+        return (value == 0 && time_enabled == 0).then_some(0);
+    }
+    if time_running >= time_enabled {
+        return Some(value);
+    }
+    let scaled = u128::from(value) * u128::from(time_enabled) / u128::from(time_running);
+    Some(u64::try_from(scaled).unwrap_or(u64::MAX))
+}
+
+mod sys {
+    /// `PERF_TYPE_HARDWARE`
+    pub const PERF_TYPE_HARDWARE: u32 = 0;
+    /// `PERF_FORMAT_TOTAL_TIME_ENABLED | PERF_FORMAT_TOTAL_TIME_RUNNING`
+    pub const READ_FORMAT: u64 = 1 | 2;
+    /// `PERF_FLAG_FD_CLOEXEC`
+    pub const PERF_FLAG_FD_CLOEXEC: libc::c_ulong = 1 << 3;
+    /// `PERF_FLAG_PID_CGROUP`
+    pub const PERF_FLAG_PID_CGROUP: libc::c_ulong = 1 << 2;
+    /// `PERF_ATTR_SIZE_VER5`
+    pub const PERF_ATTR_SIZE: u32 = 112;
+
+    /// `struct perf_event_attr` up to `PERF_ATTR_SIZE_VER5`.
+    ///
+    /// The kernel accepts any published size and zero-fills the rest.
+    /// Only the type, size, config, and read format are set; every flag
+    /// bit is left clear so the counters are enabled on open and count
+    /// both user and kernel time.
+    #[repr(C)]
+    #[derive(Debug, Default)]
+    pub struct PerfEventAttr {
+        pub type_: u32,
+        pub size: u32,
+        pub config: u64,
+        pub sample_period: u64,
+        pub sample_type: u64,
+        pub read_format: u64,
+        pub flags: u64,
+        pub wakeup_events: u32,
+        pub bp_type: u32,
+        pub config1: u64,
+        pub config2: u64,
+        pub branch_sample_type: u64,
+        pub sample_regs_user: u64,
+        pub sample_stack_user: u32,
+        pub clockid: i32,
+        pub sample_regs_intr: u64,
+        pub aux_watermark: u32,
+        pub sample_max_stack: u16,
+        pub reserved: u16,
+    }
+}
+
+/// Open hardware counters for a single run.
+pub(crate) struct PerfCounters {
+    /// Open counters, one per event per CPU.
+    events: Vec<(HardwareEvent, std::fs::File)>,
+}
+
+impl PerfCounters {
+    /// Start counting every task in `cgroup` while it runs on `cpus`.
+    ///
+    /// Fails if the group leader cannot be opened on every core. Other
+    /// events that fail to open are warned about and left out.
+    #[expect(clippy::print_stderr, reason = "best-effort warnings")]
+    pub(crate) fn open(cgroup: &Utf8Path, cpus: &[usize]) -> Result<Self, PerfError> {
+        use std::os::fd::AsRawFd as _;
+
+        if cpus.is_empty() {
+            return Err(PerfError::NoCpus);
+        }
+        let cgroup_dir = std::fs::File::open(cgroup).map_err(|source| PerfError::Cgroup {
+            path: cgroup.to_owned(),
+            source,
+        })?;
+
+        let mut events = Vec::with_capacity(cpus.len() * HardwareEvent::ALL.len());
+        let mut skipped = Vec::new();
+        for &cpu in cpus {
+            let [leader_event, members @ ..] = HardwareEvent::ALL;
+            let leader = open_event(leader_event, cgroup_dir.as_raw_fd(), cpu, None)?;
+            for event in members {
+                match open_event(event, cgroup_dir.as_raw_fd(), cpu, Some(leader.as_raw_fd())) {
+                    Ok(file) => events.push((event, file)),
+                    Err(e) => {
+                        if !skipped.contains(&event) {
+                            eprintln!("Warning: skipping perf counter: {e}");
+                            skipped.push(event);
+                        }
+                    },
+                }
+            }
+            events.push((leader_event, leader));
+        }
+
+        Ok(Self { events })
+    }
+
+    /// Read the counts so far, summed across cores.
+    pub(crate) fn read(&self) -> PerfCounts {
+        use std::io::Read as _;
+
+        let mut counts = PerfCounts::default();
+        for (event, file) in &self.events {
+            let mut buf = [0u8; 24];
+            // `{ value, time_enabled, time_running }` per `READ_FORMAT`
+            let Ok(()) = (&*file).read_exact(&mut buf) else {
+                continue;
+            };
+            let [value, time_enabled, time_running] = [0, 8, 16].map(|offset| {
+                let mut word = [0u8; 8];
+                word.copy_from_slice(buf.get(offset..offset + 8).unwrap_or(&[0; 8]));
+                // The kernel writes counters in host byte order
+                #[expect(clippy::host_endian_bytes, reason = "kernel ABI is host endian")]
+                u64::from_ne_bytes(word)
+            });
+            if let Some(value) = scale(value, time_enabled, time_running) {
+                event.set(&mut counts, value);
+            }
+        }
+        counts
+    }
+}
+
+fn open_event(
+    event: HardwareEvent,
+    cgroup_fd: RawFd,
+    cpu: usize,
+    group_fd: Option<RawFd>,
+) -> Result<std::fs::File, PerfError> {
+    use std::os::fd::FromRawFd as _;
+
+    let open_error = |source| PerfError::Open {
+        event: event.as_str(),
+        cpu,
+        source,
+    };
+    let cpu_id = libc::c_int::try_from(cpu)
+        .map_err(|_err| open_error(std::io::Error::from(std::io::ErrorKind::InvalidInput)))?;
+    let attr = sys::PerfEventAttr {
+        type_: sys::PERF_TYPE_HARDWARE,
+        size: sys::PERF_ATTR_SIZE,
+        config: event.config(),
+        read_format: sys::READ_FORMAT,
+        ..Default::default()
+    };
+
+    // SAFETY: `attr` is a valid, fully initialized `perf_event_attr` of the
+    // size it declares, and outlives the call. `cgroup_fd` and `group_fd`
+    // are open descriptors owned by the caller.
+    #[expect(unsafe_code, reason = "perf_event_open has no libc wrapper")]
+    let fd = unsafe {
+        libc::syscall(
+            libc::SYS_perf_event_open,
+            &raw const attr,
+            cgroup_fd,
+            cpu_id,
+            group_fd.unwrap_or(-1),
+            sys::PERF_FLAG_PID_CGROUP | sys::PERF_FLAG_FD_CLOEXEC,
+        )
+    };
+    if fd < 0 {
+        return Err(open_error(std::io::Error::last_os_error()));
+    }
+    let fd = RawFd::try_from(fd)
+        .map_err(|_err| open_error(std::io::Error::from(std::io::ErrorKind::InvalidData)))?;
+    // SAFETY: `perf_event_open` just returned this descriptor and nothing
+    // else owns it.
+    #[expect(unsafe_code, reason = "taking ownership of a raw fd")]
+    let file = unsafe { std::fs::File::from_raw_fd(fd) };
+    Ok(file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attr_matches_kernel_size() {
+        assert_eq!(
+            size_of::<sys::PerfEventAttr>(),
+            sys::PERF_ATTR_SIZE as usize
+        );
+    }
+
+    #[test]
+    fn scale_unmultiplexed() {
+        assert_eq!(scale(1_000, 50, 50), Some(1_000));
+    }
+
+    #[test]
+    fn scale_multiplexed() {
+        assert_eq!(scale(1_000, 100, 25), Some(4_000));
+    }
+
+    #[test]
+    fn scale_never_ran() {
+        assert_eq!(scale(0, 0, 0), Some(0));
+        assert_eq!(scale(0, 100, 0), None);
+    }
+
+    #[test]
+    fn scale_saturates() {
+        assert_eq!(scale(u64::MAX, u64::MAX, 1), Some(u64::MAX));
+    }
+
+    #[test]
+    fn set_sums_across_cpus() {
+        let mut counts = PerfCounts::default();
+        HardwareEvent::Cycles.set(&mut counts, 10);
+        HardwareEvent::Cycles.set(&mut counts, 5);
+        assert_eq!(counts.cycles, Some(15));
+        assert_eq!(counts.instructions, None);
+    }
+
+    #[test]
+    fn open_without_cpus() {
+        let err = PerfCounters::open(Utf8Path::new("/nonexistent"), &[]).err();
+        assert!(matches!(err, Some(PerfError::NoCpus)));
+    }
+
+    #[test]
+    fn open_missing_cgroup() {
+        let err = PerfCounters::open(Utf8Path::new("/nonexistent/bencher/cgroup"), &[0]).err();
+        assert!(matches!(err, Some(PerfError::Cgroup { .. })));
+    }
+}
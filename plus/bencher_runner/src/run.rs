@@ -13,6 +13,7 @@ use camino::{Utf8Path, Utf8PathBuf};
 use bencher_json::Iteration;
 
 use crate::error::RunnerError;
+use crate::metrics::PerfCounts;
 use crate::tuning::{TuningConfig, preflight};
 
 /// Output from a benchmark run.
@@ -26,6 +27,8 @@ pub struct RunOutput {
     pub stderr: String,
     /// Optional output files: path → contents.
     pub output_files: Option<HashMap<Utf8PathBuf, Vec<u8>>>,
+    /// Hardware performance counters, if enabled and available.
+    pub perf_counts: Option<PerfCounts>,
 }
 
 /// Arguments for the `run` subcommand.
@@ -59,6 +62,8 @@ pub struct RunArgs {
     pub env: Option<HashMap<String, String>>,
     /// Whether to enable network access in the VM.
     pub network: bool,
+    /// Collect hardware performance counters for each iteration.
+    pub perf_counters: bool,
     /// Number of benchmark iterations.
     pub iter: Iteration,
     /// Allow benchmark failure without short-circuiting iterations.
@@ -79,7 +84,8 @@ pub struct RunArgs {
 fn build_config_from_run_args(args: &RunArgs) -> Result<crate::Config, crate::error::ConfigError> {
     let mut config = crate::Config::new(args.image.clone())
         .with_timeout_secs(args.timeout_secs)
-        .with_network(args.network);
+        .with_network(args.network)
+        .with_perf_counters(args.perf_counters);
     if let Some(vcpus) = args.vcpus {
         config = config.with_vcpus(vcpus);
     }
@@ -142,11 +148,14 @@ pub fn run_with_args(args: &RunArgs) -> Result<(), RunnerError> {
 
     // Serialize host-global tuning across runner processes. Declared
     // before the guard so the lock releases only after restore completes.
-    let host_lock = crate::tuning::HostTuningLock::acquire();
+    // A journal left by a runner that died with tuning applied is
+    // replayed first, so its tuned values are not saved as originals.
+    let mut host_lock = crate::tuning::HostTuningLock::acquire();
+    host_lock.recover();
     let tuning = host_lock.effective_tuning(&args.tuning);
 
     // Apply host tuning - guard restores settings on drop (no-op on non-Linux)
-    let mut tuning_guard = crate::tuning::apply(&tuning);
+    let mut tuning_guard = crate::tuning::apply(&tuning, &host_lock);
 
     let mut config = build_config_from_run_args(args)?;
 
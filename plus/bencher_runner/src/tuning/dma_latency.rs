@@ -59,6 +59,7 @@ mod tests {
         TuningGuard {
             saved: Vec::new(),
             held_fds: Vec::new(),
+            journal: None,
         }
     }
 
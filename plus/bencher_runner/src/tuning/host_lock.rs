@@ -8,12 +8,15 @@
 //! tuning with a warning and keeps only its per-run isolation. The
 //! kernel releases the lock when the holder exits or dies, so a crashed
 //! runner cannot wedge future runs.
+//!
+//! The lock also owns the tuning journal: only the holder journals its
+//! original values, and a journal found by a new holder was left by a
+//! runner that died with tuning applied, so it is replayed first.
 
 /// Lock file path. `/run` is root-writable tmpfs: it cannot be symlink
 /// attacked like `/tmp` and clears on reboot. Unprivileged runners fail
 /// to open it and proceed unserialized, which is harmless because their
 /// host tuning writes fail anyway.
-#[cfg(target_os = "linux")]
 const LOCK_PATH: &str = "/run/bencher_runner_tuning.lock";
 
 /// Holds the host tuning lock (or records why it could not be taken).
@@ -26,33 +29,88 @@ pub struct HostTuningLock {
     /// The locked file, held only for its `flock`; `None` when the lock
     /// is contended or the file could not be opened.
     #[cfg(target_os = "linux")]
-    _file: Option<std::fs::File>,
-    /// Whether host tuning may proceed.
-    allows_tuning: bool,
+    file: Option<std::fs::File>,
+    /// The tuning journal, present only while the lock is held.
+    #[cfg(target_os = "linux")]
+    journal: Option<super::TuningJournal>,
+    /// Why host tuning must be skipped, if it must.
+    skip_reason: Option<String>,
+}
+
+/// Failure to replay the tuning journal on demand.
+#[derive(Debug, thiserror::Error)]
+pub enum RestoreJournalError {
+    #[error("Another bencher runner is active on this host; its tuning journal is not stale")]
+    Contended,
+    #[error("Failed to take the host tuning lock at {LOCK_PATH} (host tuning requires root)")]
+    Unavailable,
+    #[cfg(target_os = "linux")]
+    #[error(transparent)]
+    Journal(#[from] super::JournalError),
+    #[cfg(not(target_os = "linux"))]
+    #[error("Host tuning is only supported on Linux")]
+    Unsupported,
 }
 
 impl HostTuningLock {
+    /// Take the host tuning lock and replay any stale tuning journal.
+    ///
+    /// Unlike [`Self::recover`], this refuses to run while another runner
+    /// holds the lock, since that runner's journal is still live.
+    pub fn restore_journal() -> Result<Option<super::JournalRestore>, RestoreJournalError> {
+        #[cfg(target_os = "linux")]
+        {
+            let mut lock = Self::acquire();
+            if !lock.is_held() {
+                return Err(if lock.allows_tuning() {
+                    RestoreJournalError::Unavailable
+                } else {
+                    RestoreJournalError::Contended
+                });
+            }
+            Ok(lock.restore_stale_journal()?)
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            Err(RestoreJournalError::Unsupported)
+        }
+    }
+
     /// Try to take the host tuning lock.
     #[must_use]
     pub fn acquire() -> Self {
         #[cfg(target_os = "linux")]
         {
-            Self::acquire_at(camino::Utf8Path::new(LOCK_PATH))
+            Self::acquire_at(
+                camino::Utf8Path::new(LOCK_PATH),
+                camino::Utf8Path::new(super::journal::JOURNAL_PATH),
+            )
         }
         #[cfg(not(target_os = "linux"))]
         {
-            Self {
-                allows_tuning: true,
-            }
+            Self { skip_reason: None }
+        }
+    }
+
+    /// A lock that was never taken, for tests that apply tuning directly.
+    #[cfg(test)]
+    pub(crate) fn unlocked() -> Self {
+        Self {
+            #[cfg(target_os = "linux")]
+            file: None,
+            #[cfg(target_os = "linux")]
+            journal: None,
+            skip_reason: None,
         }
     }
 
     /// Try to take the lock at an explicit path (tests pass a tempdir).
     ///
     /// An unopenable lock file allows tuning: serialization degrades to
-    /// the pre-lock behavior instead of disabling tuning outright.
+    /// the pre-lock behavior instead of disabling tuning outright, and
+    /// nothing is journaled.
     #[cfg(target_os = "linux")]
-    fn acquire_at(path: &camino::Utf8Path) -> Self {
+    fn acquire_at(path: &camino::Utf8Path, journal_path: &camino::Utf8Path) -> Self {
         use std::os::fd::AsRawFd as _;
 
         let file = match std::fs::OpenOptions::new()
@@ -65,8 +123,9 @@ impl HostTuningLock {
             Err(e) => {
                 println!("  Tuning: host lock - unavailable ({path}: {e})");
                 return Self {
-                    _file: None,
-                    allows_tuning: true,
+                    file: None,
+                    journal: None,
+                    skip_reason: None,
                 };
             },
         };
@@ -81,36 +140,92 @@ impl HostTuningLock {
 
         if ret == 0 {
             Self {
-                _file: Some(file),
-                allows_tuning: true,
+                file: Some(file),
+                journal: Some(super::TuningJournal::new(journal_path.to_owned())),
+                skip_reason: None,
             }
         } else {
             Self {
-                _file: None,
-                allows_tuning: false,
+                file: None,
+                journal: None,
+                skip_reason: Some("another bencher runner is active on this host".to_owned()),
             }
         }
     }
 
+    /// Whether this process holds the lock file's `flock`.
+    #[must_use]
+    pub fn is_held(&self) -> bool {
+        #[cfg(target_os = "linux")]
+        {
+            self.file.is_some()
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            false
+        }
+    }
+
     /// Whether this process may apply host-global tuning.
     #[must_use]
     pub fn allows_tuning(&self) -> bool {
-        self.allows_tuning
+        self.skip_reason.is_none()
+    }
+
+    /// The tuning journal, present only while the lock is held.
+    #[cfg(target_os = "linux")]
+    pub(super) fn journal(&self) -> Option<&super::TuningJournal> {
+        self.journal.as_ref()
+    }
+
+    /// Replay the journal left by a runner that died with tuning applied.
+    ///
+    /// A no-op unless the lock is held. If the journal cannot be read or
+    /// removed, host tuning is disabled for this process: the host may
+    /// still carry tuned values that would otherwise be journaled as the
+    /// "originals".
+    #[cfg(target_os = "linux")]
+    pub fn restore_stale_journal(
+        &mut self,
+    ) -> Result<Option<super::JournalRestore>, super::JournalError> {
+        let Some(journal) = &self.journal else {
+            return Ok(None);
+        };
+        journal.restore_stale().inspect_err(|_| {
+            self.skip_reason = Some(format!(
+                "stale tuning journal {path} could not be restored; check the host settings and remove it",
+                path = journal.path()
+            ));
+        })
+    }
+
+    /// Replay any stale tuning journal before applying new tuning.
+    ///
+    /// Failures are printed and leave host tuning disabled.
+    #[expect(
+        clippy::print_stdout,
+        reason = "prints why the journal could not be restored"
+    )]
+    pub fn recover(&mut self) {
+        #[cfg(target_os = "linux")]
+        if let Err(e) = self.restore_stale_journal() {
+            println!("  Tuning: {e}");
+        }
     }
 
     /// The tuning configuration to actually apply under this lock.
     ///
-    /// Contended lock: everything is disabled, because another runner
-    /// owns the host settings. Otherwise the requested configuration
-    /// passes through unchanged.
+    /// Contended lock or unrecoverable journal: everything is disabled,
+    /// because the host settings are not this runner's to change.
+    /// Otherwise the requested configuration passes through unchanged.
     #[must_use]
     #[expect(clippy::print_stdout, reason = "prints why host tuning is skipped")]
     pub fn effective_tuning(&self, requested: &super::TuningConfig) -> super::TuningConfig {
-        if self.allows_tuning() {
-            requested.clone()
-        } else {
-            println!("  Tuning: skipped (another bencher runner is active on this host)");
+        if let Some(reason) = &self.skip_reason {
+            println!("  Tuning: skipped ({reason})");
             super::TuningConfig::disabled()
+        } else {
+            requested.clone()
         }
     }
 }
@@ -128,45 +243,51 @@ mod tests {
         (dir, path)
     }
 
+    fn acquire(path: &Utf8PathBuf) -> HostTuningLock {
+        HostTuningLock::acquire_at(path, &path.with_extension("json"))
+    }
+
     #[test]
     fn first_acquire_allows_tuning() {
         let (_dir, path) = lock_path();
-        let lock = HostTuningLock::acquire_at(&path);
+        let lock = acquire(&path);
         assert!(lock.allows_tuning());
     }
 
     #[test]
     fn contended_lock_denies_tuning() {
         let (_dir, path) = lock_path();
-        let first = HostTuningLock::acquire_at(&path);
+        let first = acquire(&path);
         assert!(first.allows_tuning());
 
         // A second open file description on the same inode contends.
-        let second = HostTuningLock::acquire_at(&path);
+        let second = acquire(&path);
         assert!(!second.allows_tuning());
     }
 
     #[test]
     fn dropped_lock_can_be_reacquired() {
         let (_dir, path) = lock_path();
-        drop(HostTuningLock::acquire_at(&path));
+        drop(acquire(&path));
 
-        let again = HostTuningLock::acquire_at(&path);
+        let again = acquire(&path);
         assert!(again.allows_tuning());
     }
 
     #[test]
     fn unopenable_path_still_allows_tuning() {
-        let lock = HostTuningLock::acquire_at(camino::Utf8Path::new(
+        let lock = acquire(&Utf8PathBuf::from(
             "/nonexistent/dir/bencher_runner_tuning.lock",
         ));
         assert!(lock.allows_tuning());
+        assert!(!lock.is_held());
+        assert!(lock.journal().is_none());
     }
 
     #[test]
     fn effective_tuning_passes_through_when_held() {
         let (_dir, path) = lock_path();
-        let lock = HostTuningLock::acquire_at(&path);
+        let lock = acquire(&path);
         let config = lock.effective_tuning(&crate::tuning::TuningConfig::default());
         assert!(config.disable_aslr);
     }
@@ -174,11 +295,86 @@ mod tests {
     #[test]
     fn effective_tuning_disabled_when_contended() {
         let (_dir, path) = lock_path();
-        let _first = HostTuningLock::acquire_at(&path);
-        let second = HostTuningLock::acquire_at(&path);
+        let _first = acquire(&path);
+        let second = acquire(&path);
 
         let config = second.effective_tuning(&crate::tuning::TuningConfig::default());
         assert!(!config.disable_aslr);
         assert!(!config.cpuset_partition);
     }
+
+    #[test]
+    fn held_lock_restores_stale_journal() {
+        let (dir, path) = lock_path();
+        let setting = Utf8PathBuf::try_from(dir.path().join("setting")).unwrap();
+        std::fs::write(&setting, "tuned").unwrap();
+
+        // A runner that died mid-run leaves its journal behind.
+        {
+            let lock = acquire(&path);
+            let mut guard = crate::tuning::apply(&crate::tuning::TuningConfig::disabled(), &lock);
+            guard
+                .save_restore(setting.clone(), "original".to_owned(), "setting".to_owned())
+                .unwrap();
+            #[expect(
+                clippy::mem_forget,
+                reason = "simulates a runner killed before its guard drops"
+            )]
+            std::mem::forget(guard);
+        }
+        assert!(path.with_extension("json").exists());
+
+        let mut lock = acquire(&path);
+        let restore = lock.restore_stale_journal().unwrap().unwrap();
+        assert_eq!(restore.restored, 1);
+        assert_eq!(std::fs::read_to_string(&setting).unwrap(), "original");
+        assert!(!path.with_extension("json").exists());
+        assert!(lock.allows_tuning());
+    }
+
+    #[test]
+    fn contended_lock_leaves_journal() {
+        let (_dir, path) = lock_path();
+        std::fs::write(path.with_extension("json"), "{}").unwrap();
+        let _first = acquire(&path);
+
+        let mut second = acquire(&path);
+        assert!(second.restore_stale_journal().unwrap().is_none());
+        assert!(path.with_extension("json").exists());
+    }
+
+    #[test]
+    fn corrupt_journal_disables_tuning() {
+        let (_dir, path) = lock_path();
+        std::fs::write(path.with_extension("json"), "not json").unwrap();
+
+        let mut lock = acquire(&path);
+        lock.restore_stale_journal().unwrap_err();
+        assert!(!lock.allows_tuning());
+        let config = lock.effective_tuning(&crate::tuning::TuningConfig::default());
+        assert!(!config.disable_aslr);
+    }
+
+    #[test]
+    fn guard_drop_removes_journal() {
+        let (dir, path) = lock_path();
+        let setting = Utf8PathBuf::try_from(dir.path().join("setting")).unwrap();
+        std::fs::write(&setting, "original").unwrap();
+
+        let lock = acquire(&path);
+        let mut guard = crate::tuning::apply(&crate::tuning::TuningConfig::disabled(), &lock);
+        guard
+            .save_and_write(
+                &setting,
+                "original".to_owned(),
+                "tuned",
+                "setting".to_owned(),
+            )
+            .unwrap();
+        assert!(path.with_extension("json").exists());
+
+        drop(guard);
+        assert_eq!(std::fs::read_to_string(&setting).unwrap(), "original");
+        assert!(!path.with_extension("json").exists());
+    }
 }
@@ -0,0 +1,257 @@
+//! On-disk journal of the original host settings replaced by tuning.
+//!
+//! The [`super::TuningGuard`] restores settings on drop, which never
+//! happens on SIGKILL or panic-abort. To survive that, every original
+//! value is appended to this journal *before* its tuned value is written,
+//! and the journal is removed once the guard has restored everything.
+//! A journal left behind therefore always describes a runner that died
+//! with host tuning applied, and replaying it in reverse recording order
+//! puts the host back the way it was.
+//!
+//! Only the holder of the [`super::HostTuningLock`] reads or writes the
+//! journal. The kernel releases the lock when its holder dies, so a
+//! journal found while holding the lock is always stale.
+
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::{Deserialize, Serialize};
+
+use super::{JournalRestore, SavedSetting};
+
+/// Journal file path. Like the host tuning lock, it lives on the
+/// root-writable `/run` tmpfs, which clears on reboot along with the
+/// tuned values themselves.
+pub(super) const JOURNAL_PATH: &str = "/run/bencher_runner_tuning.json";
+
+/// A journal of original host settings for one tuning guard.
+#[derive(Debug, Clone)]
+pub struct TuningJournal {
+    path: Utf8PathBuf,
+}
+
+/// The serialized journal contents.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct JournalFile {
+    /// The process that applied the tuning.
+    pid: u32,
+    /// Original settings in recording order.
+    settings: Vec<SavedSetting>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum JournalError {
+    #[error("Failed to read tuning journal {path}: {source}")]
+    Read {
+        path: Utf8PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Failed to parse tuning journal {path}: {source}")]
+    Parse {
+        path: Utf8PathBuf,
+        source: serde_json::Error,
+    },
+    #[error("Failed to write tuning journal {path}: {source}")]
+    Write {
+        path: Utf8PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Failed to remove tuning journal {path}: {source}")]
+    Remove {
+        path: Utf8PathBuf,
+        source: std::io::Error,
+    },
+}
+
+impl TuningJournal {
+    pub(super) fn new(path: Utf8PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// The journal file path.
+    pub fn path(&self) -> &Utf8Path {
+        &self.path
+    }
+
+    /// Persist the current set of original settings.
+    ///
+    /// The journal is written to a sibling temp file, synced, and then
+    /// renamed into place, so a crash mid-write leaves either the previous
+    /// journal or the new one, never a truncated file.
+    pub(super) fn write(&self, settings: &[SavedSetting]) -> Result<(), JournalError> {
+        use std::io::Write as _;
+
+        let journal = JournalFile {
+            pid: std::process::id(),
+            settings: settings.to_vec(),
+        };
+        let write_err = |source| JournalError::Write {
+            path: self.path.clone(),
+            source,
+        };
+        let json =
+            serde_json::to_vec_pretty(&journal).map_err(|e| write_err(std::io::Error::other(e)))?;
+
+        let tmp_path = self.path.with_extension("json.tmp");
+        let mut file = std::fs::File::create(&tmp_path).map_err(write_err)?;
+        file.write_all(&json).map_err(write_err)?;
+        file.sync_all().map_err(write_err)?;
+        std::fs::rename(&tmp_path, &self.path).map_err(write_err)
+    }
+
+    /// Remove the journal once every setting has been restored.
+    pub(super) fn remove(&self) -> Result<(), JournalError> {
+        match std::fs::remove_file(&self.path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(source) => Err(JournalError::Remove {
+                path: self.path.clone(),
+                source,
+            }),
+        }
+    }
+
+    /// Replay a stale journal, if one exists, and then remove it.
+    ///
+    /// Settings are written back in reverse recording order, mirroring
+    /// the guard's drop. The journal is removed even if some settings fail
+    /// to restore, so a broken entry cannot wedge every future startup.
+    pub(super) fn restore_stale(&self) -> Result<Option<JournalRestore>, JournalError> {
+        let json = match std::fs::read(&self.path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(source) => {
+                return Err(JournalError::Read {
+                    path: self.path.clone(),
+                    source,
+                });
+            },
+        };
+        let journal: JournalFile =
+            serde_json::from_slice(&json).map_err(|source| JournalError::Parse {
+                path: self.path.clone(),
+                source,
+            })?;
+
+        println!(
+            "  Tuning: restoring {count} setting(s) from a stale journal (pid {pid})",
+            count = journal.settings.len(),
+            pid = journal.pid
+        );
+        let mut restored = 0;
+        let mut failed = 0;
+        for entry in journal.settings.iter().rev() {
+            if super::restore(&entry.path, &entry.value, &entry.label) {
+                restored += 1;
+            } else {
+                failed += 1;
+            }
+        }
+        self.remove()?;
+
+        Ok(Some(JournalRestore {
+            pid: journal.pid,
+            restored,
+            failed,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn journal() -> (tempfile::TempDir, TuningJournal) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = Utf8PathBuf::try_from(dir.path().join("tuning.json")).unwrap();
+        (dir, TuningJournal::new(path))
+    }
+
+    fn entry(path: &Utf8Path, value: &str) -> SavedSetting {
+        SavedSetting {
+            path: path.to_owned(),
+            value: value.to_owned(),
+            label: path.file_name().unwrap_or_default().to_owned(),
+        }
+    }
+
+    #[test]
+    fn missing_journal_restores_nothing() {
+        let (_dir, journal) = journal();
+        assert_eq!(journal.restore_stale().unwrap(), None);
+    }
+
+    #[test]
+    fn write_then_restore_stale() {
+        let (dir, journal) = journal();
+        let base = Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+        let first = base.join("first");
+        let second = base.join("second");
+        fs::write(&first, "tuned").unwrap();
+        fs::write(&second, "tuned").unwrap();
+
+        journal
+            .write(&[entry(&first, "a_orig"), entry(&second, "b_orig")])
+            .unwrap();
+        assert!(journal.path().exists());
+        assert!(!journal.path().with_extension("json.tmp").exists());
+
+        let restore = journal.restore_stale().unwrap().unwrap();
+        assert_eq!(restore.pid, std::process::id());
+        assert_eq!(restore.restored, 2);
+        assert_eq!(restore.failed, 0);
+        assert_eq!(fs::read_to_string(&first).unwrap(), "a_orig");
+        assert_eq!(fs::read_to_string(&second).unwrap(), "b_orig");
+        // The journal is consumed
+        assert!(!journal.path().exists());
+        assert_eq!(journal.restore_stale().unwrap(), None);
+    }
+
+    #[test]
+    fn restore_stale_in_reverse_order() {
+        let (dir, journal) = journal();
+        let base = Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+        let path = base.join("value");
+        fs::write(&path, "tuned").unwrap();
+
+        // The same file recorded twice: the earliest value is the original.
+        journal
+            .write(&[entry(&path, "original"), entry(&path, "intermediate")])
+            .unwrap();
+        journal.restore_stale().unwrap().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "original");
+    }
+
+    #[test]
+    fn restore_stale_counts_failures() {
+        let (dir, journal) = journal();
+        let base = Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+        let present = base.join("present");
+        fs::write(&present, "tuned").unwrap();
+        let missing = base.join("missing_dir").join("value");
+
+        journal
+            .write(&[entry(&present, "orig"), entry(&missing, "orig")])
+            .unwrap();
+        let restore = journal.restore_stale().unwrap().unwrap();
+        assert_eq!(restore.restored, 1);
+        assert_eq!(restore.failed, 1);
+        assert!(!journal.path().exists());
+    }
+
+    #[test]
+    fn corrupt_journal_is_an_error() {
+        let (_dir, journal) = journal();
+        fs::write(journal.path(), "not json").unwrap();
+        assert!(matches!(
+            journal.restore_stale(),
+            Err(JournalError::Parse { .. })
+        ));
+    }
+
+    #[test]
+    fn remove_missing_journal_is_ok() {
+        let (_dir, journal) = journal();
+        journal.remove().unwrap();
+    }
+}
@@ -8,7 +8,7 @@
 
 use camino::Utf8Path;
 
-use super::{TuningGuard, write_sysctl};
+use super::{SaveWriteError, TuningGuard, write_sysctl};
 use crate::cpu::{CpuLayout, format_cpumask};
 
 /// Steer kernel work (IRQs and unbound workqueues) to housekeeping cores.
@@ -75,13 +75,21 @@ fn steer_existing_irqs(guard: &mut TuningGuard, layout: &CpuLayout, root: &Utf8P
             continue;
         }
 
-        if std::fs::write(affinity_path.as_std_path(), &housekeeping_list).is_err() {
+        match guard.save_and_write(
+            &affinity_path,
+            current,
+            &housekeeping_list,
+            format!("IRQ {name_str} affinity"),
+        ) {
+            Ok(()) => moved += 1,
             // Unmovable IRQ (EIO) or insufficient permissions - skip.
-            continue;
+            Err(SaveWriteError::Write(_)) => {},
+            // Without a journal, no further IRQs can be moved safely.
+            Err(e @ SaveWriteError::Journal(_)) => {
+                println!("  Tuning: IRQ steering - stopped ({e})");
+                break;
+            },
         }
-
-        moved += 1;
-        guard.save_restore(affinity_path, current, format!("IRQ {name_str} affinity"));
     }
 
     println!(
@@ -101,6 +109,7 @@ mod tests {
         TuningGuard {
             saved: Vec::new(),
             held_fds: Vec::new(),
+            journal: None,
         }
     }
 
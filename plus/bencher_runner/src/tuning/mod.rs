@@ -8,20 +8,24 @@
 //!
 //! # Crash safety
 //!
-//! Restoration only happens on clean shutdown (guard drop). On SIGKILL
-//! or panic-abort, sysctl writes, IRQ affinities, THP mode, and the
-//! cpuset partition stay applied. Worse, the next run then reads the
-//! already-tuned value as "current", saves nothing, and a later clean
-//! exit cannot restore the true pre-tuning value; a reboot (or manual
-//! reset) recovers it. Two mechanisms self-heal by construction: the
-//! `/dev/cpu_dma_latency` fd releases its PM `QoS` constraint when the
-//! process dies, and per-run cgroups are removed by their own cleanup.
+//! The guard only restores on clean shutdown (drop). On SIGKILL or
+//! panic-abort, sysctl writes, IRQ affinities, THP mode, and the cpuset
+//! partition stay applied, and the next run would read the already-tuned
+//! values as "current". To prevent that, every original value is written
+//! to an on-disk [`TuningJournal`] before its tuned value is applied, and
+//! the journal is removed once the guard has restored everything. A
+//! runner that takes the host tuning lock and finds a journal replays it
+//! first ([`HostTuningLock::restore_stale_journal`]), and
+//! `runner tuning restore` does the same on demand. Two mechanisms
+//! self-heal by construction: the `/dev/cpu_dma_latency` fd releases its
+//! PM `QoS` constraint when the process dies, and per-run cgroups are
+//! removed by their own cleanup.
 //!
 //! Tuning requires a single runner process per host: the sysctls, IRQ
 //! affinities, THP mode, and cpuset partition are host-global, so a
 //! second concurrent runner's shutdown would restore them out from under
-//! the first. [`HostTuningLock`] enforces this: callers acquire it before
-//! [`apply`], and a contended lock disables host tuning for that process.
+//! the first. [`HostTuningLock`] enforces this: [`apply`] takes the lock,
+//! and a contended lock disables host tuning for that process.
 
 #![cfg_attr(
     target_os = "linux",
@@ -32,6 +36,8 @@
 mod dma_latency;
 mod host_lock;
 #[cfg(target_os = "linux")]
+mod journal;
+#[cfg(target_os = "linux")]
 mod kernel_work;
 #[cfg(target_os = "linux")]
 mod partition;
@@ -41,6 +47,9 @@ mod swappiness;
 mod thp;
 
 pub use host_lock::HostTuningLock;
+pub use host_lock::RestoreJournalError;
+#[cfg(target_os = "linux")]
+pub use journal::{JournalError, TuningJournal};
 pub use perf_event_paranoid::PerfEventParanoid;
 pub use swappiness::Swappiness;
 pub use thp::{ParseThpModeError, ThpMode};
@@ -143,11 +152,23 @@ impl TuningConfig {
     }
 }
 
+/// The outcome of replaying a stale tuning journal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JournalRestore {
+    /// The process that left the journal behind.
+    pub pid: u32,
+    /// The number of settings restored.
+    pub restored: usize,
+    /// The number of settings that could not be restored.
+    pub failed: usize,
+}
+
 // ---------------------------------------------------------------------------
 // Linux implementation
 // ---------------------------------------------------------------------------
 
 #[cfg(target_os = "linux")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 struct SavedSetting {
     path: Utf8PathBuf,
     value: String,
@@ -162,6 +183,18 @@ pub struct TuningGuard {
     /// (e.g., the PM `QoS` constraint on `/dev/cpu_dma_latency`).
     /// Dropped after the saved settings are restored.
     held_fds: Vec<std::fs::File>,
+    /// On-disk copy of `saved`, present only while holding the host tuning lock.
+    journal: Option<TuningJournal>,
+}
+
+/// Failure to save an original value or write its tuned replacement.
+#[cfg(target_os = "linux")]
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum SaveWriteError {
+    #[error(transparent)]
+    Journal(#[from] JournalError),
+    #[error(transparent)]
+    Write(std::io::Error),
 }
 
 #[cfg(target_os = "linux")]
@@ -170,9 +203,47 @@ impl TuningGuard {
     ///
     /// Restoration happens in reverse recording order, so dependent writes
     /// (e.g., a cpuset partition mode that must be reverted before its
-    /// cpuset can shrink) are undone correctly.
-    pub(crate) fn save_restore(&mut self, path: Utf8PathBuf, value: String, label: String) {
+    /// cpuset can shrink) are undone correctly. The journal is updated
+    /// before this returns, so the file must only be changed on success.
+    pub(crate) fn save_restore(
+        &mut self,
+        path: Utf8PathBuf,
+        value: String,
+        label: String,
+    ) -> Result<(), JournalError> {
         self.saved.push(SavedSetting { path, value, label });
+        if let Some(journal) = &self.journal
+            && let Err(e) = journal.write(&self.saved)
+        {
+            self.saved.pop();
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Record the `current` value of a file, then write `value` to it.
+    ///
+    /// If the write fails, the recorded value is dropped again so the
+    /// guard does not "restore" a file it never changed.
+    pub(crate) fn save_and_write(
+        &mut self,
+        path: &Utf8Path,
+        current: String,
+        value: &str,
+        label: String,
+    ) -> Result<(), SaveWriteError> {
+        self.save_restore(path.to_owned(), current, label)?;
+        if let Err(e) = std::fs::write(path, value) {
+            self.saved.pop();
+            // Best effort: a stale extra entry only rewrites an unchanged value.
+            if let Some(journal) = &self.journal
+                && let Err(e) = journal.write(&self.saved)
+            {
+                println!("  Tuning: {e}");
+            }
+            return Err(SaveWriteError::Write(e));
+        }
+        Ok(())
     }
 }
 
@@ -182,15 +253,23 @@ impl Drop for TuningGuard {
         for setting in self.saved.iter().rev() {
             restore(&setting.path, &setting.value, &setting.label);
         }
+        if let Some(journal) = &self.journal
+            && let Err(e) = journal.remove()
+        {
+            println!("  Tuning: {e}");
+        }
     }
 }
 
 /// Apply host tuning. Returns a guard that restores settings on drop.
+///
+/// Original values are journaled when `host_lock` holds the host tuning lock.
 #[cfg(target_os = "linux")]
-pub fn apply(config: &TuningConfig) -> TuningGuard {
+pub fn apply(config: &TuningConfig, host_lock: &HostTuningLock) -> TuningGuard {
     let mut guard = TuningGuard {
         saved: Vec::new(),
         held_fds: Vec::new(),
+        journal: host_lock.journal().cloned(),
     };
 
     if config.disable_aslr {
@@ -335,17 +414,12 @@ fn write_sysctl(guard: &mut TuningGuard, path: &str, value: &str, label: &str) {
         return;
     }
 
-    if let Err(e) = std::fs::write(path.as_str(), value) {
+    if let Err(e) = guard.save_and_write(&path, current.clone(), value, label.to_owned()) {
         println!("  Tuning: {label} - skipped (write failed: {e})");
         return;
     }
 
     println!("  Tuning: {label} - set to {value} (was {current})");
-    guard.saved.push(SavedSetting {
-        path,
-        value: current,
-        label: label.to_owned(),
-    });
 }
 
 /// Like [`write_sysctl`], but for files using the bracketed selection
@@ -383,17 +457,12 @@ fn write_bracketed_sysctl(guard: &mut TuningGuard, path: &str, value: &str, labe
         return;
     }
 
-    if let Err(e) = std::fs::write(path.as_str(), value) {
+    if let Err(e) = guard.save_and_write(&path, current.clone(), value, label.to_owned()) {
         println!("  Tuning: {label} - skipped (write failed: {e})");
         return;
     }
 
     println!("  Tuning: {label} - set to {value} (was {current})");
-    guard.saved.push(SavedSetting {
-        path,
-        value: current,
-        label: label.to_owned(),
-    });
 }
 
 /// Extract the selected token from a bracketed sysfs value like
@@ -442,19 +511,19 @@ fn set_cpu_governor(guard: &mut TuningGuard, target: &str) {
             continue;
         }
 
-        if let Err(e) = std::fs::write(&gov_path, target) {
+        let gov_utf8_path = Utf8PathBuf::try_from(gov_path)
+            .unwrap_or_else(|p| Utf8PathBuf::from(p.into_path_buf().to_string_lossy().as_ref()));
+        if let Err(e) = guard.save_and_write(
+            &gov_utf8_path,
+            current.clone(),
+            target,
+            format!("CPU governor ({name_str})"),
+        ) {
             println!("  Tuning: CPU governor ({name_str}) - skipped (write failed: {e})");
             continue;
         }
 
         println!("  Tuning: CPU governor ({name_str}) - set to {target} (was {current})");
-        let gov_utf8_path = Utf8PathBuf::try_from(gov_path)
-            .unwrap_or_else(|p| Utf8PathBuf::from(p.into_path_buf().to_string_lossy().as_ref()));
-        guard.saved.push(SavedSetting {
-            path: gov_utf8_path,
-            value: current,
-            label: format!("CPU governor ({name_str})"),
-        });
     }
 }
 
@@ -481,17 +550,12 @@ fn set_smt(guard: &mut TuningGuard) {
         return;
     }
 
-    if let Err(e) = std::fs::write(path.as_str(), "off") {
+    if let Err(e) = guard.save_and_write(&path, current.clone(), "off", "SMT".to_owned()) {
         println!("  Tuning: SMT - skipped (write failed: {e})");
         return;
     }
 
     println!("  Tuning: SMT - disabled (was {current})");
-    guard.saved.push(SavedSetting {
-        path,
-        value: current,
-        label: "SMT".to_owned(),
-    });
 }
 
 /// Disable turboboost. Tries Intel pstate first, then generic cpufreq.
@@ -506,12 +570,19 @@ fn set_turbo(guard: &mut TuningGuard) {
     }
 }
 
-/// Restore a single setting. Used by the Drop impl.
+/// Restore a single setting, returning whether it succeeded.
+/// Used by the Drop impl and the journal replay.
 #[cfg(target_os = "linux")]
-fn restore(path: &Utf8Path, value: &str, label: &str) {
+fn restore(path: &Utf8Path, value: &str, label: &str) -> bool {
     match std::fs::write(path.as_str(), value) {
-        Ok(()) => println!("  Tuning: {label} - restored to {value}"),
-        Err(e) => println!("  Tuning: {label} - restore failed: {e}"),
+        Ok(()) => {
+            println!("  Tuning: {label} - restored to {value}");
+            true
+        },
+        Err(e) => {
+            println!("  Tuning: {label} - restore failed: {e}");
+            false
+        },
     }
 }
 
@@ -525,7 +596,7 @@ pub struct TuningGuard;
 
 /// No-op on non-Linux - returns a stub guard.
 #[cfg(not(target_os = "linux"))]
-pub fn apply(_config: &TuningConfig) -> TuningGuard {
+pub fn apply(_config: &TuningConfig, _host_lock: &HostTuningLock) -> TuningGuard {
     TuningGuard
 }
 
@@ -584,7 +655,7 @@ mod tests {
     fn apply_cpu_scoped_disabled_saves_nothing() {
         let config = TuningConfig::disabled();
         let layout = CpuLayout::with_core_count(8);
-        let mut guard = apply(&config);
+        let mut guard = apply(&config, &HostTuningLock::unlocked());
         apply_cpu_scoped(&config, &layout, &mut guard);
         #[cfg(target_os = "linux")]
         assert!(guard.saved.is_empty());
@@ -596,7 +667,7 @@ mod tests {
         let layout = CpuLayout::with_core_count(1);
         // Empty guard from a disabled config; the single-core layout means
         // apply_cpu_scoped must not touch anything.
-        let mut guard = apply(&TuningConfig::disabled());
+        let mut guard = apply(&TuningConfig::disabled(), &HostTuningLock::unlocked());
         apply_cpu_scoped(&config, &layout, &mut guard);
         #[cfg(target_os = "linux")]
         assert!(guard.saved.is_empty());
@@ -606,13 +677,13 @@ mod tests {
     fn apply_returns_guard() {
         // On any platform, apply should return without panic
         let config = TuningConfig::default();
-        let _guard = apply(&config);
+        let _guard = apply(&config, &HostTuningLock::unlocked());
     }
 
     #[test]
     fn apply_disabled_returns_guard() {
         let config = TuningConfig::disabled();
-        let _guard = apply(&config);
+        let _guard = apply(&config, &HostTuningLock::unlocked());
     }
 
     #[test]
@@ -638,6 +709,7 @@ mod tests {
             let mut guard = TuningGuard {
                 saved: Vec::new(),
                 held_fds: Vec::new(),
+                journal: None,
             };
             guard.saved.push(SavedSetting {
                 path: file_path.clone(),
@@ -668,6 +740,7 @@ mod tests {
             let mut guard = TuningGuard {
                 saved: Vec::new(),
                 held_fds: Vec::new(),
+                journal: None,
             };
             guard.saved.push(SavedSetting {
                 path: path1.clone(),
@@ -691,6 +764,7 @@ mod tests {
         let mut guard = TuningGuard {
             saved: Vec::new(),
             held_fds: Vec::new(),
+            journal: None,
         };
         write_sysctl(&mut guard, "/nonexistent/path/value", "0", "test");
         assert!(
@@ -711,6 +785,7 @@ mod tests {
         let mut guard = TuningGuard {
             saved: Vec::new(),
             held_fds: Vec::new(),
+            journal: None,
         };
         write_sysctl(&mut guard, path.to_str().unwrap(), "0", "test");
         assert!(
@@ -750,6 +825,7 @@ mod tests {
         let mut guard = TuningGuard {
             saved: Vec::new(),
             held_fds: Vec::new(),
+            journal: None,
         };
         write_bracketed_sysctl(&mut guard, path.to_str().unwrap(), "never", "test");
 
@@ -770,6 +846,7 @@ mod tests {
         let mut guard = TuningGuard {
             saved: Vec::new(),
             held_fds: Vec::new(),
+            journal: None,
         };
         write_bracketed_sysctl(&mut guard, path.to_str().unwrap(), "never", "test");
 
@@ -793,6 +870,7 @@ mod tests {
         let mut guard = TuningGuard {
             saved: Vec::new(),
             held_fds: Vec::new(),
+            journal: None,
         };
         write_bracketed_sysctl(&mut guard, path.to_str().unwrap(), "never", "test");
 
@@ -812,6 +890,7 @@ mod tests {
         let mut guard = TuningGuard {
             saved: Vec::new(),
             held_fds: Vec::new(),
+            journal: None,
         };
         write_sysctl(&mut guard, path.to_str().unwrap(), "10", "test");
 
@@ -112,11 +112,14 @@ impl BencherPartition {
         // can succeed at the syscall level while the kernel rejects the
         // partition in the read-back, and the file must still revert on
         // drop. Restoring an unchanged value is a harmless no-op.
-        guard.save_restore(
+        if let Err(e) = guard.save_restore(
             partition_path.clone(),
             original,
             "bencher cpuset partition".to_owned(),
-        );
+        ) {
+            eprintln!("Warning: {e}");
+            return PartitionLevel::Member;
+        }
 
         for (mode, level) in [
             ("isolated", PartitionLevel::Isolated),
@@ -204,17 +207,15 @@ fn save_and_write(guard: &mut TuningGuard, path: &Utf8Path, value: &str, label:
         return true;
     }
 
-    if let Err(e) = fs::write(path, value) {
-        eprintln!("Warning: failed to write {path}: {e}");
-        return false;
-    }
-
     let restore_value = if current.is_empty() {
         "\n".to_owned()
     } else {
         current
     };
-    guard.save_restore(path.to_owned(), restore_value, label.to_owned());
+    if let Err(e) = guard.save_and_write(path, restore_value, value, label.to_owned()) {
+        eprintln!("Warning: failed to write {path}: {e}");
+        return false;
+    }
     true
 }
 
@@ -223,12 +224,12 @@ mod tests {
     use camino::Utf8PathBuf;
 
     use crate::cpu::CpuLayout;
-    use crate::tuning::{TuningConfig, TuningGuard};
+    use crate::tuning::{HostTuningLock, TuningConfig, TuningGuard};
 
     use super::*;
 
     fn empty_guard() -> TuningGuard {
-        crate::tuning::apply(&TuningConfig::disabled())
+        crate::tuning::apply(&TuningConfig::disabled(), &HostTuningLock::unlocked())
     }
 
     /// A fake cgroup v2 tree mirroring what the kernel exposes.
@@ -4,7 +4,10 @@ use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use bencher_json::JsonClaimedJob;
-use bencher_json::runner::{JsonIterationOutput, RunnerMessage, ServerMessage};
+use bencher_json::runner::{
+    JsonDropReason, JsonDroppedIteration, JsonIterationOutput, JsonIterationTrim, RunnerMessage,
+    ServerMessage,
+};
 use camino::Utf8PathBuf;
 
 use super::UpConfig;
@@ -64,6 +67,10 @@ pub fn execute_job(
         },
     };
     let iter_count = job.config.iter.map_or(1, bencher_json::Iteration::as_usize);
+    let warmup_count = job
+        .config
+        .warmup
+        .map_or(0, bencher_json::Iteration::as_usize);
     let allow_failure = job.config.allow_failure.unwrap_or_default();
 
     let cancel_flag = Arc::new(AtomicBool::new(false));
@@ -72,34 +79,23 @@ pub fn execute_job(
 
     // Execute benchmark iterations — pass cancel_flag so the vsock poll loop
     // can abort early when the server sends a cancellation message.
-    let mut results = Vec::with_capacity(iter_count);
+    let mut results = Vec::with_capacity(warmup_count + iter_count);
     let mut last_exit_code = 0;
     let mut last_stdout_preview = None;
     let mut failed_error = None;
 
     let build_time = job_config.build_time;
     let file_size = job_config.file_size;
-    let benchmark_name = if build_time {
-        match build_benchmark_name(job) {
-            Ok(name) => Some(name),
-            Err(e) => {
-                eprintln!("Warning: failed to derive benchmark name for build time: {e}");
-                None
-            },
-        }
-    } else {
-        None
-    };
+    let benchmark_name = (build_time || job_config.perf_counters)
+        .then(|| runner_benchmark_name(job))
+        .flatten();
 
-    for iteration in 0..iter_count {
+    for iteration in 0..warmup_count + iter_count {
         if cancel_flag.load(Ordering::SeqCst) {
             break;
         }
-        println!(
-            "Starting iteration {}/{iter_count} for job {}",
-            iteration + 1,
-            job.uuid
-        );
+        let warmup = iteration < warmup_count;
+        print_iteration_start(job, iteration, warmup_count, iter_count);
         let start = build_time.then(std::time::Instant::now);
         let result = crate::execute(&job_config, Some(&cancel_flag));
         let elapsed = start.map(|s| s.elapsed());
@@ -110,12 +106,15 @@ pub fn execute_job(
                     last_stdout_preview = Some(output.stdout.clone());
                 }
                 let failed = output.exit_code != 0 && !allow_failure;
-                results.push(output_to_iteration(
-                    output,
-                    elapsed,
-                    file_size,
-                    benchmark_name.as_ref(),
-                ));
+                let mut iteration_output =
+                    output_to_iteration(output, elapsed, file_size, benchmark_name.as_ref());
+                if warmup {
+                    iteration_output.dropped = Some(JsonDroppedIteration {
+                        reason: JsonDropReason::Warmup,
+                        value: None,
+                    });
+                }
+                results.push(iteration_output);
                 if failed {
                     failed_error = Some(format!(
                         "Benchmark exited with non-zero exit code: {last_exit_code}"
@@ -154,6 +153,10 @@ pub fn execute_job(
         return JobFinishResult::Canceled;
     }
 
+    if let Some(trim) = &job.config.trim {
+        trim_results(&mut results, trim);
+    }
+
     JobFinishResult::Completed {
         exit_code: last_exit_code,
         output: last_stdout_preview,
@@ -161,7 +164,60 @@ pub fn execute_job(
     }
 }
 
-/// Derive the benchmark name for build-time tracking from the job config.
+#[expect(
+    clippy::print_stdout,
+    reason = "runner CLI output for job execution status"
+)]
+fn print_iteration_start(
+    job: &JsonClaimedJob,
+    iteration: usize,
+    warmup_count: usize,
+    iter_count: usize,
+) {
+    if iteration < warmup_count {
+        println!(
+            "Starting warm-up iteration {}/{warmup_count} for job {}",
+            iteration + 1,
+            job.uuid
+        );
+    } else {
+        println!(
+            "Starting iteration {}/{iter_count} for job {}",
+            iteration - warmup_count + 1,
+            job.uuid
+        );
+    }
+}
+
+#[expect(
+    clippy::print_stdout,
+    clippy::print_stderr,
+    reason = "runner CLI output for job execution status"
+)]
+fn trim_results(results: &mut [JsonIterationOutput], trim: &JsonIterationTrim) {
+    match super::trim::trim_iterations(results, trim) {
+        Ok(()) => println!(
+            "Trimmed {} iterations from each end by {}",
+            trim.count, trim.measure
+        ),
+        Err(e) => eprintln!("Warning: not trimming iterations: {e}"),
+    }
+}
+
+#[expect(
+    clippy::print_stderr,
+    reason = "runner CLI output for job execution status"
+)]
+fn runner_benchmark_name(job: &JsonClaimedJob) -> Option<bencher_json::BenchmarkName> {
+    build_benchmark_name(job)
+        .inspect_err(|e| {
+            eprintln!("Warning: failed to derive benchmark name for runner measures: {e}");
+        })
+        .ok()
+}
+
+/// Derive the benchmark name for build time and hardware counters from the
+/// job config.
 ///
 /// Precedence: entrypoint+cmd joined string (matches CLI behavior),
 /// then image reference, then project@digest as last resort.
@@ -192,6 +248,11 @@ fn output_to_iteration(
     file_size: bool,
     benchmark_name: Option<&bencher_json::BenchmarkName>,
 ) -> JsonIterationOutput {
+    let metrics = output
+        .perf_counts
+        .as_ref()
+        .zip(benchmark_name)
+        .and_then(|(perf_counts, name)| perf_results(perf_counts, name));
     let file_output =
         build_metric_output(build_time, file_size, output.output_files, benchmark_name);
     JsonIterationOutput {
@@ -207,9 +268,43 @@ fn output_to_iteration(
             Some(output.stderr)
         },
         output: file_output,
+        metrics,
+        dropped: None,
     }
 }
 
+/// Hardware counter results for the benchmark, if any event was counted.
+fn perf_results(
+    perf_counts: &crate::metrics::PerfCounts,
+    benchmark_name: &bencher_json::BenchmarkName,
+) -> Option<bencher_json::JsonResultsMap> {
+    use bencher_json::{
+        JsonNewMetric,
+        project::measure::built_in::{BuiltInMeasure as _, perf},
+    };
+
+    let metrics = [
+        (perf::Instructions::name_id(), perf_counts.instructions),
+        (perf::Cycles::name_id(), perf_counts.cycles),
+        (perf::BranchMisses::name_id(), perf_counts.branch_misses),
+        (perf::CacheMisses::name_id(), perf_counts.cache_misses),
+    ]
+    .into_iter()
+    .filter_map(|(measure, count)| {
+        #[expect(clippy::cast_precision_loss, reason = "counter as f64 metric value")]
+        let value = count? as f64;
+        Some((
+            measure,
+            JsonNewMetric {
+                value: value.into(),
+                ..Default::default()
+            },
+        ))
+    })
+    .collect::<bencher_json::JsonMetricsMap>();
+    (!metrics.is_empty()).then(|| [(benchmark_name.clone(), metrics)].into())
+}
+
 #[expect(clippy::print_stderr, reason = "runner CLI warning output")]
 fn build_metric_output(
     build_time: Option<Duration>,
@@ -335,6 +430,7 @@ fn build_config_from_job(
         .with_network(spec.network)
         .with_build_time(config.build_time.unwrap_or_default())
         .with_file_size(config.file_size.unwrap_or_default())
+        .with_perf_counters(up_config.perf_counters)
         .with_entrypoint_opt(config.entrypoint.clone())
         .with_cmd_opt(config.cmd.clone())
         .with_env_opt(config.env.clone());
@@ -540,6 +636,7 @@ mod tests {
             grace_period: None,
             sandbox_log_level: crate::SandboxLogLevel::default(),
             allow_no_sandbox: false,
+            perf_counters: false,
             no_auto_update: false,
             update_channel: bencher_valid::UpdateChannel::default(),
             max_download_size: None,
@@ -892,6 +989,17 @@ mod tests {
         assert!(!result.build_time);
     }
 
+    #[test]
+    fn perf_counters_passed_through() {
+        let up_config = UpConfig {
+            perf_counters: true,
+            ..test_up_config()
+        };
+        let job = test_job(1, mib_to_bytes(512), mib_to_bytes(1024), 300, false);
+        let result = build_config_from_job(&up_config, &job).unwrap();
+        assert!(result.perf_counters);
+    }
+
     #[test]
     fn file_size_default_false() {
         let up_config = test_up_config();
@@ -917,6 +1025,7 @@ mod tests {
                     .map(|(k, v)| (Utf8PathBuf::from(k), v.to_vec()))
                     .collect()
             }),
+            perf_counts: None,
         }
     }
 
@@ -1082,4 +1191,48 @@ mod tests {
         // build_time duration is set but no benchmark_name → no build-time metric
         assert!(result.output.is_none());
     }
+
+    // --- output_to_iteration: perf counters ---
+
+    #[test]
+    fn output_perf_counts_as_metrics() {
+        let name = default_benchmark_name();
+        let mut output = test_output("hello", None);
+        output.perf_counts = Some(crate::metrics::PerfCounts {
+            instructions: Some(1_000),
+            cycles: Some(2_000),
+            branch_misses: None,
+            cache_misses: Some(3),
+        });
+        let result = output_to_iteration(output, None, false, Some(&name));
+        // Counters do not replace the benchmark's own output
+        assert!(result.output.is_none());
+        let metrics = result.metrics.unwrap();
+        let benchmark = &metrics[&name];
+        assert_eq!(benchmark.len(), 3);
+        let instructions: bencher_json::MeasureNameId = "instructions".parse().unwrap();
+        assert_eq!(benchmark[&instructions].value, 1_000.0);
+        let branch_misses: bencher_json::MeasureNameId = "branch-misses".parse().unwrap();
+        assert!(!benchmark.contains_key(&branch_misses));
+    }
+
+    #[test]
+    fn output_perf_counts_none_counted() {
+        let name = default_benchmark_name();
+        let mut output = test_output("hello", None);
+        output.perf_counts = Some(crate::metrics::PerfCounts::default());
+        let result = output_to_iteration(output, None, false, Some(&name));
+        assert!(result.metrics.is_none());
+    }
+
+    #[test]
+    fn output_perf_counts_no_benchmark_name() {
+        let mut output = test_output("hello", None);
+        output.perf_counts = Some(crate::metrics::PerfCounts {
+            instructions: Some(1_000),
+            ..Default::default()
+        });
+        let result = output_to_iteration(output, None, false, None);
+        assert!(result.metrics.is_none());
+    }
 }
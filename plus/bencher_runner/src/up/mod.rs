@@ -11,6 +11,7 @@ mod api_client;
 mod error;
 mod job;
 mod state_machine;
+mod trim;
 mod websocket;
 
 pub use error::UpError;
@@ -61,6 +62,8 @@ pub struct UpConfig {
     pub sandbox_log_level: SandboxLogLevel,
     /// Whether to allow non-sandboxed execution.
     pub allow_no_sandbox: bool,
+    /// Collect hardware performance counters for each job iteration.
+    pub perf_counters: bool,
     /// Disable auto-update: do not send runner metadata to the server.
     pub no_auto_update: bool,
     /// Update channel for automatic updates.
@@ -106,13 +109,16 @@ impl Up {
 
         // Serialize host-global tuning across runner processes. Declared
         // before the guard so the lock releases only after restore completes.
-        let host_lock = crate::tuning::HostTuningLock::acquire();
+        // A journal left by a runner that died with tuning applied is
+        // replayed first, so its tuned values are not saved as originals.
+        let mut host_lock = crate::tuning::HostTuningLock::acquire();
+        host_lock.recover();
         let tuning = host_lock.effective_tuning(&self.config.tuning);
 
         // Apply host tuning - guard restores settings on drop (no-op on non-Linux).
         // This must happen before CPU layout detection so that SMT changes
         // are reflected in the core count.
-        let mut tuning_guard = crate::tuning::apply(&tuning);
+        let mut tuning_guard = crate::tuning::apply(&tuning, &host_lock);
 
         // Re-detect CPU layout after tuning (SMT may have changed core count).
         // Linux-only: CpuLayout::detect() reads /sys/devices which only exists on Linux.
@@ -0,0 +1,222 @@
+//! Outlier iteration trimming.
+//!
+//! The runner never runs the adapter, so iterations are ranked by the
+//! trim measure wherever the runner can read it without one: its own
+//! built-in measures and any Bencher Metric Format (BMF) output. An
+//! iteration's value is the sum of the measure across its benchmarks.
+
+use bencher_json::JsonResultsMap;
+use bencher_json::runner::{
+    JsonDropReason, JsonDroppedIteration, JsonIterationOutput, JsonIterationTrim,
+};
+
+/// Why trimming was skipped.
+#[derive(Debug, thiserror::Error)]
+pub enum TrimError {
+    #[error("only {kept} successful iterations remain, too few to trim {count} from each end")]
+    TooFew { kept: usize, count: u32 },
+    #[error("iteration {iteration} has no `{measure}` value to rank by")]
+    MissingValue { iteration: usize, measure: String },
+}
+
+/// Drop the lowest and highest `trim.count` iterations by the trim measure.
+///
+/// Only successful iterations that have not already been dropped are
+/// ranked. Iterations are only marked as dropped, never removed, so the
+/// job output still records what was dropped and why. On error nothing
+/// is dropped.
+pub fn trim_iterations(
+    results: &mut [JsonIterationOutput],
+    trim: &JsonIterationTrim,
+) -> Result<(), TrimError> {
+    let mut ranked = Vec::with_capacity(results.len());
+    for (index, result) in results.iter().enumerate() {
+        if result.dropped.is_some() || result.exit_code != 0 {
+            continue;
+        }
+        let value = iteration_value(result, trim).ok_or_else(|| TrimError::MissingValue {
+            iteration: index + 1,
+            measure: trim.measure.to_string(),
+        })?;
+        ranked.push((index, value));
+    }
+
+    let count = usize::try_from(trim.count).unwrap_or(usize::MAX);
+    if count.saturating_mul(2) >= ranked.len() {
+        return Err(TrimError::TooFew {
+            kept: ranked.len(),
+            count: trim.count,
+        });
+    }
+
+    ranked.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+    let high_start = ranked.len() - count;
+    for (rank, (index, value)) in ranked.into_iter().enumerate() {
+        let reason = if rank < count {
+            JsonDropReason::TrimLow
+        } else if rank >= high_start {
+            JsonDropReason::TrimHigh
+        } else {
+            continue;
+        };
+        if let Some(result) = results.get_mut(index) {
+            result.dropped = Some(JsonDroppedIteration {
+                reason,
+                value: Some(value),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// The sum of the trim measure across every benchmark of an iteration.
+fn iteration_value(result: &JsonIterationOutput, trim: &JsonIterationTrim) -> Option<f64> {
+    // Output files take precedence over stdout, matching how the server
+    // picks what to parse.
+    let parsed = if let Some(output) = &result.output {
+        output
+            .values()
+            .filter_map(|contents| serde_json::from_str::<JsonResultsMap>(contents).ok())
+            .collect()
+    } else {
+        result
+            .stdout
+            .as_deref()
+            .and_then(|stdout| serde_json::from_str::<JsonResultsMap>(stdout).ok())
+            .into_iter()
+            .collect::<Vec<_>>()
+    };
+
+    parsed
+        .iter()
+        .chain(result.metrics.as_ref())
+        .flat_map(|results| results.values())
+        .filter_map(|metrics| metrics.get(&trim.measure))
+        .map(|metric| metric.value.into_inner())
+        .reduce(|sum, value| sum + value)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use camino::Utf8PathBuf;
+
+    use super::*;
+
+    fn trim(count: u32) -> JsonIterationTrim {
+        JsonIterationTrim {
+            measure: "latency".parse().unwrap(),
+            count,
+        }
+    }
+
+    fn bmf_stdout(latency: f64) -> JsonIterationOutput {
+        JsonIterationOutput {
+            exit_code: 0,
+            stdout: Some(format!(
+                r#"{{"a": {{"latency": {{"value": {latency}}}}}, "b": {{"latency": {{"value": 1.0}}}}}}"#
+            )),
+            stderr: None,
+            output: None,
+            metrics: None,
+            dropped: None,
+        }
+    }
+
+    fn reasons(results: &[JsonIterationOutput]) -> Vec<Option<JsonDropReason>> {
+        results
+            .iter()
+            .map(|result| result.dropped.map(|dropped| dropped.reason))
+            .collect()
+    }
+
+    #[test]
+    fn trims_lowest_and_highest() {
+        let mut results = [5.0, 1.0, 3.0, 9.0, 4.0].map(bmf_stdout);
+        trim_iterations(&mut results, &trim(1)).unwrap();
+        assert_eq!(
+            reasons(&results),
+            [
+                None,
+                Some(JsonDropReason::TrimLow),
+                None,
+                Some(JsonDropReason::TrimHigh),
+                None,
+            ]
+        );
+        // The value is summed across benchmarks
+        assert_eq!(results[3].dropped.unwrap().value, Some(10.0));
+    }
+
+    #[test]
+    fn skips_warmup_and_failed() {
+        let mut results = [100.0, 1.0, 2.0, 3.0, 0.0].map(bmf_stdout);
+        results[0].dropped = Some(JsonDroppedIteration {
+            reason: JsonDropReason::Warmup,
+            value: None,
+        });
+        results[4].exit_code = 1;
+        trim_iterations(&mut results, &trim(1)).unwrap();
+        assert_eq!(
+            reasons(&results),
+            [
+                Some(JsonDropReason::Warmup),
+                Some(JsonDropReason::TrimLow),
+                None,
+                Some(JsonDropReason::TrimHigh),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn too_few_iterations() {
+        let mut results = [1.0, 2.0].map(bmf_stdout);
+        let err = trim_iterations(&mut results, &trim(1)).unwrap_err();
+        assert!(matches!(err, TrimError::TooFew { kept: 2, count: 1 }));
+        assert_eq!(reasons(&results), [None, None]);
+    }
+
+    #[test]
+    fn missing_value() {
+        let mut results = [1.0, 2.0, 3.0].map(bmf_stdout);
+        results[1].stdout = Some("not bmf".to_owned());
+        let err = trim_iterations(&mut results, &trim(1)).unwrap_err();
+        assert!(matches!(err, TrimError::MissingValue { iteration: 2, .. }));
+        assert_eq!(reasons(&results), [None, None, None]);
+    }
+
+    #[test]
+    fn ranks_by_output_files_and_runner_metrics() {
+        let mut results = [1.0, 2.0, 3.0].map(|instructions| JsonIterationOutput {
+            exit_code: 0,
+            stdout: Some("plain text".to_owned()),
+            stderr: None,
+            output: Some(BTreeMap::from([(
+                Utf8PathBuf::from("results.txt"),
+                "not bmf".to_owned(),
+            )])),
+            metrics: Some(
+                serde_json::from_str(&format!(
+                    r#"{{"job": {{"instructions": {{"value": {instructions}}}}}}}"#
+                ))
+                .unwrap(),
+            ),
+            dropped: None,
+        });
+        let trim = JsonIterationTrim {
+            measure: "instructions".parse().unwrap(),
+            count: 1,
+        };
+        trim_iterations(&mut results, &trim).unwrap();
+        assert_eq!(
+            reasons(&results),
+            [
+                Some(JsonDropReason::TrimLow),
+                None,
+                Some(JsonDropReason::TrimHigh),
+            ]
+        );
+    }
+}
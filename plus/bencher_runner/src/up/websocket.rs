@@ -271,6 +271,8 @@ mod tests {
                 stdout: Some("stdout output".to_owned()),
                 stderr: Some("stderr output".to_owned()),
                 output: Some(output),
+                metrics: None,
+                dropped: None,
             }],
         };
         let json: serde_json::Value = serde_json::to_value(&msg).unwrap();
@@ -294,6 +296,8 @@ mod tests {
                 stdout: None,
                 stderr: None,
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
         };
         let json: serde_json::Value = serde_json::to_value(&msg).unwrap();
@@ -314,6 +318,8 @@ mod tests {
                 stdout: Some("partial stdout".to_owned()),
                 stderr: Some("error details".to_owned()),
                 output: None,
+                metrics: None,
+                dropped: None,
             }],
             error: "OOM killed".to_owned(),
         };
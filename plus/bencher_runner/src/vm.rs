@@ -120,6 +120,7 @@ fn build_firecracker_config(
         timeout_secs: config.timeout_secs,
         work_dir: work_dir.to_owned(),
         cpu_layout: config.cpu_layout.clone(),
+        perf_counters: config.perf_counters,
         log_level: config.sandbox_log_level,
         max_file_count: config.max_file_count,
         max_content_size: config.max_content_size,
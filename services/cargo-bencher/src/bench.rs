@@ -0,0 +1,181 @@
+use std::{
+    io::{BufRead as _, BufReader, Write as _},
+    process::{Command, Stdio},
+};
+
+use bencher_adapter::{
+    Adaptable as _, AdapterResults, Settings, results::adapter_results::ResultsMap,
+};
+use bencher_json::BenchmarkNameId;
+use camino::{Utf8Path, Utf8PathBuf};
+
+use crate::{
+    CargoBencherError, cargo,
+    harness::Harness,
+    metadata::{Package, Target},
+};
+
+/// The results for all of the bench targets in a single package
+pub struct PackageBench<'a> {
+    package: &'a Package,
+    results: AdapterResults,
+}
+
+impl<'a> PackageBench<'a> {
+    /// Run each bench target with `cargo bench` and merge the results,
+    /// prefixing each benchmark name with the package name.
+    pub fn run(package: &'a Package, targets: &[&Target]) -> Result<Self, CargoBencherError> {
+        let manifest = package.manifest()?;
+        let mut results = AdapterResults::default();
+        for target in targets {
+            let harness = Harness::detect(package, &manifest, target)?;
+            let output = cargo_bench(package, target, harness)?;
+            let adapter = harness.adapter();
+            let target_results =
+                adapter
+                    .convert(&output, Settings::default())
+                    .ok_or_else(|| CargoBencherError::ParseResults {
+                        package: package.name.clone(),
+                        bench: target.name.clone(),
+                        adapter,
+                    })?;
+            results
+                .inner
+                .extend(prefix_results(&package.name, target_results)?.inner);
+        }
+        Ok(Self { package, results })
+    }
+
+    /// Write the results as Bencher Metric Format (BMF) JSON to `{target_dir}/bencher/{package}.json`
+    pub fn write(&self, target_directory: &Utf8Path) -> Result<Utf8PathBuf, CargoBencherError> {
+        let dir = target_directory.join("bencher");
+        std::fs::create_dir_all(&dir).map_err(|err| CargoBencherError::CreateDir {
+            path: dir.clone(),
+            err,
+        })?;
+        let path = dir.join(format!("{}.json", self.package.name));
+        let bmf = serde_json::to_string_pretty(&self.results).map_err(|err| {
+            CargoBencherError::SerializeResults {
+                package: self.package.name.clone(),
+                err,
+            }
+        })?;
+        std::fs::write(&path, bmf).map_err(|err| CargoBencherError::WriteResults {
+            path: path.clone(),
+            err,
+        })?;
+        Ok(path)
+    }
+
+    /// Submit the results as a single report with `bencher run`
+    pub fn submit(
+        &self,
+        bencher: &str,
+        path: &Utf8Path,
+        run_args: &[String],
+    ) -> Result<(), CargoBencherError> {
+        let status = Command::new(bencher)
+            .arg("run")
+            .args(["--adapter", "json", "--file"])
+            .arg(path)
+            .args(run_args)
+            .status()
+            .map_err(|err| CargoBencherError::BencherRun {
+                bencher: bencher.to_owned(),
+                package: self.package.name.clone(),
+                err,
+            })?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(CargoBencherError::BencherRunStatus {
+                bencher: bencher.to_owned(),
+                package: self.package.name.clone(),
+                status,
+            })
+        }
+    }
+}
+
+/// Run `cargo bench` for a single bench target, streaming and capturing its stdout
+fn cargo_bench(
+    package: &Package,
+    target: &Target,
+    harness: Harness,
+) -> Result<String, CargoBencherError> {
+    let cargo_bench_err = |err| CargoBencherError::CargoBench {
+        package: package.name.clone(),
+        bench: target.name.clone(),
+        err,
+    };
+    let mut child = Command::new(cargo())
+        .arg("bench")
+        .arg("--manifest-path")
+        .arg(&package.manifest_path)
+        .args(["--package", &package.name, "--bench", &target.name, "--"])
+        .args(harness.args())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(cargo_bench_err)?;
+
+    let mut output = String::new();
+    if let Some(stdout) = child.stdout.take() {
+        let mut out = std::io::stdout().lock();
+        for line in BufReader::new(stdout).lines() {
+            let line = line.map_err(cargo_bench_err)?;
+            writeln!(out, "{line}").map_err(cargo_bench_err)?;
+            output.push_str(&line);
+            output.push('\n');
+        }
+    }
+
+    let status = child.wait().map_err(cargo_bench_err)?;
+    if status.success() {
+        Ok(output)
+    } else {
+        Err(CargoBencherError::CargoBenchStatus {
+            package: package.name.clone(),
+            bench: target.name.clone(),
+            status,
+        })
+    }
+}
+
+fn prefix_results(
+    package: &str,
+    results: AdapterResults,
+) -> Result<AdapterResults, CargoBencherError> {
+    results
+        .inner
+        .into_iter()
+        .map(|(name, metrics)| {
+            let prefixed = format!("{package}::{name}");
+            prefixed
+                .parse::<BenchmarkNameId>()
+                .map(|name| (name, metrics))
+                .map_err(|err| CargoBencherError::BenchmarkName(prefixed, err))
+        })
+        .collect::<Result<ResultsMap, _>>()
+        .map(AdapterResults::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use bencher_adapter::{Adaptable as _, Settings};
+    use bencher_json::{BenchmarkNameId, project::report::Adapter};
+
+    use super::prefix_results;
+
+    #[test]
+    fn prefix() {
+        let output = "test tests::bench_fib ... bench:   3,161 ns/iter (+/- 975)\n";
+        let results = Adapter::RustBench
+            .convert(output, Settings::default())
+            .unwrap();
+        let results = prefix_results("my-crate", results).unwrap();
+        assert_eq!(results.inner.len(), 1);
+        let name: BenchmarkNameId = "my-crate::tests::bench_fib".parse().unwrap();
+        assert!(results.inner.contains_key(&name));
+    }
+}
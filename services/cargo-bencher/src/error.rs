@@ -0,0 +1,101 @@
+use std::process::ExitStatus;
+
+use bencher_json::{ValidError, project::report::Adapter};
+use camino::Utf8PathBuf;
+
+#[derive(thiserror::Error, Debug)]
+pub enum CargoBencherError {
+    #[error("Failed to run `cargo metadata`: {0}")]
+    CargoMetadata(std::io::Error),
+    #[error("`cargo metadata` failed with status: {0}")]
+    CargoMetadataStatus(ExitStatus),
+    #[error("Failed to parse `cargo metadata` output: {0}")]
+    ParseMetadata(serde_json::Error),
+    #[error("Package `{0}` is not a member of the workspace")]
+    UnknownPackage(String),
+    #[error("Bench target `{0}` was not found in any of the selected packages")]
+    UnknownBench(String),
+    #[error("No bench targets found in the workspace")]
+    NoBenches,
+
+    #[error("Failed to read package manifest ({path}): {err}")]
+    ReadManifest {
+        path: Utf8PathBuf,
+        err: std::io::Error,
+    },
+    #[error("Failed to parse package manifest ({path}): {err}")]
+    ParseManifest {
+        path: Utf8PathBuf,
+        err: toml::de::Error,
+    },
+    #[error("Failed to read bench target source ({path}): {err}")]
+    ReadSource {
+        path: Utf8PathBuf,
+        err: std::io::Error,
+    },
+    #[error(
+        "Bench target `{bench}` in package `{package}` has `harness = false`, but neither Criterion nor Gungraun is a dependency"
+    )]
+    UnknownHarness { package: String, bench: String },
+
+    #[error("Failed to run `cargo bench` for `{package}` bench target `{bench}`: {err}")]
+    CargoBench {
+        package: String,
+        bench: String,
+        err: std::io::Error,
+    },
+    #[error("`cargo bench` for `{package}` bench target `{bench}` failed with status: {status}")]
+    CargoBenchStatus {
+        package: String,
+        bench: String,
+        status: ExitStatus,
+    },
+    #[error(
+        "Failed to parse the results for `{package}` bench target `{bench}` with the `{adapter}` adapter"
+    )]
+    ParseResults {
+        package: String,
+        bench: String,
+        adapter: Adapter,
+    },
+    #[error("Failed to prefix benchmark name ({0}): {1}")]
+    BenchmarkName(String, ValidError),
+
+    #[error("Failed to create results directory ({path}): {err}")]
+    CreateDir {
+        path: Utf8PathBuf,
+        err: std::io::Error,
+    },
+    #[error("Failed to serialize results for `{package}`: {err}")]
+    SerializeResults {
+        package: String,
+        err: serde_json::Error,
+    },
+    #[error("Failed to write results file ({path}): {err}")]
+    WriteResults {
+        path: Utf8PathBuf,
+        err: std::io::Error,
+    },
+    #[error(
+        "Failed to run `{bencher} run` for `{package}` (is the `bencher` CLI installed? https://bencher.dev/docs/how-to/install-cli/): {err}"
+    )]
+    BencherRun {
+        bencher: String,
+        package: String,
+        err: std::io::Error,
+    },
+    #[error("`{bencher} run` for `{package}` failed with status: {status}")]
+    BencherRunStatus {
+        bencher: String,
+        package: String,
+        status: ExitStatus,
+    },
+
+    #[error(
+        "Failed to run `{bencher}` (is the `bencher` CLI installed? https://bencher.dev/docs/how-to/install-cli/): {err}"
+    )]
+    Bencher {
+        bencher: String,
+        err: std::io::Error,
+    },
+}
@@ -0,0 +1,189 @@
+use bencher_json::project::report::Adapter;
+
+use crate::{
+    CargoBencherError,
+    metadata::{Manifest, Package, Target},
+};
+
+const CRITERION: &[&str] = &["criterion", "codspeed-criterion-compat"];
+const GUNGRAUN: &[&str] = &["gungraun", "iai-callgrind"];
+const CRITERION_MAIN: &str = "criterion_main!";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Harness {
+    Libtest,
+    Criterion,
+    Gungraun,
+}
+
+impl Harness {
+    /// Detect the harness for a bench target from its `harness` setting and the package dev-dependencies.
+    /// If both Criterion and Gungraun are dependencies, then the target source is checked for `criterion_main!`.
+    pub fn detect(
+        package: &Package,
+        manifest: &Manifest,
+        target: &Target,
+    ) -> Result<Self, CargoBencherError> {
+        if manifest.harness(package.dir(), target) {
+            return Ok(Self::Libtest);
+        }
+        match (
+            package.has_dependency(CRITERION),
+            package.has_dependency(GUNGRAUN),
+        ) {
+            (true, false) => Ok(Self::Criterion),
+            (false, true) => Ok(Self::Gungraun),
+            (true, true) => {
+                let source = std::fs::read_to_string(&target.src_path).map_err(|err| {
+                    CargoBencherError::ReadSource {
+                        path: target.src_path.clone(),
+                        err,
+                    }
+                })?;
+                Ok(Self::from_source(&source))
+            },
+            (false, false) => Err(CargoBencherError::UnknownHarness {
+                package: package.name.clone(),
+                bench: target.name.clone(),
+            }),
+        }
+    }
+
+    fn from_source(source: &str) -> Self {
+        if source.contains(CRITERION_MAIN) {
+            Self::Criterion
+        } else {
+            Self::Gungraun
+        }
+    }
+
+    pub fn adapter(self) -> Adapter {
+        match self {
+            Self::Libtest => Adapter::RustBench,
+            Self::Criterion => Adapter::RustCriterion,
+            Self::Gungraun => Adapter::RustGungraun,
+        }
+    }
+
+    /// Arguments passed through to the bench target harness
+    pub fn args(self) -> &'static [&'static str] {
+        match self {
+            Self::Libtest | Self::Gungraun => &[],
+            Self::Criterion => &["--noplot"],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metadata::{Dependency, Manifest, ManifestTarget, Package, Target};
+
+    use super::Harness;
+
+    fn package(dependencies: &[&str]) -> Package {
+        Package {
+            id: "example 0.1.0".to_owned(),
+            name: "example".to_owned(),
+            manifest_path: "Cargo.toml".into(),
+            dependencies: dependencies
+                .iter()
+                .map(|name| Dependency {
+                    name: (*name).to_owned(),
+                })
+                .collect(),
+            targets: Vec::new(),
+        }
+    }
+
+    fn target() -> Target {
+        Target {
+            name: "bench".to_owned(),
+            kind: vec!["bench".to_owned()],
+            src_path: "benches/bench.rs".into(),
+        }
+    }
+
+    fn manifest(harness: Option<bool>) -> Manifest {
+        Manifest {
+            bench: vec![ManifestTarget {
+                name: Some("bench".to_owned()),
+                path: None,
+                harness,
+            }],
+        }
+    }
+
+    #[test]
+    fn detect_libtest() {
+        let harness =
+            Harness::detect(&package(&["criterion"]), &manifest(None), &target()).unwrap();
+        assert_eq!(harness, Harness::Libtest);
+        let harness = Harness::detect(&package(&[]), &Manifest::default(), &target()).unwrap();
+        assert_eq!(harness, Harness::Libtest);
+    }
+
+    #[test]
+    fn detect_unnamed_path() {
+        // A `[[bench]]` entry without a name is matched by its path
+        let manifest = Manifest {
+            bench: vec![ManifestTarget {
+                name: None,
+                path: Some("benches/bench.rs".into()),
+                harness: Some(false),
+            }],
+        };
+        let harness = Harness::detect(&package(&["criterion"]), &manifest, &target()).unwrap();
+        assert_eq!(harness, Harness::Criterion);
+
+        let manifest = Manifest {
+            bench: vec![ManifestTarget {
+                name: None,
+                path: Some("benches/other.rs".into()),
+                harness: Some(false),
+            }],
+        };
+        let harness = Harness::detect(&package(&["criterion"]), &manifest, &target()).unwrap();
+        assert_eq!(harness, Harness::Libtest);
+    }
+
+    #[test]
+    fn detect_criterion() {
+        let harness = Harness::detect(
+            &package(&["codspeed-criterion-compat"]),
+            &manifest(Some(false)),
+            &target(),
+        )
+        .unwrap();
+        assert_eq!(harness, Harness::Criterion);
+    }
+
+    #[test]
+    fn detect_gungraun() {
+        let harness = Harness::detect(
+            &package(&["iai-callgrind"]),
+            &manifest(Some(false)),
+            &target(),
+        )
+        .unwrap();
+        assert_eq!(harness, Harness::Gungraun);
+    }
+
+    #[test]
+    fn detect_unknown() {
+        Harness::detect(&package(&["divan"]), &manifest(Some(false)), &target()).unwrap_err();
+    }
+
+    #[test]
+    fn from_source() {
+        assert_eq!(
+            Harness::from_source("criterion_group!(benches, bench);\ncriterion_main!(benches);"),
+            Harness::Criterion
+        );
+        assert_eq!(
+            Harness::from_source(
+                "library_benchmark_group!(name = group);\nmain!(library_benchmark_groups = group);"
+            ),
+            Harness::Gungraun
+        );
+    }
+}
@@ -1,18 +1,105 @@
-use std::env;
-use std::process::Command;
+use std::process::{Command, ExitCode};
 
-fn main() {
-    // Collect all command-line arguments
-    let args: Vec<String> = env::args().skip(2).collect();
+use clap::Parser as _;
 
-    // Run the `bencher` command with the collected arguments
-    let status = Command::new("bencher")
-        .args(&args)
+mod bench;
+mod error;
+mod harness;
+mod metadata;
+mod parser;
+
+use bench::PackageBench;
+use error::CargoBencherError;
+use metadata::Metadata;
+use parser::{CargoCli, CliBencher};
+
+#[expect(
+    clippy::print_stderr,
+    reason = "Cargo subcommand reports errors to stderr"
+)]
+fn main() -> ExitCode {
+    let args = std::env::args().collect::<Vec<_>>();
+    let result = if let Some(args) = passthrough_args(&args) {
+        passthrough(args)
+    } else {
+        let CargoCli::Bencher(bencher) = CargoCli::parse_from(&args);
+        run(&bencher).map(|()| ExitCode::SUCCESS)
+    };
+    match result {
+        Ok(exit_code) => exit_code,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        },
+    }
+}
+
+/// Run the benchmarks for each package and submit one report per package
+fn run(bencher: &CliBencher) -> Result<(), CargoBencherError> {
+    let metadata = Metadata::new(bencher.manifest_path.as_deref())?;
+    for (package, targets) in metadata.bench_packages(&bencher.package, &bencher.bench)? {
+        let package_bench = PackageBench::run(package, &targets)?;
+        let path = package_bench.write(&metadata.target_directory)?;
+        package_bench.submit(&bencher.bencher, &path, &bencher.run_args)?;
+    }
+    Ok(())
+}
+
+/// The arguments for `cargo bencher <COMMAND> [ARGS]...`, if a `bencher` subcommand is given.
+/// These are passed through to `bencher <COMMAND> [ARGS]...` as they were before
+/// `cargo bencher` could run the benchmarks itself, so `cargo bencher run ...` still works.
+fn passthrough_args(args: &[String]) -> Option<&[String]> {
+    // Cargo invokes `cargo-bencher bencher [ARGS]...`
+    let args = args.get(2..)?;
+    args.first()
+        .is_some_and(|command| !command.starts_with('-'))
+        .then_some(args)
+}
+
+fn passthrough(args: &[String]) -> Result<ExitCode, CargoBencherError> {
+    let bencher = std::env::var("BENCHER_BIN").unwrap_or_else(|_| "bencher".to_owned());
+    let status = Command::new(&bencher)
+        .args(args)
         .status()
-        .expect("Failed to execute `bencher` command");
+        .map_err(|err| CargoBencherError::Bencher { bencher, err })?;
+    Ok(status
+        .code()
+        .and_then(|code| u8::try_from(code).ok())
+        .map_or(ExitCode::FAILURE, ExitCode::from))
+}
+
+/// The `cargo` binary that invoked this subcommand
+fn cargo() -> String {
+    std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::passthrough_args;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        ["cargo-bencher", "bencher"]
+            .iter()
+            .chain(args)
+            .map(|arg| (*arg).to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn passthrough() {
+        let run = args(&["run", "--project", "my-project", "cargo bench"]);
+        assert_eq!(passthrough_args(&run), run.get(2..));
+        let mock = args(&["mock"]);
+        assert_eq!(passthrough_args(&mock), mock.get(2..));
+    }
 
-    // Check if the command executed successfully
-    if !status.success() {
-        eprintln!("`bencher` command failed with status: {status}");
+    #[test]
+    fn no_passthrough() {
+        assert_eq!(passthrough_args(&args(&[])), None);
+        assert_eq!(passthrough_args(&args(&["--package", "my-crate"])), None);
+        assert_eq!(
+            passthrough_args(&args(&["--", "--project", "my-project"])),
+            None
+        );
     }
 }
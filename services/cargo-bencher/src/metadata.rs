@@ -0,0 +1,164 @@
+use std::process::{Command, Stdio};
+
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::Deserialize;
+
+use crate::{CargoBencherError, cargo};
+
+/// The subset of `cargo metadata --format-version 1 --no-deps` that is needed
+#[derive(Debug, Deserialize)]
+pub struct Metadata {
+    pub packages: Vec<Package>,
+    pub workspace_members: Vec<String>,
+    pub target_directory: Utf8PathBuf,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Package {
+    pub id: String,
+    pub name: String,
+    pub manifest_path: Utf8PathBuf,
+    pub dependencies: Vec<Dependency>,
+    pub targets: Vec<Target>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Dependency {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Target {
+    pub name: String,
+    pub kind: Vec<String>,
+    pub src_path: Utf8PathBuf,
+}
+
+impl Metadata {
+    pub fn new(manifest_path: Option<&Utf8Path>) -> Result<Self, CargoBencherError> {
+        let mut command = Command::new(cargo());
+        command.args(["metadata", "--format-version", "1", "--no-deps"]);
+        if let Some(manifest_path) = manifest_path {
+            command.arg("--manifest-path").arg(manifest_path);
+        }
+        let output = command
+            .stderr(Stdio::inherit())
+            .output()
+            .map_err(CargoBencherError::CargoMetadata)?;
+        if !output.status.success() {
+            return Err(CargoBencherError::CargoMetadataStatus(output.status));
+        }
+        serde_json::from_slice(&output.stdout).map_err(CargoBencherError::ParseMetadata)
+    }
+
+    /// The workspace members to benchmark, along with their selected bench targets.
+    /// If no packages are given, then all of the workspace members with bench targets are selected.
+    /// If no bench targets are given, then all of the bench targets are selected.
+    pub fn bench_packages(
+        &self,
+        packages: &[String],
+        benches: &[String],
+    ) -> Result<Vec<(&Package, Vec<&Target>)>, CargoBencherError> {
+        let members = self
+            .packages
+            .iter()
+            .filter(|package| self.workspace_members.contains(&package.id))
+            .collect::<Vec<_>>();
+        for name in packages {
+            if !members.iter().any(|package| &package.name == name) {
+                return Err(CargoBencherError::UnknownPackage(name.clone()));
+            }
+        }
+
+        let bench_packages = members
+            .into_iter()
+            .filter(|package| packages.is_empty() || packages.contains(&package.name))
+            .filter_map(|package| {
+                let targets = package
+                    .bench_targets()
+                    .filter(|target| benches.is_empty() || benches.contains(&target.name))
+                    .collect::<Vec<_>>();
+                (!targets.is_empty()).then_some((package, targets))
+            })
+            .collect::<Vec<_>>();
+        for name in benches {
+            if !bench_packages
+                .iter()
+                .any(|(_, targets)| targets.iter().any(|target| &target.name == name))
+            {
+                return Err(CargoBencherError::UnknownBench(name.clone()));
+            }
+        }
+        if bench_packages.is_empty() {
+            return Err(CargoBencherError::NoBenches);
+        }
+
+        Ok(bench_packages)
+    }
+}
+
+impl Package {
+    fn bench_targets(&self) -> impl Iterator<Item = &Target> {
+        self.targets
+            .iter()
+            .filter(|target| target.kind.iter().any(|kind| kind == "bench"))
+    }
+
+    /// The directory that contains the package `Cargo.toml`
+    pub fn dir(&self) -> &Utf8Path {
+        self.manifest_path
+            .parent()
+            .unwrap_or_else(|| Utf8Path::new("."))
+    }
+
+    pub fn has_dependency(&self, names: &[&str]) -> bool {
+        self.dependencies
+            .iter()
+            .any(|dependency| names.contains(&dependency.name.as_str()))
+    }
+
+    pub fn manifest(&self) -> Result<Manifest, CargoBencherError> {
+        let manifest = std::fs::read_to_string(&self.manifest_path).map_err(|err| {
+            CargoBencherError::ReadManifest {
+                path: self.manifest_path.clone(),
+                err,
+            }
+        })?;
+        toml::from_str(&manifest).map_err(|err| CargoBencherError::ParseManifest {
+            path: self.manifest_path.clone(),
+            err,
+        })
+    }
+}
+
+/// The subset of a package `Cargo.toml` that is needed.
+/// `cargo metadata` does not include whether a target uses the libtest harness.
+#[derive(Debug, Default, Deserialize)]
+pub struct Manifest {
+    #[serde(default)]
+    pub bench: Vec<ManifestTarget>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ManifestTarget {
+    pub name: Option<String>,
+    pub path: Option<Utf8PathBuf>,
+    pub harness: Option<bool>,
+}
+
+impl Manifest {
+    /// Whether the bench target uses the libtest harness (default `true`).
+    /// A `[[bench]]` entry is matched by its `name`,
+    /// or by its `path` relative to the package directory if it does not have a name.
+    pub fn harness(&self, package_dir: &Utf8Path, target: &Target) -> bool {
+        self.bench
+            .iter()
+            .find(|bench| match (&bench.name, &bench.path) {
+                (Some(name), _) => name == &target.name,
+                (None, Some(path)) => package_dir.join(path) == target.src_path,
+                (None, None) => false,
+            })
+            .and_then(|bench| bench.harness)
+            .unwrap_or(true)
+    }
+}
@@ -0,0 +1,34 @@
+use camino::Utf8PathBuf;
+use clap::{Args, Parser};
+
+/// Cargo invokes `cargo-bencher bencher [ARGS]...` for `cargo bencher [ARGS]...`
+#[derive(Parser, Debug)]
+#[clap(name = "cargo", bin_name = "cargo")]
+pub enum CargoCli {
+    /// Run the benchmarks for a Cargo workspace and track them with Bencher
+    Bencher(CliBencher),
+}
+
+#[derive(Args, Debug)]
+#[clap(version, about)]
+pub struct CliBencher {
+    /// Path to the `Cargo.toml` of the workspace or package
+    #[clap(long, value_name = "PATH")]
+    pub manifest_path: Option<Utf8PathBuf>,
+
+    /// Package(s) to benchmark (default: all workspace members with bench targets)
+    #[clap(long, short, value_name = "SPEC")]
+    pub package: Vec<String>,
+
+    /// Bench target(s) to run (default: all bench targets)
+    #[clap(long, value_name = "NAME")]
+    pub bench: Vec<String>,
+
+    /// Path to the `bencher` CLI
+    #[clap(long, env = "BENCHER_BIN", default_value = "bencher")]
+    pub bencher: String,
+
+    /// Arguments for `bencher run` (ie `--project`, `--branch`, `--testbed`, and `--threshold-*`)
+    #[clap(last = true, value_name = "BENCHER_RUN_ARGS")]
+    pub run_args: Vec<String>,
+}
@@ -24,6 +24,7 @@ use project::{
     archive::{Archive, ArchiveAction},
     benchmark::Benchmark,
     branch::Branch,
+    compare::Compare,
     measure::Measure,
     metric::Metric,
     perf::Perf,
@@ -64,6 +65,7 @@ pub enum Sub {
     #[cfg(feature = "plus")]
     Job(Job),
     Perf(Perf),
+    Compare(Compare),
     Plot(Plot),
     Branch(Branch),
     Testbed(Testbed),
@@ -109,7 +111,8 @@ impl TryFrom<CliSub> for Sub {
             CliSub::Report(report) => Self::Report(report.try_into()?),
             #[cfg(feature = "plus")]
             CliSub::Job(job) => Self::Job(job.try_into()?),
-            CliSub::Perf(perf) => Self::Perf(perf.try_into()?),
+            CliSub::Perf(perf) => Self::Perf((*perf).try_into()?),
+            CliSub::Compare(compare) => Self::Compare((*compare).try_into()?),
             CliSub::Plot(plot) => Self::Plot(plot.try_into()?),
             CliSub::Branch(branch) => Self::Branch(branch.try_into()?),
             CliSub::Testbed(testbed) => Self::Testbed(testbed.try_into()?),
@@ -152,6 +155,7 @@ impl SubCmd for Sub {
             #[cfg(feature = "plus")]
             Self::Job(job) => job.exec().await,
             Self::Perf(perf) => perf.exec().await,
+            Self::Compare(compare) => compare.exec().await,
             Self::Plot(plot) => plot.exec().await,
             Self::Branch(branch) => branch.exec().await,
             Self::Testbed(testbed) => testbed.exec().await,
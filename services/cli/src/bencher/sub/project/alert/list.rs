@@ -73,6 +73,7 @@ impl From<CliAlertStatus> for AlertStatus {
         match status {
             CliAlertStatus::Active => Self::Active,
             CliAlertStatus::Dismissed => Self::Dismissed,
+            CliAlertStatus::Resolved => Self::Resolved,
             CliAlertStatus::Silenced => Self::Silenced,
         }
     }
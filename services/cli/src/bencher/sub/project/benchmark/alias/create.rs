@@ -0,0 +1,60 @@
+use bencher_client::types::JsonNewBenchmarkAlias;
+use bencher_json::{BenchmarkName, BenchmarkResourceId, ProjectResourceId};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::benchmark::CliBenchmarkAliasCreate,
+};
+
+#[derive(Debug, Clone)]
+pub struct Create {
+    pub project: ProjectResourceId,
+    pub benchmark: BenchmarkResourceId,
+    pub name: BenchmarkName,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliBenchmarkAliasCreate> for Create {
+    type Error = CliError;
+
+    fn try_from(create: CliBenchmarkAliasCreate) -> Result<Self, Self::Error> {
+        let CliBenchmarkAliasCreate {
+            project,
+            benchmark,
+            name,
+            backend,
+        } = create;
+        Ok(Self {
+            project,
+            benchmark,
+            name,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Create> for JsonNewBenchmarkAlias {
+    fn from(create: Create) -> Self {
+        let Create { name, .. } = create;
+        Self { name: name.into() }
+    }
+}
+
+impl SubCmd for Create {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_benchmark_alias_post()
+                    .project(self.project.clone())
+                    .benchmark(self.benchmark.clone())
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
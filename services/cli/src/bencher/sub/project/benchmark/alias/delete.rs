@@ -0,0 +1,52 @@
+use bencher_json::{BenchmarkAliasUuid, BenchmarkResourceId, ProjectResourceId};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::benchmark::CliBenchmarkAliasDelete,
+};
+
+#[derive(Debug)]
+pub struct Delete {
+    pub project: ProjectResourceId,
+    pub benchmark: BenchmarkResourceId,
+    pub alias: BenchmarkAliasUuid,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliBenchmarkAliasDelete> for Delete {
+    type Error = CliError;
+
+    fn try_from(delete: CliBenchmarkAliasDelete) -> Result<Self, Self::Error> {
+        let CliBenchmarkAliasDelete {
+            project,
+            benchmark,
+            alias,
+            backend,
+        } = delete;
+        Ok(Self {
+            project,
+            benchmark,
+            alias,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Delete {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_benchmark_alias_delete()
+                    .project(self.project.clone())
+                    .benchmark(self.benchmark.clone())
+                    .alias(self.alias)
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use bencher_json::{BenchmarkResourceId, ProjectResourceId};
+
+use crate::{
+    CliError,
+    bencher::{backend::PubBackend, sub::SubCmd},
+    parser::project::benchmark::CliBenchmarkAliasList,
+};
+
+#[derive(Debug)]
+pub struct List {
+    pub project: ProjectResourceId,
+    pub benchmark: BenchmarkResourceId,
+    pub backend: PubBackend,
+}
+
+impl TryFrom<CliBenchmarkAliasList> for List {
+    type Error = CliError;
+
+    fn try_from(list: CliBenchmarkAliasList) -> Result<Self, Self::Error> {
+        let CliBenchmarkAliasList {
+            project,
+            benchmark,
+            backend,
+        } = list;
+        Ok(Self {
+            project,
+            benchmark,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for List {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_benchmark_aliases_get()
+                    .project(self.project.clone())
+                    .benchmark(self.benchmark.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
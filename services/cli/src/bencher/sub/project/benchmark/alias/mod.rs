@@ -0,0 +1,34 @@
+use crate::{CliError, bencher::sub::SubCmd, parser::project::benchmark::CliBenchmarkAlias};
+
+mod create;
+mod delete;
+mod list;
+
+#[derive(Debug)]
+pub enum Alias {
+    List(list::List),
+    Create(create::Create),
+    Delete(delete::Delete),
+}
+
+impl TryFrom<CliBenchmarkAlias> for Alias {
+    type Error = CliError;
+
+    fn try_from(alias: CliBenchmarkAlias) -> Result<Self, Self::Error> {
+        Ok(match alias {
+            CliBenchmarkAlias::List(list) => Self::List(list.try_into()?),
+            CliBenchmarkAlias::Create(create) => Self::Create(create.try_into()?),
+            CliBenchmarkAlias::Delete(delete) => Self::Delete(delete.try_into()?),
+        })
+    }
+}
+
+impl SubCmd for Alias {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::List(list) => list.exec().await,
+            Self::Create(create) => create.exec().await,
+            Self::Delete(delete) => delete.exec().await,
+        }
+    }
+}
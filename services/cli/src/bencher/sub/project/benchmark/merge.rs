@@ -0,0 +1,62 @@
+use bencher_client::types::JsonMergeBenchmarks;
+use bencher_json::{BenchmarkResourceId, ProjectResourceId};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::benchmark::CliBenchmarkMerge,
+};
+
+#[derive(Debug, Clone)]
+pub struct Merge {
+    pub project: ProjectResourceId,
+    pub benchmark: BenchmarkResourceId,
+    pub from: Vec<BenchmarkResourceId>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliBenchmarkMerge> for Merge {
+    type Error = CliError;
+
+    fn try_from(merge: CliBenchmarkMerge) -> Result<Self, Self::Error> {
+        let CliBenchmarkMerge {
+            project,
+            benchmark,
+            from,
+            backend,
+        } = merge;
+        Ok(Self {
+            project,
+            benchmark,
+            from,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Merge> for JsonMergeBenchmarks {
+    fn from(merge: Merge) -> Self {
+        let Merge { from, .. } = merge;
+        Self {
+            benchmarks: from.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl SubCmd for Merge {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_benchmark_merge_post()
+                    .project(self.project.clone())
+                    .benchmark(self.benchmark.clone())
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
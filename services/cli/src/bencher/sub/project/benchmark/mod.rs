@@ -1,8 +1,10 @@
 use crate::{CliError, bencher::sub::SubCmd, parser::project::benchmark::CliBenchmark};
 
+mod alias;
 mod create;
 mod delete;
 mod list;
+mod merge;
 mod update;
 mod view;
 
@@ -13,6 +15,8 @@ pub enum Benchmark {
     View(view::View),
     Update(update::Update),
     Delete(delete::Delete),
+    Merge(merge::Merge),
+    Alias(alias::Alias),
 }
 
 impl TryFrom<CliBenchmark> for Benchmark {
@@ -25,6 +29,8 @@ impl TryFrom<CliBenchmark> for Benchmark {
             CliBenchmark::View(view) => Self::View(view.try_into()?),
             CliBenchmark::Update(update) => Self::Update(update.try_into()?),
             CliBenchmark::Delete(delete) => Self::Delete(delete.try_into()?),
+            CliBenchmark::Merge(merge) => Self::Merge(merge.try_into()?),
+            CliBenchmark::Alias(alias) => Self::Alias(alias.try_into()?),
         })
     }
 }
@@ -37,6 +43,8 @@ impl SubCmd for Benchmark {
             Self::View(create) => create.exec().await,
             Self::Update(update) => update.exec().await,
             Self::Delete(delete) => delete.exec().await,
+            Self::Merge(merge) => merge.exec().await,
+            Self::Alias(alias) => alias.exec().await,
         }
     }
 }
@@ -0,0 +1,122 @@
+use bencher_json::{
+    JsonCompare, ProjectResourceId,
+    project::compare::{JsonCompareHeadQuery, JsonCompareQuery},
+};
+use tabled::Table;
+
+use crate::{
+    CliError,
+    bencher::{backend::PubBackend, sub::SubCmd},
+    cli_println,
+    parser::project::compare::CliCompare,
+};
+
+use super::perf::table_style::TableStyle;
+
+#[derive(Debug)]
+#[expect(
+    clippy::option_option,
+    reason = "None = not specified, Some(None) = explicitly unset"
+)]
+pub struct Compare {
+    project: ProjectResourceId,
+    query: JsonCompareQuery,
+    table: Option<Option<TableStyle>>,
+    backend: PubBackend,
+}
+
+impl TryFrom<CliCompare> for Compare {
+    type Error = CliError;
+
+    fn try_from(compare: CliCompare) -> Result<Self, Self::Error> {
+        let CliCompare {
+            project,
+            baseline_branch,
+            baseline_head,
+            baseline_hash,
+            candidate_branch,
+            candidate_head,
+            candidate_hash,
+            testbed,
+            #[cfg(feature = "plus")]
+            spec,
+            reports,
+            table,
+            backend,
+        } = compare;
+        let backend = PubBackend::try_from(backend)?.log(table.is_none());
+        Ok(Self {
+            project,
+            query: JsonCompareQuery {
+                baseline: JsonCompareHeadQuery {
+                    branch: baseline_branch,
+                    head: baseline_head,
+                    hash: baseline_hash,
+                },
+                candidate: JsonCompareHeadQuery {
+                    branch: candidate_branch,
+                    head: candidate_head,
+                    hash: candidate_hash,
+                },
+                testbed,
+                #[cfg(feature = "plus")]
+                spec,
+                reports,
+            },
+            table: table.map(|t| t.map(Into::into)),
+            backend,
+        })
+    }
+}
+
+impl SubCmd for Compare {
+    async fn exec(&self) -> Result<(), CliError> {
+        let json_compare: JsonCompare = self
+            .backend
+            .send_with(|client| async move {
+                let JsonCompareQuery {
+                    baseline,
+                    candidate,
+                    reports,
+                    ..
+                } = &self.query;
+                let mut client = client
+                    .proj_compare_get()
+                    .project(self.project.clone())
+                    .baseline_branch(baseline.branch())
+                    .candidate_branch(candidate.branch())
+                    .testbed(self.query.testbed());
+
+                if let Some(head) = baseline.head {
+                    client = client.baseline_head(head);
+                }
+                if let Some(hash) = baseline.hash.clone() {
+                    client = client.baseline_hash(hash);
+                }
+                if let Some(head) = candidate.head {
+                    client = client.candidate_head(head);
+                }
+                if let Some(hash) = candidate.hash.clone() {
+                    client = client.candidate_hash(hash);
+                }
+                #[cfg(feature = "plus")]
+                if let Some(spec) = self.query.spec {
+                    client = client.spec(spec);
+                }
+                if let Some(reports) = *reports {
+                    client = client.reports(reports);
+                }
+
+                client.send().await
+            })
+            .await?;
+        if let Some(table_style) = self.table {
+            let mut compare_table: Table = json_compare.into();
+            if let Some(table_style) = table_style {
+                table_style.stylize(&mut compare_table);
+            }
+            cli_println!("{compare_table}");
+        }
+        Ok(())
+    }
+}
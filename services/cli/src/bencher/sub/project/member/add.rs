@@ -0,0 +1,72 @@
+use bencher_client::types::{JsonNewProjectMember, ProjectRole};
+use bencher_json::{Email, ProjectResourceId};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::member::{CliProjectMemberAdd, CliProjectMemberRole},
+};
+
+#[derive(Debug, Clone)]
+pub struct Add {
+    pub project: ProjectResourceId,
+    pub email: Email,
+    pub role: ProjectRole,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliProjectMemberAdd> for Add {
+    type Error = CliError;
+
+    fn try_from(add: CliProjectMemberAdd) -> Result<Self, Self::Error> {
+        let CliProjectMemberAdd {
+            project,
+            email,
+            role,
+            backend,
+        } = add;
+        Ok(Self {
+            project,
+            email,
+            role: role.into(),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<CliProjectMemberRole> for ProjectRole {
+    fn from(role: CliProjectMemberRole) -> Self {
+        match role {
+            CliProjectMemberRole::Viewer => Self::Viewer,
+            CliProjectMemberRole::Developer => Self::Developer,
+            CliProjectMemberRole::Maintainer => Self::Maintainer,
+        }
+    }
+}
+
+impl From<Add> for JsonNewProjectMember {
+    fn from(add: Add) -> Self {
+        let Add { email, role, .. } = add;
+        Self {
+            email: email.into(),
+            role,
+        }
+    }
+}
+
+impl SubCmd for Add {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_member_post()
+                    .project(self.project.clone())
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use bencher_client::types::{JsonDirection, ProjMembersSort};
+use bencher_json::{ProjectResourceId, UserName};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::{
+        CliPagination,
+        project::member::{CliProjectMemberList, CliProjectMembersSort},
+    },
+};
+
+#[derive(Debug)]
+pub struct List {
+    pub project: ProjectResourceId,
+    pub name: Option<UserName>,
+    pub search: Option<String>,
+    pub pagination: Pagination,
+    pub backend: AuthBackend,
+}
+
+#[derive(Debug)]
+pub struct Pagination {
+    pub sort: Option<ProjMembersSort>,
+    pub direction: Option<JsonDirection>,
+    pub per_page: Option<u8>,
+    pub page: Option<u32>,
+}
+
+impl TryFrom<CliProjectMemberList> for List {
+    type Error = CliError;
+
+    fn try_from(list: CliProjectMemberList) -> Result<Self, Self::Error> {
+        let CliProjectMemberList {
+            project,
+            name,
+            search,
+            pagination,
+            backend,
+        } = list;
+        Ok(Self {
+            project,
+            name,
+            search,
+            pagination: pagination.into(),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<CliPagination<CliProjectMembersSort>> for Pagination {
+    fn from(pagination: CliPagination<CliProjectMembersSort>) -> Self {
+        let CliPagination {
+            sort,
+            direction,
+            per_page,
+            page,
+        } = pagination;
+        Self {
+            sort: sort.map(|sort| match sort {
+                CliProjectMembersSort::Name => ProjMembersSort::Name,
+            }),
+            direction: direction.map(Into::into),
+            page,
+            per_page,
+        }
+    }
+}
+
+impl SubCmd for List {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                let mut client = client.proj_members_get().project(self.project.clone());
+                if let Some(name) = self.name.clone() {
+                    client = client.name(name);
+                }
+                if let Some(search) = self.search.clone() {
+                    client = client.search(search);
+                }
+                if let Some(sort) = self.pagination.sort {
+                    client = client.sort(sort);
+                }
+                if let Some(direction) = self.pagination.direction {
+                    client = client.direction(direction);
+                }
+                if let Some(per_page) = self.pagination.per_page {
+                    client = client.per_page(per_page);
+                }
+                if let Some(page) = self.pagination.page {
+                    client = client.page(page);
+                }
+                client.send().await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{CliError, bencher::sub::SubCmd, parser::project::member::CliProjectMember};
+
+mod add;
+mod list;
+mod remove;
+mod update;
+mod view;
+
+#[derive(Debug)]
+pub enum Member {
+    List(list::List),
+    Add(add::Add),
+    View(view::View),
+    Update(update::Update),
+    Remove(remove::Remove),
+}
+
+impl TryFrom<CliProjectMember> for Member {
+    type Error = CliError;
+
+    fn try_from(member: CliProjectMember) -> Result<Self, Self::Error> {
+        Ok(match member {
+            CliProjectMember::List(list) => Self::List(list.try_into()?),
+            CliProjectMember::Add(add) => Self::Add(add.try_into()?),
+            CliProjectMember::View(view) => Self::View(view.try_into()?),
+            CliProjectMember::Update(update) => Self::Update(update.try_into()?),
+            CliProjectMember::Remove(remove) => Self::Remove(remove.try_into()?),
+        })
+    }
+}
+
+impl SubCmd for Member {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::List(list) => list.exec().await,
+            Self::Add(add) => add.exec().await,
+            Self::View(view) => view.exec().await,
+            Self::Update(update) => update.exec().await,
+            Self::Remove(remove) => remove.exec().await,
+        }
+    }
+}
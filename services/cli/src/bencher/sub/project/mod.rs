@@ -2,9 +2,11 @@ pub mod alert;
 pub mod archive;
 pub mod benchmark;
 pub mod branch;
+pub mod compare;
 pub mod job;
 pub mod key;
 pub mod measure;
+pub mod member;
 pub mod metric;
 pub mod perf;
 pub mod plot;
@@ -14,5 +16,7 @@ pub mod plot;
 )]
 pub mod project;
 pub mod report;
+pub mod retention;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
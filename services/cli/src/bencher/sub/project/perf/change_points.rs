@@ -0,0 +1,94 @@
+use bencher_json::{JsonPerfQuery, ProjectResourceId};
+
+use crate::{
+    CliError,
+    bencher::{backend::PubBackend, sub::SubCmd},
+    parser::{ElidedOption, project::perf::CliPerfChangePoints},
+};
+
+#[derive(Debug)]
+pub struct ChangePoints {
+    project: ProjectResourceId,
+    query: JsonPerfQuery,
+    penalty: Option<f64>,
+    backend: PubBackend,
+}
+
+impl TryFrom<CliPerfChangePoints> for ChangePoints {
+    type Error = CliError;
+
+    fn try_from(change_points: CliPerfChangePoints) -> Result<Self, Self::Error> {
+        let CliPerfChangePoints {
+            project,
+            branches,
+            heads,
+            testbeds,
+            #[cfg(feature = "plus")]
+            specs,
+            benchmarks,
+            measures,
+            start_time,
+            end_time,
+            versions,
+            penalty,
+            backend,
+        } = change_points;
+        Ok(Self {
+            project,
+            query: JsonPerfQuery {
+                branches,
+                heads: heads.into_iter().map(ElidedOption::into).collect(),
+                testbeds,
+                #[cfg(feature = "plus")]
+                specs: specs.into_iter().map(ElidedOption::into).collect(),
+                benchmarks,
+                measures,
+                start_time,
+                end_time,
+                versions,
+            },
+            penalty,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for ChangePoints {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                let mut client = client
+                    .proj_perf_change_points_get()
+                    .project(self.project.clone())
+                    .branches(self.query.branches())
+                    .testbeds(self.query.testbeds())
+                    .benchmarks(self.query.benchmarks())
+                    .measures(self.query.measures());
+
+                if let Some(heads) = self.query.heads() {
+                    client = client.heads(heads);
+                }
+                if let Some(specs) = self.query.specs() {
+                    client = client.specs(specs);
+                }
+
+                if let Some(start_time) = self.query.start_time() {
+                    client = client.start_time(start_time);
+                }
+                if let Some(end_time) = self.query.end_time() {
+                    client = client.end_time(end_time);
+                }
+                if let Some(versions) = self.query.versions {
+                    client = client.versions(versions);
+                }
+                if let Some(penalty) = self.penalty {
+                    client = client.penalty(penalty);
+                }
+
+                client.send().await
+            })
+            .await?;
+        Ok(())
+    }
+}
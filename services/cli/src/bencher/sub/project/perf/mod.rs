@@ -5,25 +5,38 @@ use std::pin::Pin;
 use bencher_json::SpecUuid;
 use bencher_json::{
     BenchmarkUuid, BranchUuid, DateTime, HeadUuid, JsonPerf, JsonPerfQuery, MeasureUuid,
-    ProjectResourceId, TestbedUuid,
+    ProjectResourceId, SampleSize, TestbedUuid,
 };
 use tabled::Table;
 
 use crate::parser::ElidedOption;
-use crate::{CliError, bencher::backend::PubBackend, cli_println, parser::project::perf::CliPerf};
+use crate::{
+    CliError,
+    bencher::backend::PubBackend,
+    cli_println,
+    parser::project::perf::{CliPerf, CliPerfSub},
+};
 
 use crate::bencher::SubCmd;
 
-mod table_style;
+mod change_points;
+pub(crate) mod table_style;
 
+use change_points::ChangePoints;
 use table_style::TableStyle;
 
+#[derive(Debug)]
+pub enum Perf {
+    Query(PerfQuery),
+    ChangePoints(ChangePoints),
+}
+
 #[derive(Debug, Clone)]
 #[expect(
     clippy::option_option,
     reason = "None = not specified, Some(None) = explicitly unset"
 )]
-pub struct Perf {
+pub struct PerfQuery {
     project: ProjectResourceId,
     branches: Vec<BranchUuid>,
     heads: Vec<Option<HeadUuid>>,
@@ -34,6 +47,7 @@ pub struct Perf {
     measures: Vec<MeasureUuid>,
     start_time: Option<DateTime>,
     end_time: Option<DateTime>,
+    versions: Option<SampleSize>,
     table: Option<Option<TableStyle>>,
     backend: PubBackend,
 }
@@ -43,6 +57,7 @@ impl TryFrom<CliPerf> for Perf {
 
     fn try_from(perf: CliPerf) -> Result<Self, Self::Error> {
         let CliPerf {
+            sub,
             project,
             branches,
             heads,
@@ -53,11 +68,22 @@ impl TryFrom<CliPerf> for Perf {
             measures,
             start_time,
             end_time,
+            versions,
             table,
             backend,
         } = perf;
+        if let Some(CliPerfSub::ChangePoints(change_points)) = sub {
+            return change_points.try_into().map(Self::ChangePoints);
+        }
+        #[expect(
+            clippy::unreachable,
+            reason = "clap requires a project without a subcommand"
+        )]
+        let Some(project) = project else {
+            unreachable!("Cannot query perf without a project")
+        };
         let backend = PubBackend::try_from(backend)?.log(table.is_none());
-        Ok(Self {
+        Ok(Self::Query(PerfQuery {
             project,
             branches,
             heads: heads.into_iter().map(ElidedOption::into).collect(),
@@ -68,15 +94,16 @@ impl TryFrom<CliPerf> for Perf {
             measures,
             start_time,
             end_time,
+            versions,
             table: table.map(|t| t.map(Into::into)),
             backend,
-        })
+        }))
     }
 }
 
-impl From<Perf> for JsonPerfQuery {
-    fn from(perf: Perf) -> Self {
-        let Perf {
+impl From<PerfQuery> for JsonPerfQuery {
+    fn from(perf: PerfQuery) -> Self {
+        let PerfQuery {
             branches,
             heads,
             testbeds,
@@ -86,6 +113,7 @@ impl From<Perf> for JsonPerfQuery {
             measures,
             start_time,
             end_time,
+            versions,
             ..
         } = perf;
         Self {
@@ -98,11 +126,21 @@ impl From<Perf> for JsonPerfQuery {
             measures,
             start_time,
             end_time,
+            versions,
         }
     }
 }
 
 impl SubCmd for Perf {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::Query(query) => query.exec().await,
+            Self::ChangePoints(change_points) => change_points.exec().await,
+        }
+    }
+}
+
+impl SubCmd for PerfQuery {
     async fn exec(&self) -> Result<(), CliError> {
         let sender = perf_sender(self.project.clone(), self.clone());
         if let Some(table_style) = self.table {
@@ -159,6 +197,9 @@ fn perf_sender(
             if let Some(end_time) = json_perf_query.end_time() {
                 client = client.end_time(end_time);
             }
+            if let Some(versions) = json_perf_query.versions {
+                client = client.versions(versions);
+            }
 
             client.send().await
         })
@@ -1,6 +1,6 @@
 use crate::{CliError, bencher::sub::SubCmd, parser::project::CliProject};
 
-use super::key;
+use super::{key, member, retention, webhook};
 
 mod allowed;
 mod create;
@@ -18,6 +18,9 @@ pub enum Project {
     Delete(delete::Delete),
     Allowed(allowed::Allowed),
     Key(key::Key),
+    Member(member::Member),
+    Webhook(webhook::Webhook),
+    Retention(retention::Retention),
 }
 
 impl TryFrom<CliProject> for Project {
@@ -32,6 +35,9 @@ impl TryFrom<CliProject> for Project {
             CliProject::Delete(delete) => Self::Delete(delete.try_into()?),
             CliProject::Allowed(allowed) => Self::Allowed(allowed.try_into()?),
             CliProject::Key(key) => Self::Key(key.try_into()?),
+            CliProject::Member(member) => Self::Member(member.try_into()?),
+            CliProject::Webhook(webhook) => Self::Webhook(webhook.try_into()?),
+            CliProject::Retention(retention) => Self::Retention(retention.try_into()?),
         })
     }
 }
@@ -46,6 +52,9 @@ impl SubCmd for Project {
             Self::Delete(delete) => delete.exec().await,
             Self::Allowed(allowed) => allowed.exec().await,
             Self::Key(key) => key.exec().await,
+            Self::Member(member) => member.exec().await,
+            Self::Webhook(webhook) => webhook.exec().await,
+            Self::Retention(retention) => retention.exec().await,
         }
     }
 }
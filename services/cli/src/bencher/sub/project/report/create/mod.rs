@@ -1,6 +1,6 @@
 use bencher_client::types::{
     Adapter, DateTime, GitHash, JsonAverage, JsonFold, JsonNewReport, JsonReportSettings,
-    JsonUpdateStartPoint,
+    JsonStatistic, JsonUpdateStartPoint,
 };
 use bencher_json::{BranchNameId, ProjectResourceId, TestbedNameId};
 
@@ -10,14 +10,17 @@ use crate::{
         backend::AuthBackend,
         sub::{SubCmd, project::branch::start_point::StartPoint},
     },
+    cli_eprintln,
     parser::project::report::CliReportCreate,
 };
 
 mod adapter;
 mod average;
 mod fold;
+mod statistic;
 mod thresholds;
 
+pub use statistic::statistic_warning;
 pub use thresholds::{Thresholds, ThresholdsError};
 
 #[derive(Debug, Clone)]
@@ -34,6 +37,9 @@ pub struct Create {
     pub adapter: Option<Adapter>,
     pub average: Option<JsonAverage>,
     pub fold: Option<JsonFold>,
+    pub bootstrap: bool,
+    pub statistic: Option<JsonStatistic>,
+    pub params: bool,
     pub backend: AuthBackend,
 }
 
@@ -54,6 +60,9 @@ impl TryFrom<CliReportCreate> for Create {
             adapter,
             average,
             fold,
+            bootstrap,
+            statistic,
+            params,
             backend,
         } = create;
         Ok(Self {
@@ -69,6 +78,9 @@ impl TryFrom<CliReportCreate> for Create {
             adapter: adapter.map(Into::into),
             average: average.map(Into::into),
             fold: fold.map(Into::into),
+            bootstrap,
+            statistic: statistic.map(Into::into),
+            params,
             backend: backend.try_into()?,
         })
     }
@@ -87,6 +99,9 @@ impl From<Create> for JsonNewReport {
             adapter,
             average,
             fold,
+            bootstrap,
+            statistic,
+            params,
             ..
         } = create;
         Self {
@@ -102,13 +117,24 @@ impl From<Create> for JsonNewReport {
                 adapter,
                 average,
                 fold,
+                bootstrap: bootstrap.then_some(true),
+                statistic,
+                params: params.then_some(true),
             }),
+            noise: None,
         }
     }
 }
 
 impl SubCmd for Create {
     async fn exec(&self) -> Result<(), CliError> {
+        if let Some(warning) = self
+            .adapter
+            .zip(self.statistic)
+            .and_then(|(adapter, statistic)| statistic_warning(adapter, statistic))
+        {
+            cli_eprintln!("{warning}");
+        }
         let _json = self
             .backend
             .send(|client| async move {
@@ -0,0 +1,34 @@
+use bencher_client::types::{Adapter, JsonStatistic};
+
+use crate::parser::project::report::CliReportStatistic;
+
+impl From<CliReportStatistic> for JsonStatistic {
+    fn from(statistic: CliReportStatistic) -> Self {
+        match statistic {
+            CliReportStatistic::Mean => Self::Mean,
+            CliReportStatistic::Median => Self::Median,
+            CliReportStatistic::Min => Self::Min,
+            CliReportStatistic::Iqr => Self::Iqr,
+            CliReportStatistic::P90 => Self::P90,
+            CliReportStatistic::P95 => Self::P95,
+            CliReportStatistic::Ops => Self::Ops,
+        }
+    }
+}
+
+/// A warning if the adapter does not report the statistic,
+/// in which case the median is used instead.
+pub fn statistic_warning(adapter: Adapter, statistic: JsonStatistic) -> Option<String> {
+    let unsupported = matches!(
+        (adapter, statistic),
+        (
+            Adapter::PythonPytest,
+            JsonStatistic::P90 | JsonStatistic::P95
+        ) | (Adapter::CSharpDotNet, JsonStatistic::Ops)
+    );
+    unsupported.then(|| {
+        format!(
+            "Warning: The {adapter} adapter does not report the {statistic} statistic, so the median is used instead."
+        )
+    })
+}
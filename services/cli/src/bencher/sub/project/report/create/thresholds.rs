@@ -44,11 +44,20 @@ pub enum ThresholdsError {
     ExtraLowerBoundaries(Vec<ElidedOption<Boundary>>),
     #[error("There are more upper boundaries than model tests")]
     ExtraUpperBoundaries(Vec<ElidedOption<Boundary>>),
+    #[error("There are more confirmation outliers than model tests")]
+    ExtraConfirmOutliers(Vec<ElidedOption<SampleSize>>),
+    #[error("There are more confirmation reports than model tests")]
+    ExtraConfirmReports(Vec<ElidedOption<SampleSize>>),
+    #[error("There are more resolution reports than model tests")]
+    ExtraResolveReports(Vec<ElidedOption<SampleSize>>),
+    #[error("There are more version windows than model tests")]
+    ExtraVersionWindows(Vec<ElidedOption<SampleSize>>),
 }
 
 impl TryFrom<CliReportThresholds> for Thresholds {
     type Error = ThresholdsError;
 
+    #[expect(clippy::too_many_lines, reason = "one iterator per model option")]
     fn try_from(thresholds: CliReportThresholds) -> Result<Self, Self::Error> {
         let CliReportThresholds {
             threshold_measure,
@@ -58,6 +67,10 @@ impl TryFrom<CliReportThresholds> for Thresholds {
             threshold_window,
             threshold_lower_boundary,
             threshold_upper_boundary,
+            threshold_confirm_outliers,
+            threshold_confirm_reports,
+            threshold_resolve_reports,
+            threshold_version_window,
             thresholds_reset,
         } = thresholds;
 
@@ -69,6 +82,10 @@ impl TryFrom<CliReportThresholds> for Thresholds {
         let mut windows = threshold_window.into_iter();
         let mut lower_boundaries = threshold_lower_boundary.into_iter();
         let mut upper_boundaries = threshold_upper_boundary.into_iter();
+        let mut confirm_outliers = threshold_confirm_outliers.into_iter();
+        let mut confirm_reports = threshold_confirm_reports.into_iter();
+        let mut resolve_reports = threshold_resolve_reports.into_iter();
+        let mut version_windows = threshold_version_window.into_iter();
         for measure in threshold_measure {
             let test = tests
                 .next()
@@ -78,6 +95,10 @@ impl TryFrom<CliReportThresholds> for Thresholds {
             let window = windows.next();
             let lower_boundary = lower_boundaries.next();
             let upper_boundary = upper_boundaries.next();
+            let confirm_outlier = confirm_outliers.next();
+            let confirm_report = confirm_reports.next();
+            let resolve_report = resolve_reports.next();
+            let version_window = version_windows.next();
 
             let cli_model = CliModel {
                 test,
@@ -86,6 +107,10 @@ impl TryFrom<CliReportThresholds> for Thresholds {
                 window: window.and_then(Into::into),
                 lower_boundary: lower_boundary.and_then(Into::into),
                 upper_boundary: upper_boundary.and_then(Into::into),
+                confirm_outliers: confirm_outlier.and_then(Into::into),
+                confirm_reports: confirm_report.and_then(Into::into),
+                resolve_reports: resolve_report.and_then(Into::into),
+                version_window: version_window.and_then(Into::into),
             };
             let model = Model::try_from(cli_model).map_err(|err| ThresholdsError::BadModel {
                 measure: measure.clone(),
@@ -127,6 +152,30 @@ impl TryFrom<CliReportThresholds> for Thresholds {
                 remaining_upper_boundaries,
             ));
         }
+        let remaining_confirm_outliers = confirm_outliers.collect::<Vec<_>>();
+        if !remaining_confirm_outliers.is_empty() {
+            return Err(ThresholdsError::ExtraConfirmOutliers(
+                remaining_confirm_outliers,
+            ));
+        }
+        let remaining_confirm_reports = confirm_reports.collect::<Vec<_>>();
+        if !remaining_confirm_reports.is_empty() {
+            return Err(ThresholdsError::ExtraConfirmReports(
+                remaining_confirm_reports,
+            ));
+        }
+        let remaining_resolve_reports = resolve_reports.collect::<Vec<_>>();
+        if !remaining_resolve_reports.is_empty() {
+            return Err(ThresholdsError::ExtraResolveReports(
+                remaining_resolve_reports,
+            ));
+        }
+        let remaining_version_windows = version_windows.collect::<Vec<_>>();
+        if !remaining_version_windows.is_empty() {
+            return Err(ThresholdsError::ExtraVersionWindows(
+                remaining_version_windows,
+            ));
+        }
 
         Ok(Self {
             // Do not short circuit early if there are no measures
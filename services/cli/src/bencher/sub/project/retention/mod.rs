@@ -0,0 +1,34 @@
+use crate::{CliError, bencher::sub::SubCmd, parser::project::retention::CliProjectRetention};
+
+mod dry_run;
+mod update;
+mod view;
+
+#[derive(Debug)]
+pub enum Retention {
+    View(view::View),
+    Update(update::Update),
+    DryRun(dry_run::DryRun),
+}
+
+impl TryFrom<CliProjectRetention> for Retention {
+    type Error = CliError;
+
+    fn try_from(retention: CliProjectRetention) -> Result<Self, Self::Error> {
+        Ok(match retention {
+            CliProjectRetention::View(view) => Self::View(view.try_into()?),
+            CliProjectRetention::Update(update) => Self::Update(update.try_into()?),
+            CliProjectRetention::DryRun(dry_run) => Self::DryRun(dry_run.try_into()?),
+        })
+    }
+}
+
+impl SubCmd for Retention {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::View(view) => view.exec().await,
+            Self::Update(update) => update.exec().await,
+            Self::DryRun(dry_run) => dry_run.exec().await,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use bencher_json::{BranchResourceId, ProjectResourceId};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::retention::CliProjectRetentionUpdate,
+};
+
+#[derive(Debug, Clone)]
+pub struct Update {
+    pub project: ProjectResourceId,
+    pub report_days: Option<u32>,
+    pub default_branch: Option<BranchResourceId>,
+    pub downsample_days: Option<u32>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliProjectRetentionUpdate> for Update {
+    type Error = CliError;
+
+    fn try_from(update: CliProjectRetentionUpdate) -> Result<Self, Self::Error> {
+        let CliProjectRetentionUpdate {
+            project,
+            report_days,
+            default_branch,
+            downsample_days,
+            backend,
+        } = update;
+        Ok(Self {
+            project,
+            report_days,
+            default_branch,
+            downsample_days,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Update {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_retention_put()
+                    .project(self.project.clone())
+                    .body(bencher_client::types::JsonUpdateRetention {
+                        report_days: self.report_days,
+                        default_branch: self.default_branch.clone().map(Into::into),
+                        downsample_days: self.downsample_days,
+                    })
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
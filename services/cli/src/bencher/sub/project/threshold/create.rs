@@ -57,6 +57,10 @@ impl From<Create> for JsonNewThreshold {
             window,
             lower_boundary,
             upper_boundary,
+            confirm_outliers,
+            confirm_reports,
+            resolve_reports,
+            version_window,
         } = model;
         Self {
             branch: branch.into(),
@@ -68,6 +72,10 @@ impl From<Create> for JsonNewThreshold {
             window,
             lower_boundary,
             upper_boundary,
+            confirm_outliers,
+            confirm_reports,
+            resolve_reports,
+            version_window,
         }
     }
 }
@@ -12,6 +12,10 @@ pub struct Model {
     pub window: Option<Window>,
     pub lower_boundary: Option<Boundary>,
     pub upper_boundary: Option<Boundary>,
+    pub confirm_outliers: Option<SampleSize>,
+    pub confirm_reports: Option<SampleSize>,
+    pub resolve_reports: Option<SampleSize>,
+    pub version_window: Option<SampleSize>,
 }
 
 impl TryFrom<CliModel> for Model {
@@ -25,6 +29,10 @@ impl TryFrom<CliModel> for Model {
             window,
             lower_boundary,
             upper_boundary,
+            confirm_outliers,
+            confirm_reports,
+            resolve_reports,
+            version_window,
         } = model;
         bencher_json::Model {
             test: test.into(),
@@ -33,6 +41,10 @@ impl TryFrom<CliModel> for Model {
             window,
             lower_boundary,
             upper_boundary,
+            confirm_outliers,
+            confirm_reports,
+            resolve_reports,
+            version_window,
         }
         .validate()
         .map_err(ThresholdError::BadModel)?;
@@ -43,6 +55,10 @@ impl TryFrom<CliModel> for Model {
             window: window.map(Into::into),
             lower_boundary: lower_boundary.map(Into::into),
             upper_boundary: upper_boundary.map(Into::into),
+            confirm_outliers: confirm_outliers.map(Into::into),
+            confirm_reports: confirm_reports.map(Into::into),
+            resolve_reports: resolve_reports.map(Into::into),
+            version_window: version_window.map(Into::into),
         })
     }
 }
@@ -57,6 +73,7 @@ impl From<CliModelTest> for bencher_json::ModelTest {
             CliModelTest::LogNormal => Self::LogNormal,
             CliModelTest::Iqr => Self::Iqr,
             CliModelTest::DeltaIqr => Self::DeltaIqr,
+            CliModelTest::MannWhitney => Self::MannWhitney,
         }
     }
 }
@@ -71,6 +88,7 @@ impl From<CliModelTest> for ModelTest {
             CliModelTest::LogNormal => Self::LogNormal,
             CliModelTest::Iqr => Self::Iqr,
             CliModelTest::DeltaIqr => Self::DeltaIqr,
+            CliModelTest::MannWhitney => Self::MannWhitney,
         }
     }
 }
@@ -84,6 +102,10 @@ impl From<Model> for bencher_client::types::Model {
             window,
             lower_boundary,
             upper_boundary,
+            confirm_outliers,
+            confirm_reports,
+            resolve_reports,
+            version_window,
         } = model;
         bencher_client::types::Model {
             test,
@@ -92,6 +114,10 @@ impl From<Model> for bencher_client::types::Model {
             window,
             lower_boundary,
             upper_boundary,
+            confirm_outliers,
+            confirm_reports,
+            resolve_reports,
+            version_window,
         }
     }
 }
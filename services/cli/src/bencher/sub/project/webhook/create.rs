@@ -0,0 +1,58 @@
+use bencher_json::{ProjectResourceId, Url};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::webhook::{CliProjectWebhookCreate, CliWebhookEvent},
+};
+
+#[derive(Debug, Clone)]
+pub struct Create {
+    pub project: ProjectResourceId,
+    pub url: Url,
+    pub events: Option<Vec<CliWebhookEvent>>,
+    pub disabled: bool,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliProjectWebhookCreate> for Create {
+    type Error = CliError;
+
+    fn try_from(create: CliProjectWebhookCreate) -> Result<Self, Self::Error> {
+        let CliProjectWebhookCreate {
+            project,
+            url,
+            events,
+            disabled,
+            backend,
+        } = create;
+        Ok(Self {
+            project,
+            url,
+            events,
+            disabled,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Create {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_webhook_post()
+                    .project(self.project.clone())
+                    .body(bencher_client::types::JsonNewWebhook {
+                        url: self.url.clone().into(),
+                        events: self.events.as_deref().map(super::into_events),
+                        enabled: self.disabled.then_some(false),
+                    })
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
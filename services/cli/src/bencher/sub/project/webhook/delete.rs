@@ -0,0 +1,48 @@
+use bencher_json::{ProjectResourceId, WebhookUuid};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::webhook::CliProjectWebhookDelete,
+};
+
+#[derive(Debug, Clone)]
+pub struct Delete {
+    pub project: ProjectResourceId,
+    pub uuid: WebhookUuid,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliProjectWebhookDelete> for Delete {
+    type Error = CliError;
+
+    fn try_from(delete: CliProjectWebhookDelete) -> Result<Self, Self::Error> {
+        let CliProjectWebhookDelete {
+            project,
+            uuid,
+            backend,
+        } = delete;
+        Ok(Self {
+            project,
+            uuid,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Delete {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_webhook_delete()
+                    .project(self.project.clone())
+                    .webhook(self.uuid)
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
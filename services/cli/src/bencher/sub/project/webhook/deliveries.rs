@@ -0,0 +1,93 @@
+use bencher_client::types::{JsonDirection, ProjWebhookDeliveriesSort};
+use bencher_json::{ProjectResourceId, WebhookUuid};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::{
+        CliPagination,
+        project::webhook::{CliProjectWebhookDeliveries, CliProjectWebhookDeliveriesSort},
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct Deliveries {
+    pub project: ProjectResourceId,
+    pub uuid: WebhookUuid,
+    pub pagination: Pagination,
+    pub backend: AuthBackend,
+}
+
+#[derive(Debug, Clone)]
+pub struct Pagination {
+    pub sort: Option<ProjWebhookDeliveriesSort>,
+    pub direction: Option<JsonDirection>,
+    pub per_page: Option<u8>,
+    pub page: Option<u32>,
+}
+
+impl TryFrom<CliProjectWebhookDeliveries> for Deliveries {
+    type Error = CliError;
+
+    fn try_from(deliveries: CliProjectWebhookDeliveries) -> Result<Self, Self::Error> {
+        let CliProjectWebhookDeliveries {
+            project,
+            uuid,
+            pagination,
+            backend,
+        } = deliveries;
+        Ok(Self {
+            project,
+            uuid,
+            pagination: pagination.into(),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<CliPagination<CliProjectWebhookDeliveriesSort>> for Pagination {
+    fn from(pagination: CliPagination<CliProjectWebhookDeliveriesSort>) -> Self {
+        let CliPagination {
+            sort,
+            direction,
+            per_page,
+            page,
+        } = pagination;
+        Self {
+            sort: sort.map(|sort| match sort {
+                CliProjectWebhookDeliveriesSort::Created => ProjWebhookDeliveriesSort::Created,
+            }),
+            direction: direction.map(Into::into),
+            page,
+            per_page,
+        }
+    }
+}
+
+impl SubCmd for Deliveries {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                let mut request = client
+                    .proj_webhook_deliveries_get()
+                    .project(self.project.clone())
+                    .webhook(self.uuid);
+                if let Some(sort) = self.pagination.sort {
+                    request = request.sort(sort);
+                }
+                if let Some(direction) = self.pagination.direction {
+                    request = request.direction(direction);
+                }
+                if let Some(per_page) = self.pagination.per_page {
+                    request = request.per_page(per_page);
+                }
+                if let Some(page) = self.pagination.page {
+                    request = request.page(page);
+                }
+                request.send().await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use bencher_client::types::{JsonDirection, ProjWebhooksSort};
+use bencher_json::ProjectResourceId;
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::{
+        CliPagination,
+        project::webhook::{CliProjectWebhookList, CliProjectWebhooksSort},
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct List {
+    pub project: ProjectResourceId,
+    pub pagination: Pagination,
+    pub backend: AuthBackend,
+}
+
+#[derive(Debug, Clone)]
+pub struct Pagination {
+    pub sort: Option<ProjWebhooksSort>,
+    pub direction: Option<JsonDirection>,
+    pub per_page: Option<u8>,
+    pub page: Option<u32>,
+}
+
+impl TryFrom<CliProjectWebhookList> for List {
+    type Error = CliError;
+
+    fn try_from(list: CliProjectWebhookList) -> Result<Self, Self::Error> {
+        let CliProjectWebhookList {
+            project,
+            pagination,
+            backend,
+        } = list;
+        Ok(Self {
+            project,
+            pagination: pagination.into(),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<CliPagination<CliProjectWebhooksSort>> for Pagination {
+    fn from(pagination: CliPagination<CliProjectWebhooksSort>) -> Self {
+        let CliPagination {
+            sort,
+            direction,
+            per_page,
+            page,
+        } = pagination;
+        Self {
+            sort: sort.map(|sort| match sort {
+                CliProjectWebhooksSort::Created => ProjWebhooksSort::Created,
+            }),
+            direction: direction.map(Into::into),
+            page,
+            per_page,
+        }
+    }
+}
+
+impl SubCmd for List {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                let mut request = client.proj_webhooks_get().project(self.project.clone());
+                if let Some(sort) = self.pagination.sort {
+                    request = request.sort(sort);
+                }
+                if let Some(direction) = self.pagination.direction {
+                    request = request.direction(direction);
+                }
+                if let Some(per_page) = self.pagination.per_page {
+                    request = request.per_page(per_page);
+                }
+                if let Some(page) = self.pagination.page {
+                    request = request.page(page);
+                }
+                request.send().await
+            })
+            .await?;
+        Ok(())
+    }
+}
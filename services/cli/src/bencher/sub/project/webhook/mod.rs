@@ -0,0 +1,61 @@
+use crate::{
+    CliError,
+    bencher::sub::SubCmd,
+    parser::project::webhook::{CliProjectWebhook, CliWebhookEvent},
+};
+
+mod create;
+mod delete;
+mod deliveries;
+mod list;
+mod update;
+mod view;
+
+#[derive(Debug)]
+pub enum Webhook {
+    List(list::List),
+    Create(create::Create),
+    View(view::View),
+    Update(update::Update),
+    Delete(delete::Delete),
+    Deliveries(deliveries::Deliveries),
+}
+
+impl TryFrom<CliProjectWebhook> for Webhook {
+    type Error = CliError;
+
+    fn try_from(webhook: CliProjectWebhook) -> Result<Self, Self::Error> {
+        Ok(match webhook {
+            CliProjectWebhook::List(list) => Self::List(list.try_into()?),
+            CliProjectWebhook::Create(create) => Self::Create(create.try_into()?),
+            CliProjectWebhook::View(view) => Self::View(view.try_into()?),
+            CliProjectWebhook::Update(update) => Self::Update(update.try_into()?),
+            CliProjectWebhook::Delete(delete) => Self::Delete(delete.try_into()?),
+            CliProjectWebhook::Deliveries(deliveries) => Self::Deliveries(deliveries.try_into()?),
+        })
+    }
+}
+
+impl SubCmd for Webhook {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::List(list) => list.exec().await,
+            Self::Create(create) => create.exec().await,
+            Self::View(view) => view.exec().await,
+            Self::Update(update) => update.exec().await,
+            Self::Delete(delete) => delete.exec().await,
+            Self::Deliveries(deliveries) => deliveries.exec().await,
+        }
+    }
+}
+
+fn into_events(events: &[CliWebhookEvent]) -> Vec<bencher_client::types::WebhookEvent> {
+    events
+        .iter()
+        .map(|event| match event {
+            CliWebhookEvent::AlertCreated => bencher_client::types::WebhookEvent::AlertCreated,
+            CliWebhookEvent::AlertDismissed => bencher_client::types::WebhookEvent::AlertDismissed,
+            CliWebhookEvent::ReportCreated => bencher_client::types::WebhookEvent::ReportCreated,
+        })
+        .collect()
+}
@@ -0,0 +1,62 @@
+use bencher_json::{ProjectResourceId, Url, WebhookUuid};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::webhook::{CliProjectWebhookUpdate, CliWebhookEvent},
+};
+
+#[derive(Debug, Clone)]
+pub struct Update {
+    pub project: ProjectResourceId,
+    pub uuid: WebhookUuid,
+    pub url: Option<Url>,
+    pub events: Option<Vec<CliWebhookEvent>>,
+    pub enabled: Option<bool>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliProjectWebhookUpdate> for Update {
+    type Error = CliError;
+
+    fn try_from(update: CliProjectWebhookUpdate) -> Result<Self, Self::Error> {
+        let CliProjectWebhookUpdate {
+            project,
+            uuid,
+            url,
+            events,
+            enabled,
+            backend,
+        } = update;
+        Ok(Self {
+            project,
+            uuid,
+            url,
+            events,
+            enabled,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Update {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_webhook_patch()
+                    .project(self.project.clone())
+                    .webhook(self.uuid)
+                    .body(bencher_client::types::JsonUpdateWebhook {
+                        url: self.url.clone().map(Into::into),
+                        events: self.events.as_deref().map(super::into_events),
+                        enabled: self.enabled,
+                    })
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use bencher_json::{ProjectResourceId, WebhookUuid};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::webhook::CliProjectWebhookView,
+};
+
+#[derive(Debug, Clone)]
+pub struct View {
+    pub project: ProjectResourceId,
+    pub uuid: WebhookUuid,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliProjectWebhookView> for View {
+    type Error = CliError;
+
+    fn try_from(view: CliProjectWebhookView) -> Result<Self, Self::Error> {
+        let CliProjectWebhookView {
+            project,
+            uuid,
+            backend,
+        } = view;
+        Ok(Self {
+            project,
+            uuid,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for View {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_webhook_get()
+                    .project(self.project.clone())
+                    .webhook(self.uuid)
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
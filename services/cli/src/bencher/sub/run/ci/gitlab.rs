@@ -0,0 +1,602 @@
+use bencher_comment::ReportComment;
+use reqwest::{Client, Method, StatusCode};
+use serde::{Deserialize, Serialize};
+
+use crate::cli_println_quietable;
+
+const GITLAB_CI: &str = "GITLAB_CI";
+const CI_API_V4_URL: &str = "CI_API_V4_URL";
+const CI_PROJECT_ID: &str = "CI_PROJECT_ID";
+const CI_MERGE_REQUEST_PROJECT_ID: &str = "CI_MERGE_REQUEST_PROJECT_ID";
+const CI_MERGE_REQUEST_IID: &str = "CI_MERGE_REQUEST_IID";
+
+const DEFAULT_API_URL: &str = "https://gitlab.com/api/v4";
+// Personal, project, and group access tokens all authenticate with this header.
+// https://docs.gitlab.com/api/rest/authentication/#personalprojectgroup-access-tokens
+const PRIVATE_TOKEN: &str = "PRIVATE-TOKEN";
+
+// The maximum length of a note is 1,000,000 characters.
+// https://docs.gitlab.com/api/notes/#create-new-merge-request-note
+const MAX_LENGTH: usize = 1_000_000;
+
+#[derive(Debug)]
+pub struct GitLab {
+    pub token: String,
+    pub ci_only_thresholds: bool,
+    pub ci_only_on_alert: bool,
+    pub ci_public_links: bool,
+    pub ci_id: Option<String>,
+    pub ci_number: Option<u64>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum GitLabError {
+    #[error(
+        "GitLab CI/CD merge request project ID is missing\n{}",
+        docker_env(CI_MERGE_REQUEST_PROJECT_ID)
+    )]
+    NoProjectId,
+    #[error("GitLab CI/CD merge request IID is invalid ({0}): {1}")]
+    BadMergeRequestIid(String, std::num::ParseIntError),
+
+    #[error("Failed to create GitLab API client: {0}")]
+    Client(reqwest::Error),
+    #[error("Failed to list GitLab merge request notes: {0}")]
+    Notes(reqwest::Error),
+    #[error("Failed to create GitLab merge request note: {0}")]
+    CreateNote(reqwest::Error),
+    #[error("Failed to update GitLab merge request note: {0}")]
+    UpdateNote(reqwest::Error),
+    #[error("{}", permissions_help(_0))]
+    BadNotePermissions(reqwest::Error),
+}
+
+// https://docs.gitlab.com/user/project/settings/project_access_tokens/#scopes-for-a-project-access-token
+fn permissions_help(err: &reqwest::Error) -> String {
+    format!(
+        "GitLab token (`--gitlab-token`) does not have permission to comment on the merge request.\nTo fix, use a project access token with the `api` scope and at least the `Reporter` role.\nSee: https://bencher.dev/docs/explanation/bencher-run/#--gitlab-token-gitlab_token\nError: {err}",
+    )
+}
+
+fn docker_env(env_var: &str) -> String {
+    format!(
+        "If you are running in a Docker container, then you need to pass in the `{env_var}` environment variable. See https://bencher.dev/docs/explanation/bencher-run/#--gitlab-token-gitlab_token",
+    )
+}
+
+impl GitLab {
+    pub async fn run(&self, report_comment: &ReportComment, log: bool) -> Result<(), GitLabError> {
+        if !is_gitlab_ci() {
+            cli_println_quietable!(
+                log,
+                "Not running in GitLab CI/CD. Skipping CI integration.\n{}",
+                docker_env(GITLAB_CI)
+            );
+            return Ok(());
+        }
+
+        // Only post a merge request note if there are thresholds set
+        if self.ci_only_thresholds && !report_comment.has_threshold() {
+            cli_println_quietable!(log, "No thresholds set. Skipping merge request note.");
+            return Ok(());
+        }
+
+        let Some(merge_request) =
+            MergeRequest::from_env(self.ci_number, |key| std::env::var(key).ok())?
+        else {
+            cli_println_quietable!(
+                log,
+                "Not running as a GitLab CI/CD merge request pipeline and the `--ci-number` option was not set. Skipping merge request note.\n{}",
+                docker_env(CI_MERGE_REQUEST_IID)
+            );
+            return Ok(());
+        };
+
+        self.create_merge_request_note(&merge_request, report_comment, log)
+            .await
+    }
+
+    async fn create_merge_request_note(
+        &self,
+        merge_request: &MergeRequest,
+        report_comment: &ReportComment,
+        log: bool,
+    ) -> Result<(), GitLabError> {
+        let body = report_comment.html_with_max_length(
+            self.ci_only_thresholds,
+            self.ci_id.as_deref(),
+            MAX_LENGTH,
+        );
+        let note = upsert_note(
+            &self.token,
+            merge_request,
+            &report_comment.bencher_tag(self.ci_id.as_deref()),
+            body,
+            // Always update the note if it exists
+            !self.ci_only_on_alert || report_comment.has_alert(),
+        )
+        .await?;
+        if note.is_none() {
+            cli_println_quietable!(log, "No alerts found. Skipping CI integration.");
+        }
+        Ok(())
+    }
+}
+
+// https://docs.gitlab.com/ci/variables/predefined_variables/
+// Available for all jobs executed in CI/CD. `true` when available.
+fn is_gitlab_ci() -> bool {
+    std::env::var(GITLAB_CI).as_deref() == Ok("true")
+}
+
+/// The merge request to post the Report to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MergeRequest {
+    api_url: String,
+    project_id: String,
+    iid: u64,
+}
+
+impl MergeRequest {
+    // The environment is read by the caller so this stays deterministic in tests.
+    // https://docs.gitlab.com/ci/variables/predefined_variables/#predefined-variables-for-merge-request-pipelines
+    fn from_env(
+        ci_number: Option<u64>,
+        env_var: impl Fn(&str) -> Option<String>,
+    ) -> Result<Option<Self>, GitLabError> {
+        let iid = if let Some(iid) = ci_number {
+            iid
+        } else if let Some(iid) = env_var(CI_MERGE_REQUEST_IID) {
+            iid.parse()
+                .map_err(|e| GitLabError::BadMergeRequestIid(iid, e))?
+        } else {
+            return Ok(None);
+        };
+        // The merge request belongs to the target project,
+        // which is not the current project for a merge request from a fork.
+        let project_id = env_var(CI_MERGE_REQUEST_PROJECT_ID)
+            .or_else(|| env_var(CI_PROJECT_ID))
+            .ok_or(GitLabError::NoProjectId)?;
+        let api_url = env_var(CI_API_V4_URL).unwrap_or_else(|| DEFAULT_API_URL.to_owned());
+        Ok(Some(Self {
+            api_url,
+            project_id,
+            iid,
+        }))
+    }
+
+    fn notes_url(&self) -> String {
+        format!(
+            "{api_url}/projects/{project_id}/merge_requests/{iid}/notes",
+            api_url = self.api_url.trim_end_matches('/'),
+            project_id = self.project_id,
+            iid = self.iid
+        )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Note {
+    id: u64,
+    body: String,
+    #[serde(default)]
+    system: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct NoteBody {
+    body: String,
+}
+
+/// Update the merge request note tagged with the `bencher_tag` if it exists.
+/// Otherwise, create a new note if `create` is set.
+/// Returns the ID of the note, if any.
+async fn upsert_note(
+    token: &str,
+    merge_request: &MergeRequest,
+    bencher_tag: &str,
+    body: String,
+    create: bool,
+) -> Result<Option<u64>, GitLabError> {
+    let client = Client::builder().build().map_err(GitLabError::Client)?;
+    let note_id = get_note(&client, token, merge_request, bencher_tag).await?;
+
+    let notes_url = merge_request.notes_url();
+    let (method, url, error): (_, _, fn(reqwest::Error) -> GitLabError) =
+        if let Some(note_id) = note_id {
+            (
+                Method::PUT,
+                format!("{notes_url}/{note_id}"),
+                GitLabError::UpdateNote,
+            )
+        } else if create {
+            (Method::POST, notes_url, GitLabError::CreateNote)
+        } else {
+            return Ok(None);
+        };
+    let note = client
+        .request(method, url)
+        .header(PRIVATE_TOKEN, token)
+        .json(&NoteBody { body })
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(|e| note_error(e, error))?
+        .json::<Note>()
+        .await
+        .map_err(error)?;
+    Ok(Some(note.id))
+}
+
+async fn get_note(
+    client: &Client,
+    token: &str,
+    merge_request: &MergeRequest,
+    bencher_tag: &str,
+) -> Result<Option<u64>, GitLabError> {
+    const PER_PAGE: u8 = 100;
+
+    let notes_url = merge_request.notes_url();
+    let mut page: u32 = 1;
+    loop {
+        let notes = client
+            .get(&notes_url)
+            .header(PRIVATE_TOKEN, token)
+            .query(&[
+                ("sort", "asc".to_owned()),
+                ("per_page", PER_PAGE.to_string()),
+                ("page", page.to_string()),
+            ])
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| note_error(e, GitLabError::Notes))?
+            .json::<Vec<Note>>()
+            .await
+            .map_err(GitLabError::Notes)?;
+
+        let notes_len = notes.len();
+        if notes_len == 0 {
+            return Ok(None);
+        }
+
+        for note in notes {
+            // System notes are generated by GitLab, i.e. "added 1 commit"
+            if !note.system && note.body.ends_with(bencher_tag) {
+                return Ok(Some(note.id));
+            }
+        }
+
+        if notes_len < usize::from(PER_PAGE) {
+            return Ok(None);
+        }
+
+        page += 1;
+    }
+}
+
+fn note_error(err: reqwest::Error, variant: fn(reqwest::Error) -> GitLabError) -> GitLabError {
+    if matches!(
+        err.status(),
+        Some(StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN)
+    ) {
+        GitLabError::BadNotePermissions(err)
+    } else {
+        variant(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    use tokio::{
+        io::{AsyncBufReadExt as _, AsyncReadExt as _, AsyncWriteExt as _, BufReader},
+        net::TcpListener,
+    };
+    use tokio_rustls::rustls::crypto::aws_lc_rs;
+
+    use super::{
+        CI_API_V4_URL, CI_MERGE_REQUEST_IID, CI_MERGE_REQUEST_PROJECT_ID, CI_PROJECT_ID,
+        DEFAULT_API_URL, GitLabError, MergeRequest, PRIVATE_TOKEN, upsert_note,
+    };
+
+    const TOKEN: &str = "glpat-token";
+    const BENCHER_TAG: &str =
+        "<div id=\"bencher.dev/projects/project/id/main/localhost/json\"></div>";
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars = vars
+            .iter()
+            .map(|(key, value)| ((*key).to_owned(), (*value).to_owned()))
+            .collect::<HashMap<_, _>>();
+        move |key| vars.get(key).cloned()
+    }
+
+    #[test]
+    fn merge_request_from_env() {
+        let merge_request = MergeRequest::from_env(
+            None,
+            env(&[
+                (CI_API_V4_URL, "https://gitlab.example.com/api/v4"),
+                (CI_PROJECT_ID, "7"),
+                (CI_MERGE_REQUEST_PROJECT_ID, "42"),
+                (CI_MERGE_REQUEST_IID, "3"),
+            ]),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            merge_request,
+            MergeRequest {
+                api_url: "https://gitlab.example.com/api/v4".to_owned(),
+                project_id: "42".to_owned(),
+                iid: 3,
+            }
+        );
+        assert_eq!(
+            merge_request.notes_url(),
+            "https://gitlab.example.com/api/v4/projects/42/merge_requests/3/notes"
+        );
+    }
+
+    // `--ci-number` works outside of a merge request pipeline
+    #[test]
+    fn merge_request_from_ci_number() {
+        let merge_request = MergeRequest::from_env(Some(5), env(&[(CI_PROJECT_ID, "7")]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            merge_request,
+            MergeRequest {
+                api_url: DEFAULT_API_URL.to_owned(),
+                project_id: "7".to_owned(),
+                iid: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn merge_request_not_merge_request_pipeline() {
+        assert_eq!(
+            MergeRequest::from_env(None, env(&[(CI_PROJECT_ID, "7")])).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn merge_request_bad_iid() {
+        assert!(matches!(
+            MergeRequest::from_env(
+                None,
+                env(&[
+                    (CI_MERGE_REQUEST_PROJECT_ID, "42"),
+                    (CI_MERGE_REQUEST_IID, "three")
+                ])
+            ),
+            Err(GitLabError::BadMergeRequestIid(_, _))
+        ));
+    }
+
+    #[test]
+    fn merge_request_no_project_id() {
+        assert!(matches!(
+            MergeRequest::from_env(Some(5), env(&[])),
+            Err(GitLabError::NoProjectId)
+        ));
+    }
+
+    #[derive(Debug, Clone)]
+    struct Request {
+        method: String,
+        path: String,
+        token: Option<String>,
+        body: String,
+    }
+
+    /// A local stand-in for the GitLab merge request notes API.
+    struct StandIn {
+        api_url: String,
+        requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl StandIn {
+        async fn new(notes: serde_json::Value, status: u16) -> Self {
+            let _provider = aws_lc_rs::default_provider().install_default();
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let api_url = format!("http://{}/api/v4", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let server_requests = Arc::clone(&requests);
+            tokio::spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    let mut stream = BufReader::new(stream);
+                    let request = read_request(&mut stream).await;
+                    let response_body = match request.method.as_str() {
+                        "GET" if request.path.ends_with("&page=1") => notes.to_string(),
+                        "GET" => "[]".to_owned(),
+                        _ => {
+                            serde_json::json!({ "id": 99, "body": "", "system": false }).to_string()
+                        },
+                    };
+                    server_requests.lock().unwrap().push(request);
+                    let response = format!(
+                        "HTTP/1.1 {status} Stand-In\r\ncontent-type: application/json\r\ncontent-length: {len}\r\nconnection: close\r\n\r\n{response_body}",
+                        len = response_body.len()
+                    );
+                    stream
+                        .get_mut()
+                        .write_all(response.as_bytes())
+                        .await
+                        .unwrap();
+                }
+            });
+            Self { api_url, requests }
+        }
+
+        fn merge_request(&self) -> MergeRequest {
+            MergeRequest {
+                api_url: self.api_url.clone(),
+                project_id: "42".to_owned(),
+                iid: 3,
+            }
+        }
+
+        fn requests(&self) -> Vec<Request> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    async fn read_request(stream: &mut BufReader<tokio::net::TcpStream>) -> Request {
+        let mut line = String::new();
+        stream.read_line(&mut line).await.unwrap();
+        let mut parts = line.split_whitespace();
+        let method = parts.next().unwrap().to_owned();
+        let path = parts.next().unwrap().to_owned();
+        let mut token = None;
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            stream.read_line(&mut header).await.unwrap();
+            let header = header.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case(PRIVATE_TOKEN) {
+                    token = Some(value.trim().to_owned());
+                } else if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+        }
+        let mut body = vec![0; content_length];
+        stream.read_exact(&mut body).await.unwrap();
+        Request {
+            method,
+            path,
+            token,
+            body: String::from_utf8(body).unwrap(),
+        }
+    }
+
+    fn note_body(request: &Request) -> String {
+        let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+        body.get("body").unwrap().as_str().unwrap().to_owned()
+    }
+
+    #[tokio::test]
+    async fn upsert_note_create() {
+        let stand_in = StandIn::new(
+            serde_json::json!([
+                { "id": 1, "body": "LGTM", "system": false },
+                { "id": 2, "body": "added 1 commit", "system": true },
+            ]),
+            200,
+        )
+        .await;
+        let body = format!("Report{BENCHER_TAG}");
+        let note_id = upsert_note(
+            TOKEN,
+            &stand_in.merge_request(),
+            BENCHER_TAG,
+            body.clone(),
+            true,
+        )
+        .await
+        .unwrap();
+        assert_eq!(note_id, Some(99));
+
+        let requests = stand_in.requests();
+        assert_eq!(requests.len(), 2);
+        let list = requests.first().unwrap();
+        assert_eq!(list.method, "GET");
+        assert!(
+            list.path
+                .starts_with("/api/v4/projects/42/merge_requests/3/notes?"),
+            "{}",
+            list.path
+        );
+        assert_eq!(list.token.as_deref(), Some(TOKEN));
+        let create = requests.last().unwrap();
+        assert_eq!(create.method, "POST");
+        assert_eq!(create.path, "/api/v4/projects/42/merge_requests/3/notes");
+        assert_eq!(create.token.as_deref(), Some(TOKEN));
+        assert_eq!(note_body(create), body);
+    }
+
+    #[tokio::test]
+    async fn upsert_note_update() {
+        let stand_in = StandIn::new(
+            serde_json::json!([
+                { "id": 1, "body": "LGTM", "system": false },
+                { "id": 7, "body": format!("Old Report{BENCHER_TAG}"), "system": false },
+            ]),
+            200,
+        )
+        .await;
+        let body = format!("New Report{BENCHER_TAG}");
+        // An existing note is always updated, even if creating a note is not allowed.
+        let note_id = upsert_note(
+            TOKEN,
+            &stand_in.merge_request(),
+            BENCHER_TAG,
+            body.clone(),
+            false,
+        )
+        .await
+        .unwrap();
+        assert_eq!(note_id, Some(99));
+
+        let requests = stand_in.requests();
+        assert_eq!(requests.len(), 2);
+        let update = requests.last().unwrap();
+        assert_eq!(update.method, "PUT");
+        assert_eq!(update.path, "/api/v4/projects/42/merge_requests/3/notes/7");
+        assert_eq!(note_body(update), body);
+    }
+
+    // A note for a different `--ci-id` is left alone
+    #[tokio::test]
+    async fn upsert_note_skip_create() {
+        let stand_in = StandIn::new(
+            serde_json::json!([
+                { "id": 7, "body": "Other Report<div id=\"bencher.dev/projects/project/id/other\"></div>", "system": false },
+            ]),
+            200,
+        )
+        .await;
+        let note_id = upsert_note(
+            TOKEN,
+            &stand_in.merge_request(),
+            BENCHER_TAG,
+            format!("Report{BENCHER_TAG}"),
+            false,
+        )
+        .await
+        .unwrap();
+        assert_eq!(note_id, None);
+        let requests = stand_in.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests.first().unwrap().method, "GET");
+    }
+
+    #[tokio::test]
+    async fn upsert_note_forbidden() {
+        let stand_in = StandIn::new(serde_json::json!([]), 403).await;
+        let result = upsert_note(
+            TOKEN,
+            &stand_in.merge_request(),
+            BENCHER_TAG,
+            format!("Report{BENCHER_TAG}"),
+            true,
+        )
+        .await;
+        assert!(
+            matches!(result, Err(GitLabError::BadNotePermissions(_))),
+            "{result:?}"
+        );
+    }
+}
@@ -4,12 +4,15 @@ use bencher_json::ResourceName;
 use crate::parser::run::CliRunCi;
 
 mod github_actions;
+mod gitlab;
 
 use github_actions::{CheckRunHandle, GitHubActions, GitHubError};
+use gitlab::{GitLab, GitLabError};
 
 #[derive(Debug)]
 pub enum Ci {
     GitHubActions(GitHubActions),
+    GitLab(GitLab),
 }
 
 /// A CI check started before the benchmark runs,
@@ -23,6 +26,8 @@ pub enum CiCheck {
 pub enum CiError {
     #[error("{0}")]
     GitHub(#[from] GitHubError),
+    #[error("{0}")]
+    GitLab(#[from] GitLabError),
 }
 
 impl TryFrom<CliRunCi> for Option<Ci> {
@@ -31,6 +36,7 @@ impl TryFrom<CliRunCi> for Option<Ci> {
     fn try_from(ci: CliRunCi) -> Result<Self, Self::Error> {
         let CliRunCi {
             github_actions,
+            gitlab_token,
             ci_only_thresholds,
             ci_only_on_alert,
             ci_public_links,
@@ -38,8 +44,8 @@ impl TryFrom<CliRunCi> for Option<Ci> {
             ci_number,
             ci_i_am_vulnerable_to_pwn_requests,
         } = ci;
-        Ok(github_actions.map(|token| {
-            Ci::GitHubActions(GitHubActions {
+        Ok(if let Some(token) = github_actions {
+            Some(Ci::GitHubActions(GitHubActions {
                 token,
                 ci_only_thresholds,
                 ci_only_on_alert,
@@ -47,8 +53,19 @@ impl TryFrom<CliRunCi> for Option<Ci> {
                 ci_id,
                 ci_number,
                 ci_i_am_vulnerable_to_pwn_requests,
+            }))
+        } else {
+            gitlab_token.map(|token| {
+                Ci::GitLab(GitLab {
+                    token,
+                    ci_only_thresholds,
+                    ci_only_on_alert,
+                    ci_public_links,
+                    ci_id,
+                    ci_number,
+                })
             })
-        }))
+        })
     }
 }
 
@@ -58,6 +75,9 @@ impl Ci {
             Self::GitHubActions(github_actions) => {
                 github_actions.safety_check(log).map_err(Into::into)
             },
+            // A merge request pipeline from a fork runs in the fork project by default,
+            // so it does not have access to the parent project secrets.
+            Self::GitLab(_) => Ok(()),
         }
     }
 
@@ -65,6 +85,7 @@ impl Ci {
     pub fn needs_project_name(&self) -> bool {
         match self {
             Self::GitHubActions(github_actions) => github_actions.needs_project_name(),
+            Self::GitLab(_) => false,
         }
     }
 
@@ -75,6 +96,8 @@ impl Ci {
                 .start_check(project_name, log)
                 .await
                 .map(CiCheck::GitHubActions),
+            // GitLab merge request notes have no in-progress state.
+            Self::GitLab(_) => None,
         }
     }
 
@@ -92,6 +115,7 @@ impl Ci {
                     .await
                     .map_err(Into::into)
             },
+            Self::GitLab(gitlab) => gitlab.run(report_comment, log).await.map_err(Into::into),
         }
     }
 
@@ -102,12 +126,14 @@ impl Ci {
             (Self::GitHubActions(github_actions), CiCheck::GitHubActions(handle)) => {
                 github_actions.fail_check(handle, log).await;
             },
+            (Self::GitLab(_), CiCheck::GitHubActions(_)) => {},
         }
     }
 
     pub fn source(&self) -> String {
         match self {
             Self::GitHubActions(_) => "github".to_owned(),
+            Self::GitLab(_) => "gitlab".to_owned(),
         }
     }
 }
@@ -88,6 +88,18 @@ pub enum RunError {
     TooManyFilePaths { len: usize, max: usize },
     #[error("Failed to read from output file: {0}")]
     OutputFileRead(std::io::Error),
+    #[error("Failed to read Criterion output directory ({path}): {err}")]
+    CriterionDirectory {
+        path: camino::Utf8PathBuf,
+        err: std::io::Error,
+    },
+    #[error("Failed to parse Criterion output file ({path}): {err}")]
+    CriterionFile {
+        path: camino::Utf8PathBuf,
+        err: serde_json::Error,
+    },
+    #[error("Failed to serialize Criterion results: {0}")]
+    SerializeCriterion(serde_json::Error),
     #[error("Failed to parse the output file name: {0}")]
     OutputFileName(bencher_json::ValidError),
     #[error("Failed to read size of output file: {0}")]
@@ -95,6 +107,20 @@ pub enum RunError {
     #[error("Failed to serialize file size results: {0}")]
     SerializeFileSize(serde_json::Error),
 
+    #[error("Failed to measure host noise: {0}")]
+    Noise(bencher_noise::NoiseError),
+    #[error("Failed to join host noise probe: {0}")]
+    NoiseJoinError(tokio::task::JoinError),
+    #[error("Failed to serialize noise probe results: {0}")]
+    SerializeNoise(serde_json::Error),
+    #[error(
+        "The host is too noisy to benchmark: noise score ({score:.1} dB) exceeds the noise gate ({max_score:.1} dB)"
+    )]
+    NoisyHost { score: f64, max_score: f64 },
+    #[cfg(feature = "plus")]
+    #[error("The `--noise-gate` option is only supported for local runs, not with `--image`.")]
+    NoiseGateWithImage,
+
     #[error("Failed to serialize report JSON: {0}")]
     SerializeReport(serde_json::Error),
     #[error("Failed to create new report: {0}")]
@@ -5,6 +5,7 @@ pub enum Format {
     Human,
     Json,
     Html,
+    Junit,
 }
 
 impl From<CliRunFormat> for Format {
@@ -13,6 +14,7 @@ impl From<CliRunFormat> for Format {
             CliRunFormat::Human => Self::Human,
             CliRunFormat::Json => Self::Json,
             CliRunFormat::Html => Self::Html,
+            CliRunFormat::Junit => Self::Junit,
         }
     }
 }
@@ -5,7 +5,9 @@ use std::collections::HashMap;
 
 #[cfg(feature = "plus")]
 use bencher_client::types::JsonNewRunJob;
-use bencher_client::types::{Adapter, JsonAverage, JsonFold, JsonNewRun, JsonReportSettings};
+use bencher_client::types::{
+    Adapter, JsonAverage, JsonFold, JsonNewRun, JsonReportSettings, JsonStatistic,
+};
 use bencher_comment::ReportComment;
 #[cfg(feature = "plus")]
 use bencher_json::SpecResourceId;
@@ -14,6 +16,8 @@ use bencher_json::{
     project::report::Iteration,
 };
 
+#[cfg(feature = "plus")]
+use crate::parser::run::CliRunJob;
 use crate::{
     CliError,
     bencher::backend::PubBackend,
@@ -25,6 +29,7 @@ mod branch;
 mod ci;
 mod error;
 mod format;
+mod noise;
 mod project;
 pub mod runner;
 mod sub_adapter;
@@ -33,13 +38,14 @@ use branch::Branch;
 use ci::{Ci, CiCheck};
 pub use error::RunError;
 use format::Format;
+use noise::NoiseGate;
 use project::resolve_project;
 use runner::Runner;
 use sub_adapter::SubAdapter;
 
 use crate::bencher::SubCmd;
 
-use super::project::report::Thresholds;
+use super::project::report::{Thresholds, statistic_warning};
 
 #[derive(Debug)]
 #[expect(
@@ -57,8 +63,12 @@ pub struct Run {
     average: Option<JsonAverage>,
     iter: Iteration,
     fold: Option<JsonFold>,
+    bootstrap: bool,
+    statistic: Option<JsonStatistic>,
+    params: bool,
     backdate: Option<DateTime>,
     allow_failure: bool,
+    noise_gate: Option<NoiseGate>,
     thresholds: Thresholds,
     error_on_alert: bool,
     format: Format,
@@ -100,6 +110,30 @@ struct Job {
     build_time: bool,
     poll_interval: bencher_json::PollTimeout,
     detach: bool,
+    warmup: Option<Iteration>,
+    trim: Option<bencher_json::runner::JsonIterationTrim>,
+}
+
+#[cfg(feature = "plus")]
+impl Job {
+    fn new(job: CliRunJob, build_time: bool) -> Option<Self> {
+        let image = job.image?;
+        Some(Self {
+            image,
+            spec: job.spec,
+            entrypoint: job.entrypoint,
+            env: job.env.map(bencher_parser::parse_env),
+            timeout: job.job_timeout,
+            build_time,
+            poll_interval: job.job_poll_interval.unwrap_or(*DEFAULT_POLL_INTERVAL),
+            detach: job.detach,
+            warmup: job.warmup,
+            trim: job
+                .trim
+                .zip(job.trim_measure)
+                .map(|(count, measure)| bencher_json::runner::JsonIterationTrim { measure, count }),
+        })
+    }
 }
 
 impl TryFrom<CliRun> for Run {
@@ -116,8 +150,12 @@ impl TryFrom<CliRun> for Run {
             average,
             iter,
             fold,
+            bootstrap,
+            statistic,
+            params,
             backdate,
             allow_failure,
+            noise,
             thresholds,
             error_on_alert,
             output: CliRunOutput { format, quiet },
@@ -131,20 +169,7 @@ impl TryFrom<CliRun> for Run {
         #[cfg(feature = "plus")]
         let build_time = cmd.build_time;
         #[cfg(feature = "plus")]
-        let job = if let Some(image) = job.image {
-            Some(Job {
-                image,
-                spec: job.spec,
-                entrypoint: job.entrypoint,
-                env: job.env.map(bencher_parser::parse_env),
-                timeout: job.job_timeout,
-                build_time,
-                poll_interval: job.job_poll_interval.unwrap_or(*DEFAULT_POLL_INTERVAL),
-                detach: job.detach,
-            })
-        } else {
-            None
-        };
+        let job = Job::new(job, build_time);
         #[cfg(feature = "plus")]
         if build_time && job.is_none() && cmd.command.is_none() {
             return Err(RunError::BuildTimeNoCommandOrImage.into());
@@ -153,6 +178,11 @@ impl TryFrom<CliRun> for Run {
         if cmd.build_time && cmd.command.is_none() {
             return Err(RunError::BuildTimeNoCommandOrImage.into());
         }
+        let noise_gate = NoiseGate::new(&noise);
+        #[cfg(feature = "plus")]
+        if noise_gate.is_some() && job.is_some() {
+            return Err(RunError::NoiseGateWithImage.into());
+        }
         let sub_adapter: SubAdapter = (&cmd).into();
         #[cfg(feature = "plus")]
         let runner = if job.is_some() {
@@ -194,8 +224,12 @@ impl TryFrom<CliRun> for Run {
             average: average.map(Into::into),
             iter,
             fold: fold.map(Into::into),
+            bootstrap,
+            statistic: statistic.map(Into::into),
+            params,
             backdate,
             allow_failure,
+            noise_gate,
             thresholds: thresholds.try_into().map_err(RunError::Thresholds)?,
             error_on_alert,
             format: format.into(),
@@ -227,6 +261,13 @@ impl Run {
             cli_eprintln_quietable!(self.log, "Warning: {mismatch}");
         }
 
+        if let Some(warning) = self
+            .statistic
+            .and_then(|statistic| statistic_warning(self.adapter, statistic))
+        {
+            cli_eprintln_quietable!(self.log, "{warning}");
+        }
+
         if let Some(ci) = &self.ci {
             ci.safety_check(self.log)?;
         }
@@ -330,6 +371,11 @@ impl Run {
 
     async fn generate_local_report(&self) -> Result<Option<JsonNewRun>, RunError> {
         let runner = self.runner.as_ref().ok_or(RunError::NoRunner)?;
+        let noise = if let Some(noise_gate) = &self.noise_gate {
+            Some(noise_gate.probe(self.log).await?)
+        } else {
+            None
+        };
         let start_time = DateTime::now();
         let iter = self.iter.as_usize();
         let mut results = Vec::with_capacity(iter);
@@ -380,7 +426,11 @@ impl Run {
                 adapter: Some(self.adapter),
                 average: self.average,
                 fold: self.fold,
+                bootstrap: self.bootstrap.then_some(true),
+                statistic: self.statistic,
+                params: self.params.then_some(true),
             }),
+            noise,
             context: Some(RunContext::current().into()),
             job: None,
         }))
@@ -414,7 +464,11 @@ impl Run {
                 adapter: Some(self.adapter),
                 average: self.average,
                 fold: self.fold,
+                bootstrap: self.bootstrap.then_some(true),
+                statistic: self.statistic,
+                params: self.params.then_some(true),
             }),
+            noise: None,
             context: Some(RunContext::current().into()),
             job: Some(JsonNewRunJob {
                 image: job.image.clone().into(),
@@ -427,6 +481,14 @@ impl Run {
                 build_time: job.build_time.then_some(true),
                 file_size: file_size.then_some(true),
                 iter: Some(self.iter.into()),
+                warmup: job.warmup.map(Into::into),
+                trim: job
+                    .trim
+                    .as_ref()
+                    .map(|trim| bencher_client::types::JsonIterationTrim {
+                        measure: trim.measure.clone().into(),
+                        count: trim.count,
+                    }),
                 allow_failure: self.allow_failure.then_some(true),
                 backdate: self.backdate.map(Into::into),
             }),
@@ -653,6 +715,7 @@ impl Run {
             Format::Human => report_comment.human(),
             Format::Json => report_comment.json().map_err(RunError::SerializeReport)?,
             Format::Html => report_comment.html(false, None),
+            Format::Junit => report_comment.junit(),
         };
         let newline_prefix = if self.log { "\n" } else { "" };
         cli_println!("{newline_prefix}{report_str}");
@@ -770,4 +833,113 @@ mod tests {
             );
         }
     }
+
+    #[cfg(feature = "plus")]
+    mod job {
+        use clap::Parser as _;
+
+        use super::super::Run;
+        use crate::CliError;
+        use crate::bencher::sub::RunError;
+        use crate::parser::run::CliRun;
+
+        fn try_parse_run(args: &[&str]) -> Result<CliRun, clap::Error> {
+            CliRun::try_parse_from(
+                ["run", "--project", "my-project", "--image", "my-project:v1"]
+                    .into_iter()
+                    .chain(args.iter().copied()),
+            )
+        }
+
+        #[test]
+        fn warmup_and_trim() {
+            let cli_run = try_parse_run(&[
+                "--iter",
+                "5",
+                "--warmup",
+                "2",
+                "--trim",
+                "1",
+                "--trim-measure",
+                "latency",
+            ])
+            .unwrap();
+            let run = Run::try_from(cli_run).unwrap();
+            let job = run.job.unwrap();
+            assert_eq!(job.warmup.map(u32::from), Some(2));
+            let trim = job.trim.unwrap();
+            assert_eq!(trim.count, 1);
+            assert_eq!(trim.measure.to_string(), "latency");
+        }
+
+        #[test]
+        fn trim_requires_measure() {
+            try_parse_run(&["--iter", "3", "--trim", "1"]).unwrap_err();
+            try_parse_run(&["--trim-measure", "latency"]).unwrap_err();
+        }
+
+        #[test]
+        fn noise_gate_errors() {
+            let cli_run = try_parse_run(&["--noise-gate", "40"]).unwrap();
+            let result = Run::try_from(cli_run);
+            assert!(
+                matches!(result, Err(CliError::Run(RunError::NoiseGateWithImage))),
+                "{result:?}"
+            );
+        }
+    }
+
+    mod noise_gate {
+        use clap::Parser as _;
+
+        use super::super::Run;
+        use crate::parser::run::CliRun;
+
+        fn try_parse_run(args: &[&str]) -> Result<CliRun, clap::Error> {
+            CliRun::try_parse_from(
+                ["run", "--project", "my-project"]
+                    .into_iter()
+                    .chain(args.iter().copied())
+                    .chain(["bencher", "mock"]),
+            )
+        }
+
+        #[test]
+        fn without_noise_gate() {
+            let run = Run::try_from(try_parse_run(&[]).unwrap()).unwrap();
+            assert!(run.noise_gate.is_none());
+        }
+
+        #[test]
+        fn with_noise_gate() {
+            let cli_run = try_parse_run(&[
+                "--noise-gate",
+                "40",
+                "--noise-retries",
+                "0",
+                "--noise-policy",
+                "fail",
+            ])
+            .unwrap();
+            let run = Run::try_from(cli_run).unwrap();
+            assert!(run.noise_gate.is_some());
+        }
+
+        #[test]
+        fn noise_gate_range() {
+            try_parse_run(&["--noise-gate", "0"]).unwrap();
+            try_parse_run(&["--noise-gate", "100"]).unwrap();
+            for max_score in ["-1", "100.5", "NaN", "inf", "-inf", "loud"] {
+                try_parse_run(&["--noise-gate", max_score]).unwrap_err();
+            }
+        }
+
+        #[test]
+        fn noise_options_require_noise_gate() {
+            try_parse_run(&["--noise-duration", "1"]).unwrap_err();
+            try_parse_run(&["--noise-retries", "1"]).unwrap_err();
+            try_parse_run(&["--noise-retry-after", "1"]).unwrap_err();
+            try_parse_run(&["--noise-policy", "fail"]).unwrap_err();
+        }
+    }
 }
@@ -0,0 +1,113 @@
+use std::time::Duration;
+
+use bencher_client::types::JsonReportNoise;
+use bencher_noise::NoiseProbe;
+
+use crate::{
+    cli_eprintln_quietable,
+    parser::run::{CliRunNoise, CliRunNoisePolicy},
+};
+
+use super::RunError;
+
+#[derive(Debug, Clone, Copy)]
+pub struct NoiseGate {
+    max_score: f64,
+    duration: u64,
+    retries: u32,
+    retry_after: u64,
+    policy: NoisePolicy,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum NoisePolicy {
+    Fail,
+    Flag,
+}
+
+impl NoiseGate {
+    pub fn new(noise: &CliRunNoise) -> Option<Self> {
+        let &CliRunNoise {
+            noise_gate,
+            noise_duration,
+            noise_retries,
+            noise_retry_after,
+            noise_policy,
+        } = noise;
+        Some(Self {
+            max_score: noise_gate?,
+            duration: noise_duration,
+            retries: noise_retries,
+            retry_after: noise_retry_after,
+            policy: match noise_policy {
+                CliRunNoisePolicy::Fail => NoisePolicy::Fail,
+                CliRunNoisePolicy::Flag => NoisePolicy::Flag,
+            },
+        })
+    }
+
+    /// Probe the host noise, retrying with exponential backoff while the host is too noisy.
+    pub async fn probe(&self, log: bool) -> Result<JsonReportNoise, RunError> {
+        let mut retry_after = self.retry_after;
+        let mut attempt = 0;
+        let probe = loop {
+            let probe = self.probe_once(log).await?;
+            if probe.score <= self.max_score {
+                cli_eprintln_quietable!(
+                    log,
+                    "Noise score ({:.1} dB) is within the noise gate ({:.1} dB)",
+                    probe.score,
+                    self.max_score
+                );
+                return noise_json(&probe, false);
+            }
+            if attempt >= self.retries {
+                break probe;
+            }
+            attempt += 1;
+            cli_eprintln_quietable!(
+                log,
+                "Noise score ({:.1} dB) exceeds the noise gate ({:.1} dB). Retrying in {retry_after} seconds ({attempt}/{})...",
+                probe.score,
+                self.max_score,
+                self.retries
+            );
+            tokio::time::sleep(Duration::from_secs(retry_after)).await;
+            retry_after = retry_after.saturating_mul(2);
+        };
+
+        match self.policy {
+            NoisePolicy::Fail => Err(RunError::NoisyHost {
+                score: probe.score,
+                max_score: self.max_score,
+            }),
+            NoisePolicy::Flag => {
+                cli_eprintln_quietable!(
+                    log,
+                    "Warning: Noise score ({:.1} dB) exceeds the noise gate ({:.1} dB). The report will be flagged as noisy.",
+                    probe.score,
+                    self.max_score
+                );
+                noise_json(&probe, true)
+            },
+        }
+    }
+
+    /// Probe the host noise once, off of the async runtime, as the probe blocks for its whole duration.
+    async fn probe_once(&self, log: bool) -> Result<NoiseProbe, RunError> {
+        let duration = self.duration;
+        tokio::task::spawn_blocking(move || {
+            bencher_noise::probe_noise(duration, !log, &mut std::io::stderr())
+        })
+        .await
+        .map_err(RunError::NoiseJoinError)?
+        .map_err(RunError::Noise)
+    }
+}
+
+fn noise_json(probe: &NoiseProbe, noisy: bool) -> Result<JsonReportNoise, RunError> {
+    Ok(JsonReportNoise {
+        results: serde_json::to_string(&probe.results).map_err(RunError::SerializeNoise)?,
+        noisy: Some(noisy),
+    })
+}
@@ -1,6 +1,8 @@
 use std::fmt;
 
-use camino::Utf8PathBuf;
+use bencher_adapter::adapters::rust::criterion_json::{CriterionBenchmark, CriterionJson};
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::de::DeserializeOwned;
 
 use crate::RunError;
 
@@ -33,9 +35,76 @@ impl FilePath {
     pub fn get_results(&self) -> Result<Vec<String>, RunError> {
         let mut results = Vec::new();
         for path in &self.0 {
-            let result = std::fs::read_to_string(path).map_err(RunError::OutputFileRead)?;
+            let result = if path.is_dir() {
+                criterion_results(path)?
+            } else {
+                std::fs::read_to_string(path).map_err(RunError::OutputFileRead)?
+            };
             results.push(result);
         }
         Ok(results)
     }
 }
+
+/// Bundle the `benchmark.json`, `estimates.json`, and `sample.json` files
+/// for every benchmark in a Criterion output directory (ex: `target/criterion`).
+fn criterion_results(path: &Utf8Path) -> Result<String, RunError> {
+    let mut criterion = Vec::new();
+    criterion_benchmarks(path, &mut criterion)?;
+    serde_json::to_string(&CriterionJson { criterion }).map_err(RunError::SerializeCriterion)
+}
+
+fn criterion_benchmarks(
+    path: &Utf8Path,
+    benchmarks: &mut Vec<CriterionBenchmark>,
+) -> Result<(), RunError> {
+    // Criterion writes the results of the latest run to `<benchmark>/new/`.
+    if path.file_name() == Some("new") {
+        if let Some(benchmark) = read_criterion_file(&path.join("benchmark.json"))? {
+            benchmarks.push(CriterionBenchmark {
+                benchmark,
+                estimates: read_criterion_file(&path.join("estimates.json"))?,
+                sample: read_criterion_file(&path.join("sample.json"))?,
+            });
+        }
+        return Ok(());
+    }
+
+    let criterion_directory = |err| RunError::CriterionDirectory {
+        path: path.to_owned(),
+        err,
+    };
+    let mut entries = path
+        .read_dir_utf8()
+        .map_err(criterion_directory)?
+        .map(|entry| entry.map(camino::Utf8DirEntry::into_path))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(criterion_directory)?;
+    // Keep the benchmark order stable across platforms.
+    entries.sort();
+    for entry in entries {
+        if entry.is_dir() {
+            criterion_benchmarks(&entry, benchmarks)?;
+        }
+    }
+    Ok(())
+}
+
+fn read_criterion_file<T>(path: &Utf8Path) -> Result<Option<T>, RunError>
+where
+    T: DeserializeOwned,
+{
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let contents = std::fs::read_to_string(path).map_err(|err| RunError::CriterionDirectory {
+        path: path.to_owned(),
+        err,
+    })?;
+    serde_json::from_str(&contents)
+        .map(Some)
+        .map_err(|err| RunError::CriterionFile {
+            path: path.to_owned(),
+            err,
+        })
+}
@@ -1,3 +1,5 @@
+use std::num::NonZeroU32;
+
 use bencher_client::types::{JsonBackup, JsonDataStore};
 
 use crate::{
@@ -11,6 +13,7 @@ pub struct Backup {
     pub compress: Option<bool>,
     pub data_store: Option<JsonDataStore>,
     pub remove: Option<bool>,
+    pub keep: Option<NonZeroU32>,
     pub backend: AuthBackend,
 }
 
@@ -22,12 +25,14 @@ impl TryFrom<CliBackup> for Backup {
             compress,
             data_store,
             remove,
+            keep,
             backend,
         } = create;
         Ok(Self {
             compress: Some(compress),
             data_store: data_store.map(Into::into),
             remove: Some(remove),
+            keep,
             backend: backend.try_into()?,
         })
     }
@@ -37,6 +42,8 @@ impl From<CliBackupDataStore> for JsonDataStore {
     fn from(data_store: CliBackupDataStore) -> Self {
         match data_store {
             CliBackupDataStore::AwsS3 => Self::AwsS3,
+            CliBackupDataStore::S3 => Self::S3,
+            CliBackupDataStore::Local => Self::Local,
         }
     }
 }
@@ -47,12 +54,14 @@ impl From<Backup> for JsonBackup {
             compress,
             data_store,
             remove,
+            keep,
             ..
         } = backup;
         Self {
             compress,
             data_store,
             remove,
+            keep,
         }
     }
 }
@@ -2,6 +2,8 @@ use crate::{CliError, bencher::sub::SubCmd, parser::system::server::CliServer};
 
 mod backup;
 mod config;
+mod registry;
+mod restore;
 mod spec;
 mod stats;
 mod version;
@@ -12,8 +14,11 @@ pub enum Server {
     Spec(spec::OpenApiSpec),
     Config(config::Config),
     Backup(backup::Backup),
+    Restore(restore::Restore),
     #[cfg(feature = "plus")]
     Stats(stats::ServerStats),
+    #[cfg(feature = "plus")]
+    Registry(registry::Registry),
 }
 
 impl TryFrom<CliServer> for Server {
@@ -25,8 +30,11 @@ impl TryFrom<CliServer> for Server {
             CliServer::Spec(spec) => Self::Spec(spec.try_into()?),
             CliServer::Config(config) => Self::Config(config.try_into()?),
             CliServer::Backup(backup) => Self::Backup(backup.try_into()?),
+            CliServer::Restore(restore) => Self::Restore(restore.try_into()?),
             #[cfg(feature = "plus")]
             CliServer::Stats(stats) => Self::Stats(stats.try_into()?),
+            #[cfg(feature = "plus")]
+            CliServer::Registry(registry) => Self::Registry(registry.try_into()?),
         })
     }
 }
@@ -38,8 +46,11 @@ impl SubCmd for Server {
             Self::Spec(spec) => spec.exec().await,
             Self::Config(config) => config.exec().await,
             Self::Backup(backup) => backup.exec().await,
+            Self::Restore(restore) => restore.exec().await,
             #[cfg(feature = "plus")]
             Self::Stats(stats) => stats.exec().await,
+            #[cfg(feature = "plus")]
+            Self::Registry(registry) => registry.exec().await,
         }
     }
 }
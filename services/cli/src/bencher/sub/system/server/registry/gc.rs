@@ -0,0 +1,50 @@
+use bencher_client::types::JsonRegistryGc;
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::system::server::CliRegistryGc,
+};
+
+#[derive(Debug, Clone)]
+pub struct Gc {
+    pub dry_run: bool,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliRegistryGc> for Gc {
+    type Error = CliError;
+
+    fn try_from(gc: CliRegistryGc) -> Result<Self, Self::Error> {
+        let CliRegistryGc { dry_run, backend } = gc;
+        Ok(Self {
+            dry_run,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Gc> for JsonRegistryGc {
+    fn from(gc: Gc) -> Self {
+        let Gc { dry_run, .. } = gc;
+        Self {
+            dry_run: Some(dry_run),
+        }
+    }
+}
+
+impl SubCmd for Gc {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .server_registry_gc_post()
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
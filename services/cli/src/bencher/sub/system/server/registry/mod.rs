@@ -0,0 +1,28 @@
+#![cfg(feature = "plus")]
+
+use crate::{CliError, bencher::sub::SubCmd, parser::system::server::CliRegistry};
+
+mod gc;
+
+#[derive(Debug)]
+pub enum Registry {
+    Gc(gc::Gc),
+}
+
+impl TryFrom<CliRegistry> for Registry {
+    type Error = CliError;
+
+    fn try_from(registry: CliRegistry) -> Result<Self, Self::Error> {
+        Ok(match registry {
+            CliRegistry::Gc(gc) => Self::Gc(gc.try_into()?),
+        })
+    }
+}
+
+impl SubCmd for Registry {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::Gc(gc) => gc.exec().await,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use bencher_client::types::{JsonDataStore, JsonRestore};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::system::server::CliRestore,
+};
+
+#[derive(Debug, Clone)]
+pub struct Restore {
+    pub file_name: String,
+    pub data_store: Option<JsonDataStore>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliRestore> for Restore {
+    type Error = CliError;
+
+    fn try_from(restore: CliRestore) -> Result<Self, Self::Error> {
+        let CliRestore {
+            file_name,
+            data_store,
+            backend,
+        } = restore;
+        Ok(Self {
+            file_name,
+            data_store: data_store.map(Into::into),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Restore> for JsonRestore {
+    fn from(restore: Restore) -> Self {
+        let Restore {
+            file_name,
+            data_store,
+            ..
+        } = restore;
+        Self {
+            file_name,
+            data_store,
+        }
+    }
+}
+
+impl SubCmd for Restore {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json =
+            self.backend
+                .send(|client| async move {
+                    client.server_restore_post().body(self.clone()).send().await
+                })
+                .await?;
+        Ok(())
+    }
+}
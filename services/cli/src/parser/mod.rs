@@ -20,8 +20,8 @@ use organization::{CliOrganization, member::CliMember};
 use project::job::CliJob;
 use project::{
     CliProject, alert::CliAlert, archive::CliArchive, benchmark::CliBenchmark, branch::CliBranch,
-    measure::CliMeasure, metric::CliMetric, perf::CliPerf, plot::CliPlot, report::CliReport,
-    testbed::CliTestbed, threshold::CliThreshold,
+    compare::CliCompare, measure::CliMeasure, metric::CliMetric, perf::CliPerf, plot::CliPlot,
+    report::CliReport, testbed::CliTestbed, threshold::CliThreshold,
 };
 use run::CliRun;
 #[cfg(feature = "plus")]
@@ -88,7 +88,9 @@ pub enum CliSub {
     #[clap(subcommand)]
     Job(CliJob),
     /// Query benchmark data
-    Perf(CliPerf),
+    Perf(Box<CliPerf>),
+    /// Compare two branch heads
+    Compare(Box<CliCompare>),
     /// Manage plots
     #[clap(subcommand)]
     Plot(CliPlot),
@@ -52,6 +52,8 @@ pub enum CliAlertStatus {
     Active,
     /// Dismissed
     Dismissed,
+    /// Resolved
+    Resolved,
     /// Silenced
     Silenced,
 }
@@ -1,4 +1,6 @@
-use bencher_json::{BenchmarkName, BenchmarkResourceId, BenchmarkSlug, ProjectResourceId};
+use bencher_json::{
+    BenchmarkAliasUuid, BenchmarkName, BenchmarkResourceId, BenchmarkSlug, ProjectResourceId,
+};
 use clap::{Parser, Subcommand, ValueEnum};
 
 use crate::parser::{CliArchived, CliBackend, CliPagination};
@@ -20,6 +22,11 @@ pub enum CliBenchmark {
     /// Delete a benchmark
     #[clap(alias = "rm")]
     Delete(CliBenchmarkDelete),
+    /// Merge other benchmarks into a benchmark
+    Merge(CliBenchmarkMerge),
+    /// Manage benchmark aliases
+    #[clap(subcommand)]
+    Alias(CliBenchmarkAlias),
 }
 
 #[derive(Parser, Debug)]
@@ -116,3 +123,75 @@ pub struct CliBenchmarkDelete {
     #[clap(flatten)]
     pub backend: CliBackend,
 }
+
+#[derive(Parser, Debug)]
+pub struct CliBenchmarkMerge {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// Benchmark slug or UUID to merge into
+    pub benchmark: BenchmarkResourceId,
+
+    /// Benchmark slug or UUID to merge from (can be specified multiple times)
+    #[clap(long, required = true)]
+    pub from: Vec<BenchmarkResourceId>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum CliBenchmarkAlias {
+    /// List benchmark aliases
+    #[clap(alias = "ls")]
+    List(CliBenchmarkAliasList),
+    /// Create a benchmark alias
+    #[clap(alias = "add")]
+    Create(CliBenchmarkAliasCreate),
+    /// Delete a benchmark alias
+    #[clap(alias = "rm")]
+    Delete(CliBenchmarkAliasDelete),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliBenchmarkAliasList {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// Benchmark slug or UUID
+    pub benchmark: BenchmarkResourceId,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliBenchmarkAliasCreate {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// Benchmark slug or UUID
+    pub benchmark: BenchmarkResourceId,
+
+    /// Benchmark alias name
+    #[clap(long)]
+    pub name: BenchmarkName,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliBenchmarkAliasDelete {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// Benchmark slug or UUID
+    pub benchmark: BenchmarkResourceId,
+
+    /// Benchmark alias UUID
+    pub alias: BenchmarkAliasUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
@@ -0,0 +1,109 @@
+#[cfg(feature = "plus")]
+use bencher_json::SpecUuid;
+use bencher_json::{BranchNameId, GitHash, HeadUuid, ProjectResourceId, SampleSize, TestbedNameId};
+use clap::Parser;
+
+use crate::parser::{CliBackend, project::perf::CliPerfTableStyle};
+
+#[derive(Parser, Debug)]
+#[expect(
+    clippy::option_option,
+    reason = "None = not specified, Some(None) = explicitly unset"
+)]
+pub struct CliCompare {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// Baseline branch UUID, slug, or name
+    #[clap(long, value_name = "BRANCH")]
+    pub baseline_branch: BranchNameId,
+
+    /// Baseline branch head UUID (default: current branch head)
+    #[clap(long, value_name = "HEAD")]
+    pub baseline_head: Option<HeadUuid>,
+
+    /// Only compare baseline reports for this git hash
+    #[clap(long, value_name = "HASH")]
+    pub baseline_hash: Option<GitHash>,
+
+    /// Candidate branch UUID, slug, or name
+    #[clap(long, value_name = "BRANCH")]
+    pub candidate_branch: BranchNameId,
+
+    /// Candidate branch head UUID (default: current branch head)
+    #[clap(long, value_name = "HEAD")]
+    pub candidate_head: Option<HeadUuid>,
+
+    /// Only compare candidate reports for this git hash
+    #[clap(long, value_name = "HASH")]
+    pub candidate_hash: Option<GitHash>,
+
+    /// Testbed UUID, slug, or name
+    #[clap(long, value_name = "TESTBED")]
+    pub testbed: TestbedNameId,
+
+    /// Testbed spec UUID (default: current testbed spec)
+    #[cfg(feature = "plus")]
+    #[clap(long, value_name = "SPEC")]
+    pub spec: Option<SpecUuid>,
+
+    /// Number of most recent reports to compare for each side (default: 10)
+    #[clap(long, value_name = "COUNT")]
+    pub reports: Option<SampleSize>,
+
+    /// Output results in a table
+    #[clap(long)]
+    pub table: Option<Option<CliPerfTableStyle>>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser as _;
+
+    use crate::parser::{CliBencher, CliSub};
+
+    fn parse_compare(args: &[&str]) -> Option<super::CliCompare> {
+        let bencher = CliBencher::try_parse_from(["bencher", "compare"].iter().chain(args)).ok()?;
+        let CliSub::Compare(compare) = bencher.sub else {
+            return None;
+        };
+        Some(*compare)
+    }
+
+    #[test]
+    fn compare() {
+        let compare = parse_compare(&[
+            "my-project",
+            "--baseline-branch",
+            "main",
+            "--candidate-branch",
+            "feature",
+            "--testbed",
+            "localhost",
+            "--reports",
+            "5",
+        ])
+        .unwrap();
+        assert_eq!(compare.baseline_branch.to_string(), "main");
+        assert_eq!(compare.candidate_branch.to_string(), "feature");
+        assert_eq!(compare.reports.map(u32::from), Some(5));
+        assert!(compare.table.is_none());
+    }
+
+    #[test]
+    fn compare_missing_candidate() {
+        assert!(
+            parse_compare(&[
+                "my-project",
+                "--baseline-branch",
+                "main",
+                "--testbed",
+                "localhost",
+            ])
+            .is_none()
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use bencher_json::{Email, ProjectResourceId, UserName, UserResourceId};
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::parser::{CliBackend, CliPagination};
+
+#[derive(Subcommand, Debug)]
+pub enum CliProjectMember {
+    /// List project members
+    #[clap(alias = "ls")]
+    List(CliProjectMemberList),
+    /// Add a project member
+    Add(CliProjectMemberAdd),
+    /// View a project member
+    #[clap(alias = "get")]
+    View(CliProjectMemberView),
+    /// Update a project member
+    #[clap(alias = "edit")]
+    Update(CliProjectMemberUpdate),
+    /// Remove a project member
+    #[clap(alias = "rm")]
+    Remove(CliProjectMemberRemove),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectMemberList {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// Member name
+    #[clap(long)]
+    pub name: Option<UserName>,
+
+    /// Member search string
+    #[clap(long, value_name = "QUERY")]
+    pub search: Option<String>,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliProjectMembersSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliProjectMembersSort {
+    /// Name of the member
+    Name,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectMemberAdd {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// Email of an existing user
+    #[clap(long)]
+    pub email: Email,
+
+    /// Member role
+    #[clap(value_enum, long)]
+    pub role: CliProjectMemberRole,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectMemberView {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// User slug or UUID
+    pub user: UserResourceId,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectMemberUpdate {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// User slug or UUID
+    pub user: UserResourceId,
+
+    /// Member role
+    #[clap(value_enum, long)]
+    pub role: Option<CliProjectMemberRole>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectMemberRemove {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// User slug or UUID
+    pub user: UserResourceId,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+/// Role within the project
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum CliProjectMemberRole {
+    /// Read-only access
+    Viewer,
+    /// Create, edit, and delete project resources
+    Developer,
+    /// Manage project settings and members
+    Maintainer,
+}
@@ -9,15 +9,19 @@ pub mod alert;
 pub mod archive;
 pub mod benchmark;
 pub mod branch;
+pub mod compare;
 pub mod job;
 pub mod key;
 pub mod measure;
+pub mod member;
 pub mod metric;
 pub mod perf;
 pub mod plot;
 pub mod report;
+pub mod retention;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
 
 #[derive(Subcommand, Debug)]
 pub enum CliProject {
@@ -40,6 +44,15 @@ pub enum CliProject {
     /// Manage project API keys
     #[clap(subcommand)]
     Key(key::CliProjectKey),
+    /// Manage project members
+    #[clap(subcommand)]
+    Member(member::CliProjectMember),
+    /// Manage project webhooks
+    #[clap(subcommand)]
+    Webhook(webhook::CliProjectWebhook),
+    /// Manage the project retention policy
+    #[clap(subcommand)]
+    Retention(retention::CliProjectRetention),
 }
 
 #[derive(Parser, Debug)]
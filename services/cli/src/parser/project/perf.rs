@@ -1,20 +1,26 @@
 #[cfg(feature = "plus")]
 use bencher_json::SpecUuid;
 use bencher_json::{
-    BenchmarkUuid, BranchUuid, DateTime, HeadUuid, MeasureUuid, ProjectResourceId, TestbedUuid,
+    BenchmarkUuid, BranchUuid, DateTime, HeadUuid, MeasureUuid, ProjectResourceId, SampleSize,
+    TestbedUuid,
 };
-use clap::{Parser, ValueEnum};
+use clap::{Parser, Subcommand, ValueEnum};
 
 use crate::parser::{CliBackend, ElidedOption};
 
 #[derive(Parser, Debug)]
+#[clap(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 #[expect(
     clippy::option_option,
     reason = "None = not specified, Some(None) = explicitly unset"
 )]
 pub struct CliPerf {
+    #[clap(subcommand)]
+    pub sub: Option<CliPerfSub>,
+
     /// Project slug or UUID
-    pub project: ProjectResourceId,
+    #[clap(required = true)]
+    pub project: Option<ProjectResourceId>,
 
     /// Branch UUIDs
     #[clap(long, required = true, value_name = "BRANCH")]
@@ -51,6 +57,10 @@ pub struct CliPerf {
     #[clap(long, value_name = "SECONDS")]
     pub end_time: Option<DateTime>,
 
+    /// Number of most recent branch versions
+    #[clap(long, value_name = "VERSIONS")]
+    pub versions: Option<SampleSize>,
+
     /// Output results in a table
     #[clap(long)]
     pub table: Option<Option<CliPerfTableStyle>>,
@@ -59,6 +69,64 @@ pub struct CliPerf {
     pub backend: CliBackend,
 }
 
+#[derive(Subcommand, Debug)]
+pub enum CliPerfSub {
+    /// Detect change points in benchmark data
+    ChangePoints(CliPerfChangePoints),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliPerfChangePoints {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// Branch UUIDs
+    #[clap(long, required = true, value_name = "BRANCH")]
+    pub branches: Vec<BranchUuid>,
+
+    /// Optional branch head UUIDs in the same order as `branches`.
+    /// Use an underscore (`_`) to specify the current branch head.
+    #[clap(long, required = false, value_name = "HEAD")]
+    pub heads: Vec<ElidedOption<HeadUuid>>,
+
+    /// Testbed UUIDs
+    #[clap(long, required = true, value_name = "TESTBED")]
+    pub testbeds: Vec<TestbedUuid>,
+
+    /// Optional testbed spec UUIDs in the same order as `testbeds`.
+    /// Use an underscore (`_`) to specify the current testbed spec.
+    #[cfg(feature = "plus")]
+    #[clap(long, required = false, value_name = "SPEC")]
+    pub specs: Vec<ElidedOption<SpecUuid>>,
+
+    /// Benchmark UUIDs
+    #[clap(long, required = true, value_name = "BENCHMARK")]
+    pub benchmarks: Vec<BenchmarkUuid>,
+
+    /// Measure UUIDs
+    #[clap(long, required = true, value_name = "MEASURE")]
+    pub measures: Vec<MeasureUuid>,
+
+    /// Start time (seconds since epoch)
+    #[clap(long, value_name = "SECONDS")]
+    pub start_time: Option<DateTime>,
+
+    /// End time (seconds since epoch)
+    #[clap(long, value_name = "SECONDS")]
+    pub end_time: Option<DateTime>,
+
+    /// Number of most recent branch versions
+    #[clap(long, value_name = "VERSIONS")]
+    pub versions: Option<SampleSize>,
+
+    /// Change point penalty multiplier, larger values detect fewer change points (default: 1.0)
+    #[clap(long)]
+    pub penalty: Option<f64>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
 /// Supported Table Formats
 #[derive(ValueEnum, Debug, Clone)]
 #[clap(rename_all = "snake_case")]
@@ -88,3 +156,70 @@ pub enum CliPerfTableStyle {
     /// Style using only ‘.’ and ‘:’ chars with vertical and horizontal split lines
     Dots,
 }
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser as _;
+
+    use super::CliPerfSub;
+    use crate::parser::{CliBencher, CliSub};
+
+    const PROJECT: &str = "my-project";
+    const UUID: &str = "00000000-0000-0000-0000-000000000000";
+
+    fn parse_perf(args: &[&str]) -> Option<super::CliPerf> {
+        let bencher = CliBencher::try_parse_from(["bencher", "perf"].iter().chain(args)).ok()?;
+        let CliSub::Perf(perf) = bencher.sub else {
+            return None;
+        };
+        Some(*perf)
+    }
+
+    #[test]
+    fn perf_query() {
+        let perf = parse_perf(&[
+            PROJECT,
+            "--branches",
+            UUID,
+            "--testbeds",
+            UUID,
+            "--benchmarks",
+            UUID,
+            "--measures",
+            UUID,
+        ])
+        .unwrap();
+        assert!(perf.sub.is_none());
+        assert_eq!(perf.project.unwrap().to_string(), PROJECT);
+    }
+
+    #[test]
+    fn perf_query_missing_project() {
+        assert!(parse_perf(&["--branches", UUID]).is_none());
+    }
+
+    #[test]
+    fn perf_change_points() {
+        let perf = parse_perf(&[
+            "change-points",
+            PROJECT,
+            "--branches",
+            UUID,
+            "--testbeds",
+            UUID,
+            "--benchmarks",
+            UUID,
+            "--measures",
+            UUID,
+            "--penalty",
+            "2.5",
+        ])
+        .unwrap();
+        assert!(perf.project.is_none());
+        let Some(CliPerfSub::ChangePoints(change_points)) = perf.sub else {
+            panic!("Expected change points subcommand");
+        };
+        assert_eq!(change_points.project.to_string(), PROJECT);
+        assert_eq!(change_points.penalty, Some(2.5));
+    }
+}
@@ -113,6 +113,20 @@ pub struct CliReportCreate {
     #[clap(value_enum, long)]
     pub fold: Option<CliReportFold>,
 
+    /// Use bootstrap confidence intervals for the lower and upper values
+    /// when there are raw samples or multiple results to fold
+    #[clap(long)]
+    pub bootstrap: bool,
+
+    /// Benchmark harness statistic to use for the value, lower value, and upper value
+    #[clap(value_enum, long)]
+    pub statistic: Option<CliReportStatistic>,
+
+    /// Name parameterized benchmarks with their parameters
+    /// so each set of parameters is a separate benchmark
+    #[clap(long)]
+    pub params: bool,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -154,6 +168,26 @@ pub struct CliReportThresholds {
     #[clap(long, requires = "threshold_test")]
     pub threshold_upper_boundary: Vec<ElidedOption<Boundary>>,
 
+    /// Number of outliers required to generate an alert
+    /// To ignore this option when specifying multiple Thresholds, use an underscore (`_`).
+    #[clap(long, requires = "threshold_test")]
+    pub threshold_confirm_outliers: Vec<ElidedOption<SampleSize>>,
+
+    /// Number of most recent reports in which to look for outliers
+    /// To ignore this option when specifying multiple Thresholds, use an underscore (`_`).
+    #[clap(long, requires = "threshold_confirm_outliers")]
+    pub threshold_confirm_reports: Vec<ElidedOption<SampleSize>>,
+
+    /// Number of consecutive reports within the boundary required to resolve an alert
+    /// To ignore this option when specifying multiple Thresholds, use an underscore (`_`).
+    #[clap(long, requires = "threshold_test")]
+    pub threshold_resolve_reports: Vec<ElidedOption<SampleSize>>,
+
+    /// Window size (most recent branch versions)
+    /// To ignore this option when specifying multiple Thresholds, use an underscore (`_`).
+    #[clap(long, requires = "threshold_test")]
+    pub threshold_version_window: Vec<ElidedOption<SampleSize>>,
+
     /// Reset all unspecified Thresholds for the `branch` and `testbed`
     /// If a Threshold already exists and is not specified, its current Model will be removed.
     #[clap(long)]
@@ -196,6 +230,8 @@ pub enum CliReportAdapter {
     RustBench,
     /// 🦀 Rust Criterion
     RustCriterion,
+    /// 🦀 Rust Criterion JSON
+    RustCriterionJson,
     /// 🦀 Rust Iai
     RustIai,
     /// 🦀 Rust Gungraun
@@ -217,6 +253,26 @@ pub enum CliReportAverage {
     Median,
 }
 
+/// Benchmark Harness Statistic
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliReportStatistic {
+    /// Mean and standard deviation
+    Mean,
+    /// Median and interquartile range
+    Median,
+    /// Minimum and maximum
+    Min,
+    /// Median and first and third quartiles
+    Iqr,
+    /// 90th percentile and the minimum and maximum
+    P90,
+    /// 95th percentile and the minimum and maximum
+    P95,
+    /// Operations per second and the slowest and fastest rounds
+    Ops,
+}
+
 /// Supported Fold Operations
 #[derive(ValueEnum, Debug, Clone)]
 #[clap(rename_all = "snake_case")]
@@ -0,0 +1,55 @@
+use bencher_json::{BranchResourceId, ProjectResourceId};
+use clap::{Parser, Subcommand};
+
+use crate::parser::CliBackend;
+
+#[derive(Subcommand, Debug)]
+pub enum CliProjectRetention {
+    /// View the project retention policy
+    #[clap(alias = "get")]
+    View(CliProjectRetentionView),
+    /// Replace the project retention policy
+    #[clap(alias = "edit")]
+    Update(CliProjectRetentionUpdate),
+    /// Report what the project retention policy would delete
+    DryRun(CliProjectRetentionDryRun),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectRetentionView {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectRetentionUpdate {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// Delete reports older than this many days (unset to keep all reports)
+    #[clap(long, value_name = "DAYS", requires = "default_branch")]
+    pub report_days: Option<u32>,
+
+    /// Branch slug or UUID whose reports are never deleted
+    #[clap(long, value_name = "BRANCH")]
+    pub default_branch: Option<BranchResourceId>,
+
+    /// Downsample metrics older than this many days to their daily median (unset to keep all metrics)
+    #[clap(long, value_name = "DAYS")]
+    pub downsample_days: Option<u32>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectRetentionDryRun {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
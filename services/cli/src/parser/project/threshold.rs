@@ -111,6 +111,22 @@ pub struct CliModel {
     /// Upper boundary
     #[clap(long, value_name = "BOUNDARY")]
     pub upper_boundary: Option<Boundary>,
+
+    /// Number of outliers required to generate an alert
+    #[clap(long, value_name = "COUNT")]
+    pub confirm_outliers: Option<SampleSize>,
+
+    /// Number of most recent reports in which to look for outliers
+    #[clap(long, requires = "confirm_outliers", value_name = "COUNT")]
+    pub confirm_reports: Option<SampleSize>,
+
+    /// Number of consecutive reports within the boundary required to resolve an alert
+    #[clap(long, value_name = "COUNT")]
+    pub resolve_reports: Option<SampleSize>,
+
+    /// Window size (most recent branch versions)
+    #[clap(long, conflicts_with = "window", value_name = "VERSIONS")]
+    pub version_window: Option<SampleSize>,
 }
 
 /// Supported threshold model tests
@@ -133,6 +149,8 @@ pub enum CliModelTest {
     Iqr,
     /// Delta interquartile range (ΔIQR)
     DeltaIqr,
+    /// Mann-Whitney U test (rank-based)
+    MannWhitney,
 }
 
 #[derive(Parser, Debug)]
@@ -198,6 +216,27 @@ pub struct CliUpdateModel {
     #[clap(long, requires = "test", value_name = "BOUNDARY")]
     pub upper_boundary: Option<Boundary>,
 
+    /// Number of outliers required to generate an alert
+    #[clap(long, requires = "test", value_name = "COUNT")]
+    pub confirm_outliers: Option<SampleSize>,
+
+    /// Number of most recent reports in which to look for outliers
+    #[clap(long, requires = "confirm_outliers", value_name = "COUNT")]
+    pub confirm_reports: Option<SampleSize>,
+
+    /// Number of consecutive reports within the boundary required to resolve an alert
+    #[clap(long, requires = "test", value_name = "COUNT")]
+    pub resolve_reports: Option<SampleSize>,
+
+    /// Window size (most recent branch versions)
+    #[clap(
+        long,
+        requires = "test",
+        conflicts_with = "window",
+        value_name = "VERSIONS"
+    )]
+    pub version_window: Option<SampleSize>,
+
     /// Remove the threshold model
     #[clap(long)]
     pub remove_model: bool,
@@ -0,0 +1,148 @@
+use bencher_json::{ProjectResourceId, Url, WebhookUuid};
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::parser::{CliBackend, CliPagination};
+
+#[derive(Subcommand, Debug)]
+pub enum CliProjectWebhook {
+    /// List project webhooks
+    #[clap(alias = "ls")]
+    List(CliProjectWebhookList),
+    /// Create a project webhook
+    #[clap(alias = "add")]
+    Create(CliProjectWebhookCreate),
+    /// View a project webhook
+    #[clap(alias = "get")]
+    View(CliProjectWebhookView),
+    /// Update a project webhook
+    #[clap(alias = "edit")]
+    Update(CliProjectWebhookUpdate),
+    /// Delete a project webhook
+    #[clap(alias = "rm")]
+    Delete(CliProjectWebhookDelete),
+    /// List the deliveries for a project webhook
+    Deliveries(CliProjectWebhookDeliveries),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectWebhookList {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliProjectWebhooksSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliProjectWebhooksSort {
+    /// Creation date time of the webhook
+    Created,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectWebhookCreate {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// Public `https` URL to send events to
+    #[clap(long)]
+    pub url: Url,
+
+    /// Event to send (may be used multiple times, defaults to all events)
+    #[clap(long = "event", value_enum)]
+    pub events: Option<Vec<CliWebhookEvent>>,
+
+    /// Create the webhook disabled
+    #[clap(long)]
+    pub disabled: bool,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum CliWebhookEvent {
+    /// An alert was created
+    #[value(name = "alert.created")]
+    AlertCreated,
+    /// An alert was dismissed
+    #[value(name = "alert.dismissed")]
+    AlertDismissed,
+    /// A report was created
+    #[value(name = "report.created")]
+    ReportCreated,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectWebhookView {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// Webhook UUID
+    pub uuid: WebhookUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectWebhookUpdate {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// Webhook UUID
+    pub uuid: WebhookUuid,
+
+    /// Public `https` URL to send events to
+    #[clap(long)]
+    pub url: Option<Url>,
+
+    /// Event to send (may be used multiple times, replaces the current events)
+    #[clap(long = "event", value_enum)]
+    pub events: Option<Vec<CliWebhookEvent>>,
+
+    /// Whether the webhook is enabled
+    #[clap(long, value_name = "BOOL")]
+    pub enabled: Option<bool>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectWebhookDelete {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// Webhook UUID
+    pub uuid: WebhookUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectWebhookDeliveries {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// Webhook UUID
+    pub uuid: WebhookUuid,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliProjectWebhookDeliveriesSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliProjectWebhookDeliveriesSort {
+    /// Creation date time of the delivery
+    Created,
+}
@@ -1,9 +1,9 @@
-#[cfg(feature = "plus")]
-use bencher_json::SpecResourceId;
 use bencher_json::{
     BranchNameId, DateTime, GitHash, ProjectResourceId, TestbedNameId, project::report::Iteration,
 };
 #[cfg(feature = "plus")]
+use bencher_json::{MeasureNameId, SpecResourceId};
+#[cfg(feature = "plus")]
 use bencher_parser::check_env;
 use camino::Utf8PathBuf;
 use clap::{ArgGroup, Args, Parser, ValueEnum};
@@ -11,7 +11,7 @@ use clap::{ArgGroup, Args, Parser, ValueEnum};
 use crate::parser::CliBackend;
 
 use super::project::report::{
-    CliReportAdapter, CliReportAverage, CliReportFold, CliReportThresholds,
+    CliReportAdapter, CliReportAverage, CliReportFold, CliReportStatistic, CliReportThresholds,
 };
 
 // `--key` requires `--project` only for project-scoped keys (`bencher_run_*`)
@@ -61,6 +61,20 @@ pub struct CliRun {
     #[clap(value_enum, long, requires = "iter", value_name = "AGGREGATE_FUNCTION")]
     pub fold: Option<CliReportFold>,
 
+    /// Use bootstrap confidence intervals for the lower and upper values
+    /// when there are raw samples or multiple iterations to fold
+    #[clap(long)]
+    pub bootstrap: bool,
+
+    /// Benchmark harness statistic to use for the value, lower value, and upper value
+    #[clap(value_enum, long)]
+    pub statistic: Option<CliReportStatistic>,
+
+    /// Name parameterized benchmarks with their parameters
+    /// so each set of parameters is a separate benchmark
+    #[clap(long)]
+    pub params: bool,
+
     /// Backdate the report (seconds since epoch)
     /// NOTE: This will NOT effect the ordering of past reports
     #[clap(long, value_name = "SECONDS")]
@@ -70,6 +84,9 @@ pub struct CliRun {
     #[clap(long)]
     pub allow_failure: bool,
 
+    #[clap(flatten)]
+    pub noise: CliRunNoise,
+
     #[clap(flatten)]
     pub thresholds: CliReportThresholds,
 
@@ -164,6 +181,7 @@ pub struct CliRunCommand {
     pub build_time: bool,
 
     /// Benchmark command output file path
+    /// or a Criterion output directory (ex: `target/criterion`)
     #[clap(long, conflicts_with = "file_size")]
     pub file: Option<Vec<Utf8PathBuf>>,
 
@@ -209,6 +227,70 @@ pub struct CliRunShell {
     pub flag: Option<String>,
 }
 
+#[derive(Args, Debug)]
+#[expect(
+    clippy::struct_field_names,
+    reason = "noise_ prefix matches CLI flag names"
+)]
+pub struct CliRunNoise {
+    /// Run a host noise probe before the benchmarks and gate on its composite noise score (0-100 dB).
+    /// If the score is above this maximum, then the host is too noisy.
+    #[clap(long, value_name = "MAX_SCORE", value_parser = parse_noise_gate)]
+    pub noise_gate: Option<f64>,
+
+    /// Noise probe duration (requires: `--noise-gate`)
+    #[clap(
+        long,
+        value_name = "SECONDS",
+        requires = "noise_gate",
+        default_value = "5"
+    )]
+    pub noise_duration: u64,
+
+    /// Number of times to retry the noise probe if the host is too noisy (requires: `--noise-gate`)
+    #[clap(
+        long,
+        value_name = "COUNT",
+        requires = "noise_gate",
+        default_value = "2"
+    )]
+    pub noise_retries: u32,
+
+    /// Initial seconds to wait between noise probes (exponential backoff) (requires: `--noise-gate`)
+    #[clap(
+        long,
+        value_name = "SECONDS",
+        requires = "noise_gate",
+        default_value = "10"
+    )]
+    pub noise_retry_after: u64,
+
+    /// What to do if the host is still too noisy after all retries (requires: `--noise-gate`)
+    #[clap(value_enum, long, requires = "noise_gate", default_value = "flag")]
+    pub noise_policy: CliRunNoisePolicy,
+}
+
+fn parse_noise_gate(value: &str) -> Result<f64, String> {
+    let max_score = value.parse::<f64>().map_err(|e| e.to_string())?;
+    if (0.0..=100.0).contains(&max_score) {
+        Ok(max_score)
+    } else {
+        Err(format!(
+            "The noise gate must be a noise score between 0 and 100 dB, not {value}"
+        ))
+    }
+}
+
+/// Noise gate policy
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliRunNoisePolicy {
+    /// Do not run the benchmarks or submit a report
+    Fail,
+    /// Run the benchmarks and submit the report flagged as noisy
+    Flag,
+}
+
 #[derive(Args, Debug)]
 pub struct CliRunOutput {
     /// Format for the final Report
@@ -229,6 +311,8 @@ pub enum CliRunFormat {
     Json,
     /// HTML
     Html,
+    /// `JUnit` XML
+    Junit,
 }
 
 #[expect(
@@ -239,25 +323,28 @@ pub enum CliRunFormat {
 #[clap(group(
     ArgGroup::new("ci_cd")
         .multiple(false)
-        .args(&["github_actions"]),
+        .args(&["github_actions", "gitlab_token"]),
 ))]
 pub struct CliRunCi {
     /// GitHub API authentication token for GitHub Actions to create a GitHub Check and comment on PRs (ie `--github-actions ${{ secrets.GITHUB_TOKEN }}`)
     #[clap(long)]
     pub github_actions: Option<String>,
-    /// Only post results to CI if a Threshold exists for the Branch, Testbed, and Measure (requires: `--github-actions`)
+    /// GitLab API authentication token for GitLab CI/CD to comment on merge requests (ie `--gitlab-token $GITLAB_TOKEN`)
+    #[clap(long)]
+    pub gitlab_token: Option<String>,
+    /// Only post results to CI if a Threshold exists for the Branch, Testbed, and Measure (requires: `--github-actions` or `--gitlab-token`)
     #[clap(long, requires = "ci_cd")]
     pub ci_only_thresholds: bool,
-    /// Only start posting results to CI if an Alert is generated (requires: `--github-actions`)
+    /// Only start posting results to CI if an Alert is generated (requires: `--github-actions` or `--gitlab-token`)
     #[clap(long, requires = "ci_cd")]
     pub ci_only_on_alert: bool,
-    /// All links should be to public URLs that do not require a login (requires: `--github-actions`)
+    /// All links should be to public URLs that do not require a login (requires: `--github-actions` or `--gitlab-token`)
     #[clap(long, requires = "ci_cd")]
     pub ci_public_links: bool,
-    /// Custom ID for posting results to CI, used in place of the Project name in the GitHub Check name (ie `Bencher Report (<ID>)`) (requires: `--github-actions`)
+    /// Custom ID for posting results to CI, used in place of the Project name in the GitHub Check name (ie `Bencher Report (<ID>)`) (requires: `--github-actions` or `--gitlab-token`)
     #[clap(long, requires = "ci_cd")]
     pub ci_id: Option<String>,
-    /// Issue number for posting results to CI (requires: `--github-actions`)
+    /// Issue or merge request number for posting results to CI (requires: `--github-actions` or `--gitlab-token`)
     #[clap(long, requires = "ci_cd")]
     pub ci_number: Option<u64>,
     /// CAUTION: Override safety checks and accept that you are vulnerable to pwn requests (requires: `--github-actions`)
@@ -299,4 +386,16 @@ pub struct CliRunJob {
     /// Detach after submitting the remote job, without waiting for completion (requires: --image).
     #[clap(long, requires = "image", conflicts_with = "job_poll_interval")]
     pub detach: bool,
+
+    /// Number of warm-up iterations to execute and discard before the measured iterations (requires: --image)
+    #[clap(long, value_name = "COUNT", requires = "image")]
+    pub warmup: Option<Iteration>,
+
+    /// Number of outlier iterations to drop from each end, ranked by `--trim-measure` (requires: --image)
+    #[clap(long, value_name = "COUNT", requires_all = ["image", "trim_measure"])]
+    pub trim: Option<u32>,
+
+    /// Measure to rank iterations by when trimming outliers (requires: --trim)
+    #[clap(long, requires = "trim")]
+    pub trim_measure: Option<MeasureNameId>,
 }
@@ -1,3 +1,5 @@
+use std::num::NonZeroU32;
+
 use clap::{Parser, Subcommand, ValueEnum};
 
 use crate::parser::CliBackend;
@@ -13,9 +15,15 @@ pub enum CliServer {
     Config(CliConfig),
     /// Backup database
     Backup(CliBackup),
+    /// Restore database from a backup
+    Restore(CliRestore),
     #[cfg(feature = "plus")]
     /// Server usage statistics
     Stats(CliServerStats),
+    #[cfg(feature = "plus")]
+    /// Manage container registry
+    #[clap(subcommand)]
+    Registry(CliRegistry),
 }
 
 #[derive(Parser, Debug)]
@@ -64,16 +72,37 @@ pub struct CliBackup {
     #[clap(long, alias = "rm")]
     pub remove: bool,
 
+    /// Keep only this many of the most recent backups, pruning the rest
+    #[clap(long, value_name = "COUNT")]
+    pub keep: Option<NonZeroU32>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
 
-/// Supported Fold Operations
+/// Supported Data Stores
 #[derive(ValueEnum, Debug, Clone)]
 #[clap(rename_all = "snake_case")]
 pub enum CliBackupDataStore {
     /// AWS S3
     AwsS3,
+    /// S3-compatible object store
+    S3,
+    /// Local directory on the server
+    Local,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliRestore {
+    /// Backup file name, as created by `bencher server backup`
+    pub file_name: String,
+
+    /// Download the backup from data store
+    #[clap(long)]
+    pub data_store: Option<CliBackupDataStore>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
 }
 
 #[cfg(feature = "plus")]
@@ -82,3 +111,21 @@ pub struct CliServerStats {
     #[clap(flatten)]
     pub backend: CliBackend,
 }
+
+#[cfg(feature = "plus")]
+#[derive(Subcommand, Debug)]
+pub enum CliRegistry {
+    /// Remove unreferenced manifests, blobs, and stale uploads
+    Gc(CliRegistryGc),
+}
+
+#[cfg(feature = "plus")]
+#[derive(Parser, Debug)]
+pub struct CliRegistryGc {
+    /// Report what would be removed without removing anything
+    #[clap(long)]
+    pub dry_run: bool,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
@@ -23,6 +23,7 @@ export const adapterIcon = (adapter: Adapter) => {
 			return JSON_ICON;
 		case Adapter.RustBench:
 		case Adapter.RustCriterion:
+		case Adapter.RustCriterionJson:
 		case Adapter.RustIai:
 		case Adapter.RustGungraun:
 		case Adapter.RustGungraunStdout:
@@ -68,6 +69,8 @@ export const adapterCommand = (isConsole: boolean, adapter: null | Adapter) => {
 		case Adapter.RustGungraunStdout:
 		case Adapter.RustGungraunJson:
 			return `bencher run${host} "cargo bench"`;
+		case Adapter.RustCriterionJson:
+			return `bencher run${host} --adapter rust_criterion_json --file target/criterion "cargo bench"`;
 		case Adapter.CppGoogle:
 			return `bencher run${host} "make benchmarks --benchmark_format=json"`;
 		case Adapter.CppCatch2:
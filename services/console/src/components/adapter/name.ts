@@ -9,6 +9,7 @@ export const adapterName = (adapter: Adapter): string => {
 		case Adapter.RustBench:
 			return "libtest bench";
 		case Adapter.RustCriterion:
+		case Adapter.RustCriterionJson:
 			return "Criterion";
 		case Adapter.RustIai:
 			return "Iai";
@@ -57,6 +58,8 @@ export const adapterCommand = (isConsole: boolean, adapter: null | Adapter) => {
 		case Adapter.RustGungraunStdout:
 		case Adapter.RustGungraunJson:
 			return `bencher run${host} "cargo bench"`;
+		case Adapter.RustCriterionJson:
+			return `bencher run${host} --adapter rust_criterion_json --file target/criterion "cargo bench"`;
 		case Adapter.CppGoogle:
 			return `bencher run${host} "make benchmarks --benchmark_format=json"`;
 		case Adapter.CppCatch2:
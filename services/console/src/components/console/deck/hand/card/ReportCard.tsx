@@ -734,6 +734,8 @@ const alertStatus = (alert: JsonAlert) => {
 	switch (alert.status) {
 		case AlertStatus.Active:
 			return "🔔";
+		case AlertStatus.Resolved:
+			return "✅";
 		case AlertStatus.Dismissed:
 		case AlertStatus.Silenced:
 			return "🔕";
@@ -459,6 +459,7 @@ const AdapterCard = (props: Props) => {
 					case Adapter.RustBench:
 						return "-rust-bench";
 					case Adapter.RustCriterion:
+					case Adapter.RustCriterionJson:
 						return "-rust-criterion";
 					case Adapter.RustIai:
 						return "-rust-iai";
@@ -510,6 +511,7 @@ const AdapterCard = (props: Props) => {
 						case Adapter.RustBench:
 							return "Rust libtest bench";
 						case Adapter.RustCriterion:
+						case Adapter.RustCriterionJson:
 							return "Rust Criterion";
 						case Adapter.RustIai:
 							return "Rust Iai";
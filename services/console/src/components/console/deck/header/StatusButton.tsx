@@ -26,6 +26,7 @@ const StatusButton = (props: Props) => {
 			case AlertStatus.Active:
 				return { status: AlertStatus.Dismissed };
 			case AlertStatus.Dismissed:
+			case AlertStatus.Resolved:
 			case AlertStatus.Silenced:
 				return { status: AlertStatus.Active };
 			default:
@@ -89,6 +90,7 @@ const StatusButton = (props: Props) => {
 			<Match
 				when={
 					props.data()?.status === AlertStatus.Dismissed ||
+					props.data()?.status === AlertStatus.Resolved ||
 					props.data()?.status === AlertStatus.Silenced
 				}
 			>
@@ -21,6 +21,7 @@ export const AlertRow = (props: { alert: JsonAlert }) => {
 				<Match
 					when={
 						props.alert?.status === AlertStatus.Dismissed ||
+						props.alert?.status === AlertStatus.Resolved ||
 						props.alert?.status === AlertStatus.Silenced
 					}
 				>
@@ -58,6 +59,8 @@ export const fmtAlertStatus = (status: AlertStatus) => {
 			return "Active";
 		case AlertStatus.Dismissed:
 			return "Dismissed";
+		case AlertStatus.Resolved:
+			return "Resolved";
 		case AlertStatus.Silenced:
 			return "Silenced";
 		default:
@@ -133,6 +133,8 @@ export const fmtModelTest = (test: ModelTest) => {
 			return "Interquartile Range (IQR)";
 		case ModelTest.DeltaIqr:
 			return "Delta Interquartile Range (ΔIQR)";
+		case ModelTest.MannWhitney:
+			return "Mann-Whitney U";
 		default:
 			return "No Model";
 	}
@@ -166,6 +168,10 @@ const testValue = (selected: ModelTest) => {
 				value: ModelTest.DeltaIqr,
 				option: fmtModelTest(ModelTest.DeltaIqr),
 			},
+			{
+				value: ModelTest.MannWhitney,
+				option: fmtModelTest(ModelTest.MannWhitney),
+			},
 			{
 				value: ModelTest.Static,
 				option: fmtModelTest(ModelTest.Static),
@@ -220,6 +226,8 @@ export const testFragment = (modelTest: ModelTest) => {
 			return "interquartile-range";
 		case ModelTest.DeltaIqr:
 			return "delta-interquartile-range";
+		case ModelTest.MannWhitney:
+			return "mann-whitney-u";
 	}
 };
 
@@ -363,6 +371,7 @@ const FIELDS = {
 	[ModelTest.LogNormal]: cdfConfig(ModelTest.LogNormal),
 	[ModelTest.Iqr]: iqrConfig(ModelTest.Iqr),
 	[ModelTest.DeltaIqr]: iqrConfig(ModelTest.DeltaIqr),
+	[ModelTest.MannWhitney]: cdfConfig(ModelTest.MannWhitney),
 };
 
 const initForm = (fields: object[]) => {
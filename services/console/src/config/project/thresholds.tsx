@@ -273,6 +273,30 @@ const thresholdsConfig = {
 					keys: ["model", "window"],
 					display: Display.RAW,
 				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Confirmation Outliers",
+					keys: ["model", "confirm_outliers"],
+					display: Display.RAW,
+				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Confirmation Reports",
+					keys: ["model", "confirm_reports"],
+					display: Display.RAW,
+				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Resolution Reports",
+					keys: ["model", "resolve_reports"],
+					display: Display.RAW,
+				},
+				{
+					kind: Card.NESTED_FIELD,
+					label: "Window Size (versions)",
+					keys: ["model", "version_window"],
+					display: Display.RAW,
+				},
 			],
 			buttons: [
 				{
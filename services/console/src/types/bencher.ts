@@ -145,8 +145,18 @@ export interface JsonJobConfig {
 	average?: JsonAverage;
 	/** Number of benchmark iterations for the runner to execute */
 	iter?: Iteration;
+	/** Number of warm-up iterations for the runner to execute and discard first */
+	warmup?: Iteration;
+	/** Trim outlier iterations before the results are reported */
+	trim?: JsonIterationTrim;
 	/** Fold operation for combining multiple iteration results */
 	fold?: JsonFold;
+	/** Use bootstrap confidence intervals for the lower and upper values */
+	bootstrap?: boolean;
+	/** Benchmark harness statistic to use for the value, lower value, and upper value */
+	statistic?: JsonStatistic;
+	/** Name parameterized benchmarks with their parameters */
+	params?: boolean;
 	/** Allow benchmark failure without short-circuiting iterations */
 	allow_failure?: boolean;
 	/** Backdate the report start time */
@@ -163,6 +173,39 @@ export interface JsonIterationOutput {
 	stderr?: string;
 	/** File path to contents map */
 	output?: Record<string, string> | undefined;
+	/**
+	 * Built-in measures collected by the runner (e.g. hardware counters),
+	 * merged into the results parsed from this iteration's output
+	 */
+	metrics?: JsonResultsMap;
+	/** Why the runner dropped this iteration from the results, if it did */
+	dropped?: JsonDroppedIteration;
+}
+
+/** Why the runner dropped an iteration. */
+export enum JsonDropReason {
+	/** A discarded warm-up iteration */
+	Warmup = "warmup",
+	/** Trimmed as one of the lowest values of the trim measure */
+	TrimLow = "trim_low",
+	/** Trimmed as one of the highest values of the trim measure */
+	TrimHigh = "trim_high",
+}
+
+/** An iteration the runner dropped from the results. */
+export interface JsonDroppedIteration {
+	/** Why the iteration was dropped */
+	reason: JsonDropReason;
+	/** The trim measure value the iteration was ranked by (trimmed iterations only) */
+	value?: number;
+}
+
+/** Outlier iteration trimming. */
+export interface JsonIterationTrim {
+	/** The measure to rank iterations by */
+	measure: MeasureNameId;
+	/** The number of iterations to drop from each end (lowest and highest) */
+	count: number;
 }
 
 /** Job output stored in blob storage after job completion or failure. */
@@ -215,6 +258,32 @@ export interface JsonBenchmark {
 	archived?: string;
 }
 
+export interface JsonMergeBenchmarks {
+	/**
+	 * The slugs or UUIDs for the benchmarks to merge into the target benchmark.
+	 * Their metrics, boundaries, and alerts are moved to the target benchmark,
+	 * their names become aliases of the target benchmark,
+	 * and then they are deleted.
+	 */
+	benchmarks: BenchmarkResourceId[];
+}
+
+export interface JsonNewBenchmarkAlias {
+	/**
+	 * The alias name for the benchmark.
+	 * Reports that use this name are recorded under the benchmark.
+	 * Maximum length is 1,024 characters.
+	 */
+	name: BenchmarkName;
+}
+
+export interface JsonBenchmarkAlias {
+	uuid: Uuid;
+	benchmark: Uuid;
+	name: BenchmarkName;
+	created: string;
+}
+
 export interface JsonMetric {
 	uuid: Uuid;
 	value: number;
@@ -284,6 +353,7 @@ export enum ModelTest {
 	LogNormal = "log_normal",
 	Iqr = "iqr",
 	DeltaIqr = "delta_iqr",
+	MannWhitney = "mann_whitney",
 }
 
 export type SampleSize = number;
@@ -298,6 +368,10 @@ export interface JsonModel {
 	window?: Window;
 	lower_boundary?: Boundary;
 	upper_boundary?: Boundary;
+	confirm_outliers?: SampleSize;
+	confirm_reports?: SampleSize;
+	resolve_reports?: SampleSize;
+	version_window?: SampleSize;
 	created: string;
 	replaced?: string;
 }
@@ -317,6 +391,8 @@ export interface JsonBoundary {
 	baseline?: number;
 	lower_limit?: number;
 	upper_limit?: number;
+	/** The p-value of the threshold model test, if it is a hypothesis test. */
+	p_value?: number;
 }
 
 export enum BoundaryLimit {
@@ -329,6 +405,8 @@ export enum AlertStatus {
 	Active = "active",
 	/** The alert has been dismissed by a user. */
 	Dismissed = "dismissed",
+	/** The alert has been resolved by later reports falling back within the boundary. */
+	Resolved = "resolved",
 	/** The alert has been silenced by the system. */
 	Silenced = "silenced",
 }
@@ -343,6 +421,8 @@ export interface JsonAlert {
 	boundary: JsonBoundary;
 	limit: BoundaryLimit;
 	status: AlertStatus;
+	/** The report that resolved the alert, if it has been resolved. */
+	resolved_report?: Uuid;
 	created: string;
 	modified: string;
 }
@@ -447,6 +527,8 @@ export type UserResourceId = Uuid | Slug;
 
 export type Uuid = string;
 
+export type WebhookSecret = string;
+
 export interface JsonAccept {
 	invite: Jwt;
 }
@@ -706,6 +788,34 @@ export interface JsonNewProjectKey {
 	ttl?: number;
 }
 
+export enum ProjectRole {
+	/**
+	 * The project viewer role.
+	 * Viewers can view the project, even if it is private.
+	 */
+	Viewer = "viewer",
+	/**
+	 * The project developer role.
+	 * Developers can also create, edit, and delete project resources.
+	 */
+	Developer = "developer",
+	/**
+	 * The project maintainer role.
+	 * Maintainers can also manage the project and its members.
+	 */
+	Maintainer = "maintainer",
+}
+
+export interface JsonNewProjectMember {
+	/**
+	 * The email for the new member.
+	 * The user must already have a Bencher account.
+	 */
+	email: Email;
+	/** The project role for the new member. */
+	role: ProjectRole;
+}
+
 /**
  * Job configuration for a remote runner execution.
  * 
@@ -734,6 +844,10 @@ export interface JsonNewRunJob {
 	file_size?: boolean;
 	/** Number of benchmark iterations for the runner to execute */
 	iter?: Iteration;
+	/** Number of warm-up iterations for the runner to execute and discard first */
+	warmup?: Iteration;
+	/** Trim outlier iterations before the results are reported */
+	trim?: JsonIterationTrim;
 	/** Allow benchmark failure without short-circuiting iterations */
 	allow_failure?: boolean;
 	/** Backdate the report start time */
@@ -823,6 +937,33 @@ export interface JsonNewUserKey {
 	ttl?: number;
 }
 
+export enum WebhookEvent {
+	/** An alert was created by a report. */
+	AlertCreated = "alert.created",
+	/** An alert was dismissed by a user. */
+	AlertDismissed = "alert.dismissed",
+	/** A report was created and its results were processed. */
+	ReportCreated = "report.created",
+}
+
+export interface JsonNewWebhook {
+	/**
+	 * The URL to send webhook payloads to.
+	 * Must be an `https` URL with a host that resolves to public addresses.
+	 */
+	url: Url;
+	/**
+	 * The events that trigger the webhook.
+	 * If not provided, the webhook is triggered by all events.
+	 */
+	events?: WebhookEvent[];
+	/**
+	 * Whether the webhook is enabled.
+	 * If not provided, the webhook is enabled.
+	 */
+	enabled?: boolean;
+}
+
 export interface JsonOAuth {
 	state: Jwt;
 	code: Secret;
@@ -916,6 +1057,95 @@ export interface JsonPerf {
 	results: JsonPerfMetrics[];
 }
 
+export interface JsonPerfChangePoint {
+	/** The first report after the change point. */
+	report: Uuid;
+	iteration: Iteration;
+	start_time: string;
+	end_time: string;
+	/** The branch version where the distribution shifted. */
+	version: JsonVersion;
+	/** The mean of the metrics before the change point. */
+	before: number;
+	/** The mean of the metrics after the change point. */
+	after: number;
+}
+
+export interface JsonPerfChangePointMetrics {
+	branch: JsonBranch;
+	testbed: JsonTestbed;
+	benchmark: JsonBenchmark;
+	measure: JsonMeasure;
+	change_points: JsonPerfChangePoint[];
+}
+
+export interface JsonPerfChangePoints {
+	project: JsonProject;
+	start_time?: string;
+	end_time?: string;
+	results: JsonPerfChangePointMetrics[];
+}
+
+export interface JsonCompareHead {
+	branch: JsonBranch;
+	/** The most recent reports used for any of the comparisons, newest first. */
+	reports: Uuid[];
+}
+
+export interface JsonCompareSample {
+	/**
+	 * The number of reports in the sample.
+	 * The metrics for each report are reduced to their mean across iterations.
+	 */
+	size: number;
+	mean: number;
+	/**
+	 * The sample standard deviation.
+	 * This is not set if there are fewer than two reports.
+	 */
+	std_dev?: number;
+}
+
+export enum CompareVerdict {
+	/** The candidate mean is significantly greater than the baseline mean. */
+	Increase = "increase",
+	/** The candidate mean is significantly less than the baseline mean. */
+	Decrease = "decrease",
+	/** There is no significant difference between the means. */
+	NoChange = "no_change",
+	/** There are too few reports on one or both sides to test for significance. */
+	Inconclusive = "inconclusive",
+}
+
+export interface JsonCompareResult {
+	benchmark: JsonBenchmark;
+	measure: JsonMeasure;
+	baseline: JsonCompareSample;
+	candidate: JsonCompareSample;
+	/**
+	 * The relative change of the candidate mean from the baseline mean.
+	 * This is not set if the baseline mean is zero.
+	 */
+	change?: number;
+	/** The lower bound of the confidence interval for the relative change. */
+	lower_change?: number;
+	/** The upper bound of the confidence interval for the relative change. */
+	upper_change?: number;
+	/** The two-sided p-value for the difference in means, using Welch's t-test. */
+	p_value?: number;
+	verdict: CompareVerdict;
+}
+
+export interface JsonCompare {
+	project: JsonProject;
+	testbed: JsonTestbed;
+	baseline: JsonCompareHead;
+	candidate: JsonCompareHead;
+	/** The confidence level for the change intervals and significance verdicts. */
+	confidence: number;
+	results: JsonCompareResult[];
+}
+
 /**
  * `JsonPerfQuery` is the full, strongly typed version of `JsonPerfQueryParams`.
  * It should always be used to validate `JsonPerfQueryParams`.
@@ -929,6 +1159,7 @@ export interface JsonPerfQuery {
 	measures: Uuid[];
 	start_time?: string;
 	end_time?: string;
+	versions?: SampleSize;
 }
 
 /**
@@ -1033,6 +1264,23 @@ export interface JsonProjectKeyCreated {
 	expiration: string;
 }
 
+export interface JsonProjectMember {
+	/** The member UUID. */
+	uuid: Uuid;
+	/** The member user name. */
+	name: UserName;
+	/** The member slug. */
+	slug: Slug;
+	/** The member email. */
+	email: Email;
+	/** The member project role. */
+	role: ProjectRole;
+	/** The date time the member was created. */
+	created: string;
+	/** The date time the member was last modified. */
+	modified: string;
+}
+
 export interface JsonPubUser {
 	uuid: Uuid;
 	name: UserName;
@@ -1049,6 +1297,7 @@ export enum Adapter {
 	RustGungraun = "rust_gungraun",
 	RustGungraunStdout = "rust_gungraun_stdout",
 	RustGungraunJson = "rust_gungraun_json",
+	RustCriterionJson = "rust_criterion_json",
 	Cpp = "cpp",
 	CppGoogle = "cpp_google",
 	CppCatch2 = "cpp_catch2",
@@ -1118,6 +1367,11 @@ export interface JsonReport {
 	alerts?: JsonReportAlerts;
 	/** The report counts. */
 	counts?: JsonReportCounts;
+	/**
+	 * Whether the report was run on a host that was too noisy to trust its results.
+	 * Noisy reports are excluded from the historical metrics used to detect regressions.
+	 */
+	noisy?: boolean;
 	job?: Uuid;
 	created: string;
 }
@@ -1185,6 +1439,11 @@ export interface JsonUpdateProjectKey {
 	name?: ResourceName;
 }
 
+export interface JsonUpdateProjectMember {
+	/** The new project role for the member. */
+	role?: ProjectRole;
+}
+
 /** Update a runner */
 export interface JsonUpdateRunner {
 	/** The new name for the runner. */
@@ -1241,6 +1500,18 @@ export interface JsonUpdateUserKey {
 	name?: ResourceName;
 }
 
+export interface JsonUpdateWebhook {
+	/**
+	 * The new URL to send webhook payloads to.
+	 * Must be an `https` URL with a host that resolves to public addresses.
+	 */
+	url?: Url;
+	/** The new events that trigger the webhook. */
+	events?: WebhookEvent[];
+	/** Enable or disable the webhook. */
+	enabled?: boolean;
+}
+
 export enum UsageKind {
 	/** Bencher Cloud (Free) */
 	CloudFree = "cloud_free",
@@ -1304,6 +1575,101 @@ export interface JsonUserKeyCreated {
 	expiration: string;
 }
 
+export interface JsonWebhook {
+	uuid: Uuid;
+	project: Uuid;
+	url: Url;
+	events: WebhookEvent[];
+	enabled: boolean;
+	created: string;
+	modified: string;
+}
+
+export interface JsonWebhookCreated {
+	uuid: Uuid;
+	project: Uuid;
+	url: Url;
+	events: WebhookEvent[];
+	enabled: boolean;
+	/**
+	 * The secret used to sign webhook payloads. Only returned once, at creation.
+	 * Each payload is signed with HMAC-SHA256 using this secret,
+	 * and the hex encoded signature is sent in the `X-Bencher-Signature-256` header
+	 * prefixed with `sha256=`.
+	 */
+	secret: WebhookSecret;
+	created: string;
+	modified: string;
+}
+
+export enum WebhookDeliveryStatus {
+	/** The delivery has not succeeded yet, and it will be retried. */
+	Pending = "pending",
+	/** The delivery received a successful (2xx) response. */
+	Succeeded = "succeeded",
+	/** The delivery failed and it will not be retried. */
+	Failed = "failed",
+}
+
+export interface JsonWebhookDelivery {
+	uuid: Uuid;
+	webhook: Uuid;
+	event: WebhookEvent;
+	status: WebhookDeliveryStatus;
+	/** The number of delivery attempts made so far. */
+	attempts: number;
+	/** The HTTP status code of the response to the last attempt, if any. */
+	response_status?: number;
+	/** The error from the last attempt, if it failed. */
+	error?: string;
+	created: string;
+	modified: string;
+}
+
+export interface JsonRetention {
+	project: Uuid;
+	/**
+	 * Reports older than this many days are deleted.
+	 * If not set, reports are never deleted.
+	 */
+	report_days?: number;
+	/**
+	 * The default branch is exempt from `report_days`.
+	 * If the default branch is deleted, `report_days` is not applied until a new one is set.
+	 */
+	default_branch?: Uuid;
+	/**
+	 * Metrics older than this many days are downsampled to a single daily median
+	 * for each branch, testbed, benchmark, and measure.
+	 * If not set, metrics are never downsampled.
+	 */
+	downsample_days?: number;
+	/** The last time the retention policy was changed, if ever. */
+	modified?: string;
+}
+
+export interface JsonUpdateRetention {
+	/**
+	 * Delete reports older than this many days.
+	 * Must be greater than zero.
+	 * If not set, reports are never deleted.
+	 */
+	report_days?: number;
+	/**
+	 * The slug or UUID for the default branch, which is exempt from `report_days`.
+	 * Required if `report_days` is set.
+	 */
+	default_branch?: BranchResourceId;
+	/**
+	 * Downsample metrics older than this many days to a single daily median
+	 * for each branch, testbed, benchmark, and measure.
+	 * The median metric is kept and all others for that day are deleted.
+	 * Must be greater than zero.
+	 * If not set, metrics are never downsampled.
+	 */
+	downsample_days?: number;
+}
+
 export enum OrganizationPermission {
 	View = "view",
 	Create = "create",
@@ -1325,6 +1691,7 @@ export enum PerfQueryKey {
 	Measures = "measures",
 	StartTime = "start_time",
 	EndTime = "end_time",
+	Versions = "versions",
 }
 
 export enum PlotKey {
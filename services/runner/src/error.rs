@@ -8,6 +8,10 @@ pub enum RunnerCliError {
     #[error(transparent)]
     Up(#[from] bencher_runner::up::UpError),
 
+    #[cfg(feature = "plus")]
+    #[error(transparent)]
+    RestoreJournal(#[from] bencher_runner::tuning::RestoreJournalError),
+
     #[cfg(feature = "plus")]
     #[error(transparent)]
     Valid(#[from] bencher_json::ValidError),
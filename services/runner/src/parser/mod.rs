@@ -12,7 +12,7 @@ use camino::Utf8PathBuf;
 use clap::{Parser, Subcommand};
 
 #[cfg(feature = "plus")]
-pub use tuning::CliTuning;
+pub use tuning::{CliTuning, CliTuningSub};
 #[cfg(feature = "plus")]
 pub use up::CliUp;
 
@@ -32,6 +32,10 @@ pub enum CliSub {
     #[cfg(feature = "plus")]
     /// Pull image, create rootfs, and execute in isolated Firecracker microVM.
     Run(CliRun),
+    #[cfg(feature = "plus")]
+    /// Manage host tuning.
+    #[command(subcommand)]
+    Tuning(CliTuningSub),
 }
 
 /// Arguments for the `run` subcommand.
@@ -95,6 +99,11 @@ pub struct CliRun {
     #[arg(long)]
     pub network: bool,
 
+    /// Report hardware performance counters (instructions, cycles, branch misses, cache misses).
+    /// Requires CPU isolation and `perf_event_paranoid <= 0` (Linux-only).
+    #[arg(long)]
+    pub perf_counters: bool,
+
     /// Sandbox mode for benchmark execution.
     /// Use "firecracker" for Firecracker microVM (Linux-only).
     /// Omit for non-sandboxed host execution.
@@ -1,5 +1,5 @@
 use bencher_runner::{PerfEventParanoid, Swappiness, ThpMode, TuningConfig};
-use clap::Args;
+use clap::{Args, Subcommand};
 
 /// Host tuning flags shared by `run` and `up` subcommands.
 ///
@@ -118,6 +118,16 @@ impl TryFrom<CliTuning> for TuningConfig {
     }
 }
 
+#[derive(Subcommand, Debug)]
+pub enum CliTuningSub {
+    /// Restore host settings from a stale tuning journal.
+    ///
+    /// A runner that is killed with host tuning applied leaves its
+    /// original settings in a journal. `runner up` and `runner run`
+    /// replay it automatically; this restores them without starting one.
+    Restore,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,4 +220,16 @@ mod tests {
         assert!(config.disable_soft_watchdog);
         assert!(config.disable_ksm);
     }
+
+    #[test]
+    fn tuning_restore_parses() {
+        use clap::Parser as _;
+
+        let cli =
+            crate::parser::CliRunner::try_parse_from(["runner", "tuning", "restore"]).unwrap();
+        assert!(matches!(
+            cli.sub,
+            crate::parser::CliSub::Tuning(CliTuningSub::Restore)
+        ));
+    }
 }
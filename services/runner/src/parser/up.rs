@@ -51,6 +51,11 @@ pub struct CliUp {
     #[arg(long, env = "BENCHER_DANGER_ALLOW_NO_SANDBOX")]
     pub danger_allow_no_sandbox: bool,
 
+    /// Report hardware performance counters (instructions, cycles, branch misses, cache misses)
+    /// for every job. Requires CPU isolation and `perf_event_paranoid <= 0` (Linux-only).
+    #[arg(long, env = "BENCHER_PERF_COUNTERS")]
+    pub perf_counters: bool,
+
     /// Disable automatic updates from the server.
     #[arg(long, env = "BENCHER_NO_AUTO_UPDATE")]
     pub no_auto_update: bool,
@@ -111,4 +116,10 @@ mod tests {
     fn update_channel_conflicts_with_no_auto_update() {
         parse(&["--update-channel", "canary", "--no-auto-update"]).unwrap_err();
     }
+
+    #[test]
+    fn perf_counters_flag() {
+        assert!(!parse(&[]).unwrap().perf_counters);
+        assert!(parse(&["--perf-counters"]).unwrap().perf_counters);
+    }
 }
@@ -1,6 +1,8 @@
 #[cfg(feature = "plus")]
 mod run;
 #[cfg(feature = "plus")]
+mod tuning;
+#[cfg(feature = "plus")]
 mod up;
 
 #[cfg(feature = "plus")]
@@ -8,6 +10,8 @@ use clap::Parser as _;
 #[cfg(feature = "plus")]
 use run::Run;
 #[cfg(feature = "plus")]
+use tuning::Tuning;
+#[cfg(feature = "plus")]
 use up::Up;
 
 #[cfg(feature = "plus")]
@@ -26,6 +30,7 @@ pub struct Runner {
 enum Sub {
     Up(Up),
     Run(Run),
+    Tuning(Tuning),
 }
 
 #[cfg(feature = "plus")]
@@ -47,6 +52,7 @@ impl TryFrom<CliSub> for Sub {
         Ok(match sub {
             CliSub::Up(up) => Self::Up(up.try_into()?),
             CliSub::Run(run) => Self::Run(run.try_into()?),
+            CliSub::Tuning(tuning) => Self::Tuning(tuning.into()),
         })
     }
 }
@@ -68,6 +74,7 @@ impl Sub {
         match self {
             Self::Up(up) => up.exec(),
             Self::Run(run) => run.exec(),
+            Self::Tuning(tuning) => tuning.exec(),
         }
     }
 }
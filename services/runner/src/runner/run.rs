@@ -48,6 +48,7 @@ impl TryFrom<CliRun> for Run {
                 cmd: task.cmd,
                 env,
                 network: task.network,
+                perf_counters: task.perf_counters,
                 iter: task.iter,
                 allow_failure: task.allow_failure,
                 tuning,
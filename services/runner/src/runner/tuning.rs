@@ -0,0 +1,40 @@
+use bencher_runner::tuning::HostTuningLock;
+
+use crate::error::RunnerCliError;
+use crate::parser::CliTuningSub;
+
+#[derive(Debug)]
+pub enum Tuning {
+    Restore,
+}
+
+impl From<CliTuningSub> for Tuning {
+    fn from(sub: CliTuningSub) -> Self {
+        match sub {
+            CliTuningSub::Restore => Self::Restore,
+        }
+    }
+}
+
+impl Tuning {
+    #[expect(clippy::print_stdout, reason = "runner CLI output")]
+    pub fn exec(self) -> Result<(), RunnerCliError> {
+        match self {
+            Self::Restore => match HostTuningLock::restore_journal()? {
+                Some(restore) if restore.failed == 0 => println!(
+                    "Restored {restored} host setting(s) from the tuning journal of runner process {pid}.",
+                    restored = restore.restored,
+                    pid = restore.pid
+                ),
+                Some(restore) => println!(
+                    "Restored {restored} host setting(s) from the tuning journal of runner process {pid}; {failed} could not be restored.",
+                    restored = restore.restored,
+                    failed = restore.failed,
+                    pid = restore.pid
+                ),
+                None => println!("No stale tuning journal found."),
+            },
+        }
+        Ok(())
+    }
+}